@@ -0,0 +1,135 @@
+//! Providers which distribute provisions between many equivalent providers.
+//!
+//! See [crate] documentation for more.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{
+    cell::Cell,
+    error::Error,
+    fmt::{self, Display},
+};
+
+/// Provider which provides dependencies by reference from the collection
+/// of equivalent providers in rotation, one provider per each provision.
+///
+/// This is useful to distribute work across a pool of injected resources,
+/// such as clients of the same service.
+/// Collection of providers can be an array, a slice
+/// or `Vec` with `alloc` feature enabled, and it should not be empty.
+///
+/// Dependencies are provided by [shared](crate::with::ProvideRefWith)
+/// or [unique](crate::with::ProvideMutWith) reference
+/// with [`RotateWith`](crate::context::balance::RotateWith) context,
+/// which advances the rotation on each provision.
+/// Use [`next_provider`](RoundRobin::next_provider) method
+/// to get the next provider in rotation explicitly.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{balance::RoundRobin, context::balance::Rotate, with::ProvideRefWith};
+///
+/// struct Client(&'static str);
+///
+/// impl AsRef<str> for Client {
+///     fn as_ref(&self) -> &str {
+///         let Self(host) = self;
+///         host
+///     }
+/// }
+///
+/// let provider = RoundRobin::new([Client("first"), Client("second")]).unwrap();
+///
+/// let host: &str = provider.provide_ref_with(Rotate::default());
+/// assert_eq!(host, "first");
+/// let host: &str = provider.provide_ref_with(Rotate::default());
+/// assert_eq!(host, "second");
+/// let host: &str = provider.provide_ref_with(Rotate::default());
+/// assert_eq!(host, "first");
+/// ```
+#[derive(Debug, Clone)]
+pub struct RoundRobin<C>
+where
+    C: ?Sized,
+{
+    next: Cell<usize>,
+    providers: C,
+}
+
+impl<C> RoundRobin<C> {
+    /// Creates new provider from the collection of equivalent providers,
+    /// starting rotation from the first one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NoProviders`] error if the collection of providers is empty.
+    pub fn new<P>(providers: C) -> Result<Self, NoProviders>
+    where
+        C: AsRef<[P]>,
+    {
+        if providers.as_ref().is_empty() {
+            return Err(NoProviders);
+        }
+        let next = Cell::new(0);
+        Ok(Self { next, providers })
+    }
+
+    /// Returns the collection of providers.
+    pub fn into_inner(self) -> C {
+        let Self { providers, .. } = self;
+        providers
+    }
+}
+
+impl<C> RoundRobin<C>
+where
+    C: ?Sized,
+{
+    fn advance(&self, len: usize) -> usize {
+        let Self { next, .. } = self;
+        let index = next.get() % len;
+        next.set((index + 1) % len);
+        index
+    }
+}
+
+/// Implements rotation of providers for the collection which dereferences into the slice.
+macro_rules! impl_round_robin {
+    ($(#[$meta:meta])* [$($param:tt)*] $collection:ty) => {
+        $(#[$meta])*
+        impl<$($param)*> RoundRobin<$collection> {
+            /// Returns shared reference to the next provider in rotation, advancing the rotation.
+            pub fn next_provider(&self) -> &P {
+                let Self { providers, .. } = self;
+                let index = self.advance(providers.len());
+                &providers[index]
+            }
+
+            /// Returns unique reference to the next provider in rotation, advancing the rotation.
+            pub fn next_provider_mut(&mut self) -> &mut P {
+                let index = self.advance(self.providers.len());
+                let Self { providers, .. } = self;
+                &mut providers[index]
+            }
+        }
+    };
+}
+
+impl_round_robin!([P][P]);
+impl_round_robin!([P, const N: usize] [P; N]);
+impl_round_robin!(#[cfg(feature = "alloc")] [P] Vec<P>);
+
+/// The error type returned when [round-robin](RoundRobin) provider
+/// is created from the empty collection of providers.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct NoProviders;
+
+impl Display for NoProviders {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("collection of providers should not be empty")
+    }
+}
+
+impl Error for NoProviders {}
@@ -0,0 +1,96 @@
+//! Dependencies which are gracefully degraded if their provision fails.
+//!
+//! See [crate] documentation for more.
+
+/// Dependency which was either provided in full,
+/// or replaced with its [default](Default) value because provision failed.
+///
+/// Error of the failed provision is kept, so the degraded dependency
+/// can be reported while the system keeps running with partial functionality.
+/// Default value of [`Option<T>`] is [`None`], so optional dependencies
+/// are simply absent when degraded.
+///
+/// Such dependencies are provided with the [`BestEffortWith`](crate::context::best_effort::BestEffortWith)
+/// context, which degrades the dependency to its [default](Default) value
+/// if provision with the inner context fails, keeping the error of the provision.
+/// This allows systems which must keep running with partial functionality
+/// to encode such policy in the wiring while failures are still observable.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{best_effort::Degraded, context::Context, with::ProvideRefWith, TryProvideRef};
+///
+/// struct Cache;
+///
+/// struct Provider {
+///     cache: Option<Cache>,
+/// }
+///
+/// impl<'me> TryProvideRef<'me, Option<&'me Cache>> for Provider {
+///     type Error = &'static str;
+///
+///     fn try_provide_ref(&'me self) -> Result<Option<&'me Cache>, Self::Error> {
+///         let Self { cache } = self;
+///         cache.as_ref().map(Some).ok_or("cache is unavailable")
+///     }
+/// }
+///
+/// let provider = Provider { cache: None };
+///
+/// let cache: Degraded<Option<&Cache>, _> = provider.provide_ref_with(().then_best_effort());
+/// assert!(cache.is_degraded());
+/// assert!(cache.dependency().is_none());
+/// assert_eq!(cache.error(), Some(&"cache is unavailable"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Degraded<T, E> {
+    dependency: T,
+    error: Option<E>,
+}
+
+impl<T, E> Degraded<T, E> {
+    /// Creates new dependency which was provided in full.
+    pub const fn full(dependency: T) -> Self {
+        Self {
+            dependency,
+            error: None,
+        }
+    }
+
+    /// Creates new dependency which was degraded to the fallback value because of the error.
+    pub const fn fallback(dependency: T, error: E) -> Self {
+        Self {
+            dependency,
+            error: Some(error),
+        }
+    }
+
+    /// Checks if dependency was degraded.
+    pub const fn is_degraded(&self) -> bool {
+        self.error.is_some()
+    }
+
+    /// Returns shared reference to the dependency, either provided or degraded.
+    pub const fn dependency(&self) -> &T {
+        &self.dependency
+    }
+
+    /// Returns shared reference to the error of the provision if dependency was degraded.
+    pub const fn error(&self) -> Option<&E> {
+        self.error.as_ref()
+    }
+
+    /// Returns the dependency, either provided or degraded, discarding the error.
+    pub fn into_inner(self) -> T {
+        let Self { dependency, .. } = self;
+        dependency
+    }
+
+    /// Returns the dependency and the error of the provision if dependency was degraded.
+    pub fn into_parts(self) -> (T, Option<E>) {
+        let Self { dependency, error } = self;
+        (dependency, error)
+    }
+}
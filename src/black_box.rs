@@ -0,0 +1,55 @@
+//! Providers which hide provisions from the optimizer for benchmarking.
+//!
+//! See [crate] documentation for more.
+
+/// Provider which passes the inner provider, contexts and provided dependencies
+/// through [`black_box`](core::hint::black_box) function.
+///
+/// Dependencies are provided with the [`BlackBoxDependencyWith`](crate::context::black_box::BlackBoxDependencyWith)
+/// context. Provision is usually zero cost, so the optimizer is free to elide it entirely
+/// when the dependency is not used, or to compute it at compile time.
+/// This provider prevents such optimizations, so benchmarks of compositions
+/// of providers and contexts measure the provision itself.
+///
+/// This provider is available only with `bench` feature enabled,
+/// so production builds are left untouched.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{black_box::BlackBox, context::black_box::BlackBoxDependency, with::ProvideRefWith};
+///
+/// let provider = BlackBox::new(Box::new(42));
+///
+/// let dependency: &i32 = provider.provide_ref_with(BlackBoxDependency::default());
+/// assert_eq!(*dependency, 42);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BlackBox<P> {
+    provider: P,
+}
+
+impl<P> BlackBox<P> {
+    /// Creates new provider from the inner provider.
+    pub const fn new(provider: P) -> Self {
+        Self { provider }
+    }
+
+    /// Returns shared reference to the inner provider.
+    pub fn get_ref(&self) -> &P {
+        let Self { provider } = self;
+        provider
+    }
+
+    /// Returns unique reference to the inner provider.
+    pub fn get_mut(&mut self) -> &mut P {
+        let Self { provider } = self;
+        provider
+    }
+
+    /// Returns the inner provider.
+    pub fn into_inner(self) -> P {
+        let Self { provider } = self;
+        provider
+    }
+}
@@ -0,0 +1,111 @@
+//! Fixed bundles of dependencies declared with the [`bundle!`](crate::bundle!) macro.
+//!
+//! See [crate] documentation for more.
+
+/// Type which is a bundle of dependencies provided by value from provider `P` at once.
+///
+/// Unlike [`ProvideAll`](crate::ProvideAll), which is implemented for tuples
+/// of up to 12 dependencies (or up to 32 with `tuples_32` feature),
+/// this trait is implemented by the [`bundle!`](crate::bundle!) macro
+/// for structs with any number of fields, so only the bundle shapes
+/// actually needed by the project are generated.
+///
+/// See [crate] documentation for more.
+pub trait Bundle<P>: Sized {
+    /// Remaining part of the provider after providing all the dependencies of the bundle.
+    type Remainder;
+
+    /// Provides all the dependencies of the bundle by value in the order of declaration,
+    /// also returning [remaining part](Bundle::Remainder) of the provider.
+    fn provide_bundle(provider: P) -> (Self, Self::Remainder);
+}
+
+/// Declares the bundle of dependencies.
+///
+/// Each dependency is declared as the named field of the bundle struct.
+/// Dependencies are provided by value with the [`Provide`](crate::Provide) trait
+/// in the order of declaration, threading [remaining part](crate::Provide::Remainder)
+/// of the provider into the provision of the next dependency.
+///
+/// The macro generates:
+/// - the bundle struct with all the dependencies as its fields;
+/// - implementation of the [`Bundle`](crate::bundle::Bundle) trait for any provider
+///   which can provide all of them.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{bundle, bundle::Bundle, Provide};
+///
+/// struct Provider {
+///     name: &'static str,
+///     port: u16,
+/// }
+///
+/// impl Provide<&'static str> for Provider {
+///     type Remainder = u16;
+///
+///     fn provide(self) -> (&'static str, Self::Remainder) {
+///         let Self { name, port } = self;
+///         (name, port)
+///     }
+/// }
+///
+/// bundle! {
+///     /// Dependencies of the server.
+///     pub struct Server {
+///         pub name: &'static str,
+///         pub port: u16,
+///     }
+/// }
+///
+/// let provider = Provider { name: "server", port: 8080 };
+/// let (server, ()) = Server::provide_bundle(provider);
+/// assert_eq!((server.name, server.port), ("server", 8080));
+/// ```
+#[macro_export]
+macro_rules! bundle {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident : $ty:ty
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $(
+                $(#[$field_meta])*
+                $field_vis $field: $ty,
+            )*
+        }
+
+        $crate::bundle!(@impl $name [$($field: $ty),*] [] P; $($ty,)*);
+    };
+    (@impl $name:ident $fields:tt [$($bounds:tt)*] $remainder:ty; $ty:ty, $($tail:ty,)*) => {
+        $crate::bundle!(
+            @impl $name $fields
+            [$($bounds)* $remainder: $crate::Provide<$ty>,]
+            <$remainder as $crate::Provide<$ty>>::Remainder;
+            $($tail,)*
+        );
+    };
+    (@impl $name:ident [$($field:ident : $ty:ty),*] [$($bounds:tt)*] $remainder:ty;) => {
+        impl<P> $crate::bundle::Bundle<P> for $name
+        where
+            $($bounds)*
+        {
+            type Remainder = $remainder;
+
+            fn provide_bundle(provider: P) -> (Self, Self::Remainder) {
+                let remainder = provider;
+                $(
+                    let ($field, remainder) = $crate::Provide::<$ty>::provide(remainder);
+                )*
+                (Self { $($field),* }, remainder)
+            }
+        }
+    };
+}
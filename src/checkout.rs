@@ -0,0 +1,163 @@
+//! Providers which lend out instances and take them back.
+//!
+//! This module is available with `alloc` feature enabled.
+//!
+//! See [crate] documentation for more.
+
+use alloc::vec::Vec;
+use core::{
+    error::Error,
+    fmt::{self, Debug, Display},
+};
+
+/// Provider which lends out instances of type `T` and takes them back.
+///
+/// Instances are provided by value with the [`Lend`](crate::context::checkout::Lend)
+/// context, which checks out one of the available instances
+/// and returns the provider itself as the remainder.
+/// Checked out instances are put back with [`return_value`](Checkout::return_value),
+/// which fails with [`NothingCheckedOut`] error if there is nothing to put back,
+/// and provision fails with [`AllCheckedOut`] error if all the instances are checked out.
+/// This makes a minimal object pool built on the remainder of the provider.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{checkout::Checkout, context::checkout::Lend, with::TryProvideWith};
+///
+/// let provider = Checkout::new(vec![String::from("license")]);
+///
+/// let (license, mut provider): (String, _) = provider.try_provide_with(Lend).unwrap();
+/// assert_eq!(provider.checked_out(), 1);
+///
+/// let result: Result<(String, _), _> = provider.try_provide_with(Lend);
+/// let mut provider = result.unwrap_err().into_inner();
+///
+/// provider.return_value(license).unwrap();
+/// assert_eq!((provider.available(), provider.checked_out()), (1, 0));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Checkout<T> {
+    instances: Vec<T>,
+    checked_out: usize,
+}
+
+impl<T> Checkout<T> {
+    /// Creates new provider from the instances to lend out.
+    pub const fn new(instances: Vec<T>) -> Self {
+        Self {
+            instances,
+            checked_out: 0,
+        }
+    }
+
+    /// Returns count of the instances available to be checked out.
+    pub fn available(&self) -> usize {
+        let Self { instances, .. } = self;
+        instances.len()
+    }
+
+    /// Returns count of the instances which are currently checked out.
+    pub const fn checked_out(&self) -> usize {
+        self.checked_out
+    }
+
+    /// Puts checked out instance back into the provider,
+    /// so it can be checked out again.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NothingCheckedOut`] error carrying the value back
+    /// if no instances are currently checked out,
+    /// so the provider never holds more instances than it was created with.
+    pub fn return_value(&mut self, value: T) -> Result<(), NothingCheckedOut<T>> {
+        let Self {
+            instances,
+            checked_out,
+        } = self;
+        let Some(remaining) = checked_out.checked_sub(1) else {
+            return Err(NothingCheckedOut(value));
+        };
+        instances.push(value);
+        *checked_out = remaining;
+        Ok(())
+    }
+
+    /// Returns the instances which are available to be checked out.
+    pub fn into_inner(self) -> Vec<T> {
+        let Self { instances, .. } = self;
+        instances
+    }
+
+    pub(crate) fn check_out(mut self) -> Result<(T, Self), AllCheckedOut<T>> {
+        let Self {
+            instances,
+            checked_out,
+        } = &mut self;
+        let Some(instance) = instances.pop() else {
+            return Err(AllCheckedOut(self));
+        };
+        *checked_out += 1;
+        Ok((instance, self))
+    }
+}
+
+impl<T> FromIterator<T> for Checkout<T> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+/// The error type returned when all the instances of the [checkout provider](Checkout)
+/// are checked out.
+///
+/// This error carries the provider back, so it can be used again
+/// after checked out instances are returned.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AllCheckedOut<T>(Checkout<T>);
+
+impl<T> AllCheckedOut<T> {
+    /// Returns the provider all the instances of which are checked out.
+    pub fn into_inner(self) -> Checkout<T> {
+        let Self(provider) = self;
+        provider
+    }
+}
+
+impl<T> Display for AllCheckedOut<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(provider) = self;
+        match provider.checked_out() {
+            0 => f.write_str("there are no instances to check out"),
+            checked_out => write!(f, "all {checked_out} instances are checked out"),
+        }
+    }
+}
+
+impl<T> Error for AllCheckedOut<T> where T: Debug {}
+
+/// The error type returned when the value is returned into the [checkout provider](Checkout)
+/// which has no instances checked out.
+///
+/// This error carries the returned value back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NothingCheckedOut<T>(T);
+
+impl<T> NothingCheckedOut<T> {
+    /// Returns the value which was returned into the provider.
+    pub fn into_inner(self) -> T {
+        let Self(value) = self;
+        value
+    }
+}
+
+impl<T> Display for NothingCheckedOut<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("there are no checked out instances to return")
+    }
+}
+
+impl<T> Error for NothingCheckedOut<T> where T: Debug {}
@@ -0,0 +1,90 @@
+//! Sources of time which can be provided as dependencies.
+//!
+//! Services which depend on time should receive it through these traits
+//! instead of reading it directly, so it can be replaced in tests.
+//! Deterministic implementations are available in `test_util` module
+//! with `test_util` feature enabled.
+//!
+//! See [crate] documentation for more.
+
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+/// Type of dependency which tells the current time.
+pub trait Clock {
+    /// Returns the current time as duration elapsed since some fixed point
+    /// specific to this clock.
+    fn now(&self) -> Duration;
+}
+
+impl<C> Clock for &C
+where
+    C: Clock + ?Sized,
+{
+    fn now(&self) -> Duration {
+        C::now(self)
+    }
+}
+
+/// [Clock] which tells the time elapsed since its creation,
+/// measured with the monotonic system clock.
+///
+/// This clock is available with `std` feature enabled.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use provide::clock::{Clock, SystemClock};
+///
+/// let clock = SystemClock::new();
+/// let earlier = clock.now();
+/// assert!(clock.now() >= earlier);
+/// # }
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SystemClock {
+    origin: Instant,
+}
+
+#[cfg(feature = "std")]
+impl SystemClock {
+    /// Creates new clock which starts counting time from now.
+    pub fn new() -> Self {
+        Self {
+            origin: Instant::now(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        let Self { origin } = self;
+        origin.elapsed()
+    }
+}
+
+/// Type of dependency which tells whether some period of time has expired.
+pub trait Timer {
+    /// Returns `true` if the timer has expired.
+    fn is_expired(&self) -> bool;
+}
+
+impl<T> Timer for &T
+where
+    T: Timer + ?Sized,
+{
+    fn is_expired(&self) -> bool {
+        T::is_expired(self)
+    }
+}
@@ -0,0 +1,153 @@
+//! Constructor injection: types which are constructed out of dependencies of the provider.
+//!
+//! See [crate] documentation for more.
+
+use core::future::Future;
+
+use crate::ProvideRefAsync;
+
+/// Type which can be constructed asynchronously out of dependencies
+/// provided by shared reference from the provider.
+///
+/// Dependencies are provided one after another in order, awaiting each of them,
+/// so services which need handshakes or I/O during construction
+/// can be assembled with one call.
+///
+/// This trait is implemented for tuples of dependencies,
+/// and for structs declared with the [`async_construct!`](crate::async_construct!) macro.
+///
+/// See [crate] documentation for more.
+pub trait AsyncConstruct<'me, P>: Sized
+where
+    P: ?Sized,
+{
+    /// Constructs the value asynchronously out of dependencies of the provider.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use provide::construct::AsyncConstruct;
+    ///
+    /// let provider = String::from("hello");
+    /// let (text, bytes) = block_on(<(&str, &[u8])>::construct_async(&provider));
+    /// assert_eq!(text, "hello");
+    /// assert_eq!(bytes, b"hello");
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    fn construct_async(provider: &'me P) -> impl Future<Output = Self>;
+}
+
+impl<'me, P> AsyncConstruct<'me, P> for ()
+where
+    P: ?Sized,
+{
+    async fn construct_async(_: &'me P) -> Self {}
+}
+
+macro_rules! impl_construct {
+    () => {};
+    ($head:ident $(, $tail:ident)* $(,)?) => {
+        impl<'me, P, $head, $($tail),*> AsyncConstruct<'me, P> for ($head, $($tail,)*)
+        where
+            P: ProvideRefAsync<'me, $head> $(+ ProvideRefAsync<'me, $tail>)* + ?Sized,
+        {
+            #[allow(non_snake_case)]
+            async fn construct_async(provider: &'me P) -> Self {
+                let $head = ProvideRefAsync::<'me, $head>::provide_ref_async(provider).await;
+                $(let $tail = ProvideRefAsync::<'me, $tail>::provide_ref_async(provider).await;)*
+                ($head, $($tail,)*)
+            }
+        }
+
+        impl_construct!($($tail),*);
+    };
+}
+
+for_tuples!(impl_construct);
+
+/// Declares the struct which is constructed asynchronously out of dependencies of the provider.
+///
+/// The macro generates the struct itself and implementation of the
+/// [`AsyncConstruct`](crate::construct::AsyncConstruct) trait for it,
+/// which awaits provision of each field by shared reference in order of declaration.
+///
+/// # Examples
+///
+/// ```
+/// use futures::executor::block_on;
+/// use provide::{async_construct, construct::AsyncConstruct, ProvideRefAsync};
+///
+/// struct Config {
+///     url: &'static str,
+/// }
+///
+/// struct Connection {
+///     url: &'static str,
+/// }
+///
+/// struct Provider {
+///     config: Config,
+/// }
+///
+/// impl<'me> ProvideRefAsync<'me, Connection> for Provider {
+///     async fn provide_ref_async(&'me self) -> Connection {
+///         // some handshake with the remote server...
+///         let Self { config } = self;
+///         Connection { url: config.url }
+///     }
+/// }
+///
+/// impl<'me> ProvideRefAsync<'me, u16> for Provider {
+///     async fn provide_ref_async(&'me self) -> u16 {
+///         5432
+///     }
+/// }
+///
+/// async_construct! {
+///     /// Service which needs connection to construct.
+///     pub struct Service {
+///         connection: Connection,
+///         port: u16,
+///     }
+/// }
+///
+/// let provider = Provider {
+///     config: Config { url: "localhost" },
+/// };
+/// let service = block_on(Service::construct_async(&provider));
+/// assert_eq!(service.connection.url, "localhost");
+/// assert_eq!(service.port, 5432);
+/// ```
+#[macro_export]
+macro_rules! async_construct {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident : $ty:ty
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $(
+                $(#[$field_meta])*
+                $field_vis $field: $ty,
+            )*
+        }
+
+        impl<'me, P> $crate::construct::AsyncConstruct<'me, P> for $name
+        where
+            P: $($crate::ProvideRefAsync<'me, $ty> +)* ?Sized,
+        {
+            async fn construct_async(provider: &'me P) -> Self {
+                $(
+                    let $field = <P as $crate::ProvideRefAsync<'me, $ty>>::provide_ref_async(provider).await;
+                )*
+                Self { $($field),* }
+            }
+        }
+    };
+}
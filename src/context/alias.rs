@@ -0,0 +1,295 @@
+//! Context which provides inner dependency of the [aliased](Alias) newtype.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
+
+use super::{depth::Depth, Context, Empty};
+use crate::{
+    name::type_name_of,
+    named::Named,
+    with::{
+        ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideMutWith, TryProvideRefWith,
+        TryProvideWith,
+    },
+};
+
+/// Newtype which transparently satisfies requests for its inner type.
+///
+/// Newtypes are the usual way to register several dependencies of the same type
+/// in one provider. This trait declares how to unwrap such a newtype,
+/// so its inner value could be provided with [`AliasDependencyWith`] context
+/// wherever the newtype itself is registered.
+///
+/// For newtypes with a single field, this trait can be implemented with [`alias!`](crate::alias) macro.
+pub trait Alias {
+    /// Inner type of this newtype.
+    type Target;
+
+    /// Returns the inner value of this newtype.
+    fn into_target(self) -> Self::Target;
+
+    /// Returns shared reference to the inner value of this newtype.
+    fn target(&self) -> &Self::Target;
+
+    /// Returns unique reference to the inner value of this newtype.
+    fn target_mut(&mut self) -> &mut Self::Target;
+}
+
+/// Implements [`Alias`](crate::context::alias::Alias) trait
+/// for one or more newtypes with a single unnamed field.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{alias, context::alias::Alias};
+///
+/// struct Port(u16);
+/// struct Host(&'static str);
+///
+/// alias!(Port => u16, Host => &'static str);
+///
+/// assert_eq!(Port(8080).into_target(), 8080);
+/// assert_eq!(*Host("localhost").target(), "localhost");
+/// ```
+#[macro_export]
+macro_rules! alias {
+    ($($newtype:ty => $target:ty),+ $(,)?) => {
+        $(
+            impl $crate::context::alias::Alias for $newtype {
+                type Target = $target;
+
+                fn into_target(self) -> Self::Target {
+                    self.0
+                }
+
+                fn target(&self) -> &Self::Target {
+                    &self.0
+                }
+
+                fn target_mut(&mut self) -> &mut Self::Target {
+                    &mut self.0
+                }
+            }
+        )+
+    };
+}
+
+/// Context which provides inner dependency of the [aliased](Alias) newtype `N`
+/// provided with the inner context.
+///
+/// Inner value is provided by value out of the newtype provided by value,
+/// and by reference out of the reference to the newtype.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     alias,
+///     context::alias::AliasDependency,
+///     with::{ProvideRefWith, ProvideWith},
+/// };
+///
+/// struct Port(u16);
+///
+/// alias!(Port => u16);
+///
+/// struct Config {
+///     port: Port,
+/// }
+///
+/// impl AsRef<Port> for Config {
+///     fn as_ref(&self) -> &Port {
+///         let Self { port } = self;
+///         port
+///     }
+/// }
+///
+/// let config = Config { port: Port(8080) };
+/// let port: &u16 = config.provide_ref_with(AliasDependency::<Port>::default());
+/// assert_eq!(*port, 8080);
+///
+/// let (port, _): (u16, _) = Port(5432).provide_with(AliasDependency::<Port>::default());
+/// assert_eq!(port, 5432);
+/// ```
+pub struct AliasDependencyWith<N, C> {
+    context: C,
+    marker: PhantomData<fn() -> N>,
+}
+
+/// Context which provides inner dependency of the [aliased](Alias) newtype `N`
+/// provided with [empty context](Empty).
+pub type AliasDependency<N> = AliasDependencyWith<N, Empty>;
+
+/// Context which provides dependency of type `T` [named](Named) `NAME`
+/// provided with the inner context.
+pub type NamedDependencyWith<T, const NAME: u128, C> = AliasDependencyWith<Named<T, NAME>, C>;
+
+/// Context which provides dependency of type `T` [named](Named) `NAME`
+/// provided with [empty context](Empty).
+pub type NamedDependency<T, const NAME: u128> = AliasDependency<Named<T, NAME>>;
+
+impl<N, C> AliasDependencyWith<N, C> {
+    /// Creates new context from the context to provide aliased newtype with.
+    pub const fn new(context: C) -> Self {
+        Self {
+            context,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self { context, .. } = self;
+        context
+    }
+}
+
+impl<N, C> Debug for AliasDependencyWith<N, C>
+where
+    C: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { context, .. } = self;
+        f.debug_struct("AliasDependencyWith")
+            .field("type", &type_name_of::<N>())
+            .field("context", context)
+            .finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<N, C> defmt::Format for AliasDependencyWith<N, C>
+where
+    C: defmt::Format,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        let Self { context, .. } = self;
+        defmt::write!(
+            f,
+            "AliasDependencyWith {{ type: {}, context: {} }}",
+            type_name_of::<N>(),
+            context,
+        );
+    }
+}
+
+impl<N, C> Default for AliasDependencyWith<N, C>
+where
+    C: Default,
+{
+    fn default() -> Self {
+        Self::new(C::default())
+    }
+}
+
+impl<N, C> Clone for AliasDependencyWith<N, C>
+where
+    C: Clone,
+{
+    fn clone(&self) -> Self {
+        let Self { context, .. } = self;
+        Self::new(context.clone())
+    }
+}
+
+impl<N, C> Copy for AliasDependencyWith<N, C> where C: Copy {}
+
+impl<N, C> Context for AliasDependencyWith<N, C> {}
+
+impl<N, C> Depth for AliasDependencyWith<N, C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<N, U, C> ProvideWith<N::Target, AliasDependencyWith<N, C>> for U
+where
+    N: Alias,
+    U: ProvideWith<N, C>,
+{
+    type Remainder = U::Remainder;
+
+    fn provide_with(self, context: AliasDependencyWith<N, C>) -> (N::Target, Self::Remainder) {
+        let AliasDependencyWith { context, .. } = context;
+        let (dependency, remainder) = self.provide_with(context);
+        (dependency.into_target(), remainder)
+    }
+}
+
+impl<N, U, C> TryProvideWith<N::Target, AliasDependencyWith<N, C>> for U
+where
+    N: Alias,
+    U: TryProvideWith<N, C>,
+{
+    type Remainder = U::Remainder;
+
+    type Error = U::Error;
+
+    fn try_provide_with(
+        self,
+        context: AliasDependencyWith<N, C>,
+    ) -> Result<(N::Target, Self::Remainder), Self::Error> {
+        let AliasDependencyWith { context, .. } = context;
+        let (dependency, remainder) = self.try_provide_with(context)?;
+        Ok((dependency.into_target(), remainder))
+    }
+}
+
+impl<'me, 'a, N, U, C> ProvideRefWith<'me, &'a N::Target, AliasDependencyWith<N, C>> for U
+where
+    N: Alias + 'a,
+    U: ProvideRefWith<'me, &'a N, C> + ?Sized,
+{
+    fn provide_ref_with(&'me self, context: AliasDependencyWith<N, C>) -> &'a N::Target {
+        let AliasDependencyWith { context, .. } = context;
+        self.provide_ref_with(context).target()
+    }
+}
+
+impl<'me, 'a, N, U, C> TryProvideRefWith<'me, &'a N::Target, AliasDependencyWith<N, C>> for U
+where
+    N: Alias + 'a,
+    U: TryProvideRefWith<'me, &'a N, C> + ?Sized,
+{
+    type Error = U::Error;
+
+    fn try_provide_ref_with(
+        &'me self,
+        context: AliasDependencyWith<N, C>,
+    ) -> Result<&'a N::Target, Self::Error> {
+        let AliasDependencyWith { context, .. } = context;
+        self.try_provide_ref_with(context).map(N::target)
+    }
+}
+
+impl<'me, 'a, N, U, C> ProvideMutWith<'me, &'a mut N::Target, AliasDependencyWith<N, C>> for U
+where
+    N: Alias + 'a,
+    U: ProvideMutWith<'me, &'a mut N, C> + ?Sized,
+{
+    fn provide_mut_with(&'me mut self, context: AliasDependencyWith<N, C>) -> &'a mut N::Target {
+        let AliasDependencyWith { context, .. } = context;
+        self.provide_mut_with(context).target_mut()
+    }
+}
+
+impl<'me, 'a, N, U, C> TryProvideMutWith<'me, &'a mut N::Target, AliasDependencyWith<N, C>> for U
+where
+    N: Alias + 'a,
+    U: TryProvideMutWith<'me, &'a mut N, C> + ?Sized,
+{
+    type Error = U::Error;
+
+    fn try_provide_mut_with(
+        &'me mut self,
+        context: AliasDependencyWith<N, C>,
+    ) -> Result<&'a mut N::Target, Self::Error> {
+        let AliasDependencyWith { context, .. } = context;
+        self.try_provide_mut_with(context).map(N::target_mut)
+    }
+}
@@ -0,0 +1,127 @@
+//! Context which provides dependencies from [round-robin](RoundRobin) providers.
+//!
+//! See [crate] documentation for more.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{
+    balance::RoundRobin,
+    context::{depth::Depth, Context, Empty},
+    with::{ProvideMutWith, ProvideRefWith, TryProvideMutWith, TryProvideRefWith},
+};
+
+/// Context which provides dependency by reference from the next provider
+/// of the [round-robin](RoundRobin) provider, advancing its rotation.
+///
+/// Dependency itself is provided from the next provider with the inner context.
+///
+/// This context is implemented only for provision by reference,
+/// because there is no single remainder of the rotation to provide dependency by value.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     balance::RoundRobin,
+///     context::{balance::Rotate, Context},
+///     with::{ProvideMutWith, ProvideRefWith},
+/// };
+///
+/// let mut provider = RoundRobin::new([vec![1], vec![2]]).unwrap();
+///
+/// let first: &mut Vec<i32> = provider.provide_mut_with(Rotate::default());
+/// first.push(3);
+///
+/// let second: &[i32] = provider.provide_ref_with(().then_rotate());
+/// let first: &[i32] = provider.provide_ref_with(().then_rotate());
+/// assert_eq!((first, second), (&[1, 3][..], &[2][..]));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RotateWith<C>(C);
+
+/// Context which provides dependency by reference from the next provider
+/// of the [round-robin](RoundRobin) provider, advancing its rotation.
+///
+/// Dependency itself is provided from the next provider with [empty context](Empty).
+pub type Rotate = RotateWith<Empty>;
+
+impl<C> RotateWith<C> {
+    /// Creates new context from the context to provide dependency from the next provider with.
+    pub const fn new(context: C) -> Self {
+        Self(context)
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self(context) = self;
+        context
+    }
+}
+
+impl<C> Context for RotateWith<C> {}
+
+impl<C> Depth for RotateWith<C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+/// Implements rotation context for the collection of providers.
+macro_rules! impl_rotate {
+    ($(#[$meta:meta])* [$($param:tt)*] $collection:ty) => {
+        $(#[$meta])*
+        impl<'me, T, C, $($param)*> ProvideRefWith<'me, T, RotateWith<C>> for RoundRobin<$collection>
+        where
+            P: ProvideRefWith<'me, T, C>,
+        {
+            fn provide_ref_with(&'me self, context: RotateWith<C>) -> T {
+                let RotateWith(context) = context;
+                self.next_provider().provide_ref_with(context)
+            }
+        }
+
+        $(#[$meta])*
+        impl<'me, T, C, $($param)*> TryProvideRefWith<'me, T, RotateWith<C>> for RoundRobin<$collection>
+        where
+            P: TryProvideRefWith<'me, T, C>,
+        {
+            type Error = P::Error;
+
+            fn try_provide_ref_with(&'me self, context: RotateWith<C>) -> Result<T, Self::Error> {
+                let RotateWith(context) = context;
+                self.next_provider().try_provide_ref_with(context)
+            }
+        }
+
+        $(#[$meta])*
+        impl<'me, T, C, $($param)*> ProvideMutWith<'me, T, RotateWith<C>> for RoundRobin<$collection>
+        where
+            P: ProvideMutWith<'me, T, C>,
+        {
+            fn provide_mut_with(&'me mut self, context: RotateWith<C>) -> T {
+                let RotateWith(context) = context;
+                self.next_provider_mut().provide_mut_with(context)
+            }
+        }
+
+        $(#[$meta])*
+        impl<'me, T, C, $($param)*> TryProvideMutWith<'me, T, RotateWith<C>> for RoundRobin<$collection>
+        where
+            P: TryProvideMutWith<'me, T, C>,
+        {
+            type Error = P::Error;
+
+            fn try_provide_mut_with(&'me mut self, context: RotateWith<C>) -> Result<T, Self::Error> {
+                let RotateWith(context) = context;
+                self.next_provider_mut().try_provide_mut_with(context)
+            }
+        }
+    };
+}
+
+impl_rotate!([P][P]);
+impl_rotate!([P, const N: usize] [P; N]);
+impl_rotate!(#[cfg(feature = "alloc")] [P] Vec<P>);
@@ -0,0 +1,132 @@
+//! Context which provides dependency gracefully degrading on failure.
+//!
+//! See [crate] documentation for more.
+
+use super::{depth::Depth, Context, Empty};
+use crate::{
+    best_effort::Degraded,
+    with::{ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideMutWith, TryProvideRefWith},
+};
+
+/// Context which provides [degraded](Degraded) dependency,
+/// providing it fallibly with the inner context
+/// and degrading it to its [default](Default) value if provision fails.
+///
+/// Unlike [`OrDefaultDependencyWith`](super::default::OrDefaultDependencyWith),
+/// error of the failed provision is not discarded.
+/// Provision with this context never fails, so it is implemented
+/// for infallible provider traits on top of fallible provision with the inner context.
+///
+/// Provision by value consumes the provider even if it fails,
+/// so the [remaining part](crate::with::ProvideWith::Remainder) of the provider is [`None`] in such case.
+///
+/// See [`Degraded`] for examples.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BestEffortWith<C>(C);
+
+/// Context which provides [degraded](Degraded) dependency,
+/// providing it fallibly with [empty context](Empty).
+pub type BestEffort = BestEffortWith<Empty>;
+
+impl<C> BestEffortWith<C> {
+    /// Creates new context from the context to provide dependency with.
+    pub const fn new(context: C) -> Self {
+        Self(context)
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self(context) = self;
+        context
+    }
+}
+
+impl<C> Context for BestEffortWith<C> {}
+
+impl<C> Depth for BestEffortWith<C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<T, E, U, C> ProvideWith<Degraded<T, E>, BestEffortWith<C>> for U
+where
+    C: degrade::Degrade<T, U, Error = E>,
+{
+    type Remainder = Option<C::Remainder>;
+
+    fn provide_with(self, context: BestEffortWith<C>) -> (Degraded<T, E>, Self::Remainder) {
+        let BestEffortWith(context) = context;
+        context.provide_degraded(self)
+    }
+}
+
+impl<'me, T, E, U, C> ProvideRefWith<'me, Degraded<T, E>, BestEffortWith<C>> for U
+where
+    T: Default,
+    U: TryProvideRefWith<'me, T, C, Error = E> + ?Sized,
+{
+    fn provide_ref_with(&'me self, context: BestEffortWith<C>) -> Degraded<T, E> {
+        let BestEffortWith(context) = context;
+        match self.try_provide_ref_with(context) {
+            Ok(dependency) => Degraded::full(dependency),
+            Err(error) => Degraded::fallback(T::default(), error),
+        }
+    }
+}
+
+impl<'me, T, E, U, C> ProvideMutWith<'me, Degraded<T, E>, BestEffortWith<C>> for U
+where
+    T: Default,
+    U: TryProvideMutWith<'me, T, C, Error = E> + ?Sized,
+{
+    fn provide_mut_with(&'me mut self, context: BestEffortWith<C>) -> Degraded<T, E> {
+        let BestEffortWith(context) = context;
+        match self.try_provide_mut_with(context) {
+            Ok(dependency) => Degraded::full(dependency),
+            Err(error) => Degraded::fallback(T::default(), error),
+        }
+    }
+}
+
+/// Provision by value which degrades on failure, implemented by the inner context.
+///
+/// Error of the provision is checked only when the inner context is known,
+/// so the compiler does not recurse into it while looking for the context
+/// of some unrelated provision.
+mod degrade {
+    use crate::{best_effort::Degraded, with::TryProvideWith};
+
+    pub trait Degrade<T, U>: Sized {
+        type Remainder;
+
+        type Error;
+
+        fn provide_degraded(
+            self,
+            provider: U,
+        ) -> (Degraded<T, Self::Error>, Option<Self::Remainder>);
+    }
+
+    impl<T, U, C> Degrade<T, U> for C
+    where
+        T: Default,
+        U: TryProvideWith<T, C>,
+    {
+        type Remainder = U::Remainder;
+
+        type Error = U::Error;
+
+        fn provide_degraded(
+            self,
+            provider: U,
+        ) -> (Degraded<T, Self::Error>, Option<Self::Remainder>) {
+            match provider.try_provide_with(self) {
+                Ok((dependency, remainder)) => (Degraded::full(dependency), Some(remainder)),
+                Err(error) => (Degraded::fallback(T::default(), error), None),
+            }
+        }
+    }
+}
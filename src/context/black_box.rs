@@ -0,0 +1,136 @@
+//! Context which provides dependency from the [black box](BlackBox) provider.
+//!
+//! See [crate] documentation for more.
+
+use core::hint::black_box;
+
+use crate::{
+    black_box::BlackBox,
+    context::{depth::Depth, Context, Empty},
+    with::{
+        ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideMutWith, TryProvideRefWith,
+        TryProvideWith,
+    },
+};
+
+/// Context which provides dependency from the [black box](BlackBox) provider,
+/// providing it from the inner provider with the inner context.
+///
+/// Inner provider, inner context and provided dependency (or error)
+/// are passed through [`black_box`] function, so the optimizer cannot elide the provision.
+///
+/// See [`BlackBox`] for examples.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BlackBoxDependencyWith<C>(C);
+
+/// Context which provides dependency from the [black box](BlackBox) provider,
+/// providing it from the inner provider with [empty context](Empty).
+pub type BlackBoxDependency = BlackBoxDependencyWith<Empty>;
+
+impl<C> BlackBoxDependencyWith<C> {
+    /// Creates new context from the context to provide dependency from the inner provider with.
+    pub const fn new(context: C) -> Self {
+        Self(context)
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self(context) = self;
+        context
+    }
+}
+
+impl<C> Context for BlackBoxDependencyWith<C> {}
+
+impl<C> Depth for BlackBoxDependencyWith<C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<T, P, C> ProvideWith<T, BlackBoxDependencyWith<C>> for BlackBox<P>
+where
+    P: ProvideWith<T, C>,
+{
+    type Remainder = P::Remainder;
+
+    fn provide_with(self, context: BlackBoxDependencyWith<C>) -> (T, Self::Remainder) {
+        let provider = black_box(self.into_inner());
+        let context = black_box(context.into_inner());
+        black_box(provider.provide_with(context))
+    }
+}
+
+impl<T, P, C> TryProvideWith<T, BlackBoxDependencyWith<C>> for BlackBox<P>
+where
+    P: TryProvideWith<T, C>,
+{
+    type Remainder = P::Remainder;
+
+    type Error = P::Error;
+
+    fn try_provide_with(
+        self,
+        context: BlackBoxDependencyWith<C>,
+    ) -> Result<(T, Self::Remainder), Self::Error> {
+        let provider = black_box(self.into_inner());
+        let context = black_box(context.into_inner());
+        black_box(provider.try_provide_with(context))
+    }
+}
+
+impl<'me, T, P, C> ProvideRefWith<'me, T, BlackBoxDependencyWith<C>> for BlackBox<P>
+where
+    P: ProvideRefWith<'me, T, C>,
+{
+    fn provide_ref_with(&'me self, context: BlackBoxDependencyWith<C>) -> T {
+        let provider = black_box(self.get_ref());
+        let context = black_box(context.into_inner());
+        black_box(provider.provide_ref_with(context))
+    }
+}
+
+impl<'me, T, P, C> TryProvideRefWith<'me, T, BlackBoxDependencyWith<C>> for BlackBox<P>
+where
+    P: TryProvideRefWith<'me, T, C>,
+{
+    type Error = P::Error;
+
+    fn try_provide_ref_with(
+        &'me self,
+        context: BlackBoxDependencyWith<C>,
+    ) -> Result<T, Self::Error> {
+        let provider = black_box(self.get_ref());
+        let context = black_box(context.into_inner());
+        black_box(provider.try_provide_ref_with(context))
+    }
+}
+
+impl<'me, T, P, C> ProvideMutWith<'me, T, BlackBoxDependencyWith<C>> for BlackBox<P>
+where
+    P: ProvideMutWith<'me, T, C>,
+{
+    fn provide_mut_with(&'me mut self, context: BlackBoxDependencyWith<C>) -> T {
+        let provider = black_box(self.get_mut());
+        let context = black_box(context.into_inner());
+        black_box(provider.provide_mut_with(context))
+    }
+}
+
+impl<'me, T, P, C> TryProvideMutWith<'me, T, BlackBoxDependencyWith<C>> for BlackBox<P>
+where
+    P: TryProvideMutWith<'me, T, C>,
+{
+    type Error = P::Error;
+
+    fn try_provide_mut_with(
+        &'me mut self,
+        context: BlackBoxDependencyWith<C>,
+    ) -> Result<T, Self::Error> {
+        let provider = black_box(self.get_mut());
+        let context = black_box(context.into_inner());
+        black_box(provider.try_provide_mut_with(context))
+    }
+}
@@ -0,0 +1,246 @@
+//! Context which provides reference dependency by borrowing another provided dependency.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    borrow::{Borrow, BorrowMut},
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
+
+use super::{depth::Depth, Context, Empty};
+use crate::{
+    name::type_name_of,
+    with::{
+        ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideMutWith, TryProvideRefWith,
+        TryProvideWith,
+    },
+};
+
+/// Context which provides reference dependency
+/// by [borrowing](Borrow) the reference to dependency of type `D` provided with the inner context.
+///
+/// This allows to provide, for example, `&str` from the provider of `&String`
+/// or `&Path` from the provider of `&PathBuf`, even if the provider itself
+/// does not implement [`AsRef`] for the borrowed type.
+/// Unique reference dependency is provided by [mutable borrowing](BorrowMut).
+///
+/// # Examples
+///
+/// ```
+/// use std::path::{Path, PathBuf};
+///
+/// use provide::{context::borrow::BorrowDependency, with::ProvideRefWith};
+///
+/// struct Config {
+///     root: PathBuf,
+/// }
+///
+/// impl AsRef<PathBuf> for Config {
+///     fn as_ref(&self) -> &PathBuf {
+///         let Self { root } = self;
+///         root
+///     }
+/// }
+///
+/// let provider = Config { root: PathBuf::from("/srv") };
+///
+/// let root: &Path = provider.provide_ref_with(BorrowDependency::<PathBuf>::default());
+/// assert_eq!(root, Path::new("/srv"));
+/// ```
+pub struct BorrowDependencyWith<D, C>
+where
+    D: ?Sized,
+{
+    context: C,
+    marker: PhantomData<D>,
+}
+
+/// Context which provides reference dependency
+/// by [borrowing](Borrow) the reference to dependency of type `D` provided with [empty context](Empty).
+pub type BorrowDependency<D> = BorrowDependencyWith<D, Empty>;
+
+impl<D, C> BorrowDependencyWith<D, C>
+where
+    D: ?Sized,
+{
+    /// Creates new context from the context to provide borrowed dependency with.
+    pub const fn new(context: C) -> Self {
+        Self {
+            context,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self { context, .. } = self;
+        context
+    }
+}
+
+impl<D, C> Debug for BorrowDependencyWith<D, C>
+where
+    D: ?Sized,
+    C: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { context, .. } = self;
+        f.debug_struct("BorrowDependencyWith")
+            .field("type", &type_name_of::<D>())
+            .field("context", context)
+            .finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<D, C> defmt::Format for BorrowDependencyWith<D, C>
+where
+    D: ?Sized,
+    C: defmt::Format,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        let Self { context, .. } = self;
+        defmt::write!(
+            f,
+            "BorrowDependencyWith {{ type: {}, context: {} }}",
+            type_name_of::<D>(),
+            context,
+        );
+    }
+}
+
+impl<D, C> Default for BorrowDependencyWith<D, C>
+where
+    D: ?Sized,
+    C: Default,
+{
+    fn default() -> Self {
+        Self::new(C::default())
+    }
+}
+
+impl<D, C> Clone for BorrowDependencyWith<D, C>
+where
+    D: ?Sized,
+    C: Clone,
+{
+    fn clone(&self) -> Self {
+        let Self { context, .. } = self;
+        Self::new(context.clone())
+    }
+}
+
+impl<D, C> Copy for BorrowDependencyWith<D, C>
+where
+    D: ?Sized,
+    C: Copy,
+{
+}
+
+impl<D, C> Context for BorrowDependencyWith<D, C> where D: ?Sized {}
+
+impl<D, C> Depth for BorrowDependencyWith<D, C>
+where
+    D: ?Sized,
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<'a, T, D, U, C> ProvideWith<&'a T, BorrowDependencyWith<D, C>> for U
+where
+    T: ?Sized,
+    D: Borrow<T> + ?Sized + 'a,
+    U: ProvideWith<&'a D, C>,
+{
+    type Remainder = U::Remainder;
+
+    fn provide_with(self, context: BorrowDependencyWith<D, C>) -> (&'a T, Self::Remainder) {
+        let BorrowDependencyWith { context, .. } = context;
+        let (dependency, remainder) = self.provide_with(context);
+        (dependency.borrow(), remainder)
+    }
+}
+
+impl<'a, T, D, U, C> TryProvideWith<&'a T, BorrowDependencyWith<D, C>> for U
+where
+    T: ?Sized,
+    D: Borrow<T> + ?Sized + 'a,
+    U: TryProvideWith<&'a D, C>,
+{
+    type Remainder = U::Remainder;
+
+    type Error = U::Error;
+
+    fn try_provide_with(
+        self,
+        context: BorrowDependencyWith<D, C>,
+    ) -> Result<(&'a T, Self::Remainder), Self::Error> {
+        let BorrowDependencyWith { context, .. } = context;
+        let (dependency, remainder) = self.try_provide_with(context)?;
+        Ok((dependency.borrow(), remainder))
+    }
+}
+
+impl<'me, 'a, T, D, U, C> ProvideRefWith<'me, &'a T, BorrowDependencyWith<D, C>> for U
+where
+    T: ?Sized,
+    D: Borrow<T> + ?Sized + 'a,
+    U: ProvideRefWith<'me, &'a D, C> + ?Sized,
+{
+    fn provide_ref_with(&'me self, context: BorrowDependencyWith<D, C>) -> &'a T {
+        let BorrowDependencyWith { context, .. } = context;
+        let dependency = self.provide_ref_with(context);
+        dependency.borrow()
+    }
+}
+
+impl<'me, 'a, T, D, U, C> TryProvideRefWith<'me, &'a T, BorrowDependencyWith<D, C>> for U
+where
+    T: ?Sized,
+    D: Borrow<T> + ?Sized + 'a,
+    U: TryProvideRefWith<'me, &'a D, C> + ?Sized,
+{
+    type Error = U::Error;
+
+    fn try_provide_ref_with(
+        &'me self,
+        context: BorrowDependencyWith<D, C>,
+    ) -> Result<&'a T, Self::Error> {
+        let BorrowDependencyWith { context, .. } = context;
+        let dependency = self.try_provide_ref_with(context)?;
+        Ok(dependency.borrow())
+    }
+}
+
+impl<'me, 'a, T, D, U, C> ProvideMutWith<'me, &'a mut T, BorrowDependencyWith<D, C>> for U
+where
+    T: ?Sized,
+    D: BorrowMut<T> + ?Sized + 'a,
+    U: ProvideMutWith<'me, &'a mut D, C> + ?Sized,
+{
+    fn provide_mut_with(&'me mut self, context: BorrowDependencyWith<D, C>) -> &'a mut T {
+        let BorrowDependencyWith { context, .. } = context;
+        let dependency = self.provide_mut_with(context);
+        dependency.borrow_mut()
+    }
+}
+
+impl<'me, 'a, T, D, U, C> TryProvideMutWith<'me, &'a mut T, BorrowDependencyWith<D, C>> for U
+where
+    T: ?Sized,
+    D: BorrowMut<T> + ?Sized + 'a,
+    U: TryProvideMutWith<'me, &'a mut D, C> + ?Sized,
+{
+    type Error = U::Error;
+
+    fn try_provide_mut_with(
+        &'me mut self,
+        context: BorrowDependencyWith<D, C>,
+    ) -> Result<&'a mut T, Self::Error> {
+        let BorrowDependencyWith { context, .. } = context;
+        let dependency = self.try_provide_mut_with(context)?;
+        Ok(dependency.borrow_mut())
+    }
+}
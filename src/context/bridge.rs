@@ -0,0 +1,274 @@
+//! Contexts which bridge borrowed and owned forms of strings and byte buffers.
+//!
+//! See [crate] documentation for more.
+
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+
+use super::{depth::Depth, Context, Empty};
+use crate::with::{
+    ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideMutWith, TryProvideRefWith,
+    TryProvideWith,
+};
+
+/// Implements provider traits for the context which converts the dependency
+/// provided with the inner context from one form into another.
+///
+/// Each kind of provider traits is implemented separately, because borrowed form
+/// can differ for shared and unique references or be tied to the provider itself.
+/// Provided expression must evaluate to the converted dependency.
+/// Lifetimes used by the forms should be listed in brackets.
+macro_rules! impl_bridge {
+    (new $context:ident) => {
+        impl<C> $context<C> {
+            /// Creates new context from the context to provide dependency with.
+            pub const fn new(context: C) -> Self {
+                Self(context)
+            }
+
+            /// Returns the inner context.
+            pub fn into_inner(self) -> C {
+                let Self(context) = self;
+                context
+            }
+        }
+
+        impl<C> Context for $context<C> {}
+
+        impl<C> Depth for $context<C>
+        where
+            C: Depth,
+        {
+            const DEPTH: usize = C::DEPTH + 1;
+        }
+    };
+    (value $context:ident, [$($lt:lifetime),*] $from:ty => $to:ty, |$arg:ident| $convert:expr) => {
+        impl<$($lt,)* U, C> ProvideWith<$to, $context<C>> for U
+        where
+            U: ProvideWith<$from, C>,
+        {
+            type Remainder = U::Remainder;
+
+            fn provide_with(self, context: $context<C>) -> ($to, Self::Remainder) {
+                let $context(context) = context;
+                let ($arg, remainder) = self.provide_with(context);
+                ($convert, remainder)
+            }
+        }
+
+        impl<$($lt,)* U, C> TryProvideWith<$to, $context<C>> for U
+        where
+            U: TryProvideWith<$from, C>,
+        {
+            type Remainder = U::Remainder;
+
+            type Error = U::Error;
+
+            fn try_provide_with(self, context: $context<C>) -> Result<($to, Self::Remainder), Self::Error> {
+                let $context(context) = context;
+                let ($arg, remainder) = self.try_provide_with(context)?;
+                Ok(($convert, remainder))
+            }
+        }
+    };
+    (ref $context:ident, [$($lt:lifetime),*] $from:ty => $to:ty, |$arg:ident| $convert:expr) => {
+        impl<'me, $($lt,)* U, C> ProvideRefWith<'me, $to, $context<C>> for U
+        where
+            U: ProvideRefWith<'me, $from, C> + ?Sized,
+        {
+            fn provide_ref_with(&'me self, context: $context<C>) -> $to {
+                let $context(context) = context;
+                let $arg = self.provide_ref_with(context);
+                $convert
+            }
+        }
+
+        impl<'me, $($lt,)* U, C> TryProvideRefWith<'me, $to, $context<C>> for U
+        where
+            U: TryProvideRefWith<'me, $from, C> + ?Sized,
+        {
+            type Error = U::Error;
+
+            fn try_provide_ref_with(&'me self, context: $context<C>) -> Result<$to, Self::Error> {
+                let $context(context) = context;
+                let $arg = self.try_provide_ref_with(context)?;
+                Ok($convert)
+            }
+        }
+    };
+    (mut $context:ident, [$($lt:lifetime),*] $from:ty => $to:ty, |$arg:ident| $convert:expr) => {
+        impl<'me, $($lt,)* U, C> ProvideMutWith<'me, $to, $context<C>> for U
+        where
+            U: ProvideMutWith<'me, $from, C> + ?Sized,
+        {
+            fn provide_mut_with(&'me mut self, context: $context<C>) -> $to {
+                let $context(context) = context;
+                let $arg = self.provide_mut_with(context);
+                $convert
+            }
+        }
+
+        impl<'me, $($lt,)* U, C> TryProvideMutWith<'me, $to, $context<C>> for U
+        where
+            U: TryProvideMutWith<'me, $from, C> + ?Sized,
+        {
+            type Error = U::Error;
+
+            fn try_provide_mut_with(&'me mut self, context: $context<C>) -> Result<$to, Self::Error> {
+                let $context(context) = context;
+                let $arg = self.try_provide_mut_with(context)?;
+                Ok($convert)
+            }
+        }
+    };
+}
+
+/// Context which provides [`String`] dependency
+/// by copying the string slice provided with the inner context.
+///
+/// String slice is borrowed from the provider, so dependency can be provided
+/// only by shared or unique reference. In the latter case, unique reference is copied.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{context::bridge::OwnStr, with::ProvideRefWith};
+///
+/// struct Config {
+///     url: Box<str>,
+/// }
+///
+/// impl AsRef<str> for Config {
+///     fn as_ref(&self) -> &str {
+///         let Self { url } = self;
+///         url
+///     }
+/// }
+///
+/// let provider = Config { url: Box::from("postgres://localhost") };
+///
+/// let url: String = provider.provide_ref_with(OwnStr::default());
+/// assert_eq!(url, "postgres://localhost");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct OwnStrWith<C>(C);
+
+/// Context which provides [`String`] dependency
+/// by copying the string slice provided with [empty context](Empty).
+pub type OwnStr = OwnStrWith<Empty>;
+
+impl_bridge!(new OwnStrWith);
+impl_bridge!(ref OwnStrWith, [] &'me str => String, |dependency| dependency.to_owned());
+impl_bridge!(mut OwnStrWith, [] &'me mut str => String, |dependency| dependency.to_owned());
+
+/// Context which provides string slice dependency
+/// by borrowing the [`String`] provided by reference with the inner context.
+///
+/// Unique reference to the [`String`] is borrowed as unique reference to the string slice.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{context::bridge::BorrowStr, with::ProvideRefWith};
+///
+/// struct Config {
+///     url: String,
+/// }
+///
+/// impl AsRef<String> for Config {
+///     fn as_ref(&self) -> &String {
+///         let Self { url } = self;
+///         url
+///     }
+/// }
+///
+/// let provider = Config { url: String::from("postgres://localhost") };
+///
+/// let url: &str = provider.provide_ref_with(BorrowStr::default());
+/// assert_eq!(url, "postgres://localhost");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BorrowStrWith<C>(C);
+
+/// Context which provides string slice dependency
+/// by borrowing the [`String`] provided by reference with [empty context](Empty).
+pub type BorrowStr = BorrowStrWith<Empty>;
+
+impl_bridge!(new BorrowStrWith);
+impl_bridge!(value BorrowStrWith, ['a] &'a String => &'a str, |dependency| dependency.as_str());
+impl_bridge!(ref BorrowStrWith, ['a] &'a String => &'a str, |dependency| dependency.as_str());
+impl_bridge!(mut BorrowStrWith, ['a] &'a mut String => &'a mut str, |dependency| {
+    dependency.as_mut_str()
+});
+
+/// Context which provides byte buffer dependency
+/// by copying the byte slice provided with the inner context.
+///
+/// Byte slice is borrowed from the provider, so dependency can be provided
+/// only by shared or unique reference. In the latter case, unique reference is copied.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{context::bridge::OwnBytes, with::ProvideRefWith};
+///
+/// let provider: Box<[u8]> = Box::new(*b"secret");
+///
+/// let key: Vec<u8> = provider.provide_ref_with(OwnBytes::default());
+/// assert_eq!(key, b"secret");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct OwnBytesWith<C>(C);
+
+/// Context which provides byte buffer dependency
+/// by copying the byte slice provided with [empty context](Empty).
+pub type OwnBytes = OwnBytesWith<Empty>;
+
+impl_bridge!(new OwnBytesWith);
+impl_bridge!(ref OwnBytesWith, [] &'me [u8] => Vec<u8>, |dependency| dependency.to_owned());
+impl_bridge!(mut OwnBytesWith, [] &'me mut [u8] => Vec<u8>, |dependency| dependency.to_owned());
+
+/// Context which provides byte slice dependency
+/// by borrowing the byte buffer provided by reference with the inner context.
+///
+/// Unique reference to the byte buffer is borrowed as unique reference to the byte slice.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{context::bridge::BorrowBytes, with::ProvideRefWith};
+///
+/// struct Keys {
+///     secret: Vec<u8>,
+/// }
+///
+/// impl AsRef<Vec<u8>> for Keys {
+///     fn as_ref(&self) -> &Vec<u8> {
+///         let Self { secret } = self;
+///         secret
+///     }
+/// }
+///
+/// let provider = Keys { secret: b"secret".to_vec() };
+///
+/// let secret: &[u8] = provider.provide_ref_with(BorrowBytes::default());
+/// assert_eq!(secret, b"secret");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BorrowBytesWith<C>(C);
+
+/// Context which provides byte slice dependency
+/// by borrowing the byte buffer provided by reference with [empty context](Empty).
+pub type BorrowBytes = BorrowBytesWith<Empty>;
+
+impl_bridge!(new BorrowBytesWith);
+impl_bridge!(value BorrowBytesWith, ['a] &'a Vec<u8> => &'a [u8], |dependency| {
+    dependency.as_slice()
+});
+impl_bridge!(ref BorrowBytesWith, ['a] &'a Vec<u8> => &'a [u8], |dependency| dependency.as_slice());
+impl_bridge!(mut BorrowBytesWith, ['a] &'a mut Vec<u8> => &'a mut [u8], |dependency| {
+    dependency.as_mut_slice()
+});
@@ -0,0 +1,140 @@
+//! Context which provides dependency by value from the reference to the provider.
+//!
+//! See [crate] documentation for more.
+
+use super::{depth::Depth, Context, Empty};
+use crate::with::{
+    ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideMutWith, TryProvideRefWith,
+    TryProvideWith,
+};
+
+/// Context which provides dependency by value from the reference to the provider,
+/// providing it by reference from the provider itself with the inner context.
+///
+/// This allows to pass borrowed providers wherever providers by value are expected.
+/// Shared reference to the provider is returned back as the remainder, so it could be used again,
+/// while unique reference to the provider is consumed by the provision,
+/// because the provided dependency may still borrow the provider.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::{by_ref::ByRef, Context},
+///     with::{ProvideWith, TryProvideWith},
+/// };
+///
+/// struct Provider {
+///     name: String,
+/// }
+///
+/// impl AsRef<str> for Provider {
+///     fn as_ref(&self) -> &str {
+///         let Self { name } = self;
+///         name
+///     }
+/// }
+///
+/// impl AsMut<str> for Provider {
+///     fn as_mut(&mut self) -> &mut str {
+///         let Self { name } = self;
+///         name
+///     }
+/// }
+///
+/// let mut provider = Provider { name: String::from("hello") };
+///
+/// let (name, remainder): (&str, _) = (&provider).provide_with(ByRef::default());
+/// assert_eq!(name, "hello");
+/// let result: Result<(&str, _), _> = remainder.try_provide_with(().then_by_ref());
+/// assert_eq!(result.map(|(name, _)| name), Ok("hello"));
+///
+/// let (name, ()): (&mut str, _) = (&mut provider).provide_with(ByRef::default());
+/// name.make_ascii_uppercase();
+/// assert_eq!(provider.name, "HELLO");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ByRefWith<C>(C);
+
+/// Context which provides dependency by value from the reference to the provider,
+/// providing it by reference from the provider itself with [empty context](Empty).
+pub type ByRef = ByRefWith<Empty>;
+
+impl<C> ByRefWith<C> {
+    /// Creates new context from the context to provide dependency by reference with.
+    pub const fn new(context: C) -> Self {
+        Self(context)
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self(context) = self;
+        context
+    }
+}
+
+impl<C> Context for ByRefWith<C> {}
+
+impl<C> Depth for ByRefWith<C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<'a, T, P, C> ProvideWith<T, ByRefWith<C>> for &'a P
+where
+    P: ProvideRefWith<'a, T, C> + ?Sized,
+{
+    type Remainder = Self;
+
+    fn provide_with(self, context: ByRefWith<C>) -> (T, Self::Remainder) {
+        let ByRefWith(context) = context;
+        let dependency = self.provide_ref_with(context);
+        (dependency, self)
+    }
+}
+
+impl<'a, T, P, C> TryProvideWith<T, ByRefWith<C>> for &'a P
+where
+    P: TryProvideRefWith<'a, T, C> + ?Sized,
+{
+    type Remainder = Self;
+
+    type Error = P::Error;
+
+    fn try_provide_with(self, context: ByRefWith<C>) -> Result<(T, Self::Remainder), Self::Error> {
+        let ByRefWith(context) = context;
+        let dependency = self.try_provide_ref_with(context)?;
+        Ok((dependency, self))
+    }
+}
+
+impl<'a, T, P, C> ProvideWith<T, ByRefWith<C>> for &'a mut P
+where
+    P: ProvideMutWith<'a, T, C> + ?Sized,
+{
+    type Remainder = ();
+
+    fn provide_with(self, context: ByRefWith<C>) -> (T, Self::Remainder) {
+        let ByRefWith(context) = context;
+        let dependency = self.provide_mut_with(context);
+        (dependency, ())
+    }
+}
+
+impl<'a, T, P, C> TryProvideWith<T, ByRefWith<C>> for &'a mut P
+where
+    P: TryProvideMutWith<'a, T, C> + ?Sized,
+{
+    type Remainder = ();
+
+    type Error = P::Error;
+
+    fn try_provide_with(self, context: ByRefWith<C>) -> Result<(T, Self::Remainder), Self::Error> {
+        let ByRefWith(context) = context;
+        let dependency = self.try_provide_mut_with(context)?;
+        Ok((dependency, ()))
+    }
+}
@@ -0,0 +1,198 @@
+//! Context which caches the first provided dependency.
+//!
+//! See [crate] documentation for more.
+
+use core::cell::OnceCell;
+
+use super::{depth::Depth, Context, Empty};
+use crate::with::{ProvideMutWith, ProvideRefWith, TryProvideMutWith, TryProvideRefWith};
+
+/// Context which caches the dependency provided with the inner context
+/// when it is requested for the first time, and then provides its clones.
+///
+/// Contexts are consumed on each provision, so this context is used by unique reference,
+/// which allows the cache to persist across provisions.
+/// This way expensive dependencies are constructed only once,
+/// even if they are requested from the provider in a loop.
+///
+/// Note that the cache is bound to the context, not to the provider,
+/// so the same context should not be used with different providers.
+/// For the provider which caches its dependency, see [`Memoized`](crate::memoize::Memoized).
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+///
+/// use provide::{
+///     context::cache::CachedDependency,
+///     with::ProvideRefWith,
+///     ProvideRef,
+/// };
+///
+/// #[derive(Clone)]
+/// struct Config(String);
+///
+/// #[derive(Default)]
+/// struct Provider {
+///     loads: Cell<u32>,
+/// }
+///
+/// impl<'me> ProvideRef<'me, Config> for Provider {
+///     fn provide_ref(&'me self) -> Config {
+///         let Self { loads } = self;
+///         loads.set(loads.get() + 1);
+///         Config(String::from("expensive"))
+///     }
+/// }
+///
+/// let provider = Provider::default();
+/// let mut context = CachedDependency::default();
+///
+/// for _ in 0..3 {
+///     let Config(config) = provider.provide_ref_with(&mut context);
+///     assert_eq!(config, "expensive");
+/// }
+/// assert_eq!(provider.loads.get(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedDependencyWith<T, C> {
+    cache: OnceCell<T>,
+    context: C,
+}
+
+/// Context which caches the dependency provided with [empty context](Empty)
+/// when it is requested for the first time, and then provides its clones.
+pub type CachedDependency<T> = CachedDependencyWith<T, Empty>;
+
+impl<T, C> CachedDependencyWith<T, C> {
+    /// Creates new context with empty cache from the context to provide dependency with.
+    pub const fn new(context: C) -> Self {
+        Self {
+            cache: OnceCell::new(),
+            context,
+        }
+    }
+
+    /// Returns shared reference to the cached dependency, if any.
+    pub fn cached(&self) -> Option<&T> {
+        let Self { cache, .. } = self;
+        cache.get()
+    }
+
+    /// Resets the cache of this context, returning previously cached dependency, if any,
+    /// so it will be provided with the inner context again on the next provision.
+    pub fn reset(&mut self) -> Option<T> {
+        let Self { cache, .. } = self;
+        cache.take()
+    }
+
+    /// Returns the cached dependency, if any, and the inner context.
+    pub fn into_inner(self) -> (Option<T>, C) {
+        let Self { cache, context } = self;
+        (cache.into_inner(), context)
+    }
+}
+
+impl<T, C> Default for CachedDependencyWith<T, C>
+where
+    C: Default,
+{
+    fn default() -> Self {
+        Self::new(C::default())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<T, C> defmt::Format for CachedDependencyWith<T, C>
+where
+    T: defmt::Format,
+    C: defmt::Format,
+{
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        let Self { cache, context } = self;
+        defmt::write!(
+            fmt,
+            "CachedDependencyWith {{ cache: {}, context: {} }}",
+            cache.get(),
+            context,
+        );
+    }
+}
+
+impl<T, C> Context for CachedDependencyWith<T, C> {}
+
+impl<T, C> Depth for CachedDependencyWith<T, C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<'me, T, U, C> ProvideRefWith<'me, T, &mut CachedDependencyWith<T, C>> for U
+where
+    U: ProvideRefWith<'me, T, C> + ?Sized,
+    T: Clone,
+    C: Clone,
+{
+    fn provide_ref_with(&'me self, context: &mut CachedDependencyWith<T, C>) -> T {
+        let CachedDependencyWith { cache, context } = context;
+        let dependency = cache.get_or_init(|| self.provide_ref_with(context.clone()));
+        dependency.clone()
+    }
+}
+
+impl<'me, T, U, C> TryProvideRefWith<'me, T, &mut CachedDependencyWith<T, C>> for U
+where
+    U: TryProvideRefWith<'me, T, C> + ?Sized,
+    T: Clone,
+    C: Clone,
+{
+    type Error = U::Error;
+
+    fn try_provide_ref_with(
+        &'me self,
+        context: &mut CachedDependencyWith<T, C>,
+    ) -> Result<T, Self::Error> {
+        let CachedDependencyWith { cache, context } = context;
+        if let Some(dependency) = cache.get() {
+            return Ok(dependency.clone());
+        }
+        let dependency = self.try_provide_ref_with(context.clone())?;
+        Ok(cache.get_or_init(|| dependency).clone())
+    }
+}
+
+impl<'me, T, U, C> ProvideMutWith<'me, T, &mut CachedDependencyWith<T, C>> for U
+where
+    U: ProvideMutWith<'me, T, C> + ?Sized,
+    T: Clone,
+    C: Clone,
+{
+    fn provide_mut_with(&'me mut self, context: &mut CachedDependencyWith<T, C>) -> T {
+        let CachedDependencyWith { cache, context } = context;
+        let dependency = cache.get_or_init(|| self.provide_mut_with(context.clone()));
+        dependency.clone()
+    }
+}
+
+impl<'me, T, U, C> TryProvideMutWith<'me, T, &mut CachedDependencyWith<T, C>> for U
+where
+    U: TryProvideMutWith<'me, T, C> + ?Sized,
+    T: Clone,
+    C: Clone,
+{
+    type Error = U::Error;
+
+    fn try_provide_mut_with(
+        &'me mut self,
+        context: &mut CachedDependencyWith<T, C>,
+    ) -> Result<T, Self::Error> {
+        let CachedDependencyWith { cache, context } = context;
+        if let Some(dependency) = cache.get() {
+            return Ok(dependency.clone());
+        }
+        let dependency = self.try_provide_mut_with(context.clone())?;
+        Ok(cache.get_or_init(|| dependency).clone())
+    }
+}
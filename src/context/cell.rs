@@ -0,0 +1,192 @@
+//! Contexts which provide dependency out of [`Cell`] dependency.
+//!
+//! See [crate] documentation for more.
+
+use core::cell::Cell;
+
+use crate::{
+    context::{depth::Depth, Context, Empty},
+    with::{ProvideRefWith, TryProvideRefWith},
+};
+
+/// Context which provides previous value of the [`Cell`] dependency
+/// while setting new value carried by this context.
+///
+/// Cell dependency itself is provided by shared reference with the inner context.
+///
+/// # Examples
+///
+/// ```
+/// use core::cell::Cell;
+///
+/// use provide::{context::cell::ReplaceCell, with::ProvideRefWith};
+///
+/// struct Provider {
+///     foo: Cell<i32>,
+/// }
+///
+/// impl AsRef<Cell<i32>> for Provider {
+///     fn as_ref(&self) -> &Cell<i32> {
+///         let Self { foo } = self;
+///         foo
+///     }
+/// }
+///
+/// let provider = Provider { foo: Cell::new(1) };
+///
+/// let dependency: i32 = provider.provide_ref_with(ReplaceCell::new(2, ()));
+/// assert_eq!(dependency, 1);
+///
+/// let dependency: i32 = provider.provide_ref_with(ReplaceCell::new(3, ()));
+/// assert_eq!(dependency, 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ReplaceCellWith<T, C> {
+    value: T,
+    context: C,
+}
+
+/// Context which provides previous value of the [`Cell`] dependency
+/// while setting new value carried by this context.
+///
+/// Cell dependency itself is provided by shared reference with [empty context](Empty).
+pub type ReplaceCell<T> = ReplaceCellWith<T, Empty>;
+
+impl<T, C> ReplaceCellWith<T, C> {
+    /// Creates new context from the value to be set
+    /// and the context to provide [`Cell`] dependency with.
+    pub const fn new(value: T, context: C) -> Self {
+        Self { value, context }
+    }
+
+    /// Returns the value to be set and the inner context.
+    pub fn into_inner(self) -> (T, C) {
+        let Self { value, context } = self;
+        (value, context)
+    }
+}
+
+impl<T, C> Context for ReplaceCellWith<T, C> {}
+
+impl<T, C> Depth for ReplaceCellWith<T, C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<'me, T, U, C> ProvideRefWith<'me, T, ReplaceCellWith<T, C>> for U
+where
+    T: 'me,
+    U: ProvideRefWith<'me, &'me Cell<T>, C> + ?Sized,
+{
+    fn provide_ref_with(&'me self, context: ReplaceCellWith<T, C>) -> T {
+        let ReplaceCellWith { value, context } = context;
+        let cell = self.provide_ref_with(context);
+        cell.replace(value)
+    }
+}
+
+impl<'me, T, U, C> TryProvideRefWith<'me, T, ReplaceCellWith<T, C>> for U
+where
+    T: 'me,
+    U: TryProvideRefWith<'me, &'me Cell<T>, C> + ?Sized,
+{
+    type Error = U::Error;
+
+    fn try_provide_ref_with(&'me self, context: ReplaceCellWith<T, C>) -> Result<T, Self::Error> {
+        let ReplaceCellWith { value, context } = context;
+        let cell = self.try_provide_ref_with(context)?;
+        Ok(cell.replace(value))
+    }
+}
+
+/// Context which provides value of the [`Cell`] dependency
+/// while leaving [default](Default) value in its place.
+///
+/// Cell dependency itself is provided by shared reference with the inner context.
+///
+/// # Examples
+///
+/// ```
+/// use core::cell::Cell;
+///
+/// use provide::{context::cell::TakeCell, with::ProvideRefWith};
+///
+/// struct Provider {
+///     foo: Cell<i32>,
+/// }
+///
+/// impl AsRef<Cell<i32>> for Provider {
+///     fn as_ref(&self) -> &Cell<i32> {
+///         let Self { foo } = self;
+///         foo
+///     }
+/// }
+///
+/// let provider = Provider { foo: Cell::new(1) };
+///
+/// let dependency: i32 = provider.provide_ref_with(TakeCell::default());
+/// assert_eq!(dependency, 1);
+///
+/// let dependency: i32 = provider.provide_ref_with(TakeCell::default());
+/// assert_eq!(dependency, 0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TakeCellWith<C>(C);
+
+/// Context which provides value of the [`Cell`] dependency
+/// while leaving [default](Default) value in its place.
+///
+/// Cell dependency itself is provided by shared reference with [empty context](Empty).
+pub type TakeCell = TakeCellWith<Empty>;
+
+impl<C> TakeCellWith<C> {
+    /// Creates new context from the context to provide [`Cell`] dependency with.
+    pub const fn new(context: C) -> Self {
+        Self(context)
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self(context) = self;
+        context
+    }
+}
+
+impl<C> Context for TakeCellWith<C> {}
+
+impl<C> Depth for TakeCellWith<C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<'me, T, U, C> ProvideRefWith<'me, T, TakeCellWith<C>> for U
+where
+    T: Default + 'me,
+    U: ProvideRefWith<'me, &'me Cell<T>, C> + ?Sized,
+{
+    fn provide_ref_with(&'me self, context: TakeCellWith<C>) -> T {
+        let TakeCellWith(context) = context;
+        let cell = self.provide_ref_with(context);
+        cell.take()
+    }
+}
+
+impl<'me, T, U, C> TryProvideRefWith<'me, T, TakeCellWith<C>> for U
+where
+    T: Default + 'me,
+    U: TryProvideRefWith<'me, &'me Cell<T>, C> + ?Sized,
+{
+    type Error = U::Error;
+
+    fn try_provide_ref_with(&'me self, context: TakeCellWith<C>) -> Result<T, Self::Error> {
+        let TakeCellWith(context) = context;
+        let cell = self.try_provide_ref_with(context)?;
+        Ok(cell.take())
+    }
+}
@@ -0,0 +1,28 @@
+//! Implementations for channel types from [`futures`](https://docs.rs/futures) crate.
+
+use core::convert::Infallible;
+
+use ::futures_channel::oneshot::{Canceled, Receiver};
+
+use crate::with::TryProvideAsyncWith;
+
+use super::ReceiveWith;
+
+impl<T, U, C> TryProvideAsyncWith<T, ReceiveWith<C>> for U
+where
+    U: TryProvideAsyncWith<Receiver<T>, C, Error = Infallible>,
+{
+    type Remainder = U::Remainder;
+
+    type Error = Canceled;
+
+    async fn try_provide_async_with(
+        self,
+        context: ReceiveWith<C>,
+    ) -> Result<(T, Self::Remainder), Self::Error> {
+        let ReceiveWith(context) = context;
+        let Ok((receiver, remainder)) = self.try_provide_async_with(context).await;
+        let dependency = receiver.await?;
+        Ok((dependency, remainder))
+    }
+}
@@ -0,0 +1,161 @@
+//! Contexts which provide dependency by receiving it from the channel dependency.
+//!
+//! Implementations for oneshot channel from [`futures`](https://docs.rs/futures) crate
+//! are available with `futures` feature enabled,
+//! and for watch channel from [`tokio`](https://docs.rs/tokio) crate —
+//! with `tokio` feature enabled.
+//!
+//! Channels are awaited through the asynchronous traits, such as
+//! [`TryProvideAsyncWith`](crate::with::TryProvideAsyncWith),
+//! so dependencies produced elsewhere in the program can be awaited
+//! in the same way as any other dependency.
+//!
+//! See [crate] documentation for more.
+
+use super::{depth::Depth, Context, Empty};
+
+#[cfg(feature = "futures")]
+mod futures;
+#[cfg(feature = "tokio")]
+mod tokio;
+
+/// Context which provides dependency by value
+/// by awaiting the value sent through the oneshot receiver dependency.
+///
+/// Oneshot receiver dependency itself is provided by value with the inner context.
+/// Provision fails if the sender was dropped without sending the value.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "futures")] {
+/// use futures::{channel::oneshot, executor::block_on};
+/// use provide::{context::channel::Receive, with::TryProvideAsyncWith};
+///
+/// let (sender, receiver) = oneshot::channel();
+/// sender.send(1).unwrap();
+///
+/// let result: Result<(i32, _), _> =
+///     block_on(receiver.try_provide_async_with(Receive::default()));
+/// assert_eq!(result, Ok((1, ())));
+///
+/// let (sender, receiver) = oneshot::channel::<i32>();
+/// drop(sender);
+///
+/// let result: Result<(i32, _), _> =
+///     block_on(receiver.try_provide_async_with(Receive::default()));
+/// assert!(result.is_err());
+/// # }
+/// ```
+#[cfg(feature = "futures")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ReceiveWith<C>(C);
+
+/// Context which provides dependency by value
+/// by awaiting the value sent through the oneshot receiver dependency.
+///
+/// Oneshot receiver dependency itself is provided by value with [empty context](Empty).
+#[cfg(feature = "futures")]
+pub type Receive = ReceiveWith<Empty>;
+
+/// Context which provides reference to the latest value
+/// of the watch receiver dependency without waiting for it to change.
+///
+/// Watch receiver dependency itself is provided by shared reference with the inner context.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "tokio")] {
+/// use futures::executor::block_on;
+/// use provide::{context::channel::Latest, with::ProvideRefAsyncWith};
+/// use tokio::sync::watch::{self, Ref};
+///
+/// let (sender, receiver) = watch::channel(1);
+/// let provider = Box::new(receiver);
+/// sender.send(2).unwrap();
+///
+/// let latest: Ref<i32> = block_on(provider.provide_ref_async_with(Latest::default()));
+/// assert_eq!(*latest, 2);
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LatestWith<C>(C);
+
+/// Context which provides reference to the latest value
+/// of the watch receiver dependency without waiting for it to change.
+///
+/// Watch receiver dependency itself is provided by shared reference with [empty context](Empty).
+#[cfg(feature = "tokio")]
+pub type Latest = LatestWith<Empty>;
+
+/// Context which provides reference to the latest value
+/// of the watch receiver dependency after waiting for it to change,
+/// marking it as seen.
+///
+/// Watch receiver dependency itself is provided by unique reference with the inner context,
+/// but through the fallible traits. Provision fails if the sender was dropped.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "tokio")] {
+/// use futures::executor::block_on;
+/// use provide::{context::channel::Changed, with::TryProvideMutAsyncWith};
+/// use tokio::sync::watch::{self, Ref};
+///
+/// let (sender, receiver) = watch::channel(1);
+/// let mut provider = Box::new(receiver);
+/// sender.send(2).unwrap();
+///
+/// let changed: Result<Ref<i32>, _> =
+///     block_on(provider.try_provide_mut_async_with(Changed::default()));
+/// assert_eq!(changed.as_deref().ok(), Some(&2));
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ChangedWith<C>(C);
+
+/// Context which provides reference to the latest value
+/// of the watch receiver dependency after waiting for it to change,
+/// marking it as seen.
+///
+/// Watch receiver dependency itself is provided by unique reference with [empty context](Empty).
+#[cfg(feature = "tokio")]
+pub type Changed = ChangedWith<Empty>;
+
+macro_rules! impl_context {
+    ($($name:ident),* $(,)?) => {$(
+        impl<C> $name<C> {
+            /// Creates new context from the context to provide channel dependency with.
+            pub const fn new(context: C) -> Self {
+                Self(context)
+            }
+
+            /// Returns the inner context.
+            pub fn into_inner(self) -> C {
+                let Self(context) = self;
+                context
+            }
+        }
+
+        impl<C> Context for $name<C> {}
+
+        impl<C> Depth for $name<C>
+        where
+            C: Depth,
+        {
+            const DEPTH: usize = C::DEPTH + 1;
+        }
+    )*};
+}
+
+#[cfg(feature = "futures")]
+impl_context!(ReceiveWith);
+#[cfg(feature = "tokio")]
+impl_context!(LatestWith, ChangedWith);
@@ -0,0 +1,56 @@
+//! Implementations for channel types from [`tokio`](::tokio) crate.
+
+use core::convert::Infallible;
+
+use ::tokio::sync::watch::{self, Ref};
+
+use crate::with::{ProvideRefAsyncWith, TryProvideMutAsyncWith, TryProvideRefAsyncWith};
+
+use super::{ChangedWith, LatestWith};
+
+impl<'me, T, U, C> ProvideRefAsyncWith<'me, Ref<'me, T>, LatestWith<C>> for U
+where
+    T: 'me,
+    U: ProvideRefAsyncWith<'me, &'me watch::Receiver<T>, C> + ?Sized,
+{
+    async fn provide_ref_async_with(&'me self, context: LatestWith<C>) -> Ref<'me, T> {
+        let LatestWith(context) = context;
+        let receiver = self.provide_ref_async_with(context).await;
+        receiver.borrow()
+    }
+}
+
+impl<'me, T, U, C> TryProvideRefAsyncWith<'me, Ref<'me, T>, LatestWith<C>> for U
+where
+    T: 'me,
+    U: TryProvideRefAsyncWith<'me, &'me watch::Receiver<T>, C> + ?Sized,
+{
+    type Error = U::Error;
+
+    async fn try_provide_ref_async_with(
+        &'me self,
+        context: LatestWith<C>,
+    ) -> Result<Ref<'me, T>, Self::Error> {
+        let LatestWith(context) = context;
+        let receiver = self.try_provide_ref_async_with(context).await?;
+        Ok(receiver.borrow())
+    }
+}
+
+impl<'me, T, U, C> TryProvideMutAsyncWith<'me, Ref<'me, T>, ChangedWith<C>> for U
+where
+    T: 'me,
+    U: TryProvideMutAsyncWith<'me, &'me mut watch::Receiver<T>, C, Error = Infallible> + ?Sized,
+{
+    type Error = watch::error::RecvError;
+
+    async fn try_provide_mut_async_with(
+        &'me mut self,
+        context: ChangedWith<C>,
+    ) -> Result<Ref<'me, T>, Self::Error> {
+        let ChangedWith(context) = context;
+        let Ok(receiver) = self.try_provide_mut_async_with(context).await;
+        receiver.changed().await?;
+        Ok(receiver.borrow_and_update())
+    }
+}
@@ -0,0 +1,51 @@
+//! Context which checks out instances from the [checkout provider](Checkout).
+//!
+//! This module is available with `alloc` feature enabled.
+//!
+//! See [crate] documentation for more.
+
+use crate::{
+    checkout::{AllCheckedOut, Checkout},
+    context::{depth::Depth, Context},
+    with::TryProvideWith,
+};
+
+/// Context which provides dependency by checking out one of the available instances
+/// from the [checkout provider](Checkout).
+///
+/// Provider itself is returned as the remainder, so the instance could be
+/// [returned](Checkout::return_value) back into it later.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{checkout::Checkout, context::checkout::Lend, with::TryProvideWith};
+///
+/// let provider: Checkout<_> = (0..2).collect();
+///
+/// let (first, provider): (i32, _) = provider.try_provide_with(Lend).unwrap();
+/// let (second, provider): (i32, _) = provider.try_provide_with(Lend).unwrap();
+/// assert_eq!((first, second), (1, 0));
+///
+/// let result: Result<(i32, _), _> = provider.try_provide_with(Lend);
+/// assert_eq!(result.unwrap_err().to_string(), "all 2 instances are checked out");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Lend;
+
+impl Context for Lend {}
+
+impl Depth for Lend {
+    const DEPTH: usize = 1;
+}
+
+impl<T> TryProvideWith<T, Lend> for Checkout<T> {
+    type Remainder = Checkout<T>;
+
+    type Error = AllCheckedOut<T>;
+
+    fn try_provide_with(self, _: Lend) -> Result<(T, Self::Remainder), Self::Error> {
+        self.check_out()
+    }
+}
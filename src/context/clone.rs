@@ -0,0 +1,93 @@
+//! Context which provides dependency by cloning it through unique reference.
+//!
+//! See [crate] documentation for more.
+
+use super::{depth::Depth, Context, Empty};
+use crate::with::{ProvideMutWith, TryProvideMutWith};
+
+/// Context which provides dependency by [cloning](Clone)
+/// the unique reference to it provided with the inner context.
+///
+/// This allows to clone dependency out of the provider
+/// which provides it only by [unique reference](crate::ProvideMut),
+/// without requiring it to provide the dependency by [shared reference](crate::ProvideRef).
+/// Unique reference is borrowed from the provider,
+/// so dependency can be cloned only while providing it by unique reference.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{context::clone::CloneMut, with::ProvideMutWith};
+///
+/// struct Provider {
+///     config: String,
+/// }
+///
+/// impl AsMut<String> for Provider {
+///     fn as_mut(&mut self) -> &mut String {
+///         let Self { config } = self;
+///         config
+///     }
+/// }
+///
+/// let mut provider = Provider { config: String::from("hello") };
+///
+/// let mut config: String = provider.provide_mut_with(CloneMut::default());
+/// config.push_str(", world");
+/// assert_eq!((config.as_str(), provider.config.as_str()), ("hello, world", "hello"));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CloneMutWith<C>(C);
+
+/// Context which provides dependency by [cloning](Clone)
+/// the unique reference to it provided with [empty context](Empty).
+pub type CloneMut = CloneMutWith<Empty>;
+
+impl<C> CloneMutWith<C> {
+    /// Creates new context from the context to provide unique reference to dependency with.
+    pub const fn new(context: C) -> Self {
+        Self(context)
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self(context) = self;
+        context
+    }
+}
+
+impl<C> Context for CloneMutWith<C> {}
+
+impl<C> Depth for CloneMutWith<C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<'me, T, U, C> ProvideMutWith<'me, T, CloneMutWith<C>> for U
+where
+    T: Clone + 'me,
+    U: ProvideMutWith<'me, &'me mut T, C> + ?Sized,
+{
+    fn provide_mut_with(&'me mut self, context: CloneMutWith<C>) -> T {
+        let CloneMutWith(context) = context;
+        let dependency = self.provide_mut_with(context);
+        dependency.clone()
+    }
+}
+
+impl<'me, T, U, C> TryProvideMutWith<'me, T, CloneMutWith<C>> for U
+where
+    T: Clone + 'me,
+    U: TryProvideMutWith<'me, &'me mut T, C> + ?Sized,
+{
+    type Error = U::Error;
+
+    fn try_provide_mut_with(&'me mut self, context: CloneMutWith<C>) -> Result<T, Self::Error> {
+        let CloneMutWith(context) = context;
+        let dependency = self.try_provide_mut_with(context)?;
+        Ok(dependency.clone())
+    }
+}
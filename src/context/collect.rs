@@ -0,0 +1,259 @@
+//! Contexts which provide arrays of dependencies.
+//!
+//! See [crate] documentation for more.
+
+use core::array;
+
+use super::{depth::Depth, Context, Empty};
+use crate::with::{
+    ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideMutWith, TryProvideRefWith,
+    TryProvideWith,
+};
+
+/// Context which provides array of `N` dependencies
+/// by providing each of them in turn with the inner context.
+///
+/// Dependencies are provided by shared reference, and inner context is cloned for each provision.
+/// To provide dependencies by value, threading remainder of each provision into the next one,
+/// use [`ProvideExt::take_array`](crate::ProvideExt::take_array) instead.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{context::collect::Collect, with::ProvideRefWith, ProvideRef};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Worker(u32);
+///
+/// struct Provider;
+///
+/// impl ProvideRef<'_, Worker> for Provider {
+///     fn provide_ref(&self) -> Worker {
+///         Worker(0)
+///     }
+/// }
+///
+/// let workers: [Worker; 3] = Provider.provide_ref_with(Collect::default());
+/// assert_eq!(workers, [Worker(0), Worker(0), Worker(0)]);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CollectWith<const N: usize, C>(C);
+
+/// Context which provides array of `N` dependencies
+/// by providing each of them in turn with [empty context](Empty).
+pub type Collect<const N: usize> = CollectWith<N, Empty>;
+
+impl<const N: usize, C> CollectWith<N, C> {
+    /// Creates new context from the context to provide each dependency with.
+    pub const fn new(context: C) -> Self {
+        Self(context)
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self(context) = self;
+        context
+    }
+}
+
+impl<const N: usize, C> Context for CollectWith<N, C> {}
+
+impl<const N: usize, C> Depth for CollectWith<N, C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<'me, const N: usize, T, U, C> ProvideRefWith<'me, [T; N], CollectWith<N, C>> for U
+where
+    U: ProvideRefWith<'me, T, C> + ?Sized,
+    C: Clone,
+{
+    fn provide_ref_with(&'me self, context: CollectWith<N, C>) -> [T; N] {
+        let CollectWith(context) = context;
+        array::from_fn(|_| self.provide_ref_with(context.clone()))
+    }
+}
+
+impl<'me, const N: usize, T, U, C> TryProvideRefWith<'me, [T; N], CollectWith<N, C>> for U
+where
+    U: TryProvideRefWith<'me, T, C> + ?Sized,
+    C: Clone,
+{
+    type Error = U::Error;
+
+    fn try_provide_ref_with(&'me self, context: CollectWith<N, C>) -> Result<[T; N], Self::Error> {
+        let CollectWith(context) = context;
+        let mut error = None;
+        let dependencies: [Option<T>; N] = array::from_fn(|_| {
+            if error.is_some() {
+                return None;
+            }
+            self.try_provide_ref_with(context.clone())
+                .map_err(|e| error = Some(e))
+                .ok()
+        });
+        if let Some(error) = error {
+            return Err(error);
+        }
+        let dependencies = dependencies
+            .map(|dependency| dependency.expect("all the dependencies should be provided"));
+        Ok(dependencies)
+    }
+}
+
+/// Context which provides array of `N` dependencies
+/// by cloning the dependency provided once with the inner context.
+///
+/// Provided dependency is cloned `N - 1` times and then moved into the last element of the array.
+/// This allows to fan out the dependency to the count of consumers known at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use provide::{context::collect::CloneN, with::ProvideWith};
+///
+/// let provider = Rc::new("shared");
+///
+/// let (handles, ()): ([Rc<&str>; 3], _) = provider.provide_with(CloneN::default());
+/// assert_eq!(Rc::strong_count(&handles[0]), 3);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CloneNWith<const N: usize, C>(C);
+
+/// Context which provides array of `N` dependencies
+/// by cloning the dependency provided once with [empty context](Empty).
+pub type CloneN<const N: usize> = CloneNWith<N, Empty>;
+
+impl<const N: usize, C> CloneNWith<N, C> {
+    /// Creates new context from the context to provide dependency with.
+    pub const fn new(context: C) -> Self {
+        Self(context)
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self(context) = self;
+        context
+    }
+}
+
+impl<const N: usize, C> Context for CloneNWith<N, C> {}
+
+impl<const N: usize, C> Depth for CloneNWith<N, C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+fn clone_n<T, const N: usize>(dependency: T) -> [T; N]
+where
+    T: Clone,
+{
+    let mut dependency = Some(dependency);
+    array::from_fn(|index| {
+        if index + 1 == N {
+            return dependency
+                .take()
+                .expect("dependency should be moved only once");
+        }
+        let dependency = dependency
+            .as_ref()
+            .expect("dependency should not be moved yet");
+        dependency.clone()
+    })
+}
+
+impl<const N: usize, T, U, C> ProvideWith<[T; N], CloneNWith<N, C>> for U
+where
+    U: ProvideWith<T, C>,
+    T: Clone,
+{
+    type Remainder = U::Remainder;
+
+    fn provide_with(self, context: CloneNWith<N, C>) -> ([T; N], Self::Remainder) {
+        let CloneNWith(context) = context;
+        let (dependency, remainder) = self.provide_with(context);
+        (clone_n(dependency), remainder)
+    }
+}
+
+impl<const N: usize, T, U, C> TryProvideWith<[T; N], CloneNWith<N, C>> for U
+where
+    U: TryProvideWith<T, C>,
+    T: Clone,
+{
+    type Remainder = U::Remainder;
+
+    type Error = U::Error;
+
+    fn try_provide_with(
+        self,
+        context: CloneNWith<N, C>,
+    ) -> Result<([T; N], Self::Remainder), Self::Error> {
+        let CloneNWith(context) = context;
+        let (dependency, remainder) = self.try_provide_with(context)?;
+        Ok((clone_n(dependency), remainder))
+    }
+}
+
+impl<'me, const N: usize, T, U, C> ProvideRefWith<'me, [T; N], CloneNWith<N, C>> for U
+where
+    U: ProvideRefWith<'me, T, C> + ?Sized,
+    T: Clone,
+{
+    fn provide_ref_with(&'me self, context: CloneNWith<N, C>) -> [T; N] {
+        let CloneNWith(context) = context;
+        let dependency = self.provide_ref_with(context);
+        clone_n(dependency)
+    }
+}
+
+impl<'me, const N: usize, T, U, C> TryProvideRefWith<'me, [T; N], CloneNWith<N, C>> for U
+where
+    U: TryProvideRefWith<'me, T, C> + ?Sized,
+    T: Clone,
+{
+    type Error = U::Error;
+
+    fn try_provide_ref_with(&'me self, context: CloneNWith<N, C>) -> Result<[T; N], Self::Error> {
+        let CloneNWith(context) = context;
+        let dependency = self.try_provide_ref_with(context)?;
+        Ok(clone_n(dependency))
+    }
+}
+
+impl<'me, const N: usize, T, U, C> ProvideMutWith<'me, [T; N], CloneNWith<N, C>> for U
+where
+    U: ProvideMutWith<'me, T, C> + ?Sized,
+    T: Clone,
+{
+    fn provide_mut_with(&'me mut self, context: CloneNWith<N, C>) -> [T; N] {
+        let CloneNWith(context) = context;
+        let dependency = self.provide_mut_with(context);
+        clone_n(dependency)
+    }
+}
+
+impl<'me, const N: usize, T, U, C> TryProvideMutWith<'me, [T; N], CloneNWith<N, C>> for U
+where
+    U: TryProvideMutWith<'me, T, C> + ?Sized,
+    T: Clone,
+{
+    type Error = U::Error;
+
+    fn try_provide_mut_with(
+        &'me mut self,
+        context: CloneNWith<N, C>,
+    ) -> Result<[T; N], Self::Error> {
+        let CloneNWith(context) = context;
+        let dependency = self.try_provide_mut_with(context)?;
+        Ok(clone_n(dependency))
+    }
+}
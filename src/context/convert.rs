@@ -0,0 +1,404 @@
+//! Contexts which provide dependency by fallible conversion from another provided dependency.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    error::Error,
+    fmt::{self, Debug, Display},
+    marker::PhantomData,
+};
+
+use super::{depth::Depth, Context, Empty};
+use crate::{
+    name::type_name_of,
+    with::{TryProvideMutWith, TryProvideRefWith, TryProvideWith},
+};
+
+/// Implements constructors and common traits for the conversion context.
+macro_rules! impl_context {
+    ($($name:ident),+ $(,)?) => {$(
+        impl<D, C> $name<D, C>
+        where
+            D: ?Sized,
+        {
+            /// Creates new context from the context to provide dependency to convert from with.
+            pub const fn new(context: C) -> Self {
+                Self {
+                    context,
+                    marker: PhantomData,
+                }
+            }
+
+            /// Returns the inner context.
+            pub fn into_inner(self) -> C {
+                let Self { context, .. } = self;
+                context
+            }
+        }
+
+        impl<D, C> Debug for $name<D, C>
+        where
+            D: ?Sized,
+            C: Debug,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let Self { context, .. } = self;
+                f.debug_struct(stringify!($name))
+                    .field("type", &type_name_of::<D>())
+                    .field("context", context)
+                    .finish()
+            }
+        }
+
+        #[cfg(feature = "defmt")]
+        impl<D, C> defmt::Format for $name<D, C>
+        where
+            D: ?Sized,
+            C: defmt::Format,
+        {
+            fn format(&self, f: defmt::Formatter<'_>) {
+                let Self { context, .. } = self;
+                defmt::write!(
+                    f,
+                    "{=str} {{ type: {}, context: {} }}",
+                    stringify!($name),
+                    type_name_of::<D>(),
+                    context,
+                );
+            }
+        }
+
+        impl<D, C> Default for $name<D, C>
+        where
+            D: ?Sized,
+            C: Default,
+        {
+            fn default() -> Self {
+                Self::new(C::default())
+            }
+        }
+
+        impl<D, C> Clone for $name<D, C>
+        where
+            D: ?Sized,
+            C: Clone,
+        {
+            fn clone(&self) -> Self {
+                let Self { context, .. } = self;
+                Self::new(context.clone())
+            }
+        }
+
+        impl<D, C> Copy for $name<D, C>
+        where
+            D: ?Sized,
+            C: Copy,
+        {
+        }
+
+        impl<D, C> Context for $name<D, C> where D: ?Sized {}
+
+        impl<D, C> Depth for $name<D, C>
+        where
+            D: ?Sized,
+            C: Depth,
+        {
+            const DEPTH: usize = C::DEPTH + 1;
+        }
+    )+};
+}
+
+/// Context which provides dependency by [fallible conversion](TryFrom)
+/// from the dependency of type `D` provided with the inner context.
+///
+/// Provision fails with [`TryFromError`] if either the dependency of type `D`
+/// could not be provided or the conversion has failed.
+///
+/// To convert from the dependency provided by reference,
+/// consider using [`TryFromDependencyRefWith`] or [`TryFromDependencyMutWith`] contexts,
+/// which do not require to name the lifetime of the reference.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::{convert::TryFromDependency, Context},
+///     with::TryProvideWith,
+/// };
+///
+/// let provider: i64 = 8080;
+/// let result: Result<(u16, _), _> = provider.try_provide_with(TryFromDependency::<i64>::default());
+/// assert!(matches!(result, Ok((8080, ()))));
+///
+/// let provider: i64 = -1;
+/// let result: Result<(Option<u16>, _), _> = provider.try_provide_with(().then_try_from::<i64>().then_some());
+/// assert!(result.is_err());
+/// ```
+pub struct TryFromDependencyWith<D, C>
+where
+    D: ?Sized,
+{
+    context: C,
+    marker: PhantomData<fn() -> D>,
+}
+
+/// Context which provides dependency by [fallible conversion](TryFrom)
+/// from the dependency of type `D` provided with [empty context](Empty).
+pub type TryFromDependency<D> = TryFromDependencyWith<D, Empty>;
+
+/// Context which provides dependency by [fallible conversion](TryFrom)
+/// from the shared reference to the dependency of type `D` provided with the inner context.
+///
+/// Provision fails with [`TryFromError`] if either the reference
+/// could not be provided or the conversion has failed.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::{convert::TryFromDependencyRef, Context},
+///     with::TryProvideRefWith,
+/// };
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Host(String);
+///
+/// impl TryFrom<&str> for Host {
+///     type Error = &'static str;
+///
+///     fn try_from(host: &str) -> Result<Self, Self::Error> {
+///         if host.is_empty() {
+///             return Err("host is empty");
+///         }
+///         Ok(Self(host.to_owned()))
+///     }
+/// }
+///
+/// let provider = String::from("localhost");
+/// let host: Result<Host, _> = provider.try_provide_ref_with(TryFromDependencyRef::<str>::default());
+/// assert_eq!(host, Ok(Host(String::from("localhost"))));
+///
+/// let provider = String::new();
+/// let host: Result<Host, _> = provider.try_provide_ref_with(().then_try_from_ref::<str>());
+/// assert!(host.is_err());
+/// ```
+pub struct TryFromDependencyRefWith<D, C>
+where
+    D: ?Sized,
+{
+    context: C,
+    marker: PhantomData<fn() -> D>,
+}
+
+/// Context which provides dependency by [fallible conversion](TryFrom)
+/// from the shared reference to the dependency of type `D` provided with [empty context](Empty).
+pub type TryFromDependencyRef<D> = TryFromDependencyRefWith<D, Empty>;
+
+/// Context which provides dependency by [fallible conversion](TryFrom)
+/// from the unique reference to the dependency of type `D` provided with the inner context.
+///
+/// Provision fails with [`TryFromError`] if either the reference
+/// could not be provided or the conversion has failed.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::{convert::TryFromDependencyMut, Context},
+///     with::TryProvideMutWith,
+/// };
+///
+/// struct Buffer<'a>(&'a mut [u8; 4]);
+///
+/// impl<'a> TryFrom<&'a mut [u8]> for Buffer<'a> {
+///     type Error = core::array::TryFromSliceError;
+///
+///     fn try_from(bytes: &'a mut [u8]) -> Result<Self, Self::Error> {
+///         bytes.try_into().map(Self)
+///     }
+/// }
+///
+/// let mut provider = vec![0; 4];
+/// let buffer: Result<Buffer, _> = provider.try_provide_mut_with(TryFromDependencyMut::<[u8]>::default());
+/// assert!(buffer.is_ok());
+///
+/// let mut provider = vec![0; 8];
+/// let buffer: Result<Buffer, _> = provider.try_provide_mut_with(().then_try_from_mut::<[u8]>());
+/// assert!(buffer.is_err());
+/// ```
+pub struct TryFromDependencyMutWith<D, C>
+where
+    D: ?Sized,
+{
+    context: C,
+    marker: PhantomData<fn() -> D>,
+}
+
+/// Context which provides dependency by [fallible conversion](TryFrom)
+/// from the unique reference to the dependency of type `D` provided with [empty context](Empty).
+pub type TryFromDependencyMut<D> = TryFromDependencyMutWith<D, Empty>;
+
+impl_context!(
+    TryFromDependencyWith,
+    TryFromDependencyRefWith,
+    TryFromDependencyMutWith,
+);
+
+impl<T, D, U, C> TryProvideWith<T, TryFromDependencyWith<D, C>> for U
+where
+    U: TryProvideWith<D, C>,
+    T: TryFrom<D>,
+{
+    type Remainder = U::Remainder;
+
+    type Error = TryFromError<U::Error, T::Error>;
+
+    fn try_provide_with(
+        self,
+        context: TryFromDependencyWith<D, C>,
+    ) -> Result<(T, Self::Remainder), Self::Error> {
+        let TryFromDependencyWith { context, .. } = context;
+        let (dependency, remainder) = self
+            .try_provide_with(context)
+            .map_err(TryFromError::Provide)?;
+        let dependency = T::try_from(dependency).map_err(TryFromError::Convert)?;
+        Ok((dependency, remainder))
+    }
+}
+
+impl<'me, T, D, U, C> TryProvideRefWith<'me, T, TryFromDependencyWith<D, C>> for U
+where
+    U: TryProvideRefWith<'me, D, C> + ?Sized,
+    T: TryFrom<D>,
+{
+    type Error = TryFromError<U::Error, T::Error>;
+
+    fn try_provide_ref_with(
+        &'me self,
+        context: TryFromDependencyWith<D, C>,
+    ) -> Result<T, Self::Error> {
+        let TryFromDependencyWith { context, .. } = context;
+        let dependency = self
+            .try_provide_ref_with(context)
+            .map_err(TryFromError::Provide)?;
+        T::try_from(dependency).map_err(TryFromError::Convert)
+    }
+}
+
+impl<'me, T, D, U, C> TryProvideMutWith<'me, T, TryFromDependencyWith<D, C>> for U
+where
+    U: TryProvideMutWith<'me, D, C> + ?Sized,
+    T: TryFrom<D>,
+{
+    type Error = TryFromError<U::Error, T::Error>;
+
+    fn try_provide_mut_with(
+        &'me mut self,
+        context: TryFromDependencyWith<D, C>,
+    ) -> Result<T, Self::Error> {
+        let TryFromDependencyWith { context, .. } = context;
+        let dependency = self
+            .try_provide_mut_with(context)
+            .map_err(TryFromError::Provide)?;
+        T::try_from(dependency).map_err(TryFromError::Convert)
+    }
+}
+
+impl<'me, T, D, U, C> TryProvideRefWith<'me, T, TryFromDependencyRefWith<D, C>> for U
+where
+    D: ?Sized + 'me,
+    U: TryProvideRefWith<'me, &'me D, C> + ?Sized,
+    T: TryFrom<&'me D>,
+{
+    type Error = TryFromError<U::Error, T::Error>;
+
+    fn try_provide_ref_with(
+        &'me self,
+        context: TryFromDependencyRefWith<D, C>,
+    ) -> Result<T, Self::Error> {
+        let TryFromDependencyRefWith { context, .. } = context;
+        let dependency = self
+            .try_provide_ref_with(context)
+            .map_err(TryFromError::Provide)?;
+        T::try_from(dependency).map_err(TryFromError::Convert)
+    }
+}
+
+impl<'me, T, D, U, C> TryProvideMutWith<'me, T, TryFromDependencyRefWith<D, C>> for U
+where
+    D: ?Sized + 'me,
+    U: TryProvideMutWith<'me, &'me D, C> + ?Sized,
+    T: TryFrom<&'me D>,
+{
+    type Error = TryFromError<U::Error, T::Error>;
+
+    fn try_provide_mut_with(
+        &'me mut self,
+        context: TryFromDependencyRefWith<D, C>,
+    ) -> Result<T, Self::Error> {
+        let TryFromDependencyRefWith { context, .. } = context;
+        let dependency = self
+            .try_provide_mut_with(context)
+            .map_err(TryFromError::Provide)?;
+        T::try_from(dependency).map_err(TryFromError::Convert)
+    }
+}
+
+impl<'me, T, D, U, C> TryProvideMutWith<'me, T, TryFromDependencyMutWith<D, C>> for U
+where
+    D: ?Sized + 'me,
+    U: TryProvideMutWith<'me, &'me mut D, C> + ?Sized,
+    T: TryFrom<&'me mut D>,
+{
+    type Error = TryFromError<U::Error, T::Error>;
+
+    fn try_provide_mut_with(
+        &'me mut self,
+        context: TryFromDependencyMutWith<D, C>,
+    ) -> Result<T, Self::Error> {
+        let TryFromDependencyMutWith { context, .. } = context;
+        let dependency = self
+            .try_provide_mut_with(context)
+            .map_err(TryFromError::Provide)?;
+        T::try_from(dependency).map_err(TryFromError::Convert)
+    }
+}
+
+/// The error type returned when dependency could not be provided
+/// with contexts of this module.
+///
+/// Error of the provision is displayed as is, while error of the conversion
+/// is reported as the [source](Error::source) of this error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TryFromError<E, F> {
+    /// Dependency to convert from could not be provided.
+    Provide(E),
+    /// Dependency was provided, but it could not be converted.
+    Convert(F),
+}
+
+impl<E, F> Display for TryFromError<E, F>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Provide(error) => Display::fmt(error, f),
+            Self::Convert(_) => f.write_str("failed to convert dependency"),
+        }
+    }
+}
+
+impl<E, F> Error for TryFromError<E, F>
+where
+    E: Error,
+    F: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Provide(error) => error.source(),
+            Self::Convert(error) => Some(error),
+        }
+    }
+}
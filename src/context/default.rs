@@ -0,0 +1,261 @@
+//! Contexts which provide dependency by constructing its default value.
+//!
+//! See [crate] documentation for more.
+
+use core::convert::Infallible;
+
+use super::{depth::Depth, Context, Empty};
+use crate::with::{
+    ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideMutWith, TryProvideRefWith,
+    TryProvideWith,
+};
+
+/// Context which provides dependency by constructing its [default](Default) value
+/// regardless of what the provider holds.
+///
+/// This allows to stub out leaf dependencies in deep chains of providers.
+/// The inner context is not used to provide dependency:
+/// it is only carried to be retrieved back with [`into_inner`](DefaultDependencyWith::into_inner).
+///
+/// # Examples
+///
+/// ```
+/// use provide::{context::default::DefaultDependency, with::ProvideRefWith};
+///
+/// struct Provider;
+///
+/// let timeout: u64 = Provider.provide_ref_with(DefaultDependency::default());
+/// let name: String = Provider.provide_ref_with(DefaultDependency::default());
+/// assert_eq!((timeout, name.as_str()), (0, ""));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DefaultDependencyWith<C>(C);
+
+/// Context which provides dependency by constructing its [default](Default) value
+/// regardless of what the provider holds.
+pub type DefaultDependency = DefaultDependencyWith<Empty>;
+
+impl<C> DefaultDependencyWith<C> {
+    /// Creates new context from the carried context.
+    pub const fn new(context: C) -> Self {
+        Self(context)
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self(context) = self;
+        context
+    }
+}
+
+impl<C> Context for DefaultDependencyWith<C> {}
+
+impl<C> Depth for DefaultDependencyWith<C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<T, U, C> ProvideWith<T, DefaultDependencyWith<C>> for U
+where
+    T: Default,
+{
+    type Remainder = U;
+
+    fn provide_with(self, _: DefaultDependencyWith<C>) -> (T, Self::Remainder) {
+        (T::default(), self)
+    }
+}
+
+impl<T, U, C> TryProvideWith<T, DefaultDependencyWith<C>> for U
+where
+    T: Default,
+{
+    type Remainder = U;
+
+    type Error = Infallible;
+
+    fn try_provide_with(
+        self,
+        context: DefaultDependencyWith<C>,
+    ) -> Result<(T, Self::Remainder), Self::Error> {
+        Ok(self.provide_with(context))
+    }
+}
+
+impl<'me, T, U, C> ProvideRefWith<'me, T, DefaultDependencyWith<C>> for U
+where
+    T: Default,
+    U: ?Sized,
+{
+    fn provide_ref_with(&'me self, _: DefaultDependencyWith<C>) -> T {
+        T::default()
+    }
+}
+
+impl<'me, T, U, C> TryProvideRefWith<'me, T, DefaultDependencyWith<C>> for U
+where
+    T: Default,
+    U: ?Sized,
+{
+    type Error = Infallible;
+
+    fn try_provide_ref_with(
+        &'me self,
+        context: DefaultDependencyWith<C>,
+    ) -> Result<T, Self::Error> {
+        Ok(self.provide_ref_with(context))
+    }
+}
+
+impl<'me, T, U, C> ProvideMutWith<'me, T, DefaultDependencyWith<C>> for U
+where
+    T: Default,
+    U: ?Sized,
+{
+    fn provide_mut_with(&'me mut self, _: DefaultDependencyWith<C>) -> T {
+        T::default()
+    }
+}
+
+impl<'me, T, U, C> TryProvideMutWith<'me, T, DefaultDependencyWith<C>> for U
+where
+    T: Default,
+    U: ?Sized,
+{
+    type Error = Infallible;
+
+    fn try_provide_mut_with(
+        &'me mut self,
+        context: DefaultDependencyWith<C>,
+    ) -> Result<T, Self::Error> {
+        Ok(self.provide_mut_with(context))
+    }
+}
+
+/// Context which provides dependency with the inner context,
+/// falling back to its [default](Default) value if provision fails.
+///
+/// This context is implemented only for the fallible traits, such as [`TryProvideRefWith`],
+/// but it never fails itself: its error type is [`Infallible`].
+///
+/// Provision by value consumes the provider even if it fails,
+/// so the [remaining part](TryProvideWith::Remainder) of the provider is replaced
+/// by the error of the inner context in such case.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::{failpoint::FailAfter, Context},
+///     failpoint::Failpoint,
+///     with::TryProvideRefWith,
+///     ProvideRef,
+/// };
+///
+/// struct Provider {
+///     retries: u32,
+/// }
+///
+/// impl ProvideRef<'_, u32> for Provider {
+///     fn provide_ref(&self) -> u32 {
+///         let Self { retries } = self;
+///         *retries
+///     }
+/// }
+///
+/// let provider = Failpoint::new(Provider { retries: 3 });
+///
+/// let retries: Result<u32, _> = provider.try_provide_ref_with(FailAfter::new(1, ()).then_or_default());
+/// assert_eq!(retries, Ok(3));
+///
+/// let retries: Result<u32, _> = provider.try_provide_ref_with(FailAfter::new(0, ()).then_or_default());
+/// assert_eq!(retries, Ok(0));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct OrDefaultDependencyWith<C>(C);
+
+/// Context which provides dependency with [empty context](Empty),
+/// falling back to its [default](Default) value if provision fails.
+pub type OrDefaultDependency = OrDefaultDependencyWith<Empty>;
+
+impl<C> OrDefaultDependencyWith<C> {
+    /// Creates new context from the context to provide dependency with.
+    pub const fn new(context: C) -> Self {
+        Self(context)
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self(context) = self;
+        context
+    }
+}
+
+impl<C> Context for OrDefaultDependencyWith<C> {}
+
+impl<C> Depth for OrDefaultDependencyWith<C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<T, U, C> TryProvideWith<T, OrDefaultDependencyWith<C>> for U
+where
+    T: Default,
+    U: TryProvideWith<T, C>,
+{
+    type Remainder = Result<U::Remainder, U::Error>;
+
+    type Error = Infallible;
+
+    fn try_provide_with(
+        self,
+        context: OrDefaultDependencyWith<C>,
+    ) -> Result<(T, Self::Remainder), Self::Error> {
+        let OrDefaultDependencyWith(context) = context;
+        let provided = match <U as TryProvideWith<T, C>>::try_provide_with(self, context) {
+            Ok((dependency, remainder)) => (dependency, Ok(remainder)),
+            Err(error) => (T::default(), Err(error)),
+        };
+        Ok(provided)
+    }
+}
+
+impl<'me, T, U, C> TryProvideRefWith<'me, T, OrDefaultDependencyWith<C>> for U
+where
+    T: Default,
+    U: TryProvideRefWith<'me, T, C> + ?Sized,
+{
+    type Error = Infallible;
+
+    fn try_provide_ref_with(
+        &'me self,
+        context: OrDefaultDependencyWith<C>,
+    ) -> Result<T, Self::Error> {
+        let OrDefaultDependencyWith(context) = context;
+        let dependency = <U as TryProvideRefWith<'me, T, C>>::try_provide_ref_with(self, context);
+        Ok(dependency.unwrap_or_default())
+    }
+}
+
+impl<'me, T, U, C> TryProvideMutWith<'me, T, OrDefaultDependencyWith<C>> for U
+where
+    T: Default,
+    U: TryProvideMutWith<'me, T, C> + ?Sized,
+{
+    type Error = Infallible;
+
+    fn try_provide_mut_with(
+        &'me mut self,
+        context: OrDefaultDependencyWith<C>,
+    ) -> Result<T, Self::Error> {
+        let OrDefaultDependencyWith(context) = context;
+        let dependency = <U as TryProvideMutWith<'me, T, C>>::try_provide_mut_with(self, context);
+        Ok(dependency.unwrap_or_default())
+    }
+}
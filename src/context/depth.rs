@@ -0,0 +1,219 @@
+//! Context which limits the depth of the inner context chain.
+//!
+//! See [crate] documentation for more.
+
+use super::{Context, Empty};
+use crate::with::{
+    ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideMutWith, TryProvideRefWith,
+    TryProvideWith,
+};
+
+/// Depth of the chain of the [context](Context).
+///
+/// [Empty context](Empty) has depth of zero,
+/// and each context of this crate adds one to the deepest of its inner contexts,
+/// so each adaptor such as [`then_some`](Context::then_some) adds one.
+/// Contexts without inner context have depth of one.
+///
+/// Implement this trait for your context to use it with [`DepthGuardWith`] context.
+pub trait Depth {
+    /// Depth of the chain of this context.
+    const DEPTH: usize;
+}
+
+impl Depth for Empty {
+    const DEPTH: usize = 0;
+}
+
+impl<C> Depth for &mut C
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH;
+}
+
+impl<C> Depth for Option<C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH;
+}
+
+/// Returns the depth of the deepest of two context chains.
+pub(crate) const fn max_depth(first: usize, second: usize) -> usize {
+    if first > second {
+        first
+    } else {
+        second
+    }
+}
+
+/// Context which provides dependency with the inner context,
+/// checking at compile time that the chain of the inner context
+/// is not deeper than `MAX` contexts.
+///
+/// Contexts are built by nesting one into another, so provision paths
+/// constructed programmatically could accidentally grow exponentially.
+/// This context guards against that at the wiring layer.
+///
+/// Depth of the chain is measured with [`Depth`] trait,
+/// which is implemented for all the contexts of this crate.
+/// Provision with this context fails to compile
+/// if the depth of the inner context exceeds `MAX`.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::{depth::DepthGuardWith, Context},
+///     with::ProvideWith,
+/// };
+///
+/// let context = DepthGuardWith::<2, _>::new(().then_some().then_some());
+/// let (dependency, _): (Option<Option<i32>>, _) = 1.provide_with(context);
+/// assert_eq!(dependency, Some(Some(1)));
+/// ```
+///
+/// ```compile_fail
+/// use provide::{
+///     context::{depth::DepthGuardWith, Context},
+///     with::ProvideWith,
+/// };
+///
+/// let context = DepthGuardWith::<1, _>::new(().then_some().then_some());
+/// let (dependency, _): (Option<Option<i32>>, _) = 1.provide_with(context);
+/// ```
+///
+/// The compiler names the guard along with the whole chain of its inner context
+/// in the note to such error:
+///
+/// ```text
+/// error[E0080]: evaluation panicked: depth of the inner context chain of `DepthGuardWith` exceeds `MAX`
+///   = note: evaluation of `DepthGuardWith::<1, OptionDependencyWith<OptionDependencyWith<()>>>::WITHIN_LIMIT` failed here
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DepthGuardWith<const MAX: usize, C>(C);
+
+/// Context which provides dependency with [empty context](Empty),
+/// checking at compile time that the chain of the inner context
+/// is not deeper than `MAX` contexts.
+pub type DepthGuard<const MAX: usize> = DepthGuardWith<MAX, Empty>;
+
+impl<const MAX: usize, C> DepthGuardWith<MAX, C> {
+    /// Creates new context from the context to provide dependency with.
+    pub const fn new(context: C) -> Self {
+        Self(context)
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self(context) = self;
+        context
+    }
+}
+
+impl<const MAX: usize, C> DepthGuardWith<MAX, C>
+where
+    C: Depth,
+{
+    const WITHIN_LIMIT: () = assert!(
+        C::DEPTH <= MAX,
+        "depth of the inner context chain of `DepthGuardWith` exceeds `MAX`",
+    );
+
+    /// Returns the depth of the chain of the inner context.
+    pub const fn depth(&self) -> usize {
+        C::DEPTH
+    }
+
+    fn check(self) -> C {
+        let () = Self::WITHIN_LIMIT;
+        self.into_inner()
+    }
+}
+
+impl<const MAX: usize, C> Context for DepthGuardWith<MAX, C> {}
+
+impl<const MAX: usize, C> Depth for DepthGuardWith<MAX, C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<const MAX: usize, T, U, C> ProvideWith<T, DepthGuardWith<MAX, C>> for U
+where
+    U: ProvideWith<T, C>,
+    C: Depth,
+{
+    type Remainder = U::Remainder;
+
+    fn provide_with(self, context: DepthGuardWith<MAX, C>) -> (T, Self::Remainder) {
+        self.provide_with(context.check())
+    }
+}
+
+impl<const MAX: usize, T, U, C> TryProvideWith<T, DepthGuardWith<MAX, C>> for U
+where
+    U: TryProvideWith<T, C>,
+    C: Depth,
+{
+    type Remainder = U::Remainder;
+
+    type Error = U::Error;
+
+    fn try_provide_with(
+        self,
+        context: DepthGuardWith<MAX, C>,
+    ) -> Result<(T, Self::Remainder), Self::Error> {
+        self.try_provide_with(context.check())
+    }
+}
+
+impl<'me, const MAX: usize, T, U, C> ProvideRefWith<'me, T, DepthGuardWith<MAX, C>> for U
+where
+    U: ProvideRefWith<'me, T, C> + ?Sized,
+    C: Depth,
+{
+    fn provide_ref_with(&'me self, context: DepthGuardWith<MAX, C>) -> T {
+        self.provide_ref_with(context.check())
+    }
+}
+
+impl<'me, const MAX: usize, T, U, C> TryProvideRefWith<'me, T, DepthGuardWith<MAX, C>> for U
+where
+    U: TryProvideRefWith<'me, T, C> + ?Sized,
+    C: Depth,
+{
+    type Error = U::Error;
+
+    fn try_provide_ref_with(&'me self, context: DepthGuardWith<MAX, C>) -> Result<T, Self::Error> {
+        self.try_provide_ref_with(context.check())
+    }
+}
+
+impl<'me, const MAX: usize, T, U, C> ProvideMutWith<'me, T, DepthGuardWith<MAX, C>> for U
+where
+    U: ProvideMutWith<'me, T, C> + ?Sized,
+    C: Depth,
+{
+    fn provide_mut_with(&'me mut self, context: DepthGuardWith<MAX, C>) -> T {
+        self.provide_mut_with(context.check())
+    }
+}
+
+impl<'me, const MAX: usize, T, U, C> TryProvideMutWith<'me, T, DepthGuardWith<MAX, C>> for U
+where
+    U: TryProvideMutWith<'me, T, C> + ?Sized,
+    C: Depth,
+{
+    type Error = U::Error;
+
+    fn try_provide_mut_with(
+        &'me mut self,
+        context: DepthGuardWith<MAX, C>,
+    ) -> Result<T, Self::Error> {
+        self.try_provide_mut_with(context.check())
+    }
+}
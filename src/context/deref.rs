@@ -0,0 +1,207 @@
+//! Context which provides dependency by dereferencing another provided dependency.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+    ops::Deref,
+};
+
+use super::{depth::Depth, Context, Empty};
+use crate::{
+    name::type_name_of,
+    with::{ProvideRefWith, ProvideWith, TryProvideRefWith, TryProvideWith},
+};
+
+/// Context which provides the [target](Deref::Target) of the dependency of type `D`
+/// provided with the inner context.
+///
+/// This allows to provide views of the dependency held behind smart pointers,
+/// for example, `&Config` from the provider of `&Arc<Config>`,
+/// without implementing provider traits for each of the targets.
+/// Reference to the target is provided out of the reference to the dependency,
+/// and owned target is provided by [cloning](Clone) it out of the owned dependency.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use provide::{
+///     context::deref::DerefDependency,
+///     with::{ProvideRefWith, ProvideWith},
+/// };
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Config {
+///     retries: u32,
+/// }
+///
+/// struct Provider {
+///     config: Arc<Config>,
+/// }
+///
+/// impl AsRef<Arc<Config>> for Provider {
+///     fn as_ref(&self) -> &Arc<Config> {
+///         let Self { config } = self;
+///         config
+///     }
+/// }
+///
+/// let provider = Provider { config: Arc::new(Config { retries: 3 }) };
+///
+/// let config: &Config = provider.provide_ref_with(DerefDependency::<Arc<Config>>::default());
+/// assert_eq!(config.retries, 3);
+///
+/// let (config, ()): (Config, _) = Arc::clone(&provider.config)
+///     .provide_with(DerefDependency::<Arc<Config>>::default());
+/// assert_eq!(config, Config { retries: 3 });
+/// ```
+pub struct DerefDependencyWith<D, C> {
+    context: C,
+    marker: PhantomData<fn() -> D>,
+}
+
+/// Context which provides the [target](Deref::Target) of the dependency of type `D`
+/// provided with [empty context](Empty).
+pub type DerefDependency<D> = DerefDependencyWith<D, Empty>;
+
+impl<D, C> DerefDependencyWith<D, C> {
+    /// Creates new context from the context to provide dependency to dereference with.
+    pub const fn new(context: C) -> Self {
+        Self {
+            context,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self { context, .. } = self;
+        context
+    }
+}
+
+impl<D, C> Debug for DerefDependencyWith<D, C>
+where
+    C: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { context, .. } = self;
+        f.debug_struct("DerefDependencyWith")
+            .field("type", &type_name_of::<D>())
+            .field("context", context)
+            .finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<D, C> defmt::Format for DerefDependencyWith<D, C>
+where
+    C: defmt::Format,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        let Self { context, .. } = self;
+        defmt::write!(
+            f,
+            "DerefDependencyWith {{ type: {}, context: {} }}",
+            type_name_of::<D>(),
+            context,
+        );
+    }
+}
+
+impl<D, C> Default for DerefDependencyWith<D, C>
+where
+    C: Default,
+{
+    fn default() -> Self {
+        Self::new(C::default())
+    }
+}
+
+impl<D, C> Clone for DerefDependencyWith<D, C>
+where
+    C: Clone,
+{
+    fn clone(&self) -> Self {
+        let Self { context, .. } = self;
+        Self::new(context.clone())
+    }
+}
+
+impl<D, C> Copy for DerefDependencyWith<D, C> where C: Copy {}
+
+impl<D, C> Context for DerefDependencyWith<D, C> {}
+
+impl<D, C> Depth for DerefDependencyWith<D, C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<D, U, C> ProvideWith<D::Target, DerefDependencyWith<D, C>> for U
+where
+    D: Deref,
+    D::Target: Clone,
+    U: ProvideWith<D, C>,
+{
+    type Remainder = U::Remainder;
+
+    fn provide_with(self, context: DerefDependencyWith<D, C>) -> (D::Target, Self::Remainder) {
+        let DerefDependencyWith { context, .. } = context;
+        let (dependency, remainder) = self.provide_with(context);
+        (dependency.deref().clone(), remainder)
+    }
+}
+
+impl<D, U, C> TryProvideWith<D::Target, DerefDependencyWith<D, C>> for U
+where
+    D: Deref,
+    D::Target: Clone,
+    U: TryProvideWith<D, C>,
+{
+    type Remainder = U::Remainder;
+
+    type Error = U::Error;
+
+    fn try_provide_with(
+        self,
+        context: DerefDependencyWith<D, C>,
+    ) -> Result<(D::Target, Self::Remainder), Self::Error> {
+        let DerefDependencyWith { context, .. } = context;
+        let (dependency, remainder) = self.try_provide_with(context)?;
+        Ok((dependency.deref().clone(), remainder))
+    }
+}
+
+impl<'me, 'a, D, U, C> ProvideRefWith<'me, &'a D::Target, DerefDependencyWith<D, C>> for U
+where
+    D: Deref + 'a,
+    U: ProvideRefWith<'me, &'a D, C> + ?Sized,
+{
+    fn provide_ref_with(&'me self, context: DerefDependencyWith<D, C>) -> &'a D::Target {
+        let DerefDependencyWith { context, .. } = context;
+        let dependency = self.provide_ref_with(context);
+        dependency.deref()
+    }
+}
+
+impl<'me, 'a, D, U, C> TryProvideRefWith<'me, &'a D::Target, DerefDependencyWith<D, C>> for U
+where
+    D: Deref + 'a,
+    U: TryProvideRefWith<'me, &'a D, C> + ?Sized,
+{
+    type Error = U::Error;
+
+    fn try_provide_ref_with(
+        &'me self,
+        context: DerefDependencyWith<D, C>,
+    ) -> Result<&'a D::Target, Self::Error> {
+        let DerefDependencyWith { context, .. } = context;
+        let dependency = self.try_provide_ref_with(context)?;
+        Ok(dependency.deref())
+    }
+}
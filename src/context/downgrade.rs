@@ -0,0 +1,76 @@
+//! Context which provides dependency by shared reference from the [downgraded](Downgraded) provider.
+//!
+//! See [crate] documentation for more.
+
+use crate::{
+    context::{depth::Depth, Context, Empty},
+    downgrade::{DowngradeError, Downgraded},
+    with::{ProvideMutWith, ProvideRefWith, TryProvideMutWith, TryProvideRefWith},
+};
+
+/// Context which provides dependency by shared reference from the [downgraded](Downgraded) provider,
+/// providing it by unique reference from the inner provider with the inner context.
+///
+/// # Panics
+///
+/// Provision panics if the inner provider is already borrowed,
+/// which may happen only if the inner provider provides dependency
+/// out of the downgraded provider recursively.
+/// Fallible provision returns [borrowed](DowngradeError::Borrowed) error instead.
+///
+/// See [`Downgraded`] for examples.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DowngradeWith<C>(C);
+
+/// Context which provides dependency by shared reference from the [downgraded](Downgraded) provider,
+/// providing it by unique reference from the inner provider with [empty context](Empty).
+pub type Downgrade = DowngradeWith<Empty>;
+
+impl<C> DowngradeWith<C> {
+    /// Creates new context from the context to provide dependency from the inner provider with.
+    pub const fn new(context: C) -> Self {
+        Self(context)
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self(context) = self;
+        context
+    }
+}
+
+impl<C> Context for DowngradeWith<C> {}
+
+impl<C> Depth for DowngradeWith<C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<'me, T, P, C> ProvideRefWith<'me, T, DowngradeWith<C>> for Downgraded<P>
+where
+    P: for<'a> ProvideMutWith<'a, T, C>,
+{
+    fn provide_ref_with(&'me self, context: DowngradeWith<C>) -> T {
+        let mut provider = self.borrow_mut();
+        <P as ProvideMutWith<'_, T, C>>::provide_mut_with(&mut provider, context.into_inner())
+    }
+}
+
+impl<'me, T, P, C, E> TryProvideRefWith<'me, T, DowngradeWith<C>> for Downgraded<P>
+where
+    P: for<'a> TryProvideMutWith<'a, T, C, Error = E>,
+{
+    type Error = DowngradeError<E>;
+
+    fn try_provide_ref_with(&'me self, context: DowngradeWith<C>) -> Result<T, Self::Error> {
+        let mut provider = self.try_borrow_mut()?;
+        <P as TryProvideMutWith<'_, T, C>>::try_provide_mut_with(
+            &mut provider,
+            context.into_inner(),
+        )
+        .map_err(DowngradeError::Provide)
+    }
+}
@@ -0,0 +1,591 @@
+//! Contexts which select one of two other contexts.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use super::{
+    depth::{max_depth, Depth},
+    Context, Empty,
+};
+use crate::with::{
+    ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideMutWith, TryProvideRefWith,
+    TryProvideWith,
+};
+
+/// Context which is either the left or the right context.
+///
+/// Dependency of the same type is provided with whichever context is carried,
+/// so the strategy of provision can be selected at runtime
+/// without boxing or duplicating the code for each of the contexts.
+///
+/// Remainders and errors of the provisions with both contexts
+/// are unified with this type as well.
+///
+/// Optional contexts of type [`Option<C>`] are provided the same way:
+/// [`None`] means the [empty context](Empty), and [`Some`] means the inner context.
+/// This allows to make the inner context optional at the call site
+/// without writing two code paths.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::{either::Either, Context},
+///     with::ProvideRefWith,
+/// };
+///
+/// struct Config {
+///     name: String,
+/// }
+///
+/// impl AsRef<String> for Config {
+///     fn as_ref(&self) -> &String {
+///         let Self { name } = self;
+///         name
+///     }
+/// }
+///
+/// let provider = Config { name: String::from("config") };
+///
+/// for enabled in [true, false] {
+///     let context = if enabled {
+///         Either::Left(().then_some())
+///     } else {
+///         Either::Right(().then_default())
+///     };
+///     let name: Option<&String> = provider.provide_ref_with(context);
+///     assert_eq!(name.is_some(), enabled);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Either<L, R> {
+    /// The left context.
+    Left(L),
+    /// The right context.
+    Right(R),
+}
+
+impl<L, R> Context for Either<L, R> {}
+
+impl<L, R> Depth for Either<L, R>
+where
+    L: Depth,
+    R: Depth,
+{
+    const DEPTH: usize = max_depth(L::DEPTH, R::DEPTH) + 1;
+}
+
+impl<T, U, L, R> ProvideWith<T, Either<L, R>> for U
+where
+    U: ProvideWith<T, L> + ProvideWith<T, R>,
+{
+    type Remainder =
+        Either<<U as ProvideWith<T, L>>::Remainder, <U as ProvideWith<T, R>>::Remainder>;
+
+    fn provide_with(self, context: Either<L, R>) -> (T, Self::Remainder) {
+        match context {
+            Either::Left(context) => {
+                let (dependency, remainder) = <U as ProvideWith<T, L>>::provide_with(self, context);
+                (dependency, Either::Left(remainder))
+            }
+            Either::Right(context) => {
+                let (dependency, remainder) = <U as ProvideWith<T, R>>::provide_with(self, context);
+                (dependency, Either::Right(remainder))
+            }
+        }
+    }
+}
+
+impl<T, U, L, R> TryProvideWith<T, Either<L, R>> for U
+where
+    U: TryProvideWith<T, L> + TryProvideWith<T, R>,
+{
+    type Remainder =
+        Either<<U as TryProvideWith<T, L>>::Remainder, <U as TryProvideWith<T, R>>::Remainder>;
+
+    type Error = Either<<U as TryProvideWith<T, L>>::Error, <U as TryProvideWith<T, R>>::Error>;
+
+    fn try_provide_with(self, context: Either<L, R>) -> Result<(T, Self::Remainder), Self::Error> {
+        match context {
+            Either::Left(context) => {
+                let (dependency, remainder) =
+                    <U as TryProvideWith<T, L>>::try_provide_with(self, context)
+                        .map_err(Either::Left)?;
+                Ok((dependency, Either::Left(remainder)))
+            }
+            Either::Right(context) => {
+                let (dependency, remainder) =
+                    <U as TryProvideWith<T, R>>::try_provide_with(self, context)
+                        .map_err(Either::Right)?;
+                Ok((dependency, Either::Right(remainder)))
+            }
+        }
+    }
+}
+
+impl<'me, T, U, L, R> ProvideRefWith<'me, T, Either<L, R>> for U
+where
+    U: ProvideRefWith<'me, T, L> + ProvideRefWith<'me, T, R> + ?Sized,
+{
+    fn provide_ref_with(&'me self, context: Either<L, R>) -> T {
+        match context {
+            Either::Left(context) => {
+                <U as ProvideRefWith<'me, T, L>>::provide_ref_with(self, context)
+            }
+            Either::Right(context) => {
+                <U as ProvideRefWith<'me, T, R>>::provide_ref_with(self, context)
+            }
+        }
+    }
+}
+
+impl<'me, T, U, L, R> TryProvideRefWith<'me, T, Either<L, R>> for U
+where
+    U: TryProvideRefWith<'me, T, L> + TryProvideRefWith<'me, T, R> + ?Sized,
+{
+    type Error = Either<
+        <U as TryProvideRefWith<'me, T, L>>::Error,
+        <U as TryProvideRefWith<'me, T, R>>::Error,
+    >;
+
+    fn try_provide_ref_with(&'me self, context: Either<L, R>) -> Result<T, Self::Error> {
+        match context {
+            Either::Left(context) => {
+                <U as TryProvideRefWith<'me, T, L>>::try_provide_ref_with(self, context)
+                    .map_err(Either::Left)
+            }
+            Either::Right(context) => {
+                <U as TryProvideRefWith<'me, T, R>>::try_provide_ref_with(self, context)
+                    .map_err(Either::Right)
+            }
+        }
+    }
+}
+
+impl<'me, T, U, L, R> ProvideMutWith<'me, T, Either<L, R>> for U
+where
+    U: ProvideMutWith<'me, T, L> + ProvideMutWith<'me, T, R> + ?Sized,
+{
+    fn provide_mut_with(&'me mut self, context: Either<L, R>) -> T {
+        match context {
+            Either::Left(context) => {
+                <U as ProvideMutWith<'me, T, L>>::provide_mut_with(self, context)
+            }
+            Either::Right(context) => {
+                <U as ProvideMutWith<'me, T, R>>::provide_mut_with(self, context)
+            }
+        }
+    }
+}
+
+impl<'me, T, U, L, R> TryProvideMutWith<'me, T, Either<L, R>> for U
+where
+    U: TryProvideMutWith<'me, T, L> + TryProvideMutWith<'me, T, R> + ?Sized,
+{
+    type Error = Either<
+        <U as TryProvideMutWith<'me, T, L>>::Error,
+        <U as TryProvideMutWith<'me, T, R>>::Error,
+    >;
+
+    fn try_provide_mut_with(&'me mut self, context: Either<L, R>) -> Result<T, Self::Error> {
+        match context {
+            Either::Left(context) => {
+                <U as TryProvideMutWith<'me, T, L>>::try_provide_mut_with(self, context)
+                    .map_err(Either::Left)
+            }
+            Either::Right(context) => {
+                <U as TryProvideMutWith<'me, T, R>>::try_provide_mut_with(self, context)
+                    .map_err(Either::Right)
+            }
+        }
+    }
+}
+
+impl<C> From<Option<C>> for Either<Empty, C> {
+    fn from(context: Option<C>) -> Self {
+        match context {
+            Some(context) => Self::Right(context),
+            None => Self::Left(()),
+        }
+    }
+}
+
+impl<T, U, C> ProvideWith<T, Option<C>> for U
+where
+    U: ProvideWith<T, Empty> + ProvideWith<T, C>,
+{
+    type Remainder =
+        Either<<U as ProvideWith<T, Empty>>::Remainder, <U as ProvideWith<T, C>>::Remainder>;
+
+    fn provide_with(self, context: Option<C>) -> (T, Self::Remainder) {
+        <U as ProvideWith<T, Either<Empty, C>>>::provide_with(self, context.into())
+    }
+}
+
+impl<T, U, C> TryProvideWith<T, Option<C>> for U
+where
+    U: TryProvideWith<T, Empty> + TryProvideWith<T, C>,
+{
+    type Remainder =
+        Either<<U as TryProvideWith<T, Empty>>::Remainder, <U as TryProvideWith<T, C>>::Remainder>;
+
+    type Error = Either<<U as TryProvideWith<T, Empty>>::Error, <U as TryProvideWith<T, C>>::Error>;
+
+    fn try_provide_with(self, context: Option<C>) -> Result<(T, Self::Remainder), Self::Error> {
+        <U as TryProvideWith<T, Either<Empty, C>>>::try_provide_with(self, context.into())
+    }
+}
+
+impl<'me, T, U, C> ProvideRefWith<'me, T, Option<C>> for U
+where
+    U: ProvideRefWith<'me, T, Empty> + ProvideRefWith<'me, T, C> + ?Sized,
+{
+    fn provide_ref_with(&'me self, context: Option<C>) -> T {
+        <U as ProvideRefWith<'me, T, Either<Empty, C>>>::provide_ref_with(self, context.into())
+    }
+}
+
+impl<'me, T, U, C> TryProvideRefWith<'me, T, Option<C>> for U
+where
+    U: TryProvideRefWith<'me, T, Empty> + TryProvideRefWith<'me, T, C> + ?Sized,
+{
+    type Error = Either<
+        <U as TryProvideRefWith<'me, T, Empty>>::Error,
+        <U as TryProvideRefWith<'me, T, C>>::Error,
+    >;
+
+    fn try_provide_ref_with(&'me self, context: Option<C>) -> Result<T, Self::Error> {
+        <U as TryProvideRefWith<'me, T, Either<Empty, C>>>::try_provide_ref_with(
+            self,
+            context.into(),
+        )
+    }
+}
+
+impl<'me, T, U, C> ProvideMutWith<'me, T, Option<C>> for U
+where
+    U: ProvideMutWith<'me, T, Empty> + ProvideMutWith<'me, T, C> + ?Sized,
+{
+    fn provide_mut_with(&'me mut self, context: Option<C>) -> T {
+        <U as ProvideMutWith<'me, T, Either<Empty, C>>>::provide_mut_with(self, context.into())
+    }
+}
+
+impl<'me, T, U, C> TryProvideMutWith<'me, T, Option<C>> for U
+where
+    U: TryProvideMutWith<'me, T, Empty> + TryProvideMutWith<'me, T, C> + ?Sized,
+{
+    type Error = Either<
+        <U as TryProvideMutWith<'me, T, Empty>>::Error,
+        <U as TryProvideMutWith<'me, T, C>>::Error,
+    >;
+
+    fn try_provide_mut_with(&'me mut self, context: Option<C>) -> Result<T, Self::Error> {
+        <U as TryProvideMutWith<'me, T, Either<Empty, C>>>::try_provide_mut_with(
+            self,
+            context.into(),
+        )
+    }
+}
+
+impl<L, R> Display for Either<L, R>
+where
+    L: Display,
+    R: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Left(left) => Display::fmt(left, f),
+            Self::Right(right) => Display::fmt(right, f),
+        }
+    }
+}
+
+impl<L, R> Error for Either<L, R>
+where
+    L: Error,
+    R: Error,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Left(left) => left.source(),
+            Self::Right(right) => right.source(),
+        }
+    }
+}
+
+/// Context which provides [either](Either) the left dependency with the left context,
+/// or the right dependency with the right context if provision of the left one fails.
+///
+/// This allows to provide one of two interchangeable dependencies,
+/// such as one of two backends which could be held by the provider.
+/// Error of the left provision is discarded, so error of this context
+/// is the error of the right provision.
+///
+/// Like [`OrElse`](super::or_else::OrElse), this context is implemented only
+/// for fallible provision by [shared reference](crate::TryProvideRef),
+/// because provision by value or by unique reference cannot be retried.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::{
+///         either::{Either, EitherDependency},
+///         Context,
+///     },
+///     with::TryProvideRefWith,
+///     TryProvideRef,
+/// };
+///
+/// struct Memory;
+///
+/// struct Disk;
+///
+/// struct Provider {
+///     memory: Option<Memory>,
+///     disk: Disk,
+/// }
+///
+/// impl<'me> TryProvideRef<'me, &'me Memory> for Provider {
+///     type Error = &'static str;
+///
+///     fn try_provide_ref(&'me self) -> Result<&'me Memory, Self::Error> {
+///         let Self { memory, .. } = self;
+///         memory.as_ref().ok_or("memory storage is not available")
+///     }
+/// }
+///
+/// impl AsRef<Disk> for Provider {
+///     fn as_ref(&self) -> &Disk {
+///         let Self { disk, .. } = self;
+///         disk
+///     }
+/// }
+///
+/// let provider = Provider { memory: None, disk: Disk };
+///
+/// let storage: Result<Either<&Memory, &Disk>, _> =
+///     provider.try_provide_ref_with(EitherDependency::new((), ()));
+/// assert!(matches!(storage, Ok(Either::Right(Disk))));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EitherDependency<L, R> {
+    left: L,
+    right: R,
+}
+
+impl<L, R> EitherDependency<L, R> {
+    /// Creates new context from the contexts to provide the left and the right dependency with.
+    pub const fn new(left: L, right: R) -> Self {
+        Self { left, right }
+    }
+
+    /// Returns the contexts to provide the left and the right dependency with.
+    pub fn into_inner(self) -> (L, R) {
+        let Self { left, right } = self;
+        (left, right)
+    }
+}
+
+impl<L, R> Context for EitherDependency<L, R> {}
+
+impl<L, R> Depth for EitherDependency<L, R>
+where
+    L: Depth,
+    R: Depth,
+{
+    const DEPTH: usize = max_depth(L::DEPTH, R::DEPTH) + 1;
+}
+
+impl<'me, A, B, U, L, R> TryProvideRefWith<'me, Either<A, B>, EitherDependency<L, R>> for U
+where
+    U: TryProvideRefWith<'me, A, L> + TryProvideRefWith<'me, B, R> + ?Sized,
+{
+    type Error = <U as TryProvideRefWith<'me, B, R>>::Error;
+
+    fn try_provide_ref_with(
+        &'me self,
+        context: EitherDependency<L, R>,
+    ) -> Result<Either<A, B>, Self::Error> {
+        let EitherDependency { left, right } = context;
+        match <U as TryProvideRefWith<'me, A, L>>::try_provide_ref_with(self, left) {
+            Ok(dependency) => Ok(Either::Left(dependency)),
+            Err(_) => <U as TryProvideRefWith<'me, B, R>>::try_provide_ref_with(self, right)
+                .map(Either::Right),
+        }
+    }
+}
+
+/// Context which selects the left or the right context by the condition known only at runtime,
+/// such as the feature flag.
+///
+/// Dependency of the same type is provided with the left context if the condition holds,
+/// or with the right context otherwise, exactly like with the [`Either`] context
+/// this context converts into. Remainders and errors are unified with [`Either`] as well.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::{either::SelectDependency, Context},
+///     with::ProvideRefWith,
+/// };
+///
+/// struct Config {
+///     retries: u32,
+/// }
+///
+/// impl AsRef<u32> for Config {
+///     fn as_ref(&self) -> &u32 {
+///         let Self { retries } = self;
+///         retries
+///     }
+/// }
+///
+/// let provider = Config { retries: 3 };
+///
+/// for (retries_enabled, expected) in [(true, Some(3)), (false, None)] {
+///     let context = SelectDependency::new(retries_enabled, ().then_some(), ().then_default());
+///     let retries: Option<&u32> = provider.provide_ref_with(context);
+///     assert_eq!(retries.copied(), expected);
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SelectDependency<L, R> {
+    condition: bool,
+    left: L,
+    right: R,
+}
+
+impl<L, R> SelectDependency<L, R> {
+    /// Creates new context from the condition
+    /// and the contexts to provide dependency with if it holds or not.
+    pub const fn new(condition: bool, left: L, right: R) -> Self {
+        Self {
+            condition,
+            left,
+            right,
+        }
+    }
+
+    /// Returns the condition and the contexts to provide dependency with if it holds or not.
+    pub fn into_inner(self) -> (bool, L, R) {
+        let Self {
+            condition,
+            left,
+            right,
+        } = self;
+        (condition, left, right)
+    }
+}
+
+impl<L, R> Context for SelectDependency<L, R> {}
+
+impl<L, R> Depth for SelectDependency<L, R>
+where
+    L: Depth,
+    R: Depth,
+{
+    const DEPTH: usize = max_depth(L::DEPTH, R::DEPTH) + 1;
+}
+
+impl<L, R> From<SelectDependency<L, R>> for Either<L, R> {
+    fn from(context: SelectDependency<L, R>) -> Self {
+        let SelectDependency {
+            condition,
+            left,
+            right,
+        } = context;
+        if condition {
+            Self::Left(left)
+        } else {
+            Self::Right(right)
+        }
+    }
+}
+
+impl<T, U, L, R> ProvideWith<T, SelectDependency<L, R>> for U
+where
+    U: ProvideWith<T, L> + ProvideWith<T, R>,
+{
+    type Remainder =
+        Either<<U as ProvideWith<T, L>>::Remainder, <U as ProvideWith<T, R>>::Remainder>;
+
+    fn provide_with(self, context: SelectDependency<L, R>) -> (T, Self::Remainder) {
+        <U as ProvideWith<T, Either<L, R>>>::provide_with(self, context.into())
+    }
+}
+
+impl<T, U, L, R> TryProvideWith<T, SelectDependency<L, R>> for U
+where
+    U: TryProvideWith<T, L> + TryProvideWith<T, R>,
+{
+    type Remainder =
+        Either<<U as TryProvideWith<T, L>>::Remainder, <U as TryProvideWith<T, R>>::Remainder>;
+
+    type Error = Either<<U as TryProvideWith<T, L>>::Error, <U as TryProvideWith<T, R>>::Error>;
+
+    fn try_provide_with(
+        self,
+        context: SelectDependency<L, R>,
+    ) -> Result<(T, Self::Remainder), Self::Error> {
+        <U as TryProvideWith<T, Either<L, R>>>::try_provide_with(self, context.into())
+    }
+}
+
+impl<'me, T, U, L, R> ProvideRefWith<'me, T, SelectDependency<L, R>> for U
+where
+    U: ProvideRefWith<'me, T, L> + ProvideRefWith<'me, T, R> + ?Sized,
+{
+    fn provide_ref_with(&'me self, context: SelectDependency<L, R>) -> T {
+        <U as ProvideRefWith<'me, T, Either<L, R>>>::provide_ref_with(self, context.into())
+    }
+}
+
+impl<'me, T, U, L, R> TryProvideRefWith<'me, T, SelectDependency<L, R>> for U
+where
+    U: TryProvideRefWith<'me, T, L> + TryProvideRefWith<'me, T, R> + ?Sized,
+{
+    type Error = Either<
+        <U as TryProvideRefWith<'me, T, L>>::Error,
+        <U as TryProvideRefWith<'me, T, R>>::Error,
+    >;
+
+    fn try_provide_ref_with(&'me self, context: SelectDependency<L, R>) -> Result<T, Self::Error> {
+        <U as TryProvideRefWith<'me, T, Either<L, R>>>::try_provide_ref_with(self, context.into())
+    }
+}
+
+impl<'me, T, U, L, R> ProvideMutWith<'me, T, SelectDependency<L, R>> for U
+where
+    U: ProvideMutWith<'me, T, L> + ProvideMutWith<'me, T, R> + ?Sized,
+{
+    fn provide_mut_with(&'me mut self, context: SelectDependency<L, R>) -> T {
+        <U as ProvideMutWith<'me, T, Either<L, R>>>::provide_mut_with(self, context.into())
+    }
+}
+
+impl<'me, T, U, L, R> TryProvideMutWith<'me, T, SelectDependency<L, R>> for U
+where
+    U: TryProvideMutWith<'me, T, L> + TryProvideMutWith<'me, T, R> + ?Sized,
+{
+    type Error = Either<
+        <U as TryProvideMutWith<'me, T, L>>::Error,
+        <U as TryProvideMutWith<'me, T, R>>::Error,
+    >;
+
+    fn try_provide_mut_with(
+        &'me mut self,
+        context: SelectDependency<L, R>,
+    ) -> Result<T, Self::Error> {
+        <U as TryProvideMutWith<'me, T, Either<L, R>>>::try_provide_mut_with(self, context.into())
+    }
+}
@@ -0,0 +1,216 @@
+//! Contexts which provide dependency from the environment variables of the current process.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+use std::{
+    env::{self, VarError},
+    ffi::OsString,
+};
+
+use super::{default::DefaultDependency, depth::Depth, Context};
+use crate::with::{TryProvideMutWith, TryProvideRefWith};
+
+/// Context which provides dependency by parsing the value of the environment variable
+/// with the carried key, falling back to the inner context if the variable is not set.
+///
+/// This context layers configuration sources: the environment variable takes precedence,
+/// and the dependency is provided with the inner context only if the variable is not present.
+/// If the variable is present but cannot be parsed, provision fails
+/// instead of silently falling back.
+///
+/// See [`FromEnvOrDefault`] for the most common layering of the environment variable
+/// over the [default](Default) value of the dependency.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{context::env::FromEnvOrWith, with::TryProvideRefWith, ProvideRef};
+///
+/// struct Provider {
+///     port: u16,
+/// }
+///
+/// impl<'me> ProvideRef<'me, u16> for Provider {
+///     fn provide_ref(&'me self) -> u16 {
+///         let Self { port } = self;
+///         *port
+///     }
+/// }
+///
+/// let provider = Provider { port: 8080 };
+/// let context = FromEnvOrWith::new("EXAMPLE_PORT", ());
+///
+/// let port: Result<u16, _> = provider.try_provide_ref_with(context);
+/// assert_eq!(port, Ok(8080));
+///
+/// std::env::set_var("EXAMPLE_PORT", "443");
+/// let port: Result<u16, _> = provider.try_provide_ref_with(context);
+/// assert_eq!(port, Ok(443));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FromEnvOrWith<C> {
+    key: &'static str,
+    context: C,
+}
+
+/// Context which provides dependency by parsing the value of the environment variable
+/// with the carried key, falling back to the [default](Default) value of the dependency
+/// if the variable is not set.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{context::env::FromEnvOrDefault, with::TryProvideRefWith};
+///
+/// struct Provider;
+///
+/// let workers: Result<usize, _> =
+///     Provider.try_provide_ref_with(FromEnvOrDefault::new("EXAMPLE_WORKERS", Default::default()));
+/// assert_eq!(workers, Ok(0));
+///
+/// std::env::set_var("EXAMPLE_WORKERS", "4");
+/// let workers: Result<usize, _> =
+///     Provider.try_provide_ref_with(FromEnvOrDefault::new("EXAMPLE_WORKERS", Default::default()));
+/// assert_eq!(workers, Ok(4));
+/// ```
+pub type FromEnvOrDefault = FromEnvOrWith<DefaultDependency>;
+
+impl<C> FromEnvOrWith<C> {
+    /// Creates new context from the key of the environment variable
+    /// and the context to provide dependency with if the variable is not set.
+    pub const fn new(key: &'static str, context: C) -> Self {
+        Self { key, context }
+    }
+
+    /// Returns the key of the environment variable this context reads.
+    pub const fn key(&self) -> &'static str {
+        self.key
+    }
+
+    /// Returns the key of the environment variable and the inner context.
+    pub fn into_inner(self) -> (&'static str, C) {
+        let Self { key, context } = self;
+        (key, context)
+    }
+
+    fn parse<T, E>(key: &'static str) -> Option<Result<T, FromEnvError<E, T::Err>>>
+    where
+        T: FromStr,
+    {
+        let value = match env::var(key) {
+            Ok(value) => value,
+            Err(VarError::NotPresent) => return None,
+            Err(VarError::NotUnicode(value)) => {
+                return Some(Err(FromEnvError::NotUnicode { key, value }))
+            }
+        };
+        let result = value
+            .parse()
+            .map_err(|error| FromEnvError::Parse { key, error });
+        Some(result)
+    }
+}
+
+impl<C> Context for FromEnvOrWith<C> {}
+
+impl<C> Depth for FromEnvOrWith<C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<'me, T, U, C> TryProvideRefWith<'me, T, FromEnvOrWith<C>> for U
+where
+    T: FromStr,
+    U: TryProvideRefWith<'me, T, C> + ?Sized,
+{
+    type Error = FromEnvError<U::Error, T::Err>;
+
+    fn try_provide_ref_with(&'me self, context: FromEnvOrWith<C>) -> Result<T, Self::Error> {
+        let FromEnvOrWith { key, context } = context;
+        if let Some(result) = FromEnvOrWith::<C>::parse(key) {
+            return result;
+        }
+        <U as TryProvideRefWith<'me, T, C>>::try_provide_ref_with(self, context)
+            .map_err(FromEnvError::Provide)
+    }
+}
+
+impl<'me, T, U, C> TryProvideMutWith<'me, T, FromEnvOrWith<C>> for U
+where
+    T: FromStr,
+    U: TryProvideMutWith<'me, T, C> + ?Sized,
+{
+    type Error = FromEnvError<U::Error, T::Err>;
+
+    fn try_provide_mut_with(&'me mut self, context: FromEnvOrWith<C>) -> Result<T, Self::Error> {
+        let FromEnvOrWith { key, context } = context;
+        if let Some(result) = FromEnvOrWith::<C>::parse(key) {
+            return result;
+        }
+        <U as TryProvideMutWith<'me, T, C>>::try_provide_mut_with(self, context)
+            .map_err(FromEnvError::Provide)
+    }
+}
+
+/// The error type returned when provision with [`FromEnvOrWith`] context fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FromEnvError<E, F> {
+    /// Environment variable is set, but its value is not valid unicode.
+    NotUnicode {
+        /// Key of the environment variable.
+        key: &'static str,
+        /// Value of the environment variable.
+        value: OsString,
+    },
+    /// Environment variable is set, but its value cannot be parsed into the dependency.
+    Parse {
+        /// Key of the environment variable.
+        key: &'static str,
+        /// Error which occurred while parsing the value.
+        error: F,
+    },
+    /// Environment variable is not set, and the inner context failed to provide dependency.
+    Provide(E),
+}
+
+impl<E, F> Display for FromEnvError<E, F>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotUnicode { key, .. } => {
+                write!(
+                    f,
+                    "value of environment variable `{key}` is not valid unicode"
+                )
+            }
+            Self::Parse { key, .. } => {
+                write!(f, "failed to parse value of environment variable `{key}`")
+            }
+            Self::Provide(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl<E, F> Error for FromEnvError<E, F>
+where
+    E: Error,
+    F: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::NotUnicode { .. } => None,
+            Self::Parse { error, .. } => Some(error),
+            Self::Provide(error) => error.source(),
+        }
+    }
+}
@@ -0,0 +1,299 @@
+//! Contexts which inject errors into the fallible provision from the [failpoint](Failpoint) provider.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
+
+use crate::{
+    context::{depth::Depth, Context, Empty},
+    failpoint::{Failpoint, FailpointError},
+    name::type_name_of,
+    with::{TryProvideMutWith, TryProvideRefWith, TryProvideWith},
+};
+
+/// Context which provides dependency from the [failpoint](Failpoint) provider
+/// only until the carried count of successful provisions is reached,
+/// failing with [injected](FailpointError::Injected) error after that.
+///
+/// Dependency itself is provided from the inner provider with the inner context.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::failpoint::FailAfter,
+///     failpoint::{Failpoint, FailpointError},
+///     with::TryProvideRefWith,
+/// };
+///
+/// let provider = Failpoint::new(Box::new(1));
+///
+/// let never: Result<&i32, _> = provider.try_provide_ref_with(FailAfter::new(0, ()));
+/// assert_eq!(never, Err(FailpointError::Injected));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FailAfterWith<C> {
+    limit: usize,
+    context: C,
+}
+
+/// Context which provides dependency from the [failpoint](Failpoint) provider
+/// only until the carried count of successful provisions is reached.
+///
+/// Dependency itself is provided from the inner provider with [empty context](Empty).
+pub type FailAfter = FailAfterWith<Empty>;
+
+impl<C> FailAfterWith<C> {
+    /// Creates new context from the count of successful provisions before failing
+    /// and the context to provide dependency from the inner provider with.
+    pub const fn new(limit: usize, context: C) -> Self {
+        Self { limit, context }
+    }
+
+    /// Returns the count of successful provisions before failing.
+    pub const fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Returns the count of successful provisions before failing and the inner context.
+    pub fn into_inner(self) -> (usize, C) {
+        let Self { limit, context } = self;
+        (limit, context)
+    }
+}
+
+impl<C> Context for FailAfterWith<C> {}
+
+impl<C> Depth for FailAfterWith<C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+/// Context which provides dependency from the [failpoint](Failpoint) provider
+/// unless it is of type `F`, failing with [injected](FailpointError::Injected) error otherwise.
+///
+/// Types are compared by their [names](core::any::type_name), so lifetimes are not taken into account:
+/// for example, `FailFor<&str>` fails provision of `&'a str` for any lifetime `'a`.
+///
+/// Dependency itself is provided from the inner provider with the inner context.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::failpoint::FailFor,
+///     failpoint::{Failpoint, FailpointError},
+///     with::TryProvideRefWith,
+/// };
+///
+/// let provider = Failpoint::new(String::from("hello"));
+///
+/// let text: Result<&str, _> = provider.try_provide_ref_with(FailFor::<&[u8]>::default());
+/// assert_eq!(text, Ok("hello"));
+///
+/// let bytes: Result<&[u8], _> = provider.try_provide_ref_with(FailFor::<&[u8]>::default());
+/// assert_eq!(bytes, Err(FailpointError::Injected));
+/// ```
+pub struct FailForWith<F, C>
+where
+    F: ?Sized,
+{
+    context: C,
+    marker: PhantomData<F>,
+}
+
+/// Context which provides dependency from the [failpoint](Failpoint) provider
+/// unless it is of type `F`.
+///
+/// Dependency itself is provided from the inner provider with [empty context](Empty).
+pub type FailFor<F> = FailForWith<F, Empty>;
+
+impl<F, C> FailForWith<F, C>
+where
+    F: ?Sized,
+{
+    /// Creates new context from the context to provide dependency from the inner provider with.
+    pub const fn new(context: C) -> Self {
+        Self {
+            context,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self { context, .. } = self;
+        context
+    }
+}
+
+impl<F, C> Debug for FailForWith<F, C>
+where
+    F: ?Sized,
+    C: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { context, .. } = self;
+        f.debug_struct("FailForWith")
+            .field("type", &type_name_of::<F>())
+            .field("context", context)
+            .finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F, C> defmt::Format for FailForWith<F, C>
+where
+    F: ?Sized,
+    C: defmt::Format,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        let Self { context, .. } = self;
+        defmt::write!(
+            f,
+            "FailForWith {{ type: {}, context: {} }}",
+            type_name_of::<F>(),
+            context,
+        );
+    }
+}
+
+impl<F, C> Default for FailForWith<F, C>
+where
+    F: ?Sized,
+    C: Default,
+{
+    fn default() -> Self {
+        Self::new(C::default())
+    }
+}
+
+impl<F, C> Clone for FailForWith<F, C>
+where
+    F: ?Sized,
+    C: Clone,
+{
+    fn clone(&self) -> Self {
+        let Self { context, .. } = self;
+        Self::new(context.clone())
+    }
+}
+
+impl<F, C> Copy for FailForWith<F, C>
+where
+    F: ?Sized,
+    C: Copy,
+{
+}
+
+impl<F, C> Context for FailForWith<F, C> where F: ?Sized {}
+
+impl<F, C> Depth for FailForWith<F, C>
+where
+    F: ?Sized,
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<T, P, C> TryProvideWith<T, FailAfterWith<C>> for Failpoint<P>
+where
+    P: TryProvideWith<T, C>,
+{
+    type Remainder = P::Remainder;
+
+    type Error = FailpointError<P::Error>;
+
+    fn try_provide_with(
+        self,
+        context: FailAfterWith<C>,
+    ) -> Result<(T, Self::Remainder), Self::Error> {
+        let FailAfterWith { limit, context } = context;
+        self.check_after(limit)?;
+        self.into_inner()
+            .try_provide_with(context)
+            .map_err(FailpointError::Provide)
+    }
+}
+
+impl<'me, T, P, C> TryProvideRefWith<'me, T, FailAfterWith<C>> for Failpoint<P>
+where
+    P: TryProvideRefWith<'me, T, C>,
+{
+    type Error = FailpointError<P::Error>;
+
+    fn try_provide_ref_with(&'me self, context: FailAfterWith<C>) -> Result<T, Self::Error> {
+        let FailAfterWith { limit, context } = context;
+        self.check_after(limit)?;
+        let (provider, counter) = self.provider();
+        counter.count(provider.try_provide_ref_with(context))
+    }
+}
+
+impl<'me, T, P, C> TryProvideMutWith<'me, T, FailAfterWith<C>> for Failpoint<P>
+where
+    P: TryProvideMutWith<'me, T, C>,
+{
+    type Error = FailpointError<P::Error>;
+
+    fn try_provide_mut_with(&'me mut self, context: FailAfterWith<C>) -> Result<T, Self::Error> {
+        let FailAfterWith { limit, context } = context;
+        self.check_after(limit)?;
+        let (provider, counter) = self.provider_mut();
+        counter.count(provider.try_provide_mut_with(context))
+    }
+}
+
+impl<T, F, P, C> TryProvideWith<T, FailForWith<F, C>> for Failpoint<P>
+where
+    F: ?Sized,
+    P: TryProvideWith<T, C>,
+{
+    type Remainder = P::Remainder;
+
+    type Error = FailpointError<P::Error>;
+
+    fn try_provide_with(
+        self,
+        context: FailForWith<F, C>,
+    ) -> Result<(T, Self::Remainder), Self::Error> {
+        self.check_for::<T, F>()?;
+        self.into_inner()
+            .try_provide_with(context.into_inner())
+            .map_err(FailpointError::Provide)
+    }
+}
+
+impl<'me, T, F, P, C> TryProvideRefWith<'me, T, FailForWith<F, C>> for Failpoint<P>
+where
+    F: ?Sized,
+    P: TryProvideRefWith<'me, T, C>,
+{
+    type Error = FailpointError<P::Error>;
+
+    fn try_provide_ref_with(&'me self, context: FailForWith<F, C>) -> Result<T, Self::Error> {
+        self.check_for::<T, F>()?;
+        let (provider, counter) = self.provider();
+        counter.count(provider.try_provide_ref_with(context.into_inner()))
+    }
+}
+
+impl<'me, T, F, P, C> TryProvideMutWith<'me, T, FailForWith<F, C>> for Failpoint<P>
+where
+    F: ?Sized,
+    P: TryProvideMutWith<'me, T, C>,
+{
+    type Error = FailpointError<P::Error>;
+
+    fn try_provide_mut_with(&'me mut self, context: FailForWith<F, C>) -> Result<T, Self::Error> {
+        self.check_for::<T, F>()?;
+        let (provider, counter) = self.provider_mut();
+        counter.count(provider.try_provide_mut_with(context.into_inner()))
+    }
+}
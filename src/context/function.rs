@@ -0,0 +1,261 @@
+//! Contexts which provide dependency by calling a function with another provided dependency.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
+
+use super::{depth::Depth, Context, Empty};
+use crate::{
+    name::type_name_of,
+    with::{
+        ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideMutWith, TryProvideRefWith,
+        TryProvideWith,
+    },
+};
+
+/// Context which provides dependency by calling the carried function
+/// with the dependency of type `D` provided with the inner context.
+///
+/// This allows to construct dependency out of other dependencies of the provider
+/// right at the call site, without implementing provider traits for it.
+/// Also, it allows to convert dependencies into types of other crates
+/// without implementing [`From`] trait for them, which orphan rules may forbid.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{context::Context, with::ProvideRefWith};
+///
+/// struct Db {
+///     url: String,
+/// }
+///
+/// struct Service<'a> {
+///     db: &'a Db,
+/// }
+///
+/// struct Provider {
+///     db: Db,
+/// }
+///
+/// impl AsRef<Db> for Provider {
+///     fn as_ref(&self) -> &Db {
+///         let Self { db } = self;
+///         db
+///     }
+/// }
+///
+/// let provider = Provider {
+///     db: Db { url: String::from("sqlite::memory:") },
+/// };
+///
+/// let service: Service = provider.provide_ref_with(().then_map_new(|db| Service { db }));
+/// assert_eq!(service.db.url, "sqlite::memory:");
+///
+/// let service: Option<Service> = provider.provide_ref_with(
+///     ().then_some().then_map_new(|db: Option<_>| db.map(|db| Service { db })),
+/// );
+/// assert!(service.is_some());
+///
+/// let len: usize = provider.provide_ref_with(().then_map_new(|db: &Db| db.url.len()));
+/// assert_eq!(len, 15);
+/// ```
+pub struct FnDependencyWith<F, D, C> {
+    function: F,
+    context: C,
+    marker: PhantomData<fn(D)>,
+}
+
+/// Context which provides dependency by calling the carried function
+/// with the dependency of type `D` provided with [empty context](Empty).
+pub type FnDependency<F, D> = FnDependencyWith<F, D, Empty>;
+
+impl<F, D, C> FnDependencyWith<F, D, C> {
+    /// Creates new context from the function to construct dependency with
+    /// and the context to provide its argument with.
+    pub const fn new<T>(function: F, context: C) -> Self
+    where
+        F: FnOnce(D) -> T,
+    {
+        Self {
+            function,
+            context,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the function to construct dependency with
+    /// and the context to provide its argument with.
+    pub fn into_inner(self) -> (F, C) {
+        let Self {
+            function, context, ..
+        } = self;
+        (function, context)
+    }
+}
+
+impl<F, D, C> Debug for FnDependencyWith<F, D, C>
+where
+    C: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { context, .. } = self;
+        f.debug_struct("FnDependencyWith")
+            .field("function", &type_name_of::<F>())
+            .field("type", &type_name_of::<D>())
+            .field("context", context)
+            .finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F, D, C> defmt::Format for FnDependencyWith<F, D, C>
+where
+    C: defmt::Format,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        let Self { context, .. } = self;
+        defmt::write!(
+            f,
+            "FnDependencyWith {{ function: {}, type: {}, context: {} }}",
+            type_name_of::<F>(),
+            type_name_of::<D>(),
+            context,
+        );
+    }
+}
+
+impl<F, D, C> Clone for FnDependencyWith<F, D, C>
+where
+    F: Clone,
+    C: Clone,
+{
+    fn clone(&self) -> Self {
+        let Self {
+            function, context, ..
+        } = self;
+        Self {
+            function: function.clone(),
+            context: context.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<F, D, C> Copy for FnDependencyWith<F, D, C>
+where
+    F: Copy,
+    C: Copy,
+{
+}
+
+impl<F, D, C> Context for FnDependencyWith<F, D, C> {}
+
+impl<F, D, C> Depth for FnDependencyWith<F, D, C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<T, D, U, F, C> ProvideWith<T, FnDependencyWith<F, D, C>> for U
+where
+    F: FnOnce(D) -> T,
+    U: ProvideWith<D, C>,
+{
+    type Remainder = U::Remainder;
+
+    fn provide_with(self, context: FnDependencyWith<F, D, C>) -> (T, Self::Remainder) {
+        let FnDependencyWith {
+            function, context, ..
+        } = context;
+        let (dependency, remainder) = self.provide_with(context);
+        (function(dependency), remainder)
+    }
+}
+
+impl<T, D, U, F, C> TryProvideWith<T, FnDependencyWith<F, D, C>> for U
+where
+    F: FnOnce(D) -> T,
+    U: TryProvideWith<D, C>,
+{
+    type Remainder = U::Remainder;
+
+    type Error = U::Error;
+
+    fn try_provide_with(
+        self,
+        context: FnDependencyWith<F, D, C>,
+    ) -> Result<(T, Self::Remainder), Self::Error> {
+        let FnDependencyWith {
+            function, context, ..
+        } = context;
+        let (dependency, remainder) = self.try_provide_with(context)?;
+        Ok((function(dependency), remainder))
+    }
+}
+
+impl<'me, T, D, U, F, C> ProvideRefWith<'me, T, FnDependencyWith<F, D, C>> for U
+where
+    F: FnOnce(D) -> T,
+    U: ProvideRefWith<'me, D, C> + ?Sized,
+{
+    fn provide_ref_with(&'me self, context: FnDependencyWith<F, D, C>) -> T {
+        let FnDependencyWith {
+            function, context, ..
+        } = context;
+        function(self.provide_ref_with(context))
+    }
+}
+
+impl<'me, T, D, U, F, C> TryProvideRefWith<'me, T, FnDependencyWith<F, D, C>> for U
+where
+    F: FnOnce(D) -> T,
+    U: TryProvideRefWith<'me, D, C> + ?Sized,
+{
+    type Error = U::Error;
+
+    fn try_provide_ref_with(
+        &'me self,
+        context: FnDependencyWith<F, D, C>,
+    ) -> Result<T, Self::Error> {
+        let FnDependencyWith {
+            function, context, ..
+        } = context;
+        self.try_provide_ref_with(context).map(function)
+    }
+}
+
+impl<'me, T, D, U, F, C> ProvideMutWith<'me, T, FnDependencyWith<F, D, C>> for U
+where
+    F: FnOnce(D) -> T,
+    U: ProvideMutWith<'me, D, C> + ?Sized,
+{
+    fn provide_mut_with(&'me mut self, context: FnDependencyWith<F, D, C>) -> T {
+        let FnDependencyWith {
+            function, context, ..
+        } = context;
+        function(self.provide_mut_with(context))
+    }
+}
+
+impl<'me, T, D, U, F, C> TryProvideMutWith<'me, T, FnDependencyWith<F, D, C>> for U
+where
+    F: FnOnce(D) -> T,
+    U: TryProvideMutWith<'me, D, C> + ?Sized,
+{
+    type Error = U::Error;
+
+    fn try_provide_mut_with(
+        &'me mut self,
+        context: FnDependencyWith<F, D, C>,
+    ) -> Result<T, Self::Error> {
+        let FnDependencyWith {
+            function, context, ..
+        } = context;
+        self.try_provide_mut_with(context).map(function)
+    }
+}
@@ -0,0 +1,45 @@
+//! Context which builds nodes of the [graph](crate::graph::Graph) anew.
+//!
+//! See [crate] documentation for more.
+
+use super::{depth::Depth, Context};
+
+/// Context which provides new instance of the node of the [graph](crate::graph::Graph),
+/// building it out of the nodes it depends on, held by the graph.
+///
+/// Provider traits with this context are implemented
+/// by the [`ordered_graph!`](crate::ordered_graph!) macro, so this context has no inner context.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{context::graph::Rebuild, graph::Graph, ordered_graph, with::ProvideRefWith};
+///
+/// struct Config {
+///     retries: u32,
+/// }
+///
+/// struct Client {
+///     retries: u32,
+/// }
+///
+/// ordered_graph! {
+///     struct App {
+///         client: Client = (config) => Client { retries: config.retries },
+///         config: Config = () => Config { retries: 3 },
+///     }
+/// }
+///
+/// let app = App::build();
+/// let client: Client = app.provide_ref_with(Rebuild);
+/// assert_eq!(client.retries, 3);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Rebuild;
+
+impl Context for Rebuild {}
+
+impl Depth for Rebuild {
+    const DEPTH: usize = 1;
+}
@@ -0,0 +1,170 @@
+//! Context which observes provided dependency without changing it.
+//!
+//! See [crate] documentation for more.
+
+use super::{depth::Depth, Context, Empty};
+use crate::with::{
+    ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideMutWith, TryProvideRefWith,
+    TryProvideWith,
+};
+
+/// Context which calls the carried function with shared reference
+/// to the dependency provided with the inner context, and then provides it unchanged.
+///
+/// This allows to add debugging or assertion hooks into long provision chains.
+/// Function is called only if provision with the inner context succeeds.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{context::Context, with::ProvideRefWith};
+///
+/// struct Provider {
+///     foo: i32,
+/// }
+///
+/// impl AsRef<i32> for Provider {
+///     fn as_ref(&self) -> &i32 {
+///         let Self { foo } = self;
+///         foo
+///     }
+/// }
+///
+/// let provider = Provider { foo: 1 };
+///
+/// let mut inspected = None;
+/// let dependency: Option<&i32> = provider
+///     .provide_ref_with(().then_some().then_inspect(|foo: &Option<&i32>| inspected = foo.copied()));
+/// assert_eq!(dependency, Some(&1));
+/// assert_eq!(inspected, Some(1));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct InspectDependencyWith<F, C> {
+    function: F,
+    context: C,
+}
+
+/// Context which calls the carried function with shared reference
+/// to the dependency provided with [empty context](Empty), and then provides it unchanged.
+pub type InspectDependency<F> = InspectDependencyWith<F, Empty>;
+
+impl<F, C> InspectDependencyWith<F, C> {
+    /// Creates new context from the function to call with the dependency
+    /// and the context to provide dependency with.
+    pub const fn new(function: F, context: C) -> Self {
+        Self { function, context }
+    }
+
+    /// Returns the function to call with the dependency and the inner context.
+    pub fn into_inner(self) -> (F, C) {
+        let Self { function, context } = self;
+        (function, context)
+    }
+}
+
+impl<F, C> Context for InspectDependencyWith<F, C> {}
+
+impl<F, C> Depth for InspectDependencyWith<F, C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<T, U, F, C> ProvideWith<T, InspectDependencyWith<F, C>> for U
+where
+    U: ProvideWith<T, C>,
+    F: FnOnce(&T),
+{
+    type Remainder = U::Remainder;
+
+    fn provide_with(self, context: InspectDependencyWith<F, C>) -> (T, Self::Remainder) {
+        let InspectDependencyWith { function, context } = context;
+        let (dependency, remainder) = <U as ProvideWith<T, C>>::provide_with(self, context);
+        function(&dependency);
+        (dependency, remainder)
+    }
+}
+
+impl<T, U, F, C> TryProvideWith<T, InspectDependencyWith<F, C>> for U
+where
+    U: TryProvideWith<T, C>,
+    F: FnOnce(&T),
+{
+    type Remainder = U::Remainder;
+
+    type Error = U::Error;
+
+    fn try_provide_with(
+        self,
+        context: InspectDependencyWith<F, C>,
+    ) -> Result<(T, Self::Remainder), Self::Error> {
+        let InspectDependencyWith { function, context } = context;
+        let (dependency, remainder) = <U as TryProvideWith<T, C>>::try_provide_with(self, context)?;
+        function(&dependency);
+        Ok((dependency, remainder))
+    }
+}
+
+impl<'me, T, U, F, C> ProvideRefWith<'me, T, InspectDependencyWith<F, C>> for U
+where
+    U: ProvideRefWith<'me, T, C> + ?Sized,
+    F: FnOnce(&T),
+{
+    fn provide_ref_with(&'me self, context: InspectDependencyWith<F, C>) -> T {
+        let InspectDependencyWith { function, context } = context;
+        let dependency = <U as ProvideRefWith<'me, T, C>>::provide_ref_with(self, context);
+        function(&dependency);
+        dependency
+    }
+}
+
+impl<'me, T, U, F, C> TryProvideRefWith<'me, T, InspectDependencyWith<F, C>> for U
+where
+    U: TryProvideRefWith<'me, T, C> + ?Sized,
+    F: FnOnce(&T),
+{
+    type Error = U::Error;
+
+    fn try_provide_ref_with(
+        &'me self,
+        context: InspectDependencyWith<F, C>,
+    ) -> Result<T, Self::Error> {
+        let InspectDependencyWith { function, context } = context;
+        let dependency = <U as TryProvideRefWith<'me, T, C>>::try_provide_ref_with(self, context)?;
+        function(&dependency);
+        Ok(dependency)
+    }
+}
+
+impl<'me, T, U, F, C> ProvideMutWith<'me, T, InspectDependencyWith<F, C>> for U
+where
+    U: ProvideMutWith<'me, T, C> + ?Sized,
+    F: FnOnce(&T),
+{
+    fn provide_mut_with(&'me mut self, context: InspectDependencyWith<F, C>) -> T {
+        let InspectDependencyWith { function, context } = context;
+        let dependency = <U as ProvideMutWith<'me, T, C>>::provide_mut_with(self, context);
+        function(&dependency);
+        dependency
+    }
+}
+
+impl<'me, T, U, F, C> TryProvideMutWith<'me, T, InspectDependencyWith<F, C>> for U
+where
+    U: TryProvideMutWith<'me, T, C> + ?Sized,
+    F: FnOnce(&T),
+{
+    type Error = U::Error;
+
+    fn try_provide_mut_with(
+        &'me mut self,
+        context: InspectDependencyWith<F, C>,
+    ) -> Result<T, Self::Error> {
+        let InspectDependencyWith { function, context } = context;
+        let dependency = <U as TryProvideMutWith<'me, T, C>>::try_provide_mut_with(self, context)?;
+        function(&dependency);
+        Ok(dependency)
+    }
+}
@@ -0,0 +1,240 @@
+//! Context which provides dependencies from [intercepted](Intercepted) providers.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    convert::Infallible,
+    error::Error,
+    fmt::{self, Display},
+};
+
+use crate::{
+    context::{depth::Depth, Context, Empty},
+    intercept::{Intercepted, Interceptor},
+    with::{
+        ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideMutWith, TryProvideRefWith,
+        TryProvideWith,
+    },
+};
+
+/// Context which provides dependency from the [intercepted](Intercepted) provider,
+/// running its [interceptor](Interceptor) around the provision.
+///
+/// Dependency itself is provided from the inner provider with the inner context,
+/// so the interceptor runs around provision by value, by reference and fallible provision alike.
+/// Both [`before`](Interceptor::before) and [`after`](Interceptor::after) methods
+/// are called with the type of provided dependency, even if the provision has failed.
+///
+/// Interceptor can deny the provision by returning an error from [`before`](Interceptor::before) method,
+/// in which case the dependency is not provided from the inner provider
+/// and [`after`](Interceptor::after) method is not called.
+/// Fallible provision reports the denial with [`InterceptError::Denied`] error,
+/// while infallible provision is implemented only for interceptors which never deny it.
+///
+/// # Examples
+///
+/// ```
+/// use std::{cell::Cell, convert::Infallible};
+///
+/// use provide::{
+///     context::{intercept::Intercept, Context},
+///     intercept::{Intercepted, Interceptor},
+///     with::{ProvideWith, TryProvideRefWith},
+/// };
+///
+/// #[derive(Default)]
+/// struct Counter {
+///     count: Cell<u32>,
+/// }
+///
+/// impl Interceptor for Counter {
+///     type Error = Infallible;
+///
+///     fn after<T: ?Sized>(&self) {
+///         self.count.set(self.count.get() + 1);
+///     }
+/// }
+///
+/// let provider = Intercepted::new(String::from("hello"), Counter::default());
+/// let result: Result<Option<&str>, _> = provider.try_provide_ref_with(().then_some().then_intercept());
+/// assert_eq!(result, Ok(Some("hello")));
+///
+/// let (dependency, remainder): (Box<str>, _) = provider.provide_with(Intercept::default());
+/// assert_eq!(&*dependency, "hello");
+///
+/// let ((), interceptor) = remainder.into_inner();
+/// assert_eq!(interceptor.count.get(), 2);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct InterceptWith<C>(C);
+
+/// Context which provides dependency from the [intercepted](Intercepted) provider,
+/// running its [interceptor](Interceptor) around the provision.
+///
+/// Dependency itself is provided from the inner provider with [empty context](Empty).
+pub type Intercept = InterceptWith<Empty>;
+
+impl<C> InterceptWith<C> {
+    /// Creates new context from the context to provide dependency from the inner provider with.
+    pub const fn new(context: C) -> Self {
+        Self(context)
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self(context) = self;
+        context
+    }
+}
+
+impl<C> Context for InterceptWith<C> {}
+
+impl<C> Depth for InterceptWith<C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<T, P, I, C> ProvideWith<T, InterceptWith<C>> for Intercepted<P, I>
+where
+    P: ProvideWith<T, C>,
+    I: Interceptor<Error = Infallible>,
+{
+    type Remainder = Intercepted<P::Remainder, I>;
+
+    fn provide_with(self, context: InterceptWith<C>) -> (T, Self::Remainder) {
+        let InterceptWith(context) = context;
+        let (provider, interceptor) = self.into_inner();
+        let Ok(()) = interceptor.before::<T>();
+        let (dependency, remainder) = provider.provide_with(context);
+        interceptor.after::<T>();
+        (dependency, Intercepted::new(remainder, interceptor))
+    }
+}
+
+impl<T, P, I, C> TryProvideWith<T, InterceptWith<C>> for Intercepted<P, I>
+where
+    P: TryProvideWith<T, C>,
+    I: Interceptor,
+{
+    type Remainder = Intercepted<P::Remainder, I>;
+
+    type Error = InterceptError<P::Error, I::Error>;
+
+    fn try_provide_with(
+        self,
+        context: InterceptWith<C>,
+    ) -> Result<(T, Self::Remainder), Self::Error> {
+        let InterceptWith(context) = context;
+        let (provider, interceptor) = self.into_inner();
+        interceptor.before::<T>().map_err(InterceptError::Denied)?;
+        let result = provider.try_provide_with(context);
+        interceptor.after::<T>();
+        let (dependency, remainder) = result.map_err(InterceptError::Provide)?;
+        Ok((dependency, Intercepted::new(remainder, interceptor)))
+    }
+}
+
+impl<'me, T, P, I, C> ProvideRefWith<'me, T, InterceptWith<C>> for Intercepted<P, I>
+where
+    P: ProvideRefWith<'me, T, C>,
+    I: Interceptor<Error = Infallible>,
+{
+    fn provide_ref_with(&'me self, context: InterceptWith<C>) -> T {
+        let InterceptWith(context) = context;
+        let (provider, interceptor) = self.parts();
+        let Ok(()) = interceptor.before::<T>();
+        let dependency = provider.provide_ref_with(context);
+        interceptor.after::<T>();
+        dependency
+    }
+}
+
+impl<'me, T, P, I, C> TryProvideRefWith<'me, T, InterceptWith<C>> for Intercepted<P, I>
+where
+    P: TryProvideRefWith<'me, T, C>,
+    I: Interceptor,
+{
+    type Error = InterceptError<P::Error, I::Error>;
+
+    fn try_provide_ref_with(&'me self, context: InterceptWith<C>) -> Result<T, Self::Error> {
+        let InterceptWith(context) = context;
+        let (provider, interceptor) = self.parts();
+        interceptor.before::<T>().map_err(InterceptError::Denied)?;
+        let result = provider.try_provide_ref_with(context);
+        interceptor.after::<T>();
+        result.map_err(InterceptError::Provide)
+    }
+}
+
+impl<'me, T, P, I, C> ProvideMutWith<'me, T, InterceptWith<C>> for Intercepted<P, I>
+where
+    P: ProvideMutWith<'me, T, C>,
+    I: Interceptor<Error = Infallible>,
+{
+    fn provide_mut_with(&'me mut self, context: InterceptWith<C>) -> T {
+        let InterceptWith(context) = context;
+        let (provider, interceptor) = self.parts_mut();
+        let Ok(()) = interceptor.before::<T>();
+        let dependency = provider.provide_mut_with(context);
+        interceptor.after::<T>();
+        dependency
+    }
+}
+
+impl<'me, T, P, I, C> TryProvideMutWith<'me, T, InterceptWith<C>> for Intercepted<P, I>
+where
+    P: TryProvideMutWith<'me, T, C>,
+    I: Interceptor,
+{
+    type Error = InterceptError<P::Error, I::Error>;
+
+    fn try_provide_mut_with(&'me mut self, context: InterceptWith<C>) -> Result<T, Self::Error> {
+        let InterceptWith(context) = context;
+        let (provider, interceptor) = self.parts_mut();
+        interceptor.before::<T>().map_err(InterceptError::Denied)?;
+        let result = provider.try_provide_mut_with(context);
+        interceptor.after::<T>();
+        result.map_err(InterceptError::Provide)
+    }
+}
+
+/// The error type returned when dependency could not be provided with [`InterceptWith`] context.
+///
+/// Error of the provision is displayed as is, while error of the interceptor
+/// is reported as the [source](Error::source) of this error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum InterceptError<E, D> {
+    /// Provision was denied by the interceptor.
+    Denied(D),
+    /// Dependency was not provided from the inner provider.
+    Provide(E),
+}
+
+impl<E, D> Display for InterceptError<E, D>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Denied(_) => f.write_str("provision of dependency was denied by the interceptor"),
+            Self::Provide(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl<E, D> Error for InterceptError<E, D>
+where
+    E: Error,
+    D: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Denied(error) => Some(error),
+            Self::Provide(error) => error.source(),
+        }
+    }
+}
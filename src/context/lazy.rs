@@ -0,0 +1,117 @@
+//! Context which provides dependency out of [lazy](Lazy) dependency.
+//!
+//! See [crate] documentation for more.
+
+use super::{depth::Depth, Context, Empty};
+use crate::{
+    lazy::{Lazy, LazyError},
+    with::{ProvideMutWith, ProvideRefWith, TryProvideMutWith, TryProvideRefWith},
+};
+
+/// Context which provides reference to the value of the [lazy](Lazy) dependency,
+/// constructing it on the first provision.
+///
+/// Lazy dependency itself is provided by reference with the inner context.
+/// Only lazy dependencies constructed with function pointers (which is the default)
+/// are supported, because the type of the function cannot be inferred
+/// from the type of the provided reference.
+///
+/// Provision panics if previous construction of the dependency has panicked
+/// or if the dependency is requested recursively while it is constructed,
+/// while fallible provision returns [poisoned](LazyError::Poisoned)
+/// or [reentrant](LazyError::Reentrant) error instead.
+///
+/// See [`Lazy`] for examples.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LazyDependencyWith<C>(C);
+
+/// Context which provides reference to the value of the [lazy](Lazy) dependency,
+/// constructing it on the first provision.
+///
+/// Lazy dependency itself is provided by reference with [empty context](Empty).
+pub type LazyDependency = LazyDependencyWith<Empty>;
+
+impl<C> LazyDependencyWith<C> {
+    /// Creates new context from the context to provide [lazy](Lazy) dependency with.
+    pub const fn new(context: C) -> Self {
+        Self(context)
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self(context) = self;
+        context
+    }
+}
+
+impl<C> Context for LazyDependencyWith<C> {}
+
+impl<C> Depth for LazyDependencyWith<C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<'me, T, U, C> ProvideRefWith<'me, &'me T, LazyDependencyWith<C>> for U
+where
+    T: 'me,
+    U: ProvideRefWith<'me, &'me Lazy<T>, C> + ?Sized,
+{
+    fn provide_ref_with(&'me self, context: LazyDependencyWith<C>) -> &'me T {
+        let LazyDependencyWith(context) = context;
+        let lazy = self.provide_ref_with(context);
+        lazy.force()
+    }
+}
+
+impl<'me, T, U, C> TryProvideRefWith<'me, &'me T, LazyDependencyWith<C>> for U
+where
+    T: 'me,
+    U: TryProvideRefWith<'me, &'me Lazy<T>, C> + ?Sized,
+{
+    type Error = LazyError<U::Error>;
+
+    fn try_provide_ref_with(
+        &'me self,
+        context: LazyDependencyWith<C>,
+    ) -> Result<&'me T, Self::Error> {
+        let LazyDependencyWith(context) = context;
+        let lazy = self
+            .try_provide_ref_with(context)
+            .map_err(LazyError::Provide)?;
+        lazy.try_force().map_err(LazyError::from)
+    }
+}
+
+impl<'me, T, U, C> ProvideMutWith<'me, &'me mut T, LazyDependencyWith<C>> for U
+where
+    T: 'me,
+    U: ProvideMutWith<'me, &'me mut Lazy<T>, C> + ?Sized,
+{
+    fn provide_mut_with(&'me mut self, context: LazyDependencyWith<C>) -> &'me mut T {
+        let LazyDependencyWith(context) = context;
+        let lazy = self.provide_mut_with(context);
+        lazy.force_mut()
+    }
+}
+
+impl<'me, T, U, C> TryProvideMutWith<'me, &'me mut T, LazyDependencyWith<C>> for U
+where
+    T: 'me,
+    U: TryProvideMutWith<'me, &'me mut Lazy<T>, C> + ?Sized,
+{
+    type Error = LazyError<U::Error>;
+
+    fn try_provide_mut_with(
+        &'me mut self,
+        context: LazyDependencyWith<C>,
+    ) -> Result<&'me mut T, Self::Error> {
+        let LazyDependencyWith(context) = context;
+        let lazy = self
+            .try_provide_mut_with(context)
+            .map_err(LazyError::Provide)?;
+        lazy.try_force_mut().map_err(LazyError::from)
+    }
+}
@@ -0,0 +1,22 @@
+//! Implementations for asynchronous lock types from [`async-lock`](::async_lock) crate.
+
+use ::async_lock::{Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::with::{
+    ProvideMutAsyncWith, ProvideRefAsyncWith, TryProvideMutAsyncWith, TryProvideMutWith,
+    TryProvideRefAsyncWith, TryProvideRefWith,
+};
+
+use super::{LockWith, ReadWith, TryLockWith, TryReadWith, TryWriteWith, WriteWith};
+
+impl_async_blocking!(LockWith, Mutex, MutexGuard, |mutex| mutex.lock());
+impl_non_blocking!(TryLockWith, Mutex, MutexGuard, |mutex| mutex.try_lock());
+
+impl_async_blocking!(ReadWith, RwLock, RwLockReadGuard, |rw_lock| rw_lock.read());
+impl_non_blocking!(TryReadWith, RwLock, RwLockReadGuard, |rw_lock| rw_lock
+    .try_read());
+
+impl_async_blocking!(WriteWith, RwLock, RwLockWriteGuard, |rw_lock| rw_lock
+    .write());
+impl_non_blocking!(TryWriteWith, RwLock, RwLockWriteGuard, |rw_lock| rw_lock
+    .try_write());
@@ -0,0 +1,784 @@
+//! Contexts which provide dependency by acquiring a lock of lock dependency.
+//!
+//! Implementations for lock types from the standard library
+//! are available with `std` feature enabled,
+//! and for lock types from [`parking_lot`](https://docs.rs/parking_lot) crate —
+//! with `parking_lot` feature enabled.
+//!
+//! Asynchronous lock types from [`tokio`](https://docs.rs/tokio)
+//! and [`async-lock`](https://docs.rs/async-lock) crates
+//! (with `tokio` and `async-lock` features enabled respectively)
+//! are locked by awaiting them through the asynchronous traits, such as
+//! `ProvideRefAsyncWith`, with blocking contexts.
+//! Contexts with `Try` prefix are implemented for them through the synchronous traits.
+//!
+//! Contexts with `Try` prefix never block: they report [`WouldBlock`] error
+//! through the fallible traits if the lock cannot be acquired right now.
+//! Locks from the standard library report `TryLockError` instead,
+//! which also describes poisoning of the lock. Fallible provision of such locks
+//! with blocking contexts reports poisoning with `LockError`.
+//! Contexts with `Try` prefix and `For` suffix block for at most the carried timeout:
+//! they report `TimedOut` error through the fallible traits if it has elapsed.
+//!
+//! Contexts with `Try` prefix provide lock dependency itself with the inner context
+//! through the fallible traits, too: errors of both the inner context and the lock acquisition
+//! are combined into [`TryAcquireError`].
+//!
+//! # Limitations
+//!
+//! Timed contexts are implemented only for lock types from `parking_lot` crate.
+//! The standard library provides no timed lock acquisition,
+//! so there are no such implementations for its lock types,
+//! and timed contexts are not available at all without `parking_lot` feature enabled.
+//!
+//! See [crate] documentation for more.
+
+#[cfg(feature = "parking_lot")]
+use core::time::Duration;
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use super::{depth::Depth, Context, Empty};
+
+/// Implements provider traits for the blocking context of some lock type.
+#[allow(unused_macros)]
+macro_rules! impl_blocking {
+    ($context:ident, $lock:ident, $guard:ident, |$arg:ident| $acquire:expr) => {
+        impl<'me, T, U, C> ProvideRefWith<'me, $guard<'me, T>, $context<C>> for U
+        where
+            T: ?Sized + 'me,
+            U: ProvideRefWith<'me, &'me $lock<T>, C> + ?Sized,
+        {
+            fn provide_ref_with(&'me self, context: $context<C>) -> $guard<'me, T> {
+                let $context(context) = context;
+                let $arg = self.provide_ref_with(context);
+                $acquire
+            }
+        }
+
+        impl<'me, T, U, C> TryProvideRefWith<'me, $guard<'me, T>, $context<C>> for U
+        where
+            T: ?Sized + 'me,
+            U: TryProvideRefWith<'me, &'me $lock<T>, C> + ?Sized,
+        {
+            type Error = U::Error;
+
+            fn try_provide_ref_with(
+                &'me self,
+                context: $context<C>,
+            ) -> Result<$guard<'me, T>, Self::Error> {
+                let $context(context) = context;
+                let $arg = self.try_provide_ref_with(context)?;
+                Ok($acquire)
+            }
+        }
+
+        impl<'me, T, U, C> ProvideMutWith<'me, $guard<'me, T>, $context<C>> for U
+        where
+            T: ?Sized + 'me,
+            U: ProvideMutWith<'me, &'me mut $lock<T>, C> + ?Sized,
+        {
+            fn provide_mut_with(&'me mut self, context: $context<C>) -> $guard<'me, T> {
+                let $context(context) = context;
+                let $arg = &*self.provide_mut_with(context);
+                $acquire
+            }
+        }
+
+        impl<'me, T, U, C> TryProvideMutWith<'me, $guard<'me, T>, $context<C>> for U
+        where
+            T: ?Sized + 'me,
+            U: TryProvideMutWith<'me, &'me mut $lock<T>, C> + ?Sized,
+        {
+            type Error = U::Error;
+
+            fn try_provide_mut_with(
+                &'me mut self,
+                context: $context<C>,
+            ) -> Result<$guard<'me, T>, Self::Error> {
+                let $context(context) = context;
+                let $arg = &*self.try_provide_mut_with(context)?;
+                Ok($acquire)
+            }
+        }
+    };
+}
+
+/// Implements provider traits for the non-blocking context of some lock type.
+///
+/// Provided expression must evaluate to `Option` of the lock guard.
+#[allow(unused_macros)]
+macro_rules! impl_non_blocking {
+    ($context:ident, $lock:ident, $guard:ident, |$arg:ident| $acquire:expr) => {
+        impl<'me, T, U, C> TryProvideRefWith<'me, $guard<'me, T>, $context<C>> for U
+        where
+            T: ?Sized + 'me,
+            U: TryProvideRefWith<'me, &'me $lock<T>, C> + ?Sized,
+        {
+            type Error =
+                $crate::context::lock::TryAcquireError<U::Error, $crate::context::lock::WouldBlock>;
+
+            fn try_provide_ref_with(
+                &'me self,
+                context: $context<C>,
+            ) -> Result<$guard<'me, T>, Self::Error> {
+                use $crate::context::lock::{TryAcquireError, WouldBlock};
+
+                let $context(context) = context;
+                let $arg = self
+                    .try_provide_ref_with(context)
+                    .map_err(TryAcquireError::Provide)?;
+                $acquire.ok_or(TryAcquireError::Acquire(WouldBlock))
+            }
+        }
+
+        impl<'me, T, U, C> TryProvideMutWith<'me, $guard<'me, T>, $context<C>> for U
+        where
+            T: ?Sized + 'me,
+            U: TryProvideMutWith<'me, &'me mut $lock<T>, C> + ?Sized,
+        {
+            type Error =
+                $crate::context::lock::TryAcquireError<U::Error, $crate::context::lock::WouldBlock>;
+
+            fn try_provide_mut_with(
+                &'me mut self,
+                context: $context<C>,
+            ) -> Result<$guard<'me, T>, Self::Error> {
+                use $crate::context::lock::{TryAcquireError, WouldBlock};
+
+                let $context(context) = context;
+                let $arg = &*self
+                    .try_provide_mut_with(context)
+                    .map_err(TryAcquireError::Provide)?;
+                $acquire.ok_or(TryAcquireError::Acquire(WouldBlock))
+            }
+        }
+    };
+}
+
+/// Implements asynchronous provider traits for the blocking context of some lock type.
+///
+/// Provided expression must evaluate to the future of the lock guard.
+#[allow(unused_macros)]
+macro_rules! impl_async_blocking {
+    ($context:ident, $lock:ident, $guard:ident, |$arg:ident| $acquire:expr) => {
+        impl<'me, T, U, C> ProvideRefAsyncWith<'me, $guard<'me, T>, $context<C>> for U
+        where
+            T: ?Sized + 'me,
+            U: ProvideRefAsyncWith<'me, &'me $lock<T>, C> + ?Sized,
+        {
+            async fn provide_ref_async_with(&'me self, context: $context<C>) -> $guard<'me, T> {
+                let $context(context) = context;
+                let $arg = self.provide_ref_async_with(context).await;
+                $acquire.await
+            }
+        }
+
+        impl<'me, T, U, C> TryProvideRefAsyncWith<'me, $guard<'me, T>, $context<C>> for U
+        where
+            T: ?Sized + 'me,
+            U: TryProvideRefAsyncWith<'me, &'me $lock<T>, C> + ?Sized,
+        {
+            type Error = U::Error;
+
+            async fn try_provide_ref_async_with(
+                &'me self,
+                context: $context<C>,
+            ) -> Result<$guard<'me, T>, Self::Error> {
+                let $context(context) = context;
+                let $arg = self.try_provide_ref_async_with(context).await?;
+                Ok($acquire.await)
+            }
+        }
+
+        impl<'me, T, U, C> ProvideMutAsyncWith<'me, $guard<'me, T>, $context<C>> for U
+        where
+            T: ?Sized + 'me,
+            U: ProvideMutAsyncWith<'me, &'me mut $lock<T>, C> + ?Sized,
+        {
+            async fn provide_mut_async_with(&'me mut self, context: $context<C>) -> $guard<'me, T> {
+                let $context(context) = context;
+                let $arg = &*self.provide_mut_async_with(context).await;
+                $acquire.await
+            }
+        }
+
+        impl<'me, T, U, C> TryProvideMutAsyncWith<'me, $guard<'me, T>, $context<C>> for U
+        where
+            T: ?Sized + 'me,
+            U: TryProvideMutAsyncWith<'me, &'me mut $lock<T>, C> + ?Sized,
+        {
+            type Error = U::Error;
+
+            async fn try_provide_mut_async_with(
+                &'me mut self,
+                context: $context<C>,
+            ) -> Result<$guard<'me, T>, Self::Error> {
+                let $context(context) = context;
+                let $arg = &*self.try_provide_mut_async_with(context).await?;
+                Ok($acquire.await)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "async-lock")]
+mod async_lock;
+#[cfg(feature = "parking_lot")]
+mod parking_lot;
+#[cfg(feature = "std")]
+mod std;
+#[cfg(feature = "tokio")]
+mod tokio;
+
+/// Context which provides guard of the mutex dependency
+/// by acquiring its lock, blocking the current thread until it is able to do so.
+///
+/// Mutex dependency itself is provided by shared reference with the inner context.
+///
+/// If the mutex dependency is provided by unique reference with the inner context instead,
+/// its guard is provided in the same way.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use std::sync::{Arc, Mutex, MutexGuard};
+///
+/// use provide::{context::lock::Lock, with::ProvideRefWith};
+///
+/// let provider = Arc::new(Mutex::new(1));
+///
+/// let mut guard: MutexGuard<i32> = provider.provide_ref_with(Lock::default());
+/// *guard += 1;
+/// drop(guard);
+///
+/// let guard: MutexGuard<i32> = provider.provide_ref_with(Lock::default());
+/// assert_eq!(*guard, 2);
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LockWith<C>(C);
+
+/// Context which provides guard of the mutex dependency
+/// by acquiring its lock, blocking the current thread until it is able to do so.
+///
+/// Mutex dependency itself is provided by shared reference with [empty context](Empty).
+pub type Lock = LockWith<Empty>;
+
+/// Context which provides guard of the mutex dependency
+/// by attempting to acquire its lock without blocking.
+///
+/// Mutex dependency itself is provided by shared reference with the inner context.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use std::sync::{Arc, Mutex, MutexGuard};
+///
+/// use provide::{
+///     context::lock::{TryAcquireError, TryLock, TryLockError},
+///     with::TryProvideRefWith,
+/// };
+///
+/// let provider = Arc::new(Mutex::new(1));
+///
+/// let guard: Result<MutexGuard<i32>, _> = provider.try_provide_ref_with(TryLock::default());
+/// assert_eq!(guard.as_deref(), Ok(&1));
+///
+/// let other: Result<MutexGuard<i32>, _> = provider.try_provide_ref_with(TryLock::default());
+/// assert_eq!(other.err(), Some(TryAcquireError::Acquire(TryLockError::WouldBlock)));
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TryLockWith<C>(C);
+
+/// Context which provides guard of the mutex dependency
+/// by attempting to acquire its lock without blocking.
+///
+/// Mutex dependency itself is provided by shared reference with [empty context](Empty).
+pub type TryLock = TryLockWith<Empty>;
+
+/// Context which provides shared guard of the read-write lock dependency
+/// by acquiring it with shared read access, blocking the current thread until it is able to do so.
+///
+/// Read-write lock dependency itself is provided by shared reference with the inner context.
+///
+/// If the read-write lock dependency is provided by unique reference with the inner context instead,
+/// its guard is provided in the same way.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use std::sync::{Arc, RwLock, RwLockReadGuard};
+///
+/// use provide::{context::lock::Read, with::ProvideRefWith};
+///
+/// let provider = Arc::new(RwLock::new(1));
+///
+/// let guard: RwLockReadGuard<i32> = provider.provide_ref_with(Read::default());
+/// let other: RwLockReadGuard<i32> = provider.provide_ref_with(Read::default());
+/// assert_eq!(*guard, *other);
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ReadWith<C>(C);
+
+/// Context which provides shared guard of the read-write lock dependency
+/// by acquiring it with shared read access, blocking the current thread until it is able to do so.
+///
+/// Read-write lock dependency itself is provided by shared reference with [empty context](Empty).
+pub type Read = ReadWith<Empty>;
+
+/// Context which provides shared guard of the read-write lock dependency
+/// by attempting to acquire it with shared read access without blocking.
+///
+/// Read-write lock dependency itself is provided by shared reference with the inner context.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+///
+/// use provide::{
+///     context::lock::{TryAcquireError, TryLockError, TryRead, Write},
+///     with::{ProvideRefWith, TryProvideRefWith},
+/// };
+///
+/// let provider = Arc::new(RwLock::new(1));
+///
+/// let guard: RwLockWriteGuard<i32> = provider.provide_ref_with(Write::default());
+/// let other: Result<RwLockReadGuard<i32>, _> = provider.try_provide_ref_with(TryRead::default());
+/// assert_eq!(other.err(), Some(TryAcquireError::Acquire(TryLockError::WouldBlock)));
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TryReadWith<C>(C);
+
+/// Context which provides shared guard of the read-write lock dependency
+/// by attempting to acquire it with shared read access without blocking.
+///
+/// Read-write lock dependency itself is provided by shared reference with [empty context](Empty).
+pub type TryRead = TryReadWith<Empty>;
+
+/// Context which provides exclusive guard of the read-write lock dependency
+/// by acquiring it with exclusive write access, blocking the current thread until it is able to do so.
+///
+/// Read-write lock dependency itself is provided by shared reference with the inner context.
+///
+/// If the read-write lock dependency is provided by unique reference with the inner context instead,
+/// its guard is provided in the same way.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use std::sync::{Arc, RwLock, RwLockWriteGuard};
+///
+/// use provide::{context::lock::Write, with::ProvideRefWith};
+///
+/// let provider = Arc::new(RwLock::new(1));
+///
+/// let mut guard: RwLockWriteGuard<i32> = provider.provide_ref_with(Write::default());
+/// *guard += 1;
+/// assert_eq!(*guard, 2);
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct WriteWith<C>(C);
+
+/// Context which provides exclusive guard of the read-write lock dependency
+/// by acquiring it with exclusive write access, blocking the current thread until it is able to do so.
+///
+/// Read-write lock dependency itself is provided by shared reference with [empty context](Empty).
+pub type Write = WriteWith<Empty>;
+
+/// Context which provides exclusive guard of the read-write lock dependency
+/// by attempting to acquire it with exclusive write access without blocking.
+///
+/// Read-write lock dependency itself is provided by shared reference with the inner context.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+///
+/// use provide::{
+///     context::lock::{Read, TryAcquireError, TryLockError, TryWrite},
+///     with::{ProvideRefWith, TryProvideRefWith},
+/// };
+///
+/// let provider = Arc::new(RwLock::new(1));
+///
+/// let guard: RwLockReadGuard<i32> = provider.provide_ref_with(Read::default());
+/// let other: Result<RwLockWriteGuard<i32>, _> = provider.try_provide_ref_with(TryWrite::default());
+/// assert_eq!(other.err(), Some(TryAcquireError::Acquire(TryLockError::WouldBlock)));
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TryWriteWith<C>(C);
+
+/// Context which provides exclusive guard of the read-write lock dependency
+/// by attempting to acquire it with exclusive write access without blocking.
+///
+/// Read-write lock dependency itself is provided by shared reference with [empty context](Empty).
+pub type TryWrite = TryWriteWith<Empty>;
+
+macro_rules! impl_context {
+    ($($name:ident),* $(,)?) => {$(
+        impl<C> $name<C> {
+            /// Creates new context from the context to provide lock dependency with.
+            pub const fn new(context: C) -> Self {
+                Self(context)
+            }
+
+            /// Returns the inner context.
+            pub fn into_inner(self) -> C {
+                let Self(context) = self;
+                context
+            }
+        }
+
+        impl<C> Context for $name<C> {}
+
+        impl<C> Depth for $name<C>
+        where
+            C: Depth,
+        {
+            const DEPTH: usize = C::DEPTH + 1;
+        }
+    )*};
+}
+
+impl_context!(
+    LockWith,
+    TryLockWith,
+    ReadWith,
+    TryReadWith,
+    WriteWith,
+    TryWriteWith,
+);
+
+/// Context which provides guard of the mutex dependency
+/// by attempting to acquire its lock until the carried timeout has elapsed.
+///
+/// Mutex dependency itself is provided by shared reference with the inner context.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "parking_lot")] {
+/// use std::{sync::Arc, time::Duration};
+///
+/// use parking_lot::{Mutex, MutexGuard};
+/// use provide::{
+///     context::lock::{TimedOut, TryAcquireError, TryLockFor},
+///     with::TryProvideRefWith,
+/// };
+///
+/// let provider = Arc::new(Mutex::new(1));
+/// let timeout = Duration::from_millis(10);
+///
+/// let guard: Result<MutexGuard<i32>, _> =
+///     provider.try_provide_ref_with(TryLockFor::new(timeout, ()));
+/// assert_eq!(guard.as_deref(), Ok(&1));
+///
+/// let other: Result<MutexGuard<i32>, _> =
+///     provider.try_provide_ref_with(TryLockFor::new(timeout, ()));
+/// assert_eq!(other.err(), Some(TryAcquireError::Acquire(TimedOut)));
+/// # }
+/// ```
+#[cfg(feature = "parking_lot")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TryLockForWith<C> {
+    timeout: Duration,
+    context: C,
+}
+
+/// Context which provides guard of the mutex dependency
+/// by attempting to acquire its lock until the carried timeout has elapsed.
+///
+/// Mutex dependency itself is provided by shared reference with [empty context](Empty).
+#[cfg(feature = "parking_lot")]
+pub type TryLockFor = TryLockForWith<Empty>;
+
+/// Context which provides shared guard of the read-write lock dependency
+/// by attempting to acquire it with shared read access until the carried timeout has elapsed.
+///
+/// Read-write lock dependency itself is provided by shared reference with the inner context.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "parking_lot")] {
+/// use std::{sync::Arc, time::Duration};
+///
+/// use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+/// use provide::{
+///     context::lock::{TimedOut, TryAcquireError, TryReadFor, Write},
+///     with::{ProvideRefWith, TryProvideRefWith},
+/// };
+///
+/// let provider = Arc::new(RwLock::new(1));
+/// let timeout = Duration::from_millis(10);
+///
+/// let guard: RwLockWriteGuard<i32> = provider.provide_ref_with(Write::default());
+/// let other: Result<RwLockReadGuard<i32>, _> =
+///     provider.try_provide_ref_with(TryReadFor::new(timeout, ()));
+/// assert_eq!(other.err(), Some(TryAcquireError::Acquire(TimedOut)));
+/// # }
+/// ```
+#[cfg(feature = "parking_lot")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TryReadForWith<C> {
+    timeout: Duration,
+    context: C,
+}
+
+/// Context which provides shared guard of the read-write lock dependency
+/// by attempting to acquire it with shared read access until the carried timeout has elapsed.
+///
+/// Read-write lock dependency itself is provided by shared reference with [empty context](Empty).
+#[cfg(feature = "parking_lot")]
+pub type TryReadFor = TryReadForWith<Empty>;
+
+/// Context which provides exclusive guard of the read-write lock dependency
+/// by attempting to acquire it with exclusive write access until the carried timeout has elapsed.
+///
+/// Read-write lock dependency itself is provided by shared reference with the inner context.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "parking_lot")] {
+/// use std::{sync::Arc, time::Duration};
+///
+/// use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+/// use provide::{
+///     context::lock::{Read, TimedOut, TryAcquireError, TryWriteFor},
+///     with::{ProvideRefWith, TryProvideRefWith},
+/// };
+///
+/// let provider = Arc::new(RwLock::new(1));
+/// let timeout = Duration::from_millis(10);
+///
+/// let guard: RwLockReadGuard<i32> = provider.provide_ref_with(Read::default());
+/// let other: Result<RwLockWriteGuard<i32>, _> =
+///     provider.try_provide_ref_with(TryWriteFor::new(timeout, ()));
+/// assert_eq!(other.err(), Some(TryAcquireError::Acquire(TimedOut)));
+/// # }
+/// ```
+#[cfg(feature = "parking_lot")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TryWriteForWith<C> {
+    timeout: Duration,
+    context: C,
+}
+
+/// Context which provides exclusive guard of the read-write lock dependency
+/// by attempting to acquire it with exclusive write access until the carried timeout has elapsed.
+///
+/// Read-write lock dependency itself is provided by shared reference with [empty context](Empty).
+#[cfg(feature = "parking_lot")]
+pub type TryWriteFor = TryWriteForWith<Empty>;
+
+#[cfg(feature = "parking_lot")]
+macro_rules! impl_timed_context {
+    ($($name:ident),* $(,)?) => {$(
+        impl<C> $name<C> {
+            /// Creates new context from the timeout of lock acquisition
+            /// and the context to provide lock dependency with.
+            pub const fn new(timeout: Duration, context: C) -> Self {
+                Self { timeout, context }
+            }
+
+            /// Returns the timeout of lock acquisition.
+            pub const fn timeout(&self) -> Duration {
+                self.timeout
+            }
+
+            /// Returns the timeout of lock acquisition and the inner context.
+            pub fn into_inner(self) -> (Duration, C) {
+                let Self { timeout, context } = self;
+                (timeout, context)
+            }
+        }
+
+        impl<C> Context for $name<C> {}
+
+        impl<C> Depth for $name<C>
+        where
+            C: Depth,
+        {
+            const DEPTH: usize = C::DEPTH + 1;
+        }
+
+        #[cfg(feature = "defmt")]
+        impl<C> defmt::Format for $name<C>
+        where
+            C: defmt::Format,
+        {
+            fn format(&self, f: defmt::Formatter<'_>) {
+                let Self { timeout, context } = self;
+                let timeout = u64::try_from(timeout.as_micros()).unwrap_or(u64::MAX);
+                defmt::write!(
+                    f,
+                    "{=str} {{ timeout: {=u64}us, context: {} }}",
+                    stringify!($name),
+                    timeout,
+                    context,
+                );
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "parking_lot")]
+impl_timed_context!(TryLockForWith, TryReadForWith, TryWriteForWith);
+
+/// The error type returned when lock could not be acquired without blocking.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct WouldBlock;
+
+impl Display for WouldBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("lock could not be acquired at this time because the operation would block")
+    }
+}
+
+impl Error for WouldBlock {}
+
+/// The error type returned when lock could not be acquired before the timeout has elapsed.
+#[cfg(feature = "parking_lot")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TimedOut;
+
+#[cfg(feature = "parking_lot")]
+impl Display for TimedOut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("lock could not be acquired before the timeout has elapsed")
+    }
+}
+
+#[cfg(feature = "parking_lot")]
+impl Error for TimedOut {}
+
+/// The error type returned when lock is provided with non-blocking or timed context.
+///
+/// Both errors of the lock dependency provision and of the lock acquisition
+/// are displayed as is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TryAcquireError<E, F> {
+    /// Lock dependency itself was not provided with the inner context.
+    Provide(E),
+    /// Lock dependency was provided, but its lock could not be acquired.
+    Acquire(F),
+}
+
+impl<E, F> Display for TryAcquireError<E, F>
+where
+    E: Display,
+    F: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Provide(error) => Display::fmt(error, f),
+            Self::Acquire(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl<E, F> Error for TryAcquireError<E, F>
+where
+    E: Error,
+    F: Error,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Provide(error) => error.source(),
+            Self::Acquire(error) => error.source(),
+        }
+    }
+}
+
+/// The error type returned when lock from the standard library is provided fallibly
+/// with blocking context.
+///
+/// This type is available with `std` feature enabled.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum LockError<E> {
+    /// Lock is poisoned because some thread has panicked while holding it.
+    Poisoned,
+    /// Lock dependency itself was not provided with the inner context.
+    Provide(E),
+}
+
+#[cfg(feature = "std")]
+impl<E> Display for LockError<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Poisoned => f.write_str("lock is poisoned because some thread has panicked"),
+            Self::Provide(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E> Error for LockError<E>
+where
+    E: Error,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Poisoned => None,
+            Self::Provide(error) => error.source(),
+        }
+    }
+}
+
+/// The error type returned when lock from the standard library is provided
+/// with non-blocking context.
+///
+/// This type is available with `std` feature enabled.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TryLockError {
+    /// Lock is poisoned because some thread has panicked while holding it.
+    Poisoned,
+    /// Lock could not be acquired without blocking.
+    WouldBlock,
+}
+
+#[cfg(feature = "std")]
+impl Display for TryLockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Poisoned => f.write_str("lock is poisoned because some thread has panicked"),
+            Self::WouldBlock => Display::fmt(&WouldBlock, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for TryLockError {}
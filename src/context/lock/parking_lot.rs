@@ -0,0 +1,82 @@
+//! Implementations for lock types from [`parking_lot`](::parking_lot) crate.
+
+use ::parking_lot::{Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::with::{ProvideMutWith, ProvideRefWith, TryProvideMutWith, TryProvideRefWith};
+
+use super::{
+    LockWith, ReadWith, TimedOut, TryAcquireError, TryLockForWith, TryLockWith, TryReadForWith,
+    TryReadWith, TryWriteForWith, TryWriteWith, WriteWith,
+};
+
+/// Implements provider traits for the timed context of some lock type.
+///
+/// Provided expression must evaluate to `Option` of the lock guard.
+macro_rules! impl_timed {
+    ($context:ident, $lock:ident, $guard:ident, |$arg:ident, $timeout:ident| $acquire:expr) => {
+        impl<'me, T, U, C> TryProvideRefWith<'me, $guard<'me, T>, $context<C>> for U
+        where
+            T: ?Sized + 'me,
+            U: TryProvideRefWith<'me, &'me $lock<T>, C> + ?Sized,
+        {
+            type Error = TryAcquireError<U::Error, TimedOut>;
+
+            fn try_provide_ref_with(
+                &'me self,
+                context: $context<C>,
+            ) -> Result<$guard<'me, T>, Self::Error> {
+                let ($timeout, context) = context.into_inner();
+                let $arg = self
+                    .try_provide_ref_with(context)
+                    .map_err(TryAcquireError::Provide)?;
+                $acquire.ok_or(TryAcquireError::Acquire(TimedOut))
+            }
+        }
+
+        impl<'me, T, U, C> TryProvideMutWith<'me, $guard<'me, T>, $context<C>> for U
+        where
+            T: ?Sized + 'me,
+            U: TryProvideMutWith<'me, &'me mut $lock<T>, C> + ?Sized,
+        {
+            type Error = TryAcquireError<U::Error, TimedOut>;
+
+            fn try_provide_mut_with(
+                &'me mut self,
+                context: $context<C>,
+            ) -> Result<$guard<'me, T>, Self::Error> {
+                let ($timeout, context) = context.into_inner();
+                let $arg = &*self
+                    .try_provide_mut_with(context)
+                    .map_err(TryAcquireError::Provide)?;
+                $acquire.ok_or(TryAcquireError::Acquire(TimedOut))
+            }
+        }
+    };
+}
+
+impl_blocking!(LockWith, Mutex, MutexGuard, |mutex| mutex.lock());
+impl_non_blocking!(TryLockWith, Mutex, MutexGuard, |mutex| mutex.try_lock());
+
+impl_blocking!(ReadWith, RwLock, RwLockReadGuard, |rw_lock| rw_lock.read());
+impl_non_blocking!(TryReadWith, RwLock, RwLockReadGuard, |rw_lock| rw_lock
+    .try_read());
+
+impl_blocking!(WriteWith, RwLock, RwLockWriteGuard, |rw_lock| rw_lock
+    .write());
+impl_non_blocking!(TryWriteWith, RwLock, RwLockWriteGuard, |rw_lock| rw_lock
+    .try_write());
+
+impl_timed!(TryLockForWith, Mutex, MutexGuard, |mutex, timeout| mutex
+    .try_lock_for(timeout));
+impl_timed!(
+    TryReadForWith,
+    RwLock,
+    RwLockReadGuard,
+    |rw_lock, timeout| rw_lock.try_read_for(timeout)
+);
+impl_timed!(
+    TryWriteForWith,
+    RwLock,
+    RwLockWriteGuard,
+    |rw_lock, timeout| rw_lock.try_write_for(timeout)
+);
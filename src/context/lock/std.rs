@@ -0,0 +1,155 @@
+//! Implementations for lock types from the standard library.
+//!
+//! Provision with infallible traits ignores poisoning: guards of poisoned locks are provided as usual,
+//! so it never panics because of a panic in some other thread.
+//! Fallible provision reports poisoning with [`LockError::Poisoned`]
+//! or [`TryLockError::Poisoned`] errors instead.
+
+use std::sync::{
+    LockResult, Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard,
+    TryLockResult,
+};
+
+use crate::with::{ProvideMutWith, ProvideRefWith, TryProvideMutWith, TryProvideRefWith};
+
+use super::{
+    LockError, LockWith, ReadWith, TryAcquireError, TryLockError, TryLockWith, TryReadWith,
+    TryWriteWith, WriteWith,
+};
+
+/// Implements provider traits for the blocking context of some lock type.
+///
+/// Provided expression must evaluate to [`LockResult`] of the lock guard.
+macro_rules! impl_poison_blocking {
+    ($context:ident, $lock:ident, $guard:ident, |$arg:ident| $acquire:expr) => {
+        impl<'me, T, U, C> ProvideRefWith<'me, $guard<'me, T>, $context<C>> for U
+        where
+            T: ?Sized + 'me,
+            U: ProvideRefWith<'me, &'me $lock<T>, C> + ?Sized,
+        {
+            fn provide_ref_with(&'me self, context: $context<C>) -> $guard<'me, T> {
+                let $context(context) = context;
+                let $arg = self.provide_ref_with(context);
+                ignore_poison($acquire)
+            }
+        }
+
+        impl<'me, T, U, C> TryProvideRefWith<'me, $guard<'me, T>, $context<C>> for U
+        where
+            T: ?Sized + 'me,
+            U: TryProvideRefWith<'me, &'me $lock<T>, C> + ?Sized,
+        {
+            type Error = LockError<U::Error>;
+
+            fn try_provide_ref_with(
+                &'me self,
+                context: $context<C>,
+            ) -> Result<$guard<'me, T>, Self::Error> {
+                let $context(context) = context;
+                let $arg = self
+                    .try_provide_ref_with(context)
+                    .map_err(LockError::Provide)?;
+                $acquire.map_err(|_| LockError::Poisoned)
+            }
+        }
+
+        impl<'me, T, U, C> ProvideMutWith<'me, $guard<'me, T>, $context<C>> for U
+        where
+            T: ?Sized + 'me,
+            U: ProvideMutWith<'me, &'me mut $lock<T>, C> + ?Sized,
+        {
+            fn provide_mut_with(&'me mut self, context: $context<C>) -> $guard<'me, T> {
+                let $context(context) = context;
+                let $arg = &*self.provide_mut_with(context);
+                ignore_poison($acquire)
+            }
+        }
+
+        impl<'me, T, U, C> TryProvideMutWith<'me, $guard<'me, T>, $context<C>> for U
+        where
+            T: ?Sized + 'me,
+            U: TryProvideMutWith<'me, &'me mut $lock<T>, C> + ?Sized,
+        {
+            type Error = LockError<U::Error>;
+
+            fn try_provide_mut_with(
+                &'me mut self,
+                context: $context<C>,
+            ) -> Result<$guard<'me, T>, Self::Error> {
+                let $context(context) = context;
+                let $arg = &*self
+                    .try_provide_mut_with(context)
+                    .map_err(LockError::Provide)?;
+                $acquire.map_err(|_| LockError::Poisoned)
+            }
+        }
+    };
+}
+
+/// Implements provider traits for the non-blocking context of some lock type.
+///
+/// Provided expression must evaluate to [`TryLockResult`] of the lock guard.
+macro_rules! impl_poison_non_blocking {
+    ($context:ident, $lock:ident, $guard:ident, |$arg:ident| $acquire:expr) => {
+        impl<'me, T, U, C> TryProvideRefWith<'me, $guard<'me, T>, $context<C>> for U
+        where
+            T: ?Sized + 'me,
+            U: TryProvideRefWith<'me, &'me $lock<T>, C> + ?Sized,
+        {
+            type Error = TryAcquireError<U::Error, TryLockError>;
+
+            fn try_provide_ref_with(
+                &'me self,
+                context: $context<C>,
+            ) -> Result<$guard<'me, T>, Self::Error> {
+                let $context(context) = context;
+                let $arg = self
+                    .try_provide_ref_with(context)
+                    .map_err(TryAcquireError::Provide)?;
+                report_poison($acquire).map_err(TryAcquireError::Acquire)
+            }
+        }
+
+        impl<'me, T, U, C> TryProvideMutWith<'me, $guard<'me, T>, $context<C>> for U
+        where
+            T: ?Sized + 'me,
+            U: TryProvideMutWith<'me, &'me mut $lock<T>, C> + ?Sized,
+        {
+            type Error = TryAcquireError<U::Error, TryLockError>;
+
+            fn try_provide_mut_with(
+                &'me mut self,
+                context: $context<C>,
+            ) -> Result<$guard<'me, T>, Self::Error> {
+                let $context(context) = context;
+                let $arg = &*self
+                    .try_provide_mut_with(context)
+                    .map_err(TryAcquireError::Provide)?;
+                report_poison($acquire).map_err(TryAcquireError::Acquire)
+            }
+        }
+    };
+}
+
+impl_poison_blocking!(LockWith, Mutex, MutexGuard, |mutex| mutex.lock());
+impl_poison_non_blocking!(TryLockWith, Mutex, MutexGuard, |mutex| mutex.try_lock());
+
+impl_poison_blocking!(ReadWith, RwLock, RwLockReadGuard, |rw_lock| rw_lock.read());
+impl_poison_non_blocking!(TryReadWith, RwLock, RwLockReadGuard, |rw_lock| rw_lock
+    .try_read());
+
+impl_poison_blocking!(WriteWith, RwLock, RwLockWriteGuard, |rw_lock| rw_lock
+    .write());
+impl_poison_non_blocking!(TryWriteWith, RwLock, RwLockWriteGuard, |rw_lock| rw_lock
+    .try_write());
+
+fn ignore_poison<G>(result: LockResult<G>) -> G {
+    result.unwrap_or_else(PoisonError::into_inner)
+}
+
+fn report_poison<G>(result: TryLockResult<G>) -> Result<G, TryLockError> {
+    result.map_err(|error| match error {
+        std::sync::TryLockError::Poisoned(_) => TryLockError::Poisoned,
+        std::sync::TryLockError::WouldBlock => TryLockError::WouldBlock,
+    })
+}
@@ -0,0 +1,26 @@
+//! Implementations for asynchronous lock types from [`tokio`](::tokio) crate.
+
+use ::tokio::sync::{Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::with::{
+    ProvideMutAsyncWith, ProvideRefAsyncWith, TryProvideMutAsyncWith, TryProvideMutWith,
+    TryProvideRefAsyncWith, TryProvideRefWith,
+};
+
+use super::{LockWith, ReadWith, TryLockWith, TryReadWith, TryWriteWith, WriteWith};
+
+impl_async_blocking!(LockWith, Mutex, MutexGuard, |mutex| mutex.lock());
+impl_non_blocking!(TryLockWith, Mutex, MutexGuard, |mutex| mutex
+    .try_lock()
+    .ok());
+
+impl_async_blocking!(ReadWith, RwLock, RwLockReadGuard, |rw_lock| rw_lock.read());
+impl_non_blocking!(TryReadWith, RwLock, RwLockReadGuard, |rw_lock| rw_lock
+    .try_read()
+    .ok());
+
+impl_async_blocking!(WriteWith, RwLock, RwLockWriteGuard, |rw_lock| rw_lock
+    .write());
+impl_non_blocking!(TryWriteWith, RwLock, RwLockWriteGuard, |rw_lock| rw_lock
+    .try_write()
+    .ok());
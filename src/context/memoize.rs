@@ -0,0 +1,94 @@
+//! Context which provides dependencies from [memoized](Memoized) providers.
+//!
+//! See [crate] documentation for more.
+
+use crate::{
+    context::{depth::Depth, Context},
+    memoize::{MemoizeError, Memoized},
+    with::{ProvideMutWith, ProvideRefWith, TryProvideMutWith, TryProvideRefWith},
+};
+
+/// Context which provides reference to the cached dependency of the [memoized](Memoized) provider,
+/// providing it from the inner provider first if it was not cached yet.
+///
+/// Dependency is provided from the inner provider with the context carried by the provider,
+/// so this context has no inner context.
+///
+/// # Panics
+///
+/// Infallible provision panics if the inner provider provides the dependency
+/// out of the memoized provider recursively.
+/// Fallible provision returns [reentrant](MemoizeError::Reentrant) error instead.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+///
+/// use provide::{
+///     context::{cell::TakeCell, memoize::Memoize},
+///     memoize::{MemoizeError, Memoized},
+///     with::{TryProvideMutWith, TryProvideRefWith},
+/// };
+///
+/// let mut provider: Memoized<_, i32, _> = Memoized::new(Box::new(Cell::new(1)), TakeCell::default());
+///
+/// let dependency: Result<&mut i32, _> = provider.try_provide_mut_with(Memoize);
+/// *dependency.unwrap() += 1;
+///
+/// let dependency: Result<&i32, MemoizeError<_>> = provider.try_provide_ref_with(Memoize);
+/// assert_eq!(dependency, Ok(&2));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Memoize;
+
+impl Context for Memoize {}
+
+impl Depth for Memoize {
+    const DEPTH: usize = 1;
+}
+
+impl<'me, P, T, C> ProvideRefWith<'me, &'me T, Memoize> for Memoized<P, T, C>
+where
+    P: for<'a> ProvideRefWith<'a, T, C>,
+    C: Clone,
+{
+    fn provide_ref_with(&'me self, _: Memoize) -> &'me T {
+        self.force()
+    }
+}
+
+impl<'me, P, T, C, E> TryProvideRefWith<'me, &'me T, Memoize> for Memoized<P, T, C>
+where
+    P: for<'a> TryProvideRefWith<'a, T, C, Error = E>,
+    C: Clone,
+{
+    type Error = MemoizeError<E>;
+
+    fn try_provide_ref_with(&'me self, _: Memoize) -> Result<&'me T, Self::Error> {
+        self.try_force()
+    }
+}
+
+impl<'me, P, T, C> ProvideMutWith<'me, &'me mut T, Memoize> for Memoized<P, T, C>
+where
+    P: for<'a> ProvideRefWith<'a, T, C>,
+    C: Clone,
+{
+    fn provide_mut_with(&'me mut self, _: Memoize) -> &'me mut T {
+        self.force_mut()
+    }
+}
+
+impl<'me, P, T, C, E> TryProvideMutWith<'me, &'me mut T, Memoize> for Memoized<P, T, C>
+where
+    P: for<'a> TryProvideRefWith<'a, T, C, Error = E>,
+    C: Clone,
+{
+    type Error = MemoizeError<E>;
+
+    fn try_provide_mut_with(&'me mut self, _: Memoize) -> Result<&'me mut T, Self::Error> {
+        self.try_force_mut()
+    }
+}
@@ -0,0 +1,256 @@
+//! Context which selects the side of the [composite](Merged) provider.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
+
+use crate::{
+    context::{depth::Depth, Context, Empty},
+    merge::{Left, Merged, Right},
+    name::type_name_of,
+    pair::Here,
+    with::{
+        ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideMutWith, TryProvideRefWith,
+        TryProvideWith,
+    },
+};
+
+/// Context which provides dependency from the side of the [composite](Merged) provider
+/// pointed by the index `I`.
+///
+/// Index is a path of [`Left`] and [`Right`] sides through nested composite providers,
+/// ending with [`Here`] at the provider which provides dependency itself with the inner context.
+/// Usually it is inferred by the compiler, so dependency can be requested by its type only.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::merge::Select,
+///     merge::{Left, Merge, Right},
+///     pair::Here,
+///     with::ProvideRefWith,
+/// };
+///
+/// let provider = Box::new(1).merge(Box::new(2)).merge("hello");
+///
+/// let name: &str = provider.provide_ref_with(Select::default());
+/// assert_eq!(name, "hello");
+///
+/// // both sides provide `i32`, so index should be specified explicitly
+/// let second: &i32 = provider.provide_ref_with(Select::<Left<Right<Here>>>::default());
+/// assert_eq!(*second, 2);
+/// ```
+pub struct SelectWith<I, C> {
+    index: PhantomData<I>,
+    context: C,
+}
+
+/// Context which provides dependency from the side of the [composite](Merged) provider
+/// pointed by the index `I`.
+///
+/// Dependency itself is provided from the selected provider with [empty context](Empty).
+pub type Select<I> = SelectWith<I, Empty>;
+
+impl<I, C> SelectWith<I, C> {
+    /// Creates new context from the context to provide dependency
+    /// from the selected provider with.
+    pub const fn new(context: C) -> Self {
+        Self {
+            index: PhantomData,
+            context,
+        }
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self { context, .. } = self;
+        context
+    }
+}
+
+impl<I, C> Debug for SelectWith<I, C>
+where
+    C: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { context, .. } = self;
+        f.debug_struct("SelectWith")
+            .field("index", &type_name_of::<I>())
+            .field("context", context)
+            .finish()
+    }
+}
+
+impl<I, C> Default for SelectWith<I, C>
+where
+    C: Default,
+{
+    fn default() -> Self {
+        Self::new(C::default())
+    }
+}
+
+impl<I, C> Clone for SelectWith<I, C>
+where
+    C: Clone,
+{
+    fn clone(&self) -> Self {
+        let Self { context, .. } = self;
+        Self::new(context.clone())
+    }
+}
+
+impl<I, C> Copy for SelectWith<I, C> where C: Copy {}
+
+#[cfg(feature = "defmt")]
+impl<I, C> defmt::Format for SelectWith<I, C>
+where
+    C: defmt::Format,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        let Self { context, .. } = self;
+        defmt::write!(
+            f,
+            "SelectWith {{ index: {}, context: {} }}",
+            type_name_of::<I>(),
+            context,
+        );
+    }
+}
+
+impl<I, C> Context for SelectWith<I, C> {}
+
+impl<I, C> Depth for SelectWith<I, C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+/// Implements all the provider traits for the [`SelectWith`] context
+/// which selects one side of the [composite](Merged) provider by the index.
+///
+/// Selected provider is provided dependency with the context built by provided expression.
+macro_rules! impl_select {
+    (
+        $provider:ident . $side:ident / $side_mut:ident,
+        [$($param:ident),*] $index:ty => $inner:ty,
+        $remainder:ty,
+        |$left:ident, $right:ident, $context:ident| $wrap:expr
+    ) => {
+        impl<T, L, R, $($param,)* C> ProvideWith<T, SelectWith<$index, C>> for Merged<L, R>
+        where
+            $provider: ProvideWith<T, $inner>,
+        {
+            type Remainder = $remainder;
+
+            fn provide_with(self, context: SelectWith<$index, C>) -> (T, Self::Remainder) {
+                let $context = context.into_inner();
+                let ($left, $right) = self.into_inner();
+                let (dependency, $side) = $side.provide_with($wrap);
+                (dependency, Merged::new($left, $right))
+            }
+        }
+
+        impl<T, L, R, $($param,)* C> TryProvideWith<T, SelectWith<$index, C>> for Merged<L, R>
+        where
+            $provider: TryProvideWith<T, $inner>,
+        {
+            type Remainder = $remainder;
+
+            type Error = $provider::Error;
+
+            fn try_provide_with(self, context: SelectWith<$index, C>) -> Result<(T, Self::Remainder), Self::Error> {
+                let $context = context.into_inner();
+                let ($left, $right) = self.into_inner();
+                let (dependency, $side) = $side.try_provide_with($wrap)?;
+                Ok((dependency, Merged::new($left, $right)))
+            }
+        }
+
+        impl<'me, T, L, R, $($param,)* C> ProvideRefWith<'me, T, SelectWith<$index, C>> for Merged<L, R>
+        where
+            $provider: ProvideRefWith<'me, T, $inner>,
+        {
+            fn provide_ref_with(&'me self, context: SelectWith<$index, C>) -> T {
+                let $context = context.into_inner();
+                self.$side().provide_ref_with($wrap)
+            }
+        }
+
+        impl<'me, T, L, R, $($param,)* C> TryProvideRefWith<'me, T, SelectWith<$index, C>> for Merged<L, R>
+        where
+            $provider: TryProvideRefWith<'me, T, $inner>,
+        {
+            type Error = $provider::Error;
+
+            fn try_provide_ref_with(&'me self, context: SelectWith<$index, C>) -> Result<T, Self::Error> {
+                let $context = context.into_inner();
+                self.$side().try_provide_ref_with($wrap)
+            }
+        }
+
+        impl<'me, T, L, R, $($param,)* C> ProvideMutWith<'me, T, SelectWith<$index, C>> for Merged<L, R>
+        where
+            $provider: ProvideMutWith<'me, T, $inner>,
+        {
+            fn provide_mut_with(&'me mut self, context: SelectWith<$index, C>) -> T {
+                let $context = context.into_inner();
+                self.$side_mut().provide_mut_with($wrap)
+            }
+        }
+
+        impl<'me, T, L, R, $($param,)* C> TryProvideMutWith<'me, T, SelectWith<$index, C>> for Merged<L, R>
+        where
+            $provider: TryProvideMutWith<'me, T, $inner>,
+        {
+            type Error = $provider::Error;
+
+            fn try_provide_mut_with(&'me mut self, context: SelectWith<$index, C>) -> Result<T, Self::Error> {
+                let $context = context.into_inner();
+                self.$side_mut().try_provide_mut_with($wrap)
+            }
+        }
+    };
+}
+
+impl_select!(
+    L.left / left_mut,
+    [] Left<Here> => C,
+    Merged<L::Remainder, R>,
+    |left, right, context| context
+);
+impl_select!(
+    L.left / left_mut,
+    [I] Left<Left<I>> => SelectWith<Left<I>, C>,
+    Merged<L::Remainder, R>,
+    |left, right, context| SelectWith::new(context)
+);
+impl_select!(
+    L.left / left_mut,
+    [I] Left<Right<I>> => SelectWith<Right<I>, C>,
+    Merged<L::Remainder, R>,
+    |left, right, context| SelectWith::new(context)
+);
+impl_select!(
+    R.right / right_mut,
+    [] Right<Here> => C,
+    Merged<L, R::Remainder>,
+    |left, right, context| context
+);
+impl_select!(
+    R.right / right_mut,
+    [I] Right<Left<I>> => SelectWith<Left<I>, C>,
+    Merged<L, R::Remainder>,
+    |left, right, context| SelectWith::new(context)
+);
+impl_select!(
+    R.right / right_mut,
+    [I] Right<Right<I>> => SelectWith<Right<I>, C>,
+    Merged<L, R::Remainder>,
+    |left, right, context| SelectWith::new(context)
+);
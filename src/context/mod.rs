@@ -2,5 +2,771 @@
 //!
 //! See [crate] documentation for more.
 
+#[cfg(feature = "parking_lot")]
+use core::time::Duration;
+
+use crate::profile::Profile;
+
+use self::{
+    cell::{ReplaceCellWith, TakeCellWith},
+    failpoint::{FailAfterWith, FailForWith},
+    profile::ProfileWith,
+    wrap::{OptionDependencyWith, ResultDependencyWith},
+};
+
+pub mod alias;
+pub mod balance;
+pub mod best_effort;
+#[cfg(feature = "bench")]
+pub mod black_box;
+pub mod borrow;
+#[cfg(feature = "alloc")]
+pub mod bridge;
+pub mod by_ref;
+pub mod cache;
+pub mod cell;
+#[cfg(any(feature = "futures", feature = "tokio"))]
+pub mod channel;
+#[cfg(feature = "alloc")]
+pub mod checkout;
+pub mod clone;
+pub mod collect;
+pub mod convert;
+pub mod default;
+pub mod depth;
+pub mod deref;
+pub mod downgrade;
+pub mod either;
+#[cfg(feature = "std")]
+pub mod env;
+pub mod failpoint;
+pub mod function;
+pub mod graph;
+pub mod inspect;
+pub mod intercept;
+pub mod lazy;
+#[cfg(any(
+    feature = "std",
+    feature = "parking_lot",
+    feature = "tokio",
+    feature = "async-lock",
+))]
+pub mod lock;
+pub mod memoize;
+pub mod merge;
+pub mod or_else;
+#[cfg(feature = "pool")]
+pub mod pool;
+pub mod profile;
+pub mod restore;
+#[cfg(feature = "alloc")]
+pub mod spy;
+pub mod take;
+#[cfg(feature = "std")]
+pub mod throttle;
+#[cfg(feature = "alloc")]
+pub mod to_owned;
+pub mod type_name;
+pub mod validate;
+#[cfg(feature = "alloc")]
+pub mod weak;
+pub mod wrap;
+pub mod zip;
+
 /// Context which represents no meaningful context.
 pub type Empty = ();
+
+/// Type of context which represents some way to provide dependency.
+///
+/// This trait allows to chain contexts fluently, where each next context
+/// uses the previous one to provide the dependency it needs.
+/// It is implemented for all the context types of this crate,
+/// and you can implement it for your own contexts to compose them in the same way.
+///
+/// Contexts are usually consumed by value on each provision.
+/// Unique reference to the context is a context too, so contexts which carry state
+/// that must persist across provisions (such as counters, round-robin selectors
+/// or rate limiters) can be passed by unique reference instead.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{context::Context, with::ProvideRefWith};
+///
+/// struct Provider {
+///     replicas: Vec<&'static str>,
+/// }
+///
+/// // Context which selects replicas in rotation.
+/// #[derive(Default)]
+/// struct RoundRobin {
+///     next: usize,
+/// }
+///
+/// impl Context for RoundRobin {}
+///
+/// impl<'me> ProvideRefWith<'me, &'me str, &mut RoundRobin> for Provider {
+///     fn provide_ref_with(&'me self, context: &mut RoundRobin) -> &'me str {
+///         let Self { replicas } = self;
+///         let replica = replicas[context.next % replicas.len()];
+///         context.next += 1;
+///         replica
+///     }
+/// }
+///
+/// let provider = Provider {
+///     replicas: vec!["first", "second"],
+/// };
+/// let mut context = RoundRobin::default();
+///
+/// let replicas: [&str; 3] = core::array::from_fn(|_| provider.provide_ref_with(&mut context));
+/// assert_eq!(replicas, ["first", "second", "first"]);
+/// ```
+///
+/// See [crate] documentation for more.
+pub trait Context: Sized {
+    /// Creates new context from this context with provided constructor function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::{
+    ///     context::Context,
+    ///     with::ProvideRefWith,
+    /// };
+    ///
+    /// struct Provider {
+    ///     foo: i32,
+    /// }
+    ///
+    /// impl AsRef<i32> for Provider {
+    ///     fn as_ref(&self) -> &i32 {
+    ///         let Self { foo } = self;
+    ///         foo
+    ///     }
+    /// }
+    ///
+    /// // Context which provides doubled dependency provided with the inner context.
+    /// struct Doubled<C>(C);
+    ///
+    /// impl<C> Context for Doubled<C> {}
+    ///
+    /// impl<'me, C> ProvideRefWith<'me, i32, Doubled<C>> for Provider
+    /// where
+    ///     Self: ProvideRefWith<'me, &'me i32, C>,
+    /// {
+    ///     fn provide_ref_with(&'me self, context: Doubled<C>) -> i32 {
+    ///         let Doubled(context) = context;
+    ///         self.provide_ref_with(context) * 2
+    ///     }
+    /// }
+    ///
+    /// let provider = Provider { foo: 1 };
+    /// let dependency: i32 = provider.provide_ref_with(().then(Doubled));
+    /// assert_eq!(dependency, 2);
+    /// ```
+    fn then<F, C>(self, f: F) -> C
+    where
+        F: FnOnce(Self) -> C,
+    {
+        f(self)
+    }
+
+    /// Converts this context into new context which implements [`From`] this context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::context::Context;
+    ///
+    /// struct MyContext<C>(C);
+    ///
+    /// impl<C> Context for MyContext<C> {}
+    ///
+    /// impl<C> From<C> for MyContext<C> {
+    ///     fn from(context: C) -> Self {
+    ///         Self(context)
+    ///     }
+    /// }
+    ///
+    /// let MyContext(()) = ().then_into();
+    /// ```
+    fn then_into<C>(self) -> C
+    where
+        C: From<Self>,
+    {
+        C::from(self)
+    }
+
+    /// Creates new context which provides dependency by constructing its [default](Default) value,
+    /// carrying this context.
+    fn then_default(self) -> default::DefaultDependencyWith<Self> {
+        default::DefaultDependencyWith::new(self)
+    }
+
+    /// Creates new context which provides dependency with this context,
+    /// falling back to its [default](Default) value if provision fails.
+    fn then_or_default(self) -> default::OrDefaultDependencyWith<Self> {
+        default::OrDefaultDependencyWith::new(self)
+    }
+
+    /// Creates new context which provides degraded dependency with this context,
+    /// degrading it to its [default](Default) value if provision fails.
+    fn then_best_effort(self) -> best_effort::BestEffortWith<Self> {
+        best_effort::BestEffortWith::new(self)
+    }
+
+    /// Creates new context which provides dependency with this context,
+    /// retrying with the fallback context if provision fails.
+    fn then_or_else<C>(self, fallback: C) -> or_else::OrElse<Self, C> {
+        or_else::OrElse::new(self, fallback)
+    }
+
+    /// Creates new context which provides either the dependency with this context,
+    /// or the dependency with the other context if provision with this context fails.
+    fn then_either<C>(self, other: C) -> either::EitherDependency<Self, C> {
+        either::EitherDependency::new(self, other)
+    }
+
+    /// Creates new context which provides dependency with this context if the condition holds,
+    /// or with the other context otherwise.
+    fn then_select_if<C>(self, condition: bool, other: C) -> either::SelectDependency<Self, C> {
+        either::SelectDependency::new(condition, self, other)
+    }
+
+    /// Creates new context which provides pair of dependencies,
+    /// the first with this context and the second with the other context.
+    fn then_zip<C>(self, other: C) -> zip::ZipDependency<Self, C> {
+        zip::ZipDependency::new(self, other)
+    }
+
+    /// Creates new context which wraps the dependency provided with this context into [`Some`].
+    fn then_some(self) -> OptionDependencyWith<Self> {
+        OptionDependencyWith::new(self)
+    }
+
+    /// Creates new context which wraps the dependency provided with this context into [`Ok`].
+    fn then_ok(self) -> ResultDependencyWith<Self> {
+        ResultDependencyWith::new(self)
+    }
+
+    /// Creates new context which wraps the dependency provided with this context
+    /// into new [`Cell`](core::cell::Cell).
+    fn then_cell(self) -> wrap::CellDependencyWith<Self> {
+        wrap::CellDependencyWith::new(self)
+    }
+
+    /// Creates new context which wraps the dependency provided with this context
+    /// into new [`RefCell`](core::cell::RefCell).
+    fn then_ref_cell(self) -> wrap::RefCellDependencyWith<Self> {
+        wrap::RefCellDependencyWith::new(self)
+    }
+
+    /// Creates new context which boxes the dependency provided with this context.
+    #[cfg(feature = "alloc")]
+    fn then_box(self) -> wrap::BoxDependencyWith<Self> {
+        wrap::BoxDependencyWith::new(self)
+    }
+
+    /// Creates new context which pins the dependency provided with this context on the heap.
+    #[cfg(feature = "alloc")]
+    fn then_box_pin(self) -> wrap::PinBoxDependencyWith<Self> {
+        wrap::PinBoxDependencyWith::new(self)
+    }
+
+    /// Creates new context which wraps the dependency provided with this context
+    /// into new [`Rc`](alloc::rc::Rc).
+    #[cfg(feature = "alloc")]
+    fn then_rc(self) -> wrap::RcDependencyWith<Self> {
+        wrap::RcDependencyWith::new(self)
+    }
+
+    /// Creates new context which wraps the dependency provided with this context
+    /// into new [`Arc`](alloc::sync::Arc).
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    fn then_arc(self) -> wrap::ArcDependencyWith<Self> {
+        wrap::ArcDependencyWith::new(self)
+    }
+
+    /// Creates new context which wraps the dependency provided with this context
+    /// into new [`Mutex`](std::sync::Mutex).
+    #[cfg(feature = "std")]
+    fn then_mutex(self) -> wrap::MutexDependencyWith<Self> {
+        wrap::MutexDependencyWith::new(self)
+    }
+
+    /// Creates new context which wraps the dependency provided with this context
+    /// into new [`RwLock`](std::sync::RwLock).
+    #[cfg(feature = "std")]
+    fn then_rw_lock(self) -> wrap::RwLockDependencyWith<Self> {
+        wrap::RwLockDependencyWith::new(self)
+    }
+
+    /// Creates new context which upgrades the weak pointer dependency
+    /// provided with this context.
+    #[cfg(feature = "alloc")]
+    fn then_upgrade(self) -> weak::UpgradeWith<Self> {
+        weak::UpgradeWith::new(self)
+    }
+
+    /// Creates new context which copies the string slice provided with this context
+    /// into new [`String`](alloc::string::String).
+    #[cfg(feature = "alloc")]
+    fn then_own_str(self) -> bridge::OwnStrWith<Self> {
+        bridge::OwnStrWith::new(self)
+    }
+
+    /// Creates new context which borrows the [`String`](alloc::string::String)
+    /// provided by reference with this context as string slice.
+    #[cfg(feature = "alloc")]
+    fn then_borrow_str(self) -> bridge::BorrowStrWith<Self> {
+        bridge::BorrowStrWith::new(self)
+    }
+
+    /// Creates new context which copies the byte slice provided with this context
+    /// into new [`Vec`](alloc::vec::Vec).
+    #[cfg(feature = "alloc")]
+    fn then_own_bytes(self) -> bridge::OwnBytesWith<Self> {
+        bridge::OwnBytesWith::new(self)
+    }
+
+    /// Creates new context which borrows the [`Vec`](alloc::vec::Vec)
+    /// provided by reference with this context as byte slice.
+    #[cfg(feature = "alloc")]
+    fn then_borrow_bytes(self) -> bridge::BorrowBytesWith<Self> {
+        bridge::BorrowBytesWith::new(self)
+    }
+
+    /// Creates new context which provides inner dependency of the aliased newtype `N`
+    /// provided with this context.
+    fn then_alias<N>(self) -> alias::AliasDependencyWith<N, Self> {
+        alias::AliasDependencyWith::new(self)
+    }
+
+    /// Creates new context which borrows the reference to dependency of type `D`
+    /// provided with this context.
+    fn then_borrow<D>(self) -> borrow::BorrowDependencyWith<D, Self>
+    where
+        D: ?Sized,
+    {
+        borrow::BorrowDependencyWith::new(self)
+    }
+
+    /// Creates new context which provides the target
+    /// of the dependency of type `D` provided with this context.
+    fn then_deref<D>(self) -> deref::DerefDependencyWith<D, Self> {
+        deref::DerefDependencyWith::new(self)
+    }
+
+    /// Creates new context which provides dependency by [fallible conversion](TryFrom)
+    /// from the dependency of type `D` provided with this context.
+    fn then_try_from<D>(self) -> convert::TryFromDependencyWith<D, Self> {
+        convert::TryFromDependencyWith::new(self)
+    }
+
+    /// Creates new context which provides dependency by [fallible conversion](TryFrom)
+    /// from the shared reference to the dependency of type `D` provided with this context.
+    fn then_try_from_ref<D>(self) -> convert::TryFromDependencyRefWith<D, Self>
+    where
+        D: ?Sized,
+    {
+        convert::TryFromDependencyRefWith::new(self)
+    }
+
+    /// Creates new context which provides dependency by [fallible conversion](TryFrom)
+    /// from the unique reference to the dependency of type `D` provided with this context.
+    fn then_try_from_mut<D>(self) -> convert::TryFromDependencyMutWith<D, Self>
+    where
+        D: ?Sized,
+    {
+        convert::TryFromDependencyMutWith::new(self)
+    }
+
+    /// Creates new context which converts the reference `R` to dependency
+    /// provided with this context [into owned](alloc::borrow::ToOwned) dependency.
+    #[cfg(feature = "alloc")]
+    fn then_to_owned<R>(self) -> to_owned::ToOwnedDependencyWith<R, Self> {
+        to_owned::ToOwnedDependencyWith::new(self)
+    }
+
+    /// Creates new context which provides reference to the value
+    /// of the [lazy](crate::lazy::Lazy) dependency provided with this context,
+    /// constructing it on the first provision.
+    fn then_lazy(self) -> lazy::LazyDependencyWith<Self> {
+        lazy::LazyDependencyWith::new(self)
+    }
+
+    /// Creates new context which provides dependency by calling provided function
+    /// with the dependency provided with this context.
+    fn then_map_new<F, D, T>(self, function: F) -> function::FnDependencyWith<F, D, Self>
+    where
+        F: FnOnce(D) -> T,
+    {
+        function::FnDependencyWith::new(function, self)
+    }
+
+    /// Creates new context which provides previous value of the [`Cell`](core::cell::Cell)
+    /// dependency provided with this context, setting provided value instead.
+    fn then_replace_cell<T>(self, value: T) -> ReplaceCellWith<T, Self> {
+        ReplaceCellWith::new(value, self)
+    }
+
+    /// Creates new context which takes value of the [`Cell`](core::cell::Cell)
+    /// dependency provided with this context.
+    fn then_take_cell(self) -> TakeCellWith<Self> {
+        TakeCellWith::new(self)
+    }
+
+    /// Creates new context which provides dependency from the failpoint provider
+    /// with this context only until provided count of successful provisions is reached.
+    fn then_fail_after(self, limit: usize) -> FailAfterWith<Self> {
+        FailAfterWith::new(limit, self)
+    }
+
+    /// Creates new context which provides dependency from the failpoint provider
+    /// with this context unless it is of type `F`.
+    fn then_fail_for<F>(self) -> FailForWith<F, Self>
+    where
+        F: ?Sized,
+    {
+        FailForWith::new(self)
+    }
+
+    /// Creates new context which provides dependency out of the guard
+    /// acquired from the pool provider with this context.
+    #[cfg(feature = "pool")]
+    fn then_acquire(self) -> pool::AcquireWith<Self> {
+        pool::AcquireWith::new(self)
+    }
+
+    /// Creates new context which provides dependency from the
+    /// [black box](crate::black_box::BlackBox) provider with this context.
+    #[cfg(feature = "bench")]
+    fn then_black_box(self) -> black_box::BlackBoxDependencyWith<Self> {
+        black_box::BlackBoxDependencyWith::new(self)
+    }
+
+    /// Creates new context which provides dependency by shared reference
+    /// from the [downgraded](crate::downgrade::Downgraded) provider
+    /// by unique reference with this context.
+    fn then_downgrade(self) -> downgrade::DowngradeWith<Self> {
+        downgrade::DowngradeWith::new(self)
+    }
+
+    /// Creates new context which clones the dependency
+    /// provided by unique reference with this context.
+    fn then_clone_mut(self) -> clone::CloneMutWith<Self> {
+        clone::CloneMutWith::new(self)
+    }
+
+    /// Creates new context which provides the clone of dependency by value with this context,
+    /// restoring the original dependency into the remainder of the provider.
+    fn then_restore(self) -> restore::RestoreWith<Self> {
+        restore::RestoreWith::new(self)
+    }
+
+    /// Creates new context which provides dependency by value from the reference to the provider,
+    /// providing it by reference from the provider itself with this context.
+    fn then_by_ref(self) -> by_ref::ByRefWith<Self> {
+        by_ref::ByRefWith::new(self)
+    }
+
+    /// Creates new context which takes the dependency out of the unique reference
+    /// provided with this context, leaving the default value in its place.
+    fn then_take(self) -> take::TakeDependencyWith<Self> {
+        take::TakeDependencyWith::new(self)
+    }
+
+    /// Creates new context which replaces the dependency through the unique reference
+    /// provided with this context with provided value, providing the previous one.
+    fn then_replace<T>(self, value: T) -> take::ReplaceDependencyWith<T, Self> {
+        take::ReplaceDependencyWith::new(value, self)
+    }
+
+    /// Creates new context which provides array of `N` dependencies
+    /// by providing each of them in turn with this context.
+    fn then_collect<const N: usize>(self) -> collect::CollectWith<N, Self> {
+        collect::CollectWith::new(self)
+    }
+
+    /// Creates new context which provides array of `N` dependencies
+    /// by cloning the dependency provided with this context.
+    fn then_clone_n<const N: usize>(self) -> collect::CloneNWith<N, Self> {
+        collect::CloneNWith::new(self)
+    }
+
+    /// Creates new context which provides dependency from the side of the composite provider
+    /// pointed by the index `I` with this context.
+    fn then_select<I>(self) -> merge::SelectWith<I, Self> {
+        merge::SelectWith::new(self)
+    }
+
+    /// Creates new context which provides dependency from the intercepted provider
+    /// with this context, running its interceptor around the provision.
+    fn then_intercept(self) -> intercept::InterceptWith<Self> {
+        intercept::InterceptWith::new(self)
+    }
+
+    /// Creates new context which provides dependency from the spy provider
+    /// with this context, recording the request of the dependency.
+    #[cfg(feature = "alloc")]
+    fn then_spy(self) -> spy::SpyWith<Self> {
+        spy::SpyWith::new(self)
+    }
+
+    /// Creates new context which provides dependency by reference
+    /// from the next provider of the round-robin provider with this context.
+    fn then_rotate(self) -> balance::RotateWith<Self> {
+        balance::RotateWith::new(self)
+    }
+
+    /// Creates new context which provides dependency from the throttled provider
+    /// with this context only if its rate limit is not exceeded.
+    #[cfg(feature = "std")]
+    fn then_throttle(self) -> throttle::ThrottleWith<Self> {
+        throttle::ThrottleWith::new(self)
+    }
+
+    /// Creates new context which calls provided function with shared reference
+    /// to the dependency provided with this context, and then provides it unchanged.
+    fn then_inspect<F>(self, function: F) -> inspect::InspectDependencyWith<F, Self> {
+        inspect::InspectDependencyWith::new(function, self)
+    }
+
+    /// Creates new context which calls provided function with shared reference
+    /// to the dependency provided with this context, and then provides it
+    /// only if the function accepts it.
+    fn then_validate<F>(self, function: F) -> validate::ValidateDependencyWith<F, Self> {
+        validate::ValidateDependencyWith::new(function, self)
+    }
+
+    /// Creates new context which caches the dependency of type `T` provided with this context
+    /// when it is requested for the first time, and then provides its clones.
+    fn then_cache<T>(self) -> cache::CachedDependencyWith<T, Self> {
+        cache::CachedDependencyWith::new(self)
+    }
+
+    /// Creates new context which provides dependency by parsing the value
+    /// of the environment variable with provided key, falling back to this context
+    /// if the variable is not set.
+    #[cfg(feature = "std")]
+    fn then_from_env(self, key: &'static str) -> env::FromEnvOrWith<Self> {
+        env::FromEnvOrWith::new(key, self)
+    }
+
+    /// Creates new context which provides dependency with this context,
+    /// checking at compile time that its chain is not deeper than `MAX` contexts.
+    fn then_depth_guard<const MAX: usize>(self) -> depth::DepthGuardWith<MAX, Self> {
+        depth::DepthGuardWith::new(self)
+    }
+
+    /// Creates new context which annotates the error of failed provision with this context
+    /// with the name of the dependency type.
+    fn then_type_name(self) -> type_name::TypeNameWith<Self> {
+        type_name::TypeNameWith::new(self)
+    }
+
+    /// Creates new context which provides dependency from the profile-gated provider
+    /// with this context only if provided profile is active for it.
+    fn then_profile(self, profile: Profile) -> ProfileWith<Self> {
+        ProfileWith::new(profile, self)
+    }
+
+    /// Creates new context which acquires the lock of the mutex dependency
+    /// provided with this context, blocking until it is able to do so.
+    #[cfg(any(
+        feature = "std",
+        feature = "parking_lot",
+        feature = "tokio",
+        feature = "async-lock",
+    ))]
+    fn then_lock(self) -> lock::LockWith<Self> {
+        lock::LockWith::new(self)
+    }
+
+    /// Creates new context which attempts to acquire the lock of the mutex dependency
+    /// provided with this context without blocking.
+    #[cfg(any(
+        feature = "std",
+        feature = "parking_lot",
+        feature = "tokio",
+        feature = "async-lock",
+    ))]
+    fn then_try_lock(self) -> lock::TryLockWith<Self> {
+        lock::TryLockWith::new(self)
+    }
+
+    /// Creates new context which attempts to acquire the lock of the mutex dependency
+    /// provided with this context until provided timeout has elapsed.
+    #[cfg(feature = "parking_lot")]
+    fn then_try_lock_for(self, timeout: Duration) -> lock::TryLockForWith<Self> {
+        lock::TryLockForWith::new(timeout, self)
+    }
+
+    /// Creates new context which acquires the read-write lock dependency
+    /// provided with this context with shared read access, blocking until it is able to do so.
+    #[cfg(any(
+        feature = "std",
+        feature = "parking_lot",
+        feature = "tokio",
+        feature = "async-lock",
+    ))]
+    fn then_read(self) -> lock::ReadWith<Self> {
+        lock::ReadWith::new(self)
+    }
+
+    /// Creates new context which attempts to acquire the read-write lock dependency
+    /// provided with this context with shared read access without blocking.
+    #[cfg(any(
+        feature = "std",
+        feature = "parking_lot",
+        feature = "tokio",
+        feature = "async-lock",
+    ))]
+    fn then_try_read(self) -> lock::TryReadWith<Self> {
+        lock::TryReadWith::new(self)
+    }
+
+    /// Creates new context which attempts to acquire the read-write lock dependency
+    /// provided with this context with shared read access until provided timeout has elapsed.
+    #[cfg(feature = "parking_lot")]
+    fn then_try_read_for(self, timeout: Duration) -> lock::TryReadForWith<Self> {
+        lock::TryReadForWith::new(timeout, self)
+    }
+
+    /// Creates new context which acquires the read-write lock dependency
+    /// provided with this context with exclusive write access, blocking until it is able to do so.
+    #[cfg(any(
+        feature = "std",
+        feature = "parking_lot",
+        feature = "tokio",
+        feature = "async-lock",
+    ))]
+    fn then_write(self) -> lock::WriteWith<Self> {
+        lock::WriteWith::new(self)
+    }
+
+    /// Creates new context which attempts to acquire the read-write lock dependency
+    /// provided with this context with exclusive write access without blocking.
+    #[cfg(any(
+        feature = "std",
+        feature = "parking_lot",
+        feature = "tokio",
+        feature = "async-lock",
+    ))]
+    fn then_try_write(self) -> lock::TryWriteWith<Self> {
+        lock::TryWriteWith::new(self)
+    }
+
+    /// Creates new context which attempts to acquire the read-write lock dependency
+    /// provided with this context with exclusive write access until provided timeout has elapsed.
+    #[cfg(feature = "parking_lot")]
+    fn then_try_write_for(self, timeout: Duration) -> lock::TryWriteForWith<Self> {
+        lock::TryWriteForWith::new(timeout, self)
+    }
+
+    /// Creates new context which awaits the value sent through the oneshot receiver dependency
+    /// provided with this context.
+    #[cfg(feature = "futures")]
+    fn then_receive(self) -> channel::ReceiveWith<Self> {
+        channel::ReceiveWith::new(self)
+    }
+
+    /// Creates new context which provides the latest value of the watch receiver dependency
+    /// provided with this context.
+    #[cfg(feature = "tokio")]
+    fn then_latest(self) -> channel::LatestWith<Self> {
+        channel::LatestWith::new(self)
+    }
+
+    /// Creates new context which waits for the value of the watch receiver dependency
+    /// provided with this context to change.
+    #[cfg(feature = "tokio")]
+    fn then_changed(self) -> channel::ChangedWith<Self> {
+        channel::ChangedWith::new(self)
+    }
+}
+
+impl Context for Empty {}
+
+impl<C> Context for &mut C where C: Context {}
+
+impl<C> Context for Option<C> where C: Context {}
+
+/// Conversion of some value into the [context](Context).
+///
+/// This trait allows to pass plain values at the call site
+/// (for example, with
+/// [`TryProvideRefWith::try_provide_ref_with_into`](crate::with::TryProvideRefWith::try_provide_ref_with_into))
+/// instead of constructing the context explicitly.
+///
+/// It is implemented for:
+/// - all the [contexts](Context), which are converted into themselves;
+/// - functions of one argument, which are converted into [`FnDependency`](function::FnDependency)
+///   context calling the function with the dependency provided with [empty context](Empty);
+/// - `Duration` with `parking_lot` feature enabled, which is converted into
+///   `TryLockFor` context acquiring the mutex within such timeout.
+///   Timed contexts of read-write locks carry the same timeout,
+///   so they should be constructed explicitly.
+///
+/// Generic parameter `M` only distinguishes these implementations from each other
+/// (for example, functions of different argument types), and is always inferred.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{context::IntoContext, with::ProvideRefWith};
+///
+/// let provider = vec![1, 2, 3];
+///
+/// // Function is converted into the context which calls it with provided dependency.
+/// let sum: i32 = provider.provide_ref_with_into(|items: &[i32]| items.iter().sum());
+/// assert_eq!(sum, 6);
+///
+/// # #[cfg(feature = "parking_lot")] {
+/// use std::time::Duration;
+///
+/// use provide::context::lock::TryLockFor;
+///
+/// let timeout = Duration::from_millis(10);
+/// assert_eq!(timeout.into_context(), TryLockFor::new(timeout, ()));
+/// # }
+/// ```
+pub trait IntoContext<M> {
+    /// Type of context this value is converted into.
+    type Context: Context;
+
+    /// Converts this value into the context.
+    fn into_context(self) -> Self::Context;
+}
+
+impl<C> IntoContext<C> for C
+where
+    C: Context,
+{
+    type Context = C;
+
+    fn into_context(self) -> Self::Context {
+        self
+    }
+}
+
+impl<F, D, T> IntoContext<fn(D) -> T> for F
+where
+    F: FnOnce(D) -> T,
+{
+    type Context = function::FnDependency<F, D>;
+
+    fn into_context(self) -> Self::Context {
+        function::FnDependency::new(self, ())
+    }
+}
+
+#[cfg(feature = "parking_lot")]
+impl IntoContext<Duration> for Duration {
+    type Context = lock::TryLockFor;
+
+    fn into_context(self) -> Self::Context {
+        lock::TryLockFor::new(self, ())
+    }
+}
@@ -0,0 +1,103 @@
+//! Context which falls back to another context on failed provision.
+//!
+//! See [crate] documentation for more.
+
+use super::{
+    depth::{max_depth, Depth},
+    Context,
+};
+use crate::with::TryProvideRefWith;
+
+/// Context which provides dependency with the first context,
+/// retrying with the fallback context if provision fails.
+///
+/// This allows to combine two strategies of provision into one context,
+/// such as cloning dependency from the cache or converting it from the raw configuration otherwise.
+/// Error of the first provision is discarded, so error of this context
+/// is the error of the provision with the fallback context.
+///
+/// This context is implemented only for fallible provision by [shared reference](crate::TryProvideRef),
+/// because provision by value consumes the provider and provision by unique reference
+/// borrows it for the whole lifetime of the dependency, so provision cannot be retried.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::{convert::TryFromDependency, Context},
+///     with::TryProvideRefWith,
+///     ProvideRef, TryProvideRef,
+/// };
+///
+/// struct Settings {
+///     cached: Option<u16>,
+///     raw: i64,
+/// }
+///
+/// impl TryProvideRef<'_, u16> for Settings {
+///     type Error = &'static str;
+///
+///     fn try_provide_ref(&self) -> Result<u16, Self::Error> {
+///         let Self { cached, .. } = self;
+///         cached.ok_or("port is not cached")
+///     }
+/// }
+///
+/// impl ProvideRef<'_, i64> for Settings {
+///     fn provide_ref(&self) -> i64 {
+///         let Self { raw, .. } = self;
+///         *raw
+///     }
+/// }
+///
+/// let settings = Settings { cached: None, raw: 8080 };
+///
+/// let context = ().then_or_else(TryFromDependency::<i64>::default());
+/// let port: Result<u16, _> = settings.try_provide_ref_with(context);
+/// assert_eq!(port, Ok(8080));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct OrElse<C1, C2> {
+    context: C1,
+    fallback: C2,
+}
+
+impl<C1, C2> OrElse<C1, C2> {
+    /// Creates new context from the context to provide dependency with
+    /// and the context to retry provision with.
+    pub const fn new(context: C1, fallback: C2) -> Self {
+        Self { context, fallback }
+    }
+
+    /// Returns the inner context and the fallback context.
+    pub fn into_inner(self) -> (C1, C2) {
+        let Self { context, fallback } = self;
+        (context, fallback)
+    }
+}
+
+impl<C1, C2> Context for OrElse<C1, C2> {}
+
+impl<C1, C2> Depth for OrElse<C1, C2>
+where
+    C1: Depth,
+    C2: Depth,
+{
+    const DEPTH: usize = max_depth(C1::DEPTH, C2::DEPTH) + 1;
+}
+
+impl<'me, T, U, C1, C2> TryProvideRefWith<'me, T, OrElse<C1, C2>> for U
+where
+    U: TryProvideRefWith<'me, T, C1> + TryProvideRefWith<'me, T, C2> + ?Sized,
+{
+    type Error = <U as TryProvideRefWith<'me, T, C2>>::Error;
+
+    fn try_provide_ref_with(&'me self, context: OrElse<C1, C2>) -> Result<T, Self::Error> {
+        let OrElse { context, fallback } = context;
+        match <U as TryProvideRefWith<'me, T, C1>>::try_provide_ref_with(self, context) {
+            Ok(dependency) => Ok(dependency),
+            Err(_) => <U as TryProvideRefWith<'me, T, C2>>::try_provide_ref_with(self, fallback),
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! Context which acquires dependency from the [pool provider](PoolProvider).
+//!
+//! This module is available with `pool` feature enabled.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use crate::{
+    context::{depth::Depth, Context, Empty},
+    pool::{Pool, PoolProvider},
+    with::{TryProvideRefWith, TryProvideWith},
+};
+
+/// Context which provides dependency by acquiring the guard of the pooled resource
+/// from the [pool provider](PoolProvider).
+///
+/// Dependency itself is provided by value out of the acquired guard with the inner context,
+/// so the guard itself is provided with [empty context](Empty).
+/// Provision fails if the guard could not be acquired from the pool,
+/// or if dependency could not be provided out of the guard.
+///
+/// For pools which implement `AsyncPool` trait with `async` feature enabled,
+/// this context is implemented through the asynchronous traits, such as
+/// `TryProvideRefAsyncWith`.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::pool::{Acquire, AcquireError},
+///     pool::{Pool, PoolProvider},
+///     with::TryProvideRefWith,
+/// };
+///
+/// struct Exhausted;
+///
+/// impl Pool for Exhausted {
+///     type Guard = String;
+///     type Error = &'static str;
+///
+///     fn get(&self) -> Result<Self::Guard, Self::Error> {
+///         Err("pool is exhausted")
+///     }
+/// }
+///
+/// let provider = PoolProvider::new(Exhausted);
+/// let connection: Result<String, _> = provider.try_provide_ref_with(Acquire::default());
+/// assert_eq!(connection, Err(AcquireError::Acquire("pool is exhausted")));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AcquireWith<C>(C);
+
+/// Context which provides the guard of the pooled resource
+/// acquired from the [pool provider](PoolProvider).
+pub type Acquire = AcquireWith<Empty>;
+
+impl<C> AcquireWith<C> {
+    /// Creates new context from the context to provide dependency out of the guard with.
+    pub const fn new(context: C) -> Self {
+        Self(context)
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self(context) = self;
+        context
+    }
+}
+
+impl<C> Context for AcquireWith<C> {}
+
+impl<C> Depth for AcquireWith<C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<T, P, C> TryProvideWith<T, AcquireWith<C>> for PoolProvider<P>
+where
+    P: Pool,
+    P::Guard: TryProvideWith<T, C>,
+{
+    type Remainder = PoolProvider<P>;
+
+    type Error = AcquireError<<P::Guard as TryProvideWith<T, C>>::Error, P::Error>;
+
+    fn try_provide_with(
+        self,
+        context: AcquireWith<C>,
+    ) -> Result<(T, Self::Remainder), Self::Error> {
+        let AcquireWith(context) = context;
+        let guard = self.pool().get().map_err(AcquireError::Acquire)?;
+        let (dependency, _) = guard
+            .try_provide_with(context)
+            .map_err(AcquireError::Provide)?;
+        Ok((dependency, self))
+    }
+}
+
+impl<'me, T, P, C> TryProvideRefWith<'me, T, AcquireWith<C>> for PoolProvider<P>
+where
+    P: Pool,
+    P::Guard: TryProvideWith<T, C>,
+{
+    type Error = AcquireError<<P::Guard as TryProvideWith<T, C>>::Error, P::Error>;
+
+    fn try_provide_ref_with(&'me self, context: AcquireWith<C>) -> Result<T, Self::Error> {
+        let AcquireWith(context) = context;
+        let guard = self.pool().get().map_err(AcquireError::Acquire)?;
+        let (dependency, _) = guard
+            .try_provide_with(context)
+            .map_err(AcquireError::Provide)?;
+        Ok(dependency)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<'me, T, P, C> crate::with::TryProvideRefAsyncWith<'me, T, AcquireWith<C>> for PoolProvider<P>
+where
+    P: crate::pool::AsyncPool,
+    P::Guard: TryProvideWith<T, C>,
+{
+    type Error = AcquireError<<P::Guard as TryProvideWith<T, C>>::Error, P::Error>;
+
+    async fn try_provide_ref_async_with(
+        &'me self,
+        context: AcquireWith<C>,
+    ) -> Result<T, Self::Error> {
+        let AcquireWith(context) = context;
+        let guard = self.pool().get().await.map_err(AcquireError::Acquire)?;
+        let (dependency, _) = guard
+            .try_provide_with(context)
+            .map_err(AcquireError::Provide)?;
+        Ok(dependency)
+    }
+}
+
+/// The error type returned when dependency could not be provided with [`AcquireWith`] context.
+///
+/// Error of the provision out of the guard is displayed as is, while error of the pool
+/// is reported as the [source](Error::source) of this error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AcquireError<E, F> {
+    /// Guard could not be acquired from the pool.
+    Acquire(F),
+    /// Guard was acquired, but dependency was not provided out of it with the inner context.
+    Provide(E),
+}
+
+impl<E, F> Display for AcquireError<E, F>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Acquire(_) => f.write_str("failed to acquire the guard from the pool"),
+            Self::Provide(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl<E, F> Error for AcquireError<E, F>
+where
+    E: Error,
+    F: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Acquire(error) => Some(error),
+            Self::Provide(error) => error.source(),
+        }
+    }
+}
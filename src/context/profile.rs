@@ -0,0 +1,125 @@
+//! Context which carries active [profile](Profile) for [profile-gated](ProfileGated) providers.
+//!
+//! See [crate] documentation for more.
+
+use crate::{
+    context::{depth::Depth, Context, Empty},
+    profile::{GatedError, Profile, ProfileGated},
+    with::{TryProvideMutWith, TryProvideRefWith, TryProvideWith},
+};
+
+/// Context which provides dependency from the [profile-gated](ProfileGated) provider
+/// only if the carried profile is active for it.
+///
+/// Dependency itself is provided from the inner provider with the inner context.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::profile::Profiled,
+///     profile::{Profile, ProfileGated},
+///     with::TryProvideRefWith,
+/// };
+///
+/// let dev = ProfileGated::new(Box::new("sqlite::memory:"), Profile::Dev);
+/// let prod = ProfileGated::new(Box::new("postgres://prod"), Profile::Prod);
+///
+/// let active = Profiled::new(Profile::Prod, ());
+/// let url: &&str = dev
+///     .try_provide_ref_with(active)
+///     .or_else(|_| prod.try_provide_ref_with(active))
+///     .unwrap();
+/// assert_eq!(*url, "postgres://prod");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ProfileWith<C> {
+    profile: Profile,
+    context: C,
+}
+
+/// Context which provides dependency from the [profile-gated](ProfileGated) provider
+/// only if the carried profile is active for it.
+///
+/// Dependency itself is provided from the inner provider with [empty context](Empty).
+pub type Profiled = ProfileWith<Empty>;
+
+impl<C> ProfileWith<C> {
+    /// Creates new context from the active profile
+    /// and the context to provide dependency from the inner provider with.
+    pub const fn new(profile: Profile, context: C) -> Self {
+        Self { profile, context }
+    }
+
+    /// Returns the active profile.
+    pub const fn profile(&self) -> Profile {
+        self.profile
+    }
+
+    /// Returns the active profile and the inner context.
+    pub fn into_inner(self) -> (Profile, C) {
+        let Self { profile, context } = self;
+        (profile, context)
+    }
+}
+
+impl<C> Context for ProfileWith<C> {}
+
+impl<C> Depth for ProfileWith<C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<T, P, C> TryProvideWith<T, ProfileWith<C>> for ProfileGated<P>
+where
+    P: TryProvideWith<T, C>,
+{
+    type Remainder = P::Remainder;
+
+    type Error = GatedError<P::Error>;
+
+    fn try_provide_with(
+        self,
+        context: ProfileWith<C>,
+    ) -> Result<(T, Self::Remainder), Self::Error> {
+        let ProfileWith { profile, context } = context;
+        self.check(profile)?;
+        let (provider, _) = self.into_inner();
+        provider
+            .try_provide_with(context)
+            .map_err(GatedError::Provide)
+    }
+}
+
+impl<'me, T, P, C> TryProvideRefWith<'me, T, ProfileWith<C>> for ProfileGated<P>
+where
+    P: TryProvideRefWith<'me, T, C>,
+{
+    type Error = GatedError<P::Error>;
+
+    fn try_provide_ref_with(&'me self, context: ProfileWith<C>) -> Result<T, Self::Error> {
+        let ProfileWith { profile, context } = context;
+        self.check(profile)?;
+        self.provider()
+            .try_provide_ref_with(context)
+            .map_err(GatedError::Provide)
+    }
+}
+
+impl<'me, T, P, C> TryProvideMutWith<'me, T, ProfileWith<C>> for ProfileGated<P>
+where
+    P: TryProvideMutWith<'me, T, C>,
+{
+    type Error = GatedError<P::Error>;
+
+    fn try_provide_mut_with(&'me mut self, context: ProfileWith<C>) -> Result<T, Self::Error> {
+        let ProfileWith { profile, context } = context;
+        self.check(profile)?;
+        self.provider_mut()
+            .try_provide_mut_with(context)
+            .map_err(GatedError::Provide)
+    }
+}
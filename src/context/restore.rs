@@ -0,0 +1,199 @@
+//! Context which provides dependency by value while restoring it into the remainder.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use super::{depth::Depth, Context, Empty};
+use crate::with::{ProvideWith, TryProvideWith};
+
+/// Context which provides the [clone](Clone) of dependency by value,
+/// restoring the original dependency into the remainder of the provider.
+///
+/// Dependency itself is provided by value with the inner context,
+/// and then the remainder is reassembled [with](crate::with::With) the original dependency,
+/// so the same dependency could be provided from the remainder again.
+/// Fallible provision reassembles the remainder [fallibly](crate::with::TryWith),
+/// so it fails if the remainder rejects the dependency.
+///
+/// This context is implemented only for provision by value,
+/// because there is no remainder to restore dependency into
+/// while providing it by reference.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::{restore::Restore, Context},
+///     with::{ProvideWith, TryProvideWith},
+/// };
+///
+/// let provider = String::from("hello");
+///
+/// let (dependency, remainder): (String, _) = provider.provide_with(Restore::default());
+/// assert_eq!((dependency.as_str(), remainder.as_str()), ("hello", "hello"));
+///
+/// let result: Result<(String, String), _> = remainder.try_provide_with(().then_restore());
+/// assert_eq!(result, Ok((String::from("hello"), String::from("hello"))));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RestoreWith<C>(C);
+
+/// Context which provides the [clone](Clone) of dependency by value,
+/// restoring the original dependency into the remainder of the provider.
+///
+/// Dependency itself is provided by value with [empty context](Empty).
+pub type Restore = RestoreWith<Empty>;
+
+impl<C> RestoreWith<C> {
+    /// Creates new context from the context to provide dependency to restore with.
+    pub const fn new(context: C) -> Self {
+        Self(context)
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self(context) = self;
+        context
+    }
+}
+
+impl<C> Context for RestoreWith<C> {}
+
+impl<C> Depth for RestoreWith<C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<T, U, C> ProvideWith<T, RestoreWith<C>> for U
+where
+    C: reassemble::Reassemble<T, U>,
+{
+    type Remainder = C::Output;
+
+    fn provide_with(self, context: RestoreWith<C>) -> (T, Self::Remainder) {
+        let RestoreWith(context) = context;
+        context.provide_reassembled(self)
+    }
+}
+
+impl<T, U, C> TryProvideWith<T, RestoreWith<C>> for U
+where
+    C: reassemble::TryReassemble<T, U>,
+{
+    type Remainder = C::Output;
+
+    type Error = C::Error;
+
+    fn try_provide_with(
+        self,
+        context: RestoreWith<C>,
+    ) -> Result<(T, Self::Remainder), Self::Error> {
+        let RestoreWith(context) = context;
+        context.try_provide_reassembled(self)
+    }
+}
+
+/// Provision which reassembles the remainder, implemented by the inner context.
+///
+/// Bounds on the remainder are checked only when the inner context is known,
+/// so the compiler does not recurse into them while looking for the context
+/// of some unrelated provision.
+mod reassemble {
+    use super::RestoreError;
+    use crate::with::{ProvideWith, TryProvideWith, TryWith, With};
+
+    pub trait Reassemble<T, U>: Sized {
+        type Output;
+
+        fn provide_reassembled(self, provider: U) -> (T, Self::Output);
+    }
+
+    impl<T, U, C> Reassemble<T, U> for C
+    where
+        T: Clone,
+        U: ProvideWith<T, C>,
+        U::Remainder: With<T>,
+    {
+        type Output = <U::Remainder as With<T>>::Output;
+
+        fn provide_reassembled(self, provider: U) -> (T, Self::Output) {
+            let (dependency, remainder) = provider.provide_with(self);
+            let remainder = remainder.with(dependency.clone());
+            (dependency, remainder)
+        }
+    }
+
+    pub trait TryReassemble<T, U>: Sized {
+        type Output;
+
+        type Error;
+
+        fn try_provide_reassembled(self, provider: U) -> Result<(T, Self::Output), Self::Error>;
+    }
+
+    impl<T, U, C> TryReassemble<T, U> for C
+    where
+        T: Clone,
+        U: TryProvideWith<T, C>,
+        U::Remainder: TryWith<T>,
+    {
+        type Output = <U::Remainder as TryWith<T>>::Output;
+
+        type Error = RestoreError<U::Error, <U::Remainder as TryWith<T>>::Error>;
+
+        fn try_provide_reassembled(self, provider: U) -> Result<(T, Self::Output), Self::Error> {
+            let (dependency, remainder) = provider
+                .try_provide_with(self)
+                .map_err(RestoreError::Provide)?;
+            let remainder = remainder
+                .try_with(dependency.clone())
+                .map_err(RestoreError::Restore)?;
+            Ok((dependency, remainder))
+        }
+    }
+}
+
+/// The error type returned when dependency could not be provided with [`RestoreWith`] context.
+///
+/// Error of the provision is displayed as is, while error of the reassembly
+/// is reported as the [source](Error::source) of this error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RestoreError<E, F> {
+    /// Dependency was not provided with the inner context.
+    Provide(E),
+    /// Dependency was provided, but the remainder rejected it.
+    Restore(F),
+}
+
+impl<E, F> Display for RestoreError<E, F>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Provide(error) => Display::fmt(error, f),
+            Self::Restore(_) => f.write_str("failed to restore dependency into the remainder"),
+        }
+    }
+}
+
+impl<E, F> Error for RestoreError<E, F>
+where
+    E: Error,
+    F: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Provide(error) => error.source(),
+            Self::Restore(error) => Some(error),
+        }
+    }
+}
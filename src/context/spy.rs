@@ -0,0 +1,155 @@
+//! Context which provides dependencies from [spy providers](SpyProvider).
+//!
+//! This module is available with `alloc` feature enabled.
+//!
+//! See [crate] documentation for more.
+
+use crate::{
+    context::{depth::Depth, Context, Empty},
+    spy::{Flavor, Request, SpyProvider},
+    with::{
+        ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideMutWith, TryProvideRefWith,
+        TryProvideWith,
+    },
+};
+
+/// Context which provides dependency from the [spy provider](SpyProvider),
+/// recording the request of the dependency together with its [flavor](Flavor).
+///
+/// Dependency itself is provided from the inner provider with the inner context.
+/// Request is recorded with the type of provided dependency before the provision,
+/// so failed requests are recorded too.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::{spy::Spy, Context},
+///     spy::{Flavor, Request, SpyProvider},
+///     with::{ProvideRefWith, ProvideWith},
+/// };
+///
+/// let provider = SpyProvider::new(String::from("hello"));
+/// let dependency: Option<&str> = provider.provide_ref_with(().then_some().then_spy());
+/// assert_eq!(dependency, Some("hello"));
+///
+/// let (dependency, remainder): (Box<str>, _) = provider.provide_with(Spy::default());
+/// assert_eq!(&*dependency, "hello");
+///
+/// let ((), requests) = remainder.into_inner();
+/// assert_eq!(
+///     requests,
+///     [
+///         Request::new::<Option<&str>>(Flavor::Ref),
+///         Request::new::<Box<str>>(Flavor::Value),
+///     ],
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SpyWith<C>(C);
+
+/// Context which provides dependency from the [spy provider](SpyProvider),
+/// recording the request of the dependency together with its [flavor](Flavor).
+///
+/// Dependency itself is provided from the inner provider with [empty context](Empty).
+pub type Spy = SpyWith<Empty>;
+
+impl<C> SpyWith<C> {
+    /// Creates new context from the context to provide dependency from the inner provider with.
+    pub const fn new(context: C) -> Self {
+        Self(context)
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self(context) = self;
+        context
+    }
+}
+
+impl<C> Context for SpyWith<C> {}
+
+impl<C> Depth for SpyWith<C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<T, P, C> ProvideWith<T, SpyWith<C>> for SpyProvider<P>
+where
+    P: ProvideWith<T, C>,
+{
+    type Remainder = SpyProvider<P::Remainder>;
+
+    fn provide_with(self, context: SpyWith<C>) -> (T, Self::Remainder) {
+        let SpyWith(context) = context;
+        let (provider, mut requests) = self.into_inner();
+        requests.push(Request::new::<T>(Flavor::Value));
+        let (dependency, remainder) = provider.provide_with(context);
+        (dependency, SpyProvider::with_requests(remainder, requests))
+    }
+}
+
+impl<T, P, C> TryProvideWith<T, SpyWith<C>> for SpyProvider<P>
+where
+    P: TryProvideWith<T, C>,
+{
+    type Remainder = SpyProvider<P::Remainder>;
+
+    type Error = P::Error;
+
+    fn try_provide_with(self, context: SpyWith<C>) -> Result<(T, Self::Remainder), Self::Error> {
+        let SpyWith(context) = context;
+        let (provider, mut requests) = self.into_inner();
+        requests.push(Request::new::<T>(Flavor::Value));
+        let (dependency, remainder) = provider.try_provide_with(context)?;
+        Ok((dependency, SpyProvider::with_requests(remainder, requests)))
+    }
+}
+
+impl<'me, T, P, C> ProvideRefWith<'me, T, SpyWith<C>> for SpyProvider<P>
+where
+    P: ProvideRefWith<'me, T, C>,
+{
+    fn provide_ref_with(&'me self, context: SpyWith<C>) -> T {
+        let SpyWith(context) = context;
+        self.record::<T>(Flavor::Ref).provide_ref_with(context)
+    }
+}
+
+impl<'me, T, P, C> TryProvideRefWith<'me, T, SpyWith<C>> for SpyProvider<P>
+where
+    P: TryProvideRefWith<'me, T, C>,
+{
+    type Error = P::Error;
+
+    fn try_provide_ref_with(&'me self, context: SpyWith<C>) -> Result<T, Self::Error> {
+        let SpyWith(context) = context;
+        self.record::<T>(Flavor::Ref).try_provide_ref_with(context)
+    }
+}
+
+impl<'me, T, P, C> ProvideMutWith<'me, T, SpyWith<C>> for SpyProvider<P>
+where
+    P: ProvideMutWith<'me, T, C>,
+{
+    fn provide_mut_with(&'me mut self, context: SpyWith<C>) -> T {
+        let SpyWith(context) = context;
+        self.record_mut::<T>(Flavor::Mut).provide_mut_with(context)
+    }
+}
+
+impl<'me, T, P, C> TryProvideMutWith<'me, T, SpyWith<C>> for SpyProvider<P>
+where
+    P: TryProvideMutWith<'me, T, C>,
+{
+    type Error = P::Error;
+
+    fn try_provide_mut_with(&'me mut self, context: SpyWith<C>) -> Result<T, Self::Error> {
+        let SpyWith(context) = context;
+        self.record_mut::<T>(Flavor::Mut)
+            .try_provide_mut_with(context)
+    }
+}
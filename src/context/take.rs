@@ -0,0 +1,188 @@
+//! Contexts which provide dependency by moving it out through unique reference.
+//!
+//! See [crate] documentation for more.
+
+use core::mem;
+
+use super::{depth::Depth, Context, Empty};
+use crate::with::{ProvideMutWith, ProvideWith};
+
+/// Context which provides dependency by [taking](mem::take) it
+/// out of the unique reference to it provided with the inner context,
+/// leaving the [default](Default) value in its place.
+///
+/// This allows to get owned dependency out of the provider
+/// without consuming the whole provider.
+/// The provider can be borrowed either explicitly, providing by [value](crate::with::ProvideWith)
+/// from the unique reference to the provider, or implicitly,
+/// providing by [unique reference](ProvideMutWith) from the provider itself.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{context::take::TakeDependency, with::ProvideMutWith};
+///
+/// struct Provider {
+///     buffer: Vec<u8>,
+/// }
+///
+/// impl AsMut<Vec<u8>> for Provider {
+///     fn as_mut(&mut self) -> &mut Vec<u8> {
+///         let Self { buffer } = self;
+///         buffer
+///     }
+/// }
+///
+/// let mut provider = Provider { buffer: vec![1, 2, 3] };
+///
+/// let buffer: Vec<u8> = provider.provide_mut_with(TakeDependency::default());
+/// assert_eq!(buffer, [1, 2, 3]);
+/// assert!(provider.buffer.is_empty());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TakeDependencyWith<C>(C);
+
+/// Context which provides dependency by [taking](mem::take) it
+/// out of the unique reference to it provided with [empty context](Empty).
+pub type TakeDependency = TakeDependencyWith<Empty>;
+
+impl<C> TakeDependencyWith<C> {
+    /// Creates new context from the context to provide unique reference to dependency with.
+    pub const fn new(context: C) -> Self {
+        Self(context)
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self(context) = self;
+        context
+    }
+}
+
+impl<C> Context for TakeDependencyWith<C> {}
+
+impl<C> Depth for TakeDependencyWith<C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<'a, T, P, C> ProvideWith<T, TakeDependencyWith<C>> for &'a mut P
+where
+    T: Default + 'a,
+    P: ProvideMutWith<'a, &'a mut T, C> + ?Sized,
+{
+    type Remainder = ();
+
+    fn provide_with(self, context: TakeDependencyWith<C>) -> (T, Self::Remainder) {
+        let TakeDependencyWith(context) = context;
+        let dependency = self.provide_mut_with(context);
+        (mem::take(dependency), ())
+    }
+}
+
+impl<'me, T, U, C> ProvideMutWith<'me, T, TakeDependencyWith<C>> for U
+where
+    T: Default + 'me,
+    U: ProvideMutWith<'me, &'me mut T, C> + ?Sized,
+{
+    fn provide_mut_with(&'me mut self, context: TakeDependencyWith<C>) -> T {
+        let TakeDependencyWith(context) = context;
+        let dependency = self.provide_mut_with(context);
+        mem::take(dependency)
+    }
+}
+
+/// Context which provides dependency by [replacing](mem::replace) it
+/// through the unique reference to it provided with the inner context
+/// with the value carried by this context.
+///
+/// Previous value of the dependency is provided, while the carried value is left in its place.
+/// This allows to hot-swap configuration or connections held inside of the provider.
+/// The provider can be borrowed either explicitly, providing by [value](crate::with::ProvideWith)
+/// from the unique reference to the provider, or implicitly,
+/// providing by [unique reference](ProvideMutWith) from the provider itself.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{context::take::ReplaceDependency, with::ProvideMutWith};
+///
+/// struct Provider {
+///     url: &'static str,
+/// }
+///
+/// impl AsMut<&'static str> for Provider {
+///     fn as_mut(&mut self) -> &mut &'static str {
+///         let Self { url } = self;
+///         url
+///     }
+/// }
+///
+/// let mut provider = Provider { url: "postgres://old" };
+///
+/// let url: &str = provider.provide_mut_with(ReplaceDependency::new("postgres://new", ()));
+/// assert_eq!((url, provider.url), ("postgres://old", "postgres://new"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ReplaceDependencyWith<T, C> {
+    value: T,
+    context: C,
+}
+
+/// Context which provides dependency by [replacing](mem::replace) it
+/// through the unique reference to it provided with [empty context](Empty)
+/// with the value carried by this context.
+pub type ReplaceDependency<T> = ReplaceDependencyWith<T, Empty>;
+
+impl<T, C> ReplaceDependencyWith<T, C> {
+    /// Creates new context from the value to be left in place of the dependency
+    /// and the context to provide unique reference to dependency with.
+    pub const fn new(value: T, context: C) -> Self {
+        Self { value, context }
+    }
+
+    /// Returns the value to be left in place of the dependency and the inner context.
+    pub fn into_inner(self) -> (T, C) {
+        let Self { value, context } = self;
+        (value, context)
+    }
+}
+
+impl<T, C> Context for ReplaceDependencyWith<T, C> {}
+
+impl<T, C> Depth for ReplaceDependencyWith<T, C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<'a, T, P, C> ProvideWith<T, ReplaceDependencyWith<T, C>> for &'a mut P
+where
+    T: 'a,
+    P: ProvideMutWith<'a, &'a mut T, C> + ?Sized,
+{
+    type Remainder = ();
+
+    fn provide_with(self, context: ReplaceDependencyWith<T, C>) -> (T, Self::Remainder) {
+        let ReplaceDependencyWith { value, context } = context;
+        let dependency = self.provide_mut_with(context);
+        (mem::replace(dependency, value), ())
+    }
+}
+
+impl<'me, T, U, C> ProvideMutWith<'me, T, ReplaceDependencyWith<T, C>> for U
+where
+    T: 'me,
+    U: ProvideMutWith<'me, &'me mut T, C> + ?Sized,
+{
+    fn provide_mut_with(&'me mut self, context: ReplaceDependencyWith<T, C>) -> T {
+        let ReplaceDependencyWith { value, context } = context;
+        let dependency = self.provide_mut_with(context);
+        mem::replace(dependency, value)
+    }
+}
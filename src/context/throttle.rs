@@ -0,0 +1,126 @@
+//! Context which provides dependencies from [throttled](Throttled) providers.
+//!
+//! This module is available with `std` feature enabled.
+//!
+//! See [crate] documentation for more.
+
+use crate::{
+    clock::Clock,
+    context::{depth::Depth, Context, Empty},
+    throttle::{ThrottleError, Throttled},
+    with::{TryProvideMutWith, TryProvideRefWith, TryProvideWith},
+};
+
+/// Context which provides dependency from the [throttled](Throttled) provider
+/// only if the rate limit of the provider is not exceeded.
+///
+/// Dependency itself is provided from the inner provider with the inner context,
+/// and only successful provision by reference takes a token out of the bucket.
+/// Provision by value consumes the provider along with its bucket,
+/// so it only checks that a token is available.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use provide::{context::throttle::Throttle, throttle::Throttled, with::TryProvideRefWith};
+///
+/// let provider = Throttled::new(String::from("license"), 2, Duration::from_secs(60));
+///
+/// let first: Result<&str, _> = provider.try_provide_ref_with(Throttle::default());
+/// let second: Result<&str, _> = provider.try_provide_ref_with(Throttle::default());
+/// let third: Result<&str, _> = provider.try_provide_ref_with(Throttle::default());
+/// assert!(first.is_ok() && second.is_ok() && third.is_err());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ThrottleWith<C>(C);
+
+/// Context which provides dependency from the [throttled](Throttled) provider
+/// only if the rate limit of the provider is not exceeded.
+///
+/// Dependency itself is provided from the inner provider with [empty context](Empty).
+pub type Throttle = ThrottleWith<Empty>;
+
+impl<C> ThrottleWith<C> {
+    /// Creates new context from the context to provide dependency from the inner provider with.
+    pub const fn new(context: C) -> Self {
+        Self(context)
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self(context) = self;
+        context
+    }
+}
+
+impl<C> Context for ThrottleWith<C> {}
+
+impl<C> Depth for ThrottleWith<C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<T, P, K, C> TryProvideWith<T, ThrottleWith<C>> for Throttled<P, K>
+where
+    P: TryProvideWith<T, C>,
+    K: Clock,
+{
+    type Remainder = P::Remainder;
+
+    type Error = ThrottleError<P::Error>;
+
+    fn try_provide_with(
+        self,
+        context: ThrottleWith<C>,
+    ) -> Result<(T, Self::Remainder), Self::Error> {
+        let ThrottleWith(context) = context;
+        let (_, bucket) = self.parts();
+        bucket.check()?;
+        self.into_inner()
+            .try_provide_with(context)
+            .map_err(ThrottleError::Provide)
+    }
+}
+
+impl<'me, T, P, K, C> TryProvideRefWith<'me, T, ThrottleWith<C>> for Throttled<P, K>
+where
+    P: TryProvideRefWith<'me, T, C>,
+    K: Clock,
+{
+    type Error = ThrottleError<P::Error>;
+
+    fn try_provide_ref_with(&'me self, context: ThrottleWith<C>) -> Result<T, Self::Error> {
+        let ThrottleWith(context) = context;
+        let (provider, bucket) = self.parts();
+        let now = bucket.check()?;
+        let dependency = provider
+            .try_provide_ref_with(context)
+            .map_err(ThrottleError::Provide)?;
+        bucket.take(now);
+        Ok(dependency)
+    }
+}
+
+impl<'me, T, P, K, C> TryProvideMutWith<'me, T, ThrottleWith<C>> for Throttled<P, K>
+where
+    P: TryProvideMutWith<'me, T, C>,
+    K: Clock,
+{
+    type Error = ThrottleError<P::Error>;
+
+    fn try_provide_mut_with(&'me mut self, context: ThrottleWith<C>) -> Result<T, Self::Error> {
+        let ThrottleWith(context) = context;
+        let (provider, bucket) = self.parts_mut();
+        let now = bucket.check()?;
+        let dependency = provider
+            .try_provide_mut_with(context)
+            .map_err(ThrottleError::Provide)?;
+        bucket.take(now);
+        Ok(dependency)
+    }
+}
@@ -0,0 +1,199 @@
+//! Context which provides owned dependency from the reference to another provided dependency.
+//!
+//! See [crate] documentation for more.
+
+use alloc::borrow::ToOwned;
+use core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
+
+use super::{depth::Depth, Context, Empty};
+use crate::{
+    name::type_name_of,
+    with::{ProvideRefWith, ProvideWith, TryProvideRefWith, TryProvideWith},
+};
+
+/// Context which provides owned dependency by [converting](ToOwned)
+/// the reference `R` to dependency provided with the inner context.
+///
+/// Unlike cloning, this allows to provide owned dependency out of the reference
+/// to unsized dependency, for example, [`String`](alloc::string::String) from the provider of `&str`
+/// or `PathBuf` from the provider of `&Path`.
+/// Type of the reference is specified in full, so the lifetime of the reference
+/// could be inferred: `ToOwnedDependency<&str>`.
+/// For strings and byte buffers specifically, see also [bridge](super::bridge) contexts.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::to_owned::ToOwnedDependency,
+///     with::{ProvideRefWith, ProvideWith},
+/// };
+///
+/// struct Config {
+///     name: &'static str,
+/// }
+///
+/// impl AsRef<str> for Config {
+///     fn as_ref(&self) -> &str {
+///         let Self { name } = self;
+///         name
+///     }
+/// }
+///
+/// let provider = Config { name: "config" };
+///
+/// let mut name: String = provider.provide_ref_with(ToOwnedDependency::<&str>::default());
+/// name.push_str(".toml");
+/// assert_eq!(name, "config.toml");
+///
+/// let (bytes, ()): (Vec<u8>, _) = b"data".as_slice().provide_with(ToOwnedDependency::<&[u8]>::default());
+/// assert_eq!(bytes, b"data");
+/// ```
+pub struct ToOwnedDependencyWith<R, C> {
+    context: C,
+    marker: PhantomData<fn() -> R>,
+}
+
+/// Context which provides owned dependency by [converting](ToOwned)
+/// the reference `R` to dependency provided with [empty context](Empty).
+pub type ToOwnedDependency<R> = ToOwnedDependencyWith<R, Empty>;
+
+impl<R, C> ToOwnedDependencyWith<R, C> {
+    /// Creates new context from the context to provide the reference to dependency with.
+    pub const fn new(context: C) -> Self {
+        Self {
+            context,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self { context, .. } = self;
+        context
+    }
+}
+
+impl<R, C> Debug for ToOwnedDependencyWith<R, C>
+where
+    C: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { context, .. } = self;
+        f.debug_struct("ToOwnedDependencyWith")
+            .field("type", &type_name_of::<R>())
+            .field("context", context)
+            .finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<R, C> defmt::Format for ToOwnedDependencyWith<R, C>
+where
+    C: defmt::Format,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        let Self { context, .. } = self;
+        defmt::write!(
+            f,
+            "ToOwnedDependencyWith {{ type: {}, context: {} }}",
+            type_name_of::<R>(),
+            context,
+        );
+    }
+}
+
+impl<R, C> Default for ToOwnedDependencyWith<R, C>
+where
+    C: Default,
+{
+    fn default() -> Self {
+        Self::new(C::default())
+    }
+}
+
+impl<R, C> Clone for ToOwnedDependencyWith<R, C>
+where
+    C: Clone,
+{
+    fn clone(&self) -> Self {
+        let Self { context, .. } = self;
+        Self::new(context.clone())
+    }
+}
+
+impl<R, C> Copy for ToOwnedDependencyWith<R, C> where C: Copy {}
+
+impl<R, C> Context for ToOwnedDependencyWith<R, C> {}
+
+impl<R, C> Depth for ToOwnedDependencyWith<R, C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<'a, D, U, C> ProvideWith<D::Owned, ToOwnedDependencyWith<&'a D, C>> for U
+where
+    D: ToOwned + ?Sized,
+    U: ProvideWith<&'a D, C>,
+{
+    type Remainder = U::Remainder;
+
+    fn provide_with(self, context: ToOwnedDependencyWith<&'a D, C>) -> (D::Owned, Self::Remainder) {
+        let ToOwnedDependencyWith { context, .. } = context;
+        let (dependency, remainder) = self.provide_with(context);
+        (dependency.to_owned(), remainder)
+    }
+}
+
+impl<'a, D, U, C> TryProvideWith<D::Owned, ToOwnedDependencyWith<&'a D, C>> for U
+where
+    D: ToOwned + ?Sized,
+    U: TryProvideWith<&'a D, C>,
+{
+    type Remainder = U::Remainder;
+
+    type Error = U::Error;
+
+    fn try_provide_with(
+        self,
+        context: ToOwnedDependencyWith<&'a D, C>,
+    ) -> Result<(D::Owned, Self::Remainder), Self::Error> {
+        let ToOwnedDependencyWith { context, .. } = context;
+        let (dependency, remainder) = self.try_provide_with(context)?;
+        Ok((dependency.to_owned(), remainder))
+    }
+}
+
+impl<'me, 'a, D, U, C> ProvideRefWith<'me, D::Owned, ToOwnedDependencyWith<&'a D, C>> for U
+where
+    D: ToOwned + ?Sized,
+    U: ProvideRefWith<'me, &'a D, C> + ?Sized,
+{
+    fn provide_ref_with(&'me self, context: ToOwnedDependencyWith<&'a D, C>) -> D::Owned {
+        let ToOwnedDependencyWith { context, .. } = context;
+        let dependency = self.provide_ref_with(context);
+        dependency.to_owned()
+    }
+}
+
+impl<'me, 'a, D, U, C> TryProvideRefWith<'me, D::Owned, ToOwnedDependencyWith<&'a D, C>> for U
+where
+    D: ToOwned + ?Sized,
+    U: TryProvideRefWith<'me, &'a D, C> + ?Sized,
+{
+    type Error = U::Error;
+
+    fn try_provide_ref_with(
+        &'me self,
+        context: ToOwnedDependencyWith<&'a D, C>,
+    ) -> Result<D::Owned, Self::Error> {
+        let ToOwnedDependencyWith { context, .. } = context;
+        let dependency = self.try_provide_ref_with(context)?;
+        Ok(dependency.to_owned())
+    }
+}
@@ -0,0 +1,167 @@
+//! Context which annotates errors of the fallible provision with the name of the dependency type.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    any::type_name,
+    error::Error,
+    fmt::{self, Display},
+};
+
+use super::{depth::Depth, Context, Empty};
+use crate::with::{TryProvideMutWith, TryProvideRefWith, TryProvideWith};
+
+/// Context which provides dependency with the inner context,
+/// annotating the error of failed provision with the [name](type_name) of the dependency type.
+///
+/// This allows to answer which dependency could not be provided from the error alone.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::{
+///         failpoint::FailAfter,
+///         type_name::{TypeName, WithTypeName},
+///         Context,
+///     },
+///     failpoint::{Failpoint, FailpointError},
+///     with::TryProvideRefWith,
+/// };
+///
+/// let provider = Failpoint::new(Box::new(1));
+///
+/// let result: Result<&i32, _> = provider.try_provide_ref_with(FailAfter::new(0, ()).then_type_name());
+/// let error = result.unwrap_err();
+/// assert_eq!(error.type_name(), "&i32");
+/// assert_eq!(error.into_inner(), FailpointError::Injected);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TypeNameWith<C>(C);
+
+/// Context which provides dependency with [empty context](Empty),
+/// annotating the error of failed provision with the [name](type_name) of the dependency type.
+pub type TypeName = TypeNameWith<Empty>;
+
+impl<C> TypeNameWith<C> {
+    /// Creates new context from the context to provide dependency with.
+    pub const fn new(context: C) -> Self {
+        Self(context)
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self(context) = self;
+        context
+    }
+}
+
+impl<C> Context for TypeNameWith<C> {}
+
+impl<C> Depth for TypeNameWith<C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<T, U, C> TryProvideWith<T, TypeNameWith<C>> for U
+where
+    U: TryProvideWith<T, C>,
+{
+    type Remainder = U::Remainder;
+
+    type Error = WithTypeName<U::Error>;
+
+    fn try_provide_with(
+        self,
+        context: TypeNameWith<C>,
+    ) -> Result<(T, Self::Remainder), Self::Error> {
+        let TypeNameWith(context) = context;
+        self.try_provide_with(context)
+            .map_err(WithTypeName::new::<T>)
+    }
+}
+
+impl<'me, T, U, C> TryProvideRefWith<'me, T, TypeNameWith<C>> for U
+where
+    U: TryProvideRefWith<'me, T, C> + ?Sized,
+{
+    type Error = WithTypeName<U::Error>;
+
+    fn try_provide_ref_with(&'me self, context: TypeNameWith<C>) -> Result<T, Self::Error> {
+        let TypeNameWith(context) = context;
+        self.try_provide_ref_with(context)
+            .map_err(WithTypeName::new::<T>)
+    }
+}
+
+impl<'me, T, U, C> TryProvideMutWith<'me, T, TypeNameWith<C>> for U
+where
+    U: TryProvideMutWith<'me, T, C> + ?Sized,
+{
+    type Error = WithTypeName<U::Error>;
+
+    fn try_provide_mut_with(&'me mut self, context: TypeNameWith<C>) -> Result<T, Self::Error> {
+        let TypeNameWith(context) = context;
+        self.try_provide_mut_with(context)
+            .map_err(WithTypeName::new::<T>)
+    }
+}
+
+/// The error type which annotates the error of failed provision
+/// with the [name](type_name) of the dependency type.
+///
+/// Name of the type is obtained with [`type_name`] function,
+/// so it is intended for diagnostics only and should not be relied upon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct WithTypeName<E> {
+    type_name: &'static str,
+    error: E,
+}
+
+impl<E> WithTypeName<E> {
+    /// Creates new error from the error of failed provision of the dependency of type `T`.
+    pub fn new<T>(error: E) -> Self
+    where
+        T: ?Sized,
+    {
+        let type_name = type_name::<T>();
+        Self { type_name, error }
+    }
+
+    /// Returns the name of the dependency type which could not be provided.
+    pub const fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns shared reference to the error of failed provision.
+    pub const fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Returns the error of failed provision.
+    pub fn into_inner(self) -> E {
+        let Self { error, .. } = self;
+        error
+    }
+}
+
+impl<E> Display for WithTypeName<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { type_name, .. } = self;
+        write!(f, "failed to provide dependency of type `{type_name}`")
+    }
+}
+
+impl<E> Error for WithTypeName<E>
+where
+    E: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        let Self { error, .. } = self;
+        Some(error)
+    }
+}
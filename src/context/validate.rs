@@ -0,0 +1,181 @@
+//! Context which validates provided dependency.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use super::{depth::Depth, Context, Empty};
+use crate::with::{TryProvideMutWith, TryProvideRefWith, TryProvideWith};
+
+/// Context which calls the carried function with shared reference
+/// to the dependency provided with the inner context, and then provides it
+/// only if the function accepts it.
+///
+/// This allows to reject invalid configuration, such as zero ports or empty names,
+/// at the time of provision rather than deep inside of the business logic.
+/// Function is called only if provision with the inner context succeeds.
+///
+/// This context is implemented only for fallible provision,
+/// because validation may fail regardless of the inner context.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::{validate::ValidateError, Context},
+///     with::TryProvideRefWith,
+/// };
+///
+/// struct Config {
+///     port: u16,
+/// }
+///
+/// impl AsRef<u16> for Config {
+///     fn as_ref(&self) -> &u16 {
+///         let Self { port } = self;
+///         port
+///     }
+/// }
+///
+/// let nonzero = |port: &&u16| if **port == 0 { Err("port is zero") } else { Ok(()) };
+///
+/// let provider = Config { port: 8080 };
+/// let port: Result<&u16, _> = provider.try_provide_ref_with(().then_validate(nonzero));
+/// assert_eq!(port, Ok(&8080));
+///
+/// let provider = Config { port: 0 };
+/// let port: Result<&u16, _> = provider.try_provide_ref_with(().then_validate(nonzero));
+/// assert_eq!(port, Err(ValidateError::Invalid("port is zero")));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ValidateDependencyWith<F, C> {
+    function: F,
+    context: C,
+}
+
+/// Context which calls the carried function with shared reference
+/// to the dependency provided with [empty context](Empty), and then provides it
+/// only if the function accepts it.
+pub type ValidateDependency<F> = ValidateDependencyWith<F, Empty>;
+
+impl<F, C> ValidateDependencyWith<F, C> {
+    /// Creates new context from the function to validate the dependency with
+    /// and the context to provide dependency with.
+    pub const fn new(function: F, context: C) -> Self {
+        Self { function, context }
+    }
+
+    /// Returns the function to validate the dependency with and the inner context.
+    pub fn into_inner(self) -> (F, C) {
+        let Self { function, context } = self;
+        (function, context)
+    }
+}
+
+impl<F, C> Context for ValidateDependencyWith<F, C> {}
+
+impl<F, C> Depth for ValidateDependencyWith<F, C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+impl<T, U, F, C, V> TryProvideWith<T, ValidateDependencyWith<F, C>> for U
+where
+    U: TryProvideWith<T, C>,
+    F: FnOnce(&T) -> Result<(), V>,
+{
+    type Remainder = U::Remainder;
+
+    type Error = ValidateError<V, U::Error>;
+
+    fn try_provide_with(
+        self,
+        context: ValidateDependencyWith<F, C>,
+    ) -> Result<(T, Self::Remainder), Self::Error> {
+        let ValidateDependencyWith { function, context } = context;
+        let (dependency, remainder) = <U as TryProvideWith<T, C>>::try_provide_with(self, context)
+            .map_err(ValidateError::Provide)?;
+        function(&dependency).map_err(ValidateError::Invalid)?;
+        Ok((dependency, remainder))
+    }
+}
+
+impl<'me, T, U, F, C, V> TryProvideRefWith<'me, T, ValidateDependencyWith<F, C>> for U
+where
+    U: TryProvideRefWith<'me, T, C> + ?Sized,
+    F: FnOnce(&T) -> Result<(), V>,
+{
+    type Error = ValidateError<V, U::Error>;
+
+    fn try_provide_ref_with(
+        &'me self,
+        context: ValidateDependencyWith<F, C>,
+    ) -> Result<T, Self::Error> {
+        let ValidateDependencyWith { function, context } = context;
+        let dependency = <U as TryProvideRefWith<'me, T, C>>::try_provide_ref_with(self, context)
+            .map_err(ValidateError::Provide)?;
+        function(&dependency).map_err(ValidateError::Invalid)?;
+        Ok(dependency)
+    }
+}
+
+impl<'me, T, U, F, C, V> TryProvideMutWith<'me, T, ValidateDependencyWith<F, C>> for U
+where
+    U: TryProvideMutWith<'me, T, C> + ?Sized,
+    F: FnOnce(&T) -> Result<(), V>,
+{
+    type Error = ValidateError<V, U::Error>;
+
+    fn try_provide_mut_with(
+        &'me mut self,
+        context: ValidateDependencyWith<F, C>,
+    ) -> Result<T, Self::Error> {
+        let ValidateDependencyWith { function, context } = context;
+        let dependency = <U as TryProvideMutWith<'me, T, C>>::try_provide_mut_with(self, context)
+            .map_err(ValidateError::Provide)?;
+        function(&dependency).map_err(ValidateError::Invalid)?;
+        Ok(dependency)
+    }
+}
+
+/// The error type returned when [validated](ValidateDependencyWith) dependency
+/// was not provided.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ValidateError<V, E> {
+    /// Dependency was rejected by the validation function.
+    Invalid(V),
+    /// Dependency was not provided with the inner context.
+    Provide(E),
+}
+
+impl<V, E> Display for ValidateError<V, E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(_) => f.write_str("dependency is invalid"),
+            Self::Provide(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl<V, E> Error for ValidateError<V, E>
+where
+    V: Error + 'static,
+    E: Error,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Invalid(error) => Some(error),
+            Self::Provide(error) => error.source(),
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! Context which upgrades the weak pointer dependency.
+//!
+//! This module is available with `alloc` feature enabled.
+//!
+//! See [crate] documentation for more.
+
+use alloc::rc::{self, Rc};
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::{self, Arc};
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use super::{depth::Depth, Context, Empty};
+use crate::with::{TryProvideMutWith, TryProvideRefWith, TryProvideWith};
+
+/// Context which provides [`Arc`] or [`Rc`] dependency
+/// by upgrading the weak pointer dependency provided with the inner context.
+///
+/// Weak pointer dependency must be provided infallibly, but through the fallible traits.
+/// Provision fails with [`Dangling`] error if the value behind the weak pointer
+/// has already been dropped.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::{Arc, Weak};
+///
+/// use provide::{
+///     context::weak::{Dangling, Upgrade},
+///     with::TryProvideWith,
+/// };
+///
+/// let strong = Arc::new(1);
+/// let provider = Arc::downgrade(&strong);
+///
+/// let result: Result<(Arc<i32>, _), _> = provider.clone().try_provide_with(Upgrade::default());
+/// assert_eq!(result, Ok((strong.clone(), ())));
+///
+/// drop((result, strong));
+/// let result: Result<(Arc<i32>, _), _> = provider.try_provide_with(Upgrade::default());
+/// assert_eq!(result, Err(Dangling));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct UpgradeWith<C>(C);
+
+/// Context which provides [`Arc`] or [`Rc`] dependency
+/// by upgrading the weak pointer dependency provided with [empty context](Empty).
+pub type Upgrade = UpgradeWith<Empty>;
+
+impl<C> UpgradeWith<C> {
+    /// Creates new context from the context to provide weak pointer dependency with.
+    pub const fn new(context: C) -> Self {
+        Self(context)
+    }
+
+    /// Returns the inner context.
+    pub fn into_inner(self) -> C {
+        let Self(context) = self;
+        context
+    }
+}
+
+impl<C> Context for UpgradeWith<C> {}
+
+impl<C> Depth for UpgradeWith<C>
+where
+    C: Depth,
+{
+    const DEPTH: usize = C::DEPTH + 1;
+}
+
+/// Implements all the fallible provider traits for the [`UpgradeWith`] context
+/// which upgrades the weak pointer into the strong one.
+macro_rules! impl_upgrade {
+    ($weak:ty, $strong:ty) => {
+        impl<T, U, C> TryProvideWith<$strong, UpgradeWith<C>> for U
+        where
+            T: ?Sized,
+            C: infallible::Provide<$weak, U>,
+        {
+            type Remainder = C::Remainder;
+
+            type Error = Dangling;
+
+            fn try_provide_with(
+                self,
+                context: UpgradeWith<C>,
+            ) -> Result<($strong, Self::Remainder), Self::Error> {
+                let UpgradeWith(context) = context;
+                let (weak, remainder) = context.provide_infallibly(self);
+                let dependency = weak.upgrade().ok_or(Dangling)?;
+                Ok((dependency, remainder))
+            }
+        }
+
+        impl<'me, T, U, C> TryProvideRefWith<'me, $strong, UpgradeWith<C>> for U
+        where
+            T: ?Sized + 'me,
+            U: ?Sized,
+            C: infallible::ProvideRef<'me, &'me $weak, U>,
+        {
+            type Error = Dangling;
+
+            fn try_provide_ref_with(
+                &'me self,
+                context: UpgradeWith<C>,
+            ) -> Result<$strong, Self::Error> {
+                let UpgradeWith(context) = context;
+                let weak = context.provide_ref_infallibly(self);
+                weak.upgrade().ok_or(Dangling)
+            }
+        }
+
+        impl<'me, T, U, C> TryProvideMutWith<'me, $strong, UpgradeWith<C>> for U
+        where
+            T: ?Sized + 'me,
+            U: ?Sized,
+            C: infallible::ProvideMut<'me, &'me mut $weak, U>,
+        {
+            type Error = Dangling;
+
+            fn try_provide_mut_with(
+                &'me mut self,
+                context: UpgradeWith<C>,
+            ) -> Result<$strong, Self::Error> {
+                let UpgradeWith(context) = context;
+                let weak = context.provide_mut_infallibly(self);
+                weak.upgrade().ok_or(Dangling)
+            }
+        }
+    };
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl_upgrade!(sync::Weak<T>, Arc<T>);
+impl_upgrade!(rc::Weak<T>, Rc<T>);
+
+/// Infallible provision through the fallible traits, implemented by the inner context.
+///
+/// Error of the provision is checked only when the inner context is known,
+/// so the compiler does not recurse into it while looking for the context
+/// of some unrelated provision.
+mod infallible {
+    use core::convert::Infallible;
+
+    use crate::with::{TryProvideMutWith, TryProvideRefWith, TryProvideWith};
+
+    pub trait Provide<T, U>: Sized {
+        type Remainder;
+
+        fn provide_infallibly(self, provider: U) -> (T, Self::Remainder);
+    }
+
+    impl<T, U, C> Provide<T, U> for C
+    where
+        U: TryProvideWith<T, C, Error = Infallible>,
+    {
+        type Remainder = U::Remainder;
+
+        fn provide_infallibly(self, provider: U) -> (T, Self::Remainder) {
+            let Ok(provision) = provider.try_provide_with(self);
+            provision
+        }
+    }
+
+    pub trait ProvideRef<'me, T, U>: Sized
+    where
+        U: ?Sized,
+    {
+        fn provide_ref_infallibly(self, provider: &'me U) -> T;
+    }
+
+    impl<'me, T, U, C> ProvideRef<'me, T, U> for C
+    where
+        U: TryProvideRefWith<'me, T, C, Error = Infallible> + ?Sized,
+    {
+        fn provide_ref_infallibly(self, provider: &'me U) -> T {
+            let Ok(dependency) = provider.try_provide_ref_with(self);
+            dependency
+        }
+    }
+
+    pub trait ProvideMut<'me, T, U>: Sized
+    where
+        U: ?Sized,
+    {
+        fn provide_mut_infallibly(self, provider: &'me mut U) -> T;
+    }
+
+    impl<'me, T, U, C> ProvideMut<'me, T, U> for C
+    where
+        U: TryProvideMutWith<'me, T, C, Error = Infallible> + ?Sized,
+    {
+        fn provide_mut_infallibly(self, provider: &'me mut U) -> T {
+            let Ok(dependency) = provider.try_provide_mut_with(self);
+            dependency
+        }
+    }
+}
+
+/// The error type returned when weak pointer could not be upgraded
+/// because the value behind it has already been dropped.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Dangling;
+
+impl Display for Dangling {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("weak pointer could not be upgraded because the value has been dropped")
+    }
+}
+
+impl Error for Dangling {}
@@ -0,0 +1,469 @@
+//! Contexts which provide dependency by wrapping the dependency provided with the inner context.
+//!
+//! See [crate] documentation for more.
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, rc::Rc};
+use core::cell::{Cell, RefCell};
+#[cfg(feature = "alloc")]
+use core::pin::Pin;
+#[cfg(feature = "std")]
+use std::sync::{Mutex, RwLock};
+
+use super::{depth::Depth, Context, Empty};
+use crate::with::{
+    ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideMutWith, TryProvideRefWith,
+    TryProvideWith,
+};
+
+/// Implements all the provider traits for the context which wraps the dependency
+/// provided with the inner context in the same way as the provider.
+///
+/// Provided expression must evaluate to the wrapped dependency.
+/// Additional generic parameters of the wrapped dependency can be listed in brackets.
+macro_rules! impl_wrap {
+    ($context:ident, [$($param:ident),*] $wrapped:ty, |$arg:ident| $wrap:expr) => {
+        impl<C> $context<C> {
+            /// Creates new context from the context to provide dependency with.
+            pub const fn new(context: C) -> Self {
+                Self(context)
+            }
+
+            /// Returns the inner context.
+            pub fn into_inner(self) -> C {
+                let Self(context) = self;
+                context
+            }
+        }
+
+        impl<C> Context for $context<C> {}
+
+        impl<C> Depth for $context<C>
+        where
+            C: Depth,
+        {
+            const DEPTH: usize = C::DEPTH + 1;
+        }
+
+        impl<T, $($param,)* U, C> ProvideWith<$wrapped, $context<C>> for U
+        where
+            U: ProvideWith<T, C>,
+        {
+            type Remainder = U::Remainder;
+
+            fn provide_with(self, context: $context<C>) -> ($wrapped, Self::Remainder) {
+                let $context(context) = context;
+                let ($arg, remainder) = self.provide_with(context);
+                ($wrap, remainder)
+            }
+        }
+
+        impl<T, $($param,)* U, C> TryProvideWith<$wrapped, $context<C>> for U
+        where
+            U: TryProvideWith<T, C>,
+        {
+            type Remainder = U::Remainder;
+
+            type Error = U::Error;
+
+            fn try_provide_with(self, context: $context<C>) -> Result<($wrapped, Self::Remainder), Self::Error> {
+                let $context(context) = context;
+                let ($arg, remainder) = self.try_provide_with(context)?;
+                Ok(($wrap, remainder))
+            }
+        }
+
+        impl<'me, T, $($param,)* U, C> ProvideRefWith<'me, $wrapped, $context<C>> for U
+        where
+            U: ProvideRefWith<'me, T, C> + ?Sized,
+        {
+            fn provide_ref_with(&'me self, context: $context<C>) -> $wrapped {
+                let $context(context) = context;
+                let $arg = self.provide_ref_with(context);
+                $wrap
+            }
+        }
+
+        impl<'me, T, $($param,)* U, C> TryProvideRefWith<'me, $wrapped, $context<C>> for U
+        where
+            U: TryProvideRefWith<'me, T, C> + ?Sized,
+        {
+            type Error = U::Error;
+
+            fn try_provide_ref_with(&'me self, context: $context<C>) -> Result<$wrapped, Self::Error> {
+                let $context(context) = context;
+                let $arg = self.try_provide_ref_with(context)?;
+                Ok($wrap)
+            }
+        }
+
+        impl<'me, T, $($param,)* U, C> ProvideMutWith<'me, $wrapped, $context<C>> for U
+        where
+            U: ProvideMutWith<'me, T, C> + ?Sized,
+        {
+            fn provide_mut_with(&'me mut self, context: $context<C>) -> $wrapped {
+                let $context(context) = context;
+                let $arg = self.provide_mut_with(context);
+                $wrap
+            }
+        }
+
+        impl<'me, T, $($param,)* U, C> TryProvideMutWith<'me, $wrapped, $context<C>> for U
+        where
+            U: TryProvideMutWith<'me, T, C> + ?Sized,
+        {
+            type Error = U::Error;
+
+            fn try_provide_mut_with(&'me mut self, context: $context<C>) -> Result<$wrapped, Self::Error> {
+                let $context(context) = context;
+                let $arg = self.try_provide_mut_with(context)?;
+                Ok($wrap)
+            }
+        }
+    };
+    ($context:ident, $wrapped:ty, |$arg:ident| $wrap:expr) => {
+        impl_wrap!($context, [] $wrapped, |$arg| $wrap);
+    };
+}
+
+/// Context which provides [`Option`] dependency
+/// by wrapping the dependency provided with the inner context into [`Some`].
+///
+/// This allows to feed optional parameters directly from the provider.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{context::wrap::OptionDependency, with::ProvideRefWith};
+///
+/// let provider = String::from("hello");
+///
+/// let dependency: Option<&str> = provider.provide_ref_with(OptionDependency::default());
+/// assert_eq!(dependency, Some("hello"));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct OptionDependencyWith<C>(C);
+
+/// Context which provides [`Option`] dependency
+/// by wrapping the dependency provided with [empty context](Empty) into [`Some`].
+pub type OptionDependency = OptionDependencyWith<Empty>;
+
+impl_wrap!(OptionDependencyWith, Option<T>, |dependency| Some(
+    dependency
+));
+
+/// Context which provides [`Result`] dependency
+/// by wrapping the dependency provided with the inner context into [`Ok`].
+///
+/// This allows to feed parameters of type [`Result`] directly from the provider.
+/// Errors of the inner context are not wrapped into [`Err`]:
+/// they are still returned through the fallible traits.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{context::wrap::ResultDependency, with::ProvideRefWith};
+///
+/// let provider = String::from("hello");
+///
+/// let dependency: Result<&str, ()> = provider.provide_ref_with(ResultDependency::default());
+/// assert_eq!(dependency, Ok("hello"));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ResultDependencyWith<C>(C);
+
+/// Context which provides [`Result`] dependency
+/// by wrapping the dependency provided with [empty context](Empty) into [`Ok`].
+pub type ResultDependency = ResultDependencyWith<Empty>;
+
+impl_wrap!(ResultDependencyWith, [E] Result<T, E>, |dependency| Ok(dependency));
+
+/// Context which provides [`Cell`] dependency
+/// by wrapping the dependency provided with the inner context into new [`Cell`].
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+///
+/// use provide::{context::wrap::CellDependency, with::ProvideWith};
+///
+/// let provider = 1;
+///
+/// let (dependency, ()): (Cell<i32>, _) = provider.provide_with(CellDependency::default());
+/// dependency.set(dependency.get() + 1);
+/// assert_eq!(dependency.get(), 2);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CellDependencyWith<C>(C);
+
+/// Context which provides [`Cell`] dependency
+/// by wrapping the dependency provided with [empty context](Empty) into new [`Cell`].
+pub type CellDependency = CellDependencyWith<Empty>;
+
+impl_wrap!(CellDependencyWith, Cell<T>, |dependency| Cell::new(
+    dependency
+));
+
+/// Context which provides [`RefCell`] dependency
+/// by wrapping the dependency provided with the inner context into new [`RefCell`].
+///
+/// This allows to construct mutable dependency shared
+/// within single-threaded application in one step.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::RefCell;
+///
+/// use provide::{context::wrap::RefCellDependency, with::ProvideWith};
+///
+/// let provider = vec![1, 2];
+///
+/// let (dependency, ()): (RefCell<Vec<i32>>, _) =
+///     provider.provide_with(RefCellDependency::default());
+/// dependency.borrow_mut().push(3);
+/// assert_eq!(*dependency.borrow(), [1, 2, 3]);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RefCellDependencyWith<C>(C);
+
+/// Context which provides [`RefCell`] dependency
+/// by wrapping the dependency provided with [empty context](Empty) into new [`RefCell`].
+pub type RefCellDependency = RefCellDependencyWith<Empty>;
+
+impl_wrap!(
+    RefCellDependencyWith,
+    RefCell<T>,
+    |dependency| RefCell::new(dependency)
+);
+
+/// Context which provides [`Box`] dependency
+/// by boxing the dependency provided with the inner context.
+///
+/// This allows to satisfy requirement of heap-allocated ownership inside the wiring
+/// rather than at every call site.
+///
+/// This context is available with `alloc` feature enabled.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use provide::{context::wrap::BoxDependency, with::ProvideWith};
+///
+/// let provider = 1;
+///
+/// let (dependency, ()): (Box<i32>, _) = provider.provide_with(BoxDependency::default());
+/// assert_eq!(*dependency, 1);
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BoxDependencyWith<C>(C);
+
+/// Context which provides [`Box`] dependency
+/// by boxing the dependency provided with [empty context](Empty).
+#[cfg(feature = "alloc")]
+pub type BoxDependency = BoxDependencyWith<Empty>;
+
+#[cfg(feature = "alloc")]
+impl_wrap!(BoxDependencyWith, Box<T>, |dependency| Box::new(dependency));
+
+/// Context which provides [`Rc`] dependency
+/// by wrapping the dependency provided with the inner context into new [`Rc`].
+///
+/// Unlike cloning an existing smart pointer, this allows to share ownership
+/// of a freshly built dependency.
+///
+/// This context is available with `alloc` feature enabled.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use std::rc::Rc;
+///
+/// use provide::{context::wrap::RcDependency, with::ProvideWith};
+///
+/// let provider = 1;
+///
+/// let (dependency, ()): (Rc<i32>, _) = provider.provide_with(RcDependency::default());
+/// let shared = Rc::clone(&dependency);
+/// assert_eq!(*shared, 1);
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RcDependencyWith<C>(C);
+
+/// Context which provides [`Rc`] dependency
+/// by wrapping the dependency provided with [empty context](Empty) into new [`Rc`].
+#[cfg(feature = "alloc")]
+pub type RcDependency = RcDependencyWith<Empty>;
+
+#[cfg(feature = "alloc")]
+impl_wrap!(RcDependencyWith, Rc<T>, |dependency| Rc::new(dependency));
+
+/// Context which provides [`Arc`] dependency
+/// by wrapping the dependency provided with the inner context into new [`Arc`].
+///
+/// Unlike cloning an existing smart pointer, this allows to share ownership
+/// of a freshly built dependency between threads.
+///
+/// This context is available with `alloc` feature enabled
+/// on targets which support atomic pointers.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use std::{sync::Arc, thread};
+///
+/// use provide::{context::wrap::ArcDependency, with::ProvideWith};
+///
+/// let provider = 1;
+///
+/// let (dependency, ()): (Arc<i32>, _) = provider.provide_with(ArcDependency::default());
+/// let shared = Arc::clone(&dependency);
+/// thread::spawn(move || assert_eq!(*shared, 1)).join().unwrap();
+/// # }
+/// ```
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ArcDependencyWith<C>(C);
+
+/// Context which provides [`Arc`] dependency
+/// by wrapping the dependency provided with [empty context](Empty) into new [`Arc`].
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub type ArcDependency = ArcDependencyWith<Empty>;
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl_wrap!(ArcDependencyWith, Arc<T>, |dependency| Arc::new(dependency));
+
+/// Context which provides pinned [`Box`] dependency
+/// by pinning the dependency provided with the inner context on the heap.
+///
+/// This allows to inject futures or other address-sensitive dependencies
+/// into APIs which demand pinned ownership.
+///
+/// This context is available with `alloc` feature enabled.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use std::{
+///     future::{self, Ready},
+///     pin::Pin,
+/// };
+///
+/// use futures::executor::block_on;
+/// use provide::{context::wrap::PinBoxDependency, with::ProvideWith};
+///
+/// let provider = future::ready(1);
+///
+/// let (future, ()): (Pin<Box<Ready<i32>>>, _) =
+///     provider.provide_with(PinBoxDependency::default());
+/// assert_eq!(block_on(future), 1);
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PinBoxDependencyWith<C>(C);
+
+/// Context which provides pinned [`Box`] dependency
+/// by pinning the dependency provided with [empty context](Empty) on the heap.
+#[cfg(feature = "alloc")]
+pub type PinBoxDependency = PinBoxDependencyWith<Empty>;
+
+#[cfg(feature = "alloc")]
+impl_wrap!(PinBoxDependencyWith, Pin<Box<T>>, |dependency| Box::pin(
+    dependency
+));
+
+/// Context which provides [`Mutex`] dependency
+/// by wrapping the dependency provided with the inner context into new [`Mutex`].
+///
+/// This allows to construct shared mutable dependency
+/// and protect it from concurrent access in one step.
+///
+/// This context is available with `std` feature enabled.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use std::sync::Mutex;
+///
+/// use provide::{context::wrap::MutexDependency, with::ProvideWith};
+///
+/// let provider = 1;
+///
+/// let (dependency, ()): (Mutex<i32>, _) = provider.provide_with(MutexDependency::default());
+/// *dependency.lock().unwrap() += 1;
+/// assert_eq!(dependency.into_inner().unwrap(), 2);
+/// # }
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MutexDependencyWith<C>(C);
+
+/// Context which provides [`Mutex`] dependency
+/// by wrapping the dependency provided with [empty context](Empty) into new [`Mutex`].
+#[cfg(feature = "std")]
+pub type MutexDependency = MutexDependencyWith<Empty>;
+
+#[cfg(feature = "std")]
+impl_wrap!(MutexDependencyWith, Mutex<T>, |dependency| Mutex::new(
+    dependency
+));
+
+/// Context which provides [`RwLock`] dependency
+/// by wrapping the dependency provided with the inner context into new [`RwLock`].
+///
+/// This allows to construct shared mutable dependency
+/// which is read much more often than written in one step.
+///
+/// This context is available with `std` feature enabled.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use std::sync::RwLock;
+///
+/// use provide::{context::wrap::RwLockDependency, with::ProvideWith};
+///
+/// let provider = 1;
+///
+/// let (dependency, ()): (RwLock<i32>, _) = provider.provide_with(RwLockDependency::default());
+/// *dependency.write().unwrap() += 1;
+/// assert_eq!(*dependency.read().unwrap(), 2);
+/// # }
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RwLockDependencyWith<C>(C);
+
+/// Context which provides [`RwLock`] dependency
+/// by wrapping the dependency provided with [empty context](Empty) into new [`RwLock`].
+#[cfg(feature = "std")]
+pub type RwLockDependency = RwLockDependencyWith<Empty>;
+
+#[cfg(feature = "std")]
+impl_wrap!(RwLockDependencyWith, RwLock<T>, |dependency| RwLock::new(
+    dependency
+));
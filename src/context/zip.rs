@@ -0,0 +1,226 @@
+//! Context which provides two dependencies at once.
+//!
+//! See [crate] documentation for more.
+
+use super::{
+    depth::{max_depth, Depth},
+    either::Either,
+    Context,
+};
+use crate::with::{ProvideRefWith, ProvideWith, TryProvideRefWith, TryProvideWith};
+
+/// Context which provides pair of dependencies,
+/// the first with the first context and the second with the second context.
+///
+/// When providing by value, the second dependency is provided
+/// out of the remainder of the provision of the first dependency,
+/// so the remainder of this context is the remainder of the second provision.
+/// Errors of the fallible provisions are unified with the [`Either`] type.
+///
+/// This context is not implemented for provision by [unique reference](crate::ProvideMut),
+/// because the provider stays borrowed for the whole lifetime of the first dependency,
+/// so the second dependency cannot be provided from it.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::{zip::ZipDependency, Context},
+///     with::{ProvideRefWith, ProvideWith},
+///     Provide,
+/// };
+///
+/// struct Provider {
+///     name: String,
+///     port: u16,
+/// }
+///
+/// impl AsRef<String> for Provider {
+///     fn as_ref(&self) -> &String {
+///         let Self { name, .. } = self;
+///         name
+///     }
+/// }
+///
+/// impl AsRef<u16> for Provider {
+///     fn as_ref(&self) -> &u16 {
+///         let Self { port, .. } = self;
+///         port
+///     }
+/// }
+///
+/// impl Provide<String> for Provider {
+///     type Remainder = u16;
+///
+///     fn provide(self) -> (String, Self::Remainder) {
+///         let Self { name, port } = self;
+///         (name, port)
+///     }
+/// }
+///
+/// let provider = Provider {
+///     name: String::from("localhost"),
+///     port: 8080,
+/// };
+///
+/// let (name, port): (&String, &u16) = provider.provide_ref_with(ZipDependency::new((), ()));
+/// assert_eq!((name.as_str(), *port), ("localhost", 8080));
+///
+/// let ((name, port), ()): ((String, Option<u16>), _) = provider.provide_with(().then_zip(().then_some()));
+/// assert_eq!((name.as_str(), port), ("localhost", Some(8080)));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ZipDependency<C1, C2> {
+    first: C1,
+    second: C2,
+}
+
+impl<C1, C2> ZipDependency<C1, C2> {
+    /// Creates new context from the contexts to provide the first and the second dependency with.
+    pub const fn new(first: C1, second: C2) -> Self {
+        Self { first, second }
+    }
+
+    /// Returns the contexts to provide the first and the second dependency with.
+    pub fn into_inner(self) -> (C1, C2) {
+        let Self { first, second } = self;
+        (first, second)
+    }
+}
+
+impl<C1, C2> Context for ZipDependency<C1, C2> {}
+
+impl<C1, C2> Depth for ZipDependency<C1, C2>
+where
+    C1: Depth,
+    C2: Depth,
+{
+    const DEPTH: usize = max_depth(C1::DEPTH, C2::DEPTH) + 1;
+}
+
+impl<A, B, U, C1, C2> ProvideWith<(A, B), ZipDependency<C1, C2>> for U
+where
+    C1: chain::Chain<(A, B), U, C2>,
+{
+    type Remainder = C1::Remainder;
+
+    fn provide_with(self, context: ZipDependency<C1, C2>) -> ((A, B), Self::Remainder) {
+        let ZipDependency { first, second } = context;
+        first.provide_chained(self, second)
+    }
+}
+
+impl<A, B, U, C1, C2> TryProvideWith<(A, B), ZipDependency<C1, C2>> for U
+where
+    C1: chain::TryChain<(A, B), U, C2>,
+{
+    type Remainder = C1::Remainder;
+
+    type Error = C1::Error;
+
+    fn try_provide_with(
+        self,
+        context: ZipDependency<C1, C2>,
+    ) -> Result<((A, B), Self::Remainder), Self::Error> {
+        let ZipDependency { first, second } = context;
+        first.try_provide_chained(self, second)
+    }
+}
+
+/// Provision of the second dependency out of the remainder of the first one,
+/// implemented by the context of the first provision.
+///
+/// Bounds on the remainder are checked only when the first context is known,
+/// so the compiler does not recurse into them while looking for the context
+/// of some unrelated provision.
+mod chain {
+    use super::Either;
+    use crate::with::{ProvideWith, TryProvideWith};
+
+    pub trait Chain<T, U, C>: Sized {
+        type Remainder;
+
+        fn provide_chained(self, provider: U, context: C) -> (T, Self::Remainder);
+    }
+
+    impl<A, B, U, C1, C2> Chain<(A, B), U, C2> for C1
+    where
+        U: ProvideWith<A, C1>,
+        U::Remainder: ProvideWith<B, C2>,
+    {
+        type Remainder = <U::Remainder as ProvideWith<B, C2>>::Remainder;
+
+        fn provide_chained(self, provider: U, context: C2) -> ((A, B), Self::Remainder) {
+            let (a, remainder) = provider.provide_with(self);
+            let (b, remainder) = remainder.provide_with(context);
+            ((a, b), remainder)
+        }
+    }
+
+    pub trait TryChain<T, U, C>: Sized {
+        type Remainder;
+
+        type Error;
+
+        fn try_provide_chained(
+            self,
+            provider: U,
+            context: C,
+        ) -> Result<(T, Self::Remainder), Self::Error>;
+    }
+
+    impl<A, B, U, C1, C2> TryChain<(A, B), U, C2> for C1
+    where
+        U: TryProvideWith<A, C1>,
+        U::Remainder: TryProvideWith<B, C2>,
+    {
+        type Remainder = <U::Remainder as TryProvideWith<B, C2>>::Remainder;
+
+        type Error = Either<U::Error, <U::Remainder as TryProvideWith<B, C2>>::Error>;
+
+        fn try_provide_chained(
+            self,
+            provider: U,
+            context: C2,
+        ) -> Result<((A, B), Self::Remainder), Self::Error> {
+            let (a, remainder) = provider.try_provide_with(self).map_err(Either::Left)?;
+            let (b, remainder) = remainder.try_provide_with(context).map_err(Either::Right)?;
+            Ok(((a, b), remainder))
+        }
+    }
+}
+
+impl<'me, A, B, U, C1, C2> ProvideRefWith<'me, (A, B), ZipDependency<C1, C2>> for U
+where
+    U: ProvideRefWith<'me, A, C1> + ProvideRefWith<'me, B, C2> + ?Sized,
+{
+    fn provide_ref_with(&'me self, context: ZipDependency<C1, C2>) -> (A, B) {
+        let ZipDependency { first, second } = context;
+        let a = <U as ProvideRefWith<'me, A, C1>>::provide_ref_with(self, first);
+        let b = <U as ProvideRefWith<'me, B, C2>>::provide_ref_with(self, second);
+        (a, b)
+    }
+}
+
+impl<'me, A, B, U, C1, C2> TryProvideRefWith<'me, (A, B), ZipDependency<C1, C2>> for U
+where
+    U: TryProvideRefWith<'me, A, C1> + TryProvideRefWith<'me, B, C2> + ?Sized,
+{
+    type Error = Either<
+        <U as TryProvideRefWith<'me, A, C1>>::Error,
+        <U as TryProvideRefWith<'me, B, C2>>::Error,
+    >;
+
+    fn try_provide_ref_with(
+        &'me self,
+        context: ZipDependency<C1, C2>,
+    ) -> Result<(A, B), Self::Error> {
+        let ZipDependency { first, second } = context;
+        let a = <U as TryProvideRefWith<'me, A, C1>>::try_provide_ref_with(self, first)
+            .map_err(Either::Left)?;
+        let b = <U as TryProvideRefWith<'me, B, C2>>::try_provide_ref_with(self, second)
+            .map_err(Either::Right)?;
+        Ok((a, b))
+    }
+}
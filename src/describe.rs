@@ -0,0 +1,266 @@
+//! Runtime introspection of dependencies held by providers.
+//!
+//! See [crate] documentation for more.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{
+    any::type_name,
+    fmt::{self, Debug, DebugList},
+    marker::PhantomData,
+};
+
+use crate::pair::{Leaf, Pair};
+
+/// Type of provider which can describe dependencies it currently holds.
+///
+/// This trait is implemented for [pairs](Pair), tuples of up to 12 elements
+/// (or 32 elements with `tuples_32` feature enabled),
+/// [leaf](Leaf) types and [exhausted](Exhausted) markers,
+/// so remainders built out of them can be described out of the box.
+/// Implement it for your own providers to describe them in the same way.
+///
+/// See [crate] documentation for more.
+pub trait DescribeProvider {
+    /// Writes all the dependencies of this provider into the describer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::describe::{DescribeProvider, Describer};
+    ///
+    /// struct Provider {
+    ///     foo: i32,
+    ///     bar: &'static str,
+    /// }
+    ///
+    /// impl DescribeProvider for Provider {
+    ///     fn describe_into(&self, describer: &mut Describer<'_, '_>) {
+    ///         describer.held::<i32>().held::<&str>();
+    ///     }
+    /// }
+    ///
+    /// let provider = Provider { foo: 1, bar: "hello" };
+    /// let description = format!("{:?}", provider.describe());
+    /// assert_eq!(description, "[i32, &str]");
+    /// ```
+    fn describe_into(&self, describer: &mut Describer<'_, '_>);
+
+    /// Returns description of this provider which can be printed with [`Debug`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::{describe::{DescribeProvider, Exhausted}, pair::Pair};
+    ///
+    /// let provider = Pair::new(1, Pair::new(Exhausted::<f32>::new(), ()));
+    /// let description = format!("{:?}", provider.describe());
+    /// assert_eq!(description, "[i32, f32 (exhausted)]");
+    /// ```
+    fn describe(&self) -> Description<'_, Self> {
+        Description(self)
+    }
+
+    /// Returns type names of all the dependencies this provider currently holds,
+    /// omitting [exhausted](Exhausted) ones.
+    ///
+    /// This is useful for runtime diagnostics, health endpoints
+    /// or logging of the active composition of providers.
+    ///
+    /// This method is available with `alloc` feature enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")] {
+    /// use provide::{describe::{DescribeProvider, Exhausted}, pair::Pair};
+    ///
+    /// let provider = Pair::new(1, Pair::new(Exhausted::<f32>::new(), Pair::new("hello", ())));
+    /// assert_eq!(provider.provided_type_names(), ["i32", "&str"]);
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn provided_type_names(&self) -> Vec<&'static str> {
+        let mut names = Vec::new();
+        let mut describer = Describer {
+            sink: Sink::Names(&mut names),
+        };
+        self.describe_into(&mut describer);
+        names
+    }
+}
+
+/// Collector of type names of dependencies described by the [provider](DescribeProvider).
+pub struct Describer<'a, 'b: 'a> {
+    sink: Sink<'a, 'b>,
+}
+
+enum Sink<'a, 'b: 'a> {
+    List(DebugList<'a, 'b>),
+    #[cfg(feature = "alloc")]
+    Names(&'a mut Vec<&'static str>),
+}
+
+impl Describer<'_, '_> {
+    /// Describes dependency of type `T` which is held by the provider.
+    pub fn held<T>(&mut self) -> &mut Self
+    where
+        T: ?Sized,
+    {
+        match &mut self.sink {
+            Sink::List(list) => {
+                list.entry(&format_args!("{}", type_name::<T>()));
+            }
+            #[cfg(feature = "alloc")]
+            Sink::Names(names) => names.push(type_name::<T>()),
+        }
+        self
+    }
+
+    /// Describes dependency of type `T` which was already provided,
+    /// so it is not held by the provider anymore.
+    pub fn exhausted<T>(&mut self) -> &mut Self
+    where
+        T: ?Sized,
+    {
+        match &mut self.sink {
+            Sink::List(list) => {
+                list.entry(&format_args!("{} (exhausted)", type_name::<T>()));
+            }
+            #[cfg(feature = "alloc")]
+            Sink::Names(_) => {}
+        }
+        self
+    }
+}
+
+/// Description of the [provider](DescribeProvider) which can be printed with [`Debug`].
+///
+/// This struct is created by the [`describe`](DescribeProvider::describe) method.
+pub struct Description<'a, P>(&'a P)
+where
+    P: ?Sized;
+
+impl<P> Debug for Description<'_, P>
+where
+    P: DescribeProvider + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(provider) = self;
+        let mut describer = Describer {
+            sink: Sink::List(f.debug_list()),
+        };
+        P::describe_into(provider, &mut describer);
+        match describer.sink {
+            Sink::List(mut list) => list.finish(),
+            #[cfg(feature = "alloc")]
+            Sink::Names(_) => {
+                unreachable!("describer of the description should write into the list")
+            }
+        }
+    }
+}
+
+/// Marker of the dependency of type `T` which was already provided.
+///
+/// Put it in place of the dependency in the remainder
+/// to let [description](DescribeProvider) of the remainder mention it.
+pub struct Exhausted<T>(PhantomData<T>)
+where
+    T: ?Sized;
+
+impl<T> Exhausted<T>
+where
+    T: ?Sized,
+{
+    /// Creates new marker of the exhausted dependency.
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Default for Exhausted<T>
+where
+    T: ?Sized,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for Exhausted<T>
+where
+    T: ?Sized,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Exhausted<T> where T: ?Sized {}
+
+impl<T> Debug for Exhausted<T>
+where
+    T: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Exhausted")
+            .field(&format_args!("{}", type_name::<T>()))
+            .finish()
+    }
+}
+
+impl<T> DescribeProvider for Exhausted<T>
+where
+    T: ?Sized,
+{
+    fn describe_into(&self, describer: &mut Describer<'_, '_>) {
+        describer.exhausted::<T>();
+    }
+}
+
+impl<T> DescribeProvider for T
+where
+    T: Leaf,
+{
+    fn describe_into(&self, describer: &mut Describer<'_, '_>) {
+        describer.held::<T>();
+    }
+}
+
+impl DescribeProvider for () {
+    fn describe_into(&self, _: &mut Describer<'_, '_>) {}
+}
+
+impl<H, T> DescribeProvider for Pair<H, T>
+where
+    H: DescribeProvider,
+    T: DescribeProvider,
+{
+    fn describe_into(&self, describer: &mut Describer<'_, '_>) {
+        self.head().describe_into(describer);
+        self.tail().describe_into(describer);
+    }
+}
+
+macro_rules! impl_describe {
+    () => {};
+    ($head:ident $(, $tail:ident)* $(,)?) => {
+        impl<$head, $($tail),*> DescribeProvider for ($head, $($tail,)*)
+        where
+            $head: DescribeProvider,
+            $($tail: DescribeProvider,)*
+        {
+            #[allow(non_snake_case)]
+            fn describe_into(&self, describer: &mut Describer<'_, '_>) {
+                let ($head, $($tail,)*) = self;
+                $head.describe_into(describer);
+                $($tail.describe_into(describer);)*
+            }
+        }
+
+        impl_describe!($($tail),*);
+    };
+}
+
+for_tuples!(impl_describe);
@@ -0,0 +1,122 @@
+//! Providers which provide dependencies by shared reference out of unique reference.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    cell::{RefCell, RefMut},
+    error::Error,
+    fmt::{self, Display},
+};
+
+/// Provider which provides dependencies by [shared reference](crate::ProvideRef)
+/// which the inner provider provides only by [unique reference](crate::ProvideMut).
+///
+/// Dependencies are provided with the [`DowngradeWith`](crate::context::downgrade::DowngradeWith)
+/// context. Inner provider is stored in the [`RefCell`], so it is borrowed uniquely
+/// only for the duration of each provision. Because of that,
+/// only dependencies which do not borrow from the inner provider can be provided,
+/// such as owned values or identifiers.
+/// This allows to reuse providers which naturally own their data mutably
+/// without duplicating their implementations for shared references.
+///
+/// This provider cannot implement [`ProvideRef`](crate::ProvideRef) trait directly,
+/// because such implementation would conflict with implementation for types
+/// which implement [`AsRef`] trait.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::downgrade::Downgrade, downgrade::Downgraded, with::ProvideRefWith, ProvideMut,
+/// };
+///
+/// #[derive(Default)]
+/// struct Sequence {
+///     next: u32,
+/// }
+///
+/// impl ProvideMut<'_, u32> for Sequence {
+///     fn provide_mut(&mut self) -> u32 {
+///         let Self { next } = self;
+///         *next += 1;
+///         *next
+///     }
+/// }
+///
+/// let provider = Downgraded::new(Sequence::default());
+///
+/// let first: u32 = provider.provide_ref_with(Downgrade::default());
+/// let second: u32 = provider.provide_ref_with(Downgrade::default());
+/// assert_eq!((first, second), (1, 2));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Downgraded<P> {
+    provider: RefCell<P>,
+}
+
+impl<P> Downgraded<P> {
+    /// Creates new provider from the inner provider.
+    pub const fn new(provider: P) -> Self {
+        Self {
+            provider: RefCell::new(provider),
+        }
+    }
+
+    /// Returns unique reference to the inner provider.
+    pub fn get_mut(&mut self) -> &mut P {
+        let Self { provider } = self;
+        provider.get_mut()
+    }
+
+    /// Returns the inner provider.
+    pub fn into_inner(self) -> P {
+        let Self { provider } = self;
+        provider.into_inner()
+    }
+
+    pub(crate) fn borrow_mut(&self) -> RefMut<'_, P> {
+        let Self { provider } = self;
+        provider.borrow_mut()
+    }
+
+    pub(crate) fn try_borrow_mut<E>(&self) -> Result<RefMut<'_, P>, DowngradeError<E>> {
+        let Self { provider } = self;
+        provider
+            .try_borrow_mut()
+            .map_err(|_| DowngradeError::Borrowed)
+    }
+}
+
+/// The error type returned when [downgraded](Downgraded) provider fails to provide dependency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DowngradeError<E> {
+    /// Inner provider is already borrowed by another provision.
+    Borrowed,
+    /// Inner provider failed to provide dependency by itself.
+    Provide(E),
+}
+
+impl<E> Display for DowngradeError<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Borrowed => f.write_str("inner provider is already borrowed"),
+            Self::Provide(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl<E> Error for DowngradeError<E>
+where
+    E: Error,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Borrowed => None,
+            Self::Provide(error) => error.source(),
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! Providers which inject errors into the fallible provision for testing.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    any::type_name,
+    cell::Cell,
+    error::Error,
+    fmt::{self, Display},
+};
+
+/// Provider which fails provision of dependencies on demand.
+///
+/// Dependencies are provided with the [`FailAfterWith`](crate::context::failpoint::FailAfterWith)
+/// or [`FailForWith`](crate::context::failpoint::FailForWith) contexts,
+/// which force the fallible provision to return [injected](FailpointError::Injected) error
+/// after some number of successes or for selected dependency types respectively.
+/// This allows to exercise error-handling branches around fallible provision
+/// without real failures.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::failpoint::FailAfter,
+///     failpoint::{Failpoint, FailpointError},
+///     with::TryProvideRefWith,
+/// };
+///
+/// let provider = Failpoint::new(Box::new(1));
+///
+/// let first: Result<&i32, _> = provider.try_provide_ref_with(FailAfter::new(1, ()));
+/// assert_eq!(first, Ok(&1));
+///
+/// let second: Result<&i32, _> = provider.try_provide_ref_with(FailAfter::new(1, ()));
+/// assert_eq!(second, Err(FailpointError::Injected));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Failpoint<P> {
+    provider: P,
+    successes: Cell<usize>,
+}
+
+impl<P> Failpoint<P> {
+    /// Creates new provider from the inner provider.
+    pub const fn new(provider: P) -> Self {
+        Self {
+            provider,
+            successes: Cell::new(0),
+        }
+    }
+
+    /// Returns count of successful provisions counted so far.
+    pub fn successes(&self) -> usize {
+        let Self { successes, .. } = self;
+        successes.get()
+    }
+
+    /// Resets count of successful provisions.
+    pub fn reset(&self) {
+        let Self { successes, .. } = self;
+        successes.set(0);
+    }
+
+    /// Returns the inner provider.
+    pub fn into_inner(self) -> P {
+        let Self { provider, .. } = self;
+        provider
+    }
+
+    pub(crate) fn check_after(&self, limit: usize) -> Result<(), Injected> {
+        if self.successes() >= limit {
+            return Err(Injected);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn check_for<T, F>(&self) -> Result<(), Injected>
+    where
+        T: ?Sized,
+        F: ?Sized,
+    {
+        if type_name::<T>() == type_name::<F>() {
+            return Err(Injected);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn provider(&self) -> (&P, Counter<'_>) {
+        let Self {
+            provider,
+            successes,
+        } = self;
+        (provider, Counter(successes))
+    }
+
+    pub(crate) fn provider_mut(&mut self) -> (&mut P, Counter<'_>) {
+        let Self {
+            provider,
+            successes,
+        } = self;
+        (provider, Counter(successes))
+    }
+}
+
+pub(crate) struct Counter<'a>(&'a Cell<usize>);
+
+impl Counter<'_> {
+    pub(crate) fn count<T, E>(self, result: Result<T, E>) -> Result<T, FailpointError<E>> {
+        let Self(successes) = self;
+        let dependency = result.map_err(FailpointError::Provide)?;
+        successes.set(successes.get() + 1);
+        Ok(dependency)
+    }
+}
+
+pub(crate) struct Injected;
+
+impl<E> From<Injected> for FailpointError<E> {
+    fn from(_: Injected) -> Self {
+        Self::Injected
+    }
+}
+
+/// The error type returned when [failpoint](Failpoint) provider fails to provide dependency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FailpointError<E> {
+    /// Error was injected by the failpoint.
+    Injected,
+    /// Inner provider failed to provide dependency by itself.
+    Provide(E),
+}
+
+impl<E> Display for FailpointError<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Injected => f.write_str("error was injected by the failpoint"),
+            Self::Provide(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl<E> Error for FailpointError<E>
+where
+    E: Error,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Injected => None,
+            Self::Provide(error) => error.source(),
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! Provider which wraps arbitrary value without any boilerplate.
+//!
+//! See [crate] documentation for more.
+
+use crate::{Provide, ProvideMut, ProvideRef};
+
+/// Provider which wraps arbitrary value, providing it wrapped into [`Dependency`].
+///
+/// Because of blanket implementations of provider traits for types which implement
+/// [`AsRef`], [`AsMut`] and [`Into`] traits, generic provider cannot provide
+/// its value directly (see [`ProvideRef`] documentation for details).
+/// This provider works around it by wrapping the value into [`Dependency`] newtype.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     generic::{Dependency, GenericProvider},
+///     Provide, ProvideMut, ProvideRef,
+/// };
+///
+/// let mut provider = GenericProvider::new(vec![1, 2, 3]);
+///
+/// let Dependency(dependency): Dependency<&mut Vec<i32>> = provider.provide_mut();
+/// dependency.push(4);
+///
+/// let Dependency(dependency): Dependency<&Vec<i32>> = provider.provide_ref();
+/// assert_eq!(dependency, &[1, 2, 3, 4]);
+///
+/// let (Dependency(dependency), ()) = provider.provide();
+/// assert_eq!(dependency, [1, 2, 3, 4]);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GenericProvider<T>(T)
+where
+    T: ?Sized;
+
+impl<T> GenericProvider<T> {
+    /// Creates new provider from the value.
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        let Self(value) = self;
+        value
+    }
+}
+
+impl<T> GenericProvider<T>
+where
+    T: ?Sized,
+{
+    /// Returns shared reference to the wrapped value.
+    pub const fn inner(&self) -> &T {
+        let Self(value) = self;
+        value
+    }
+
+    /// Returns unique reference to the wrapped value.
+    pub fn inner_mut(&mut self) -> &mut T {
+        let Self(value) = self;
+        value
+    }
+}
+
+impl<T> From<T> for GenericProvider<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Newtype wrapper of the dependency provided by [`GenericProvider`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Dependency<T>(pub T);
+
+impl<T> Dependency<T> {
+    /// Returns the wrapped dependency.
+    pub fn into_inner(self) -> T {
+        let Self(dependency) = self;
+        dependency
+    }
+}
+
+impl<T> Provide<Dependency<T>> for GenericProvider<T> {
+    type Remainder = ();
+
+    fn provide(self) -> (Dependency<T>, Self::Remainder) {
+        (Dependency(self.into_inner()), ())
+    }
+}
+
+impl<'me, T> ProvideRef<'me, Dependency<&'me T>> for GenericProvider<T>
+where
+    T: ?Sized,
+{
+    fn provide_ref(&'me self) -> Dependency<&'me T> {
+        Dependency(self.inner())
+    }
+}
+
+impl<'me, T> ProvideMut<'me, Dependency<&'me mut T>> for GenericProvider<T>
+where
+    T: ?Sized,
+{
+    fn provide_mut(&'me mut self) -> Dependency<&'me mut T> {
+        Dependency(self.inner_mut())
+    }
+}
@@ -0,0 +1,251 @@
+//! Compile-time dependency graphs declared with the [`ordered_graph!`](crate::ordered_graph!) macro.
+//!
+//! See [crate] documentation for more.
+
+/// Type of provider which is a graph of dependencies built in topological order.
+///
+/// This trait is implemented by the [`ordered_graph!`](crate::ordered_graph!) macro.
+///
+/// See [crate] documentation for more.
+pub trait Graph: Sized {
+    /// Names of the nodes of the graph in the order they are built.
+    const NODES: &'static [&'static str];
+
+    /// Builds all the nodes of the graph in topological order.
+    fn build() -> Self;
+}
+
+/// Declares the graph of dependencies built in topological order.
+///
+/// Each node of the graph is declared as the field of the provider struct
+/// with the type of the dependency, the list of nodes it depends on
+/// and the expression which builds the dependency out of them.
+/// Nodes it depends on are passed into the expression by shared reference.
+///
+/// Nodes may be declared in any order: each node is built after all the nodes it depends on,
+/// and nodes which do not depend on each other are built in the order of declaration.
+/// The order is computed at compile time, so the compilation fails
+/// if there is a cycle in the graph or if some node depends on the undeclared one.
+///
+/// Each type can be declared only once, because nodes are provided by their types,
+/// so the compilation fails if two nodes have the same type, even if it is named differently.
+///
+/// The macro generates:
+/// - the provider struct with all the nodes as its fields;
+/// - implementations of [`AsRef`] and [`AsMut`] traits for every node,
+///   so all the [contexts](crate::context) can be used with the provider;
+/// - implementations of [`ProvideRefWith`](crate::with::ProvideRefWith) trait
+///   with [`Rebuild`](crate::context::graph::Rebuild) context for every node,
+///   which build new instance of the node out of the nodes held by the provider;
+/// - implementation of the [`Graph`](crate::graph::Graph) trait
+///   with the constructor which builds all the nodes in topological order.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use provide::{graph::Graph, ordered_graph, ProvideExt};
+///
+/// struct Config {
+///     url: &'static str,
+/// }
+///
+/// struct Database {
+///     url: &'static str,
+/// }
+///
+/// struct Service {
+///     name: String,
+/// }
+///
+/// ordered_graph! {
+///     /// Dependencies of the application.
+///     pub struct App {
+///         service: Service = (config, database) => Service {
+///             name: format!("{} at {}", config.url, database.url),
+///         },
+///         database: Arc<Database> = (config) => Arc::new(Database { url: config.url }),
+///         config: Config = () => Config { url: "localhost" },
+///     }
+/// }
+///
+/// let app = App::build();
+/// assert_eq!(App::NODES, ["config", "database", "service"]);
+/// assert_eq!(app.get_dep::<Service>().name, "localhost at localhost");
+/// assert_eq!(app.get_dep::<Arc<Database>>().url, "localhost");
+/// ```
+///
+/// Graph cannot contain cycles:
+///
+/// ```compile_fail
+/// use provide::ordered_graph;
+///
+/// ordered_graph! {
+///     struct Cycle {
+///         first: i32 = (second) => *second as i32,
+///         second: u32 = (first) => *first as u32,
+///     }
+/// }
+/// ```
+///
+/// Each type of the node can be declared only once:
+///
+/// ```compile_fail
+/// use provide::ordered_graph;
+///
+/// type Count = i32;
+///
+/// ordered_graph! {
+///     struct Duplicate {
+///         first: i32 = () => 1,
+///         second: Count = (first) => *first + 1,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! ordered_graph {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident : $ty:ty = ($($dep:ident),* $(,)?) => $init:expr
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $(
+                $(#[$field_meta])*
+                $field_vis $field: $ty,
+            )*
+        }
+
+        impl $name {
+            /// Indices of the nodes in topological order.
+            const __ORDER: &'static [usize] = &{
+                #[allow(non_camel_case_types, dead_code)]
+                enum Node {
+                    $($field),*
+                }
+                $crate::graph::__topological_order([$(&[$(Node::$dep as usize),*] as &[usize]),*])
+            };
+        }
+
+        const _: &[usize] = $name::__ORDER;
+
+        impl $crate::graph::Graph for $name {
+            const NODES: &'static [&'static str] =
+                &$crate::graph::__ordered_names([$(::core::stringify!($field)),*], Self::__ORDER);
+
+            #[allow(unused_variables)]
+            fn build() -> Self {
+                #[allow(non_camel_case_types, dead_code)]
+                enum Node {
+                    $($field),*
+                }
+                $(
+                    let mut $field: ::core::option::Option<$ty> = ::core::option::Option::None;
+                )*
+                for &node in Self::__ORDER {
+                    $(
+                        if node == Node::$field as usize {
+                            let dependency: $ty = {
+                                let ($($dep,)*) = ($(
+                                    $dep.as_ref()
+                                        .expect("dependencies of the node should be built before it"),
+                                )*);
+                                $init
+                            };
+                            $field = ::core::option::Option::Some(dependency);
+                            continue;
+                        }
+                    )*
+                }
+                Self {
+                    $($field: $field.expect("each node of the graph should be built"),)*
+                }
+            }
+        }
+
+        $(
+            impl ::core::convert::AsRef<$ty> for $name {
+                fn as_ref(&self) -> &$ty {
+                    &self.$field
+                }
+            }
+
+            impl ::core::convert::AsMut<$ty> for $name {
+                fn as_mut(&mut self) -> &mut $ty {
+                    &mut self.$field
+                }
+            }
+
+            impl<'me> $crate::with::ProvideRefWith<'me, $ty, $crate::context::graph::Rebuild>
+                for $name
+            {
+                #[allow(unused_variables)]
+                fn provide_ref_with(&'me self, _: $crate::context::graph::Rebuild) -> $ty {
+                    let Self { $($dep,)* .. } = self;
+                    $init
+                }
+            }
+        )*
+    };
+}
+
+/// Returns indices of the nodes in topological order,
+/// preferring the order of declaration for nodes which do not depend on each other.
+///
+/// Dependencies of each node are passed as indices of the nodes it depends on.
+///
+/// # Panics
+///
+/// Panics if there is a cycle in the graph, which fails the compilation
+/// when called in constant context by [`ordered_graph!`](crate::ordered_graph!).
+#[doc(hidden)]
+pub const fn __topological_order<const N: usize>(dependencies: [&[usize]; N]) -> [usize; N] {
+    let mut order = [0; N];
+    let mut built = [false; N];
+    let mut count = 0;
+    while count < N {
+        let mut node = 0;
+        while built[node] || !all_built(dependencies[node], &built) {
+            node += 1;
+            if node == N {
+                panic!("dependency graph should not contain cycles");
+            }
+        }
+        built[node] = true;
+        order[count] = node;
+        count += 1;
+    }
+    order
+}
+
+const fn all_built(dependencies: &[usize], built: &[bool]) -> bool {
+    let mut index = 0;
+    while index < dependencies.len() {
+        if !built[dependencies[index]] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// Returns names of the nodes in provided order.
+#[doc(hidden)]
+pub const fn __ordered_names<const N: usize>(
+    names: [&'static str; N],
+    order: &[usize],
+) -> [&'static str; N] {
+    let mut ordered = [""; N];
+    let mut index = 0;
+    while index < N {
+        ordered[index] = names[order[index]];
+        index += 1;
+    }
+    ordered
+}
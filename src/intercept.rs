@@ -0,0 +1,178 @@
+//! Cross-cutting code which runs around provision of dependencies.
+//!
+//! See [crate] documentation for more.
+
+use core::convert::Infallible;
+
+/// Type of code which runs before and after each provision of dependency.
+///
+/// Interceptor can be attached to the provider with the [`Intercepted`] wrapper.
+///
+/// See [crate] documentation for more.
+pub trait Interceptor {
+    /// The type returned when provision of dependency was denied by the interceptor.
+    type Error;
+
+    /// Runs before provision of dependency of type `T`.
+    ///
+    /// Provision is denied if this method returns an error,
+    /// so the dependency is not provided at all.
+    fn before<T>(&self) -> Result<(), Self::Error>
+    where
+        T: ?Sized,
+    {
+        Ok(())
+    }
+
+    /// Runs after provision of dependency of type `T`.
+    fn after<T>(&self)
+    where
+        T: ?Sized,
+    {
+    }
+}
+
+impl<I> Interceptor for &I
+where
+    I: Interceptor,
+{
+    type Error = I::Error;
+
+    fn before<T>(&self) -> Result<(), Self::Error>
+    where
+        T: ?Sized,
+    {
+        I::before::<T>(self)
+    }
+
+    fn after<T>(&self)
+    where
+        T: ?Sized,
+    {
+        I::after::<T>(self);
+    }
+}
+
+/// Provider which runs [interceptor](Interceptor) around each provision
+/// of dependency by reference from the inner provider.
+///
+/// This wrapper implements [`AsRef`] and [`AsMut`] traits,
+/// so it provides dependencies by [shared](crate::ProvideRef)
+/// or [unique](crate::ProvideMut) reference with all the contexts based on them.
+/// Any other provision, including provision by value, provision with contexts
+/// of the inner provider and fallible provision, is intercepted
+/// with the [`InterceptWith`](crate::context::intercept::InterceptWith) context.
+///
+/// [`AsRef`] and [`AsMut`] traits are implemented only for interceptors
+/// which never [deny](Interceptor::before) provision.
+/// Interceptors which can deny it are used with fallible provision
+/// with the [`InterceptWith`](crate::context::intercept::InterceptWith) context only,
+/// so the denial is always reported as an error.
+///
+/// # Examples
+///
+/// ```
+/// use std::{cell::Cell, convert::Infallible};
+///
+/// use provide::{
+///     intercept::{Intercepted, Interceptor},
+///     ProvideExt,
+/// };
+///
+/// #[derive(Default)]
+/// struct Counter {
+///     count: Cell<u32>,
+/// }
+///
+/// impl Interceptor for Counter {
+///     type Error = Infallible;
+///
+///     fn before<T: ?Sized>(&self) -> Result<(), Self::Error> {
+///         self.count.set(self.count.get() + 1);
+///         Ok(())
+///     }
+/// }
+///
+/// let provider = Intercepted::new(String::from("hello"), Counter::default());
+/// assert_eq!(provider.get_dep::<str>(), "hello");
+/// assert_eq!(provider.get_dep::<[u8]>(), b"hello");
+///
+/// let (_, interceptor) = provider.into_inner();
+/// assert_eq!(interceptor.count.get(), 2);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Intercepted<P, I> {
+    provider: P,
+    interceptor: I,
+}
+
+impl<P, I> Intercepted<P, I> {
+    /// Creates new provider from the inner provider and the interceptor.
+    pub const fn new(provider: P, interceptor: I) -> Self {
+        Self {
+            provider,
+            interceptor,
+        }
+    }
+
+    /// Returns the inner provider and the interceptor.
+    pub fn into_inner(self) -> (P, I) {
+        let Self {
+            provider,
+            interceptor,
+        } = self;
+        (provider, interceptor)
+    }
+
+    pub(crate) fn parts(&self) -> (&P, &I) {
+        let Self {
+            provider,
+            interceptor,
+        } = self;
+        (provider, interceptor)
+    }
+
+    pub(crate) fn parts_mut(&mut self) -> (&mut P, &I) {
+        let Self {
+            provider,
+            interceptor,
+        } = self;
+        (provider, interceptor)
+    }
+}
+
+impl<T, P, I> AsRef<T> for Intercepted<P, I>
+where
+    T: ?Sized,
+    P: AsRef<T>,
+    I: Interceptor<Error = Infallible>,
+{
+    fn as_ref(&self) -> &T {
+        let Self {
+            provider,
+            interceptor,
+        } = self;
+        let Ok(()) = interceptor.before::<T>();
+        let dependency = provider.as_ref();
+        interceptor.after::<T>();
+        dependency
+    }
+}
+
+impl<T, P, I> AsMut<T> for Intercepted<P, I>
+where
+    T: ?Sized,
+    P: AsMut<T>,
+    I: Interceptor<Error = Infallible>,
+{
+    fn as_mut(&mut self) -> &mut T {
+        let Self {
+            provider,
+            interceptor,
+        } = self;
+        let Ok(()) = interceptor.before::<T>();
+        let dependency = provider.as_mut();
+        interceptor.after::<T>();
+        dependency
+    }
+}
@@ -0,0 +1,450 @@
+//! Providers which are materialized lazily on the first provision.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    cell::{Cell, OnceCell},
+    error::Error,
+    fmt::{self, Debug, Display},
+};
+
+use crate::memoize::Reset;
+
+/// Provider which wraps the whole sub-provider, materializing it on the first provision.
+///
+/// Sub-provider is created with the carried function when some dependency
+/// is provided from it for the first time, and then it is cached,
+/// so all the subsequent provisions reuse it and all of its dependencies.
+/// This allows to amortize construction of expensive subsystems
+/// without turning them into global singletons.
+///
+/// This provider implements [`AsRef`] and [`AsMut`] traits,
+/// so it provides dependencies by [shared](crate::ProvideRef)
+/// or [unique](crate::ProvideMut) reference with all the contexts based on them.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+///
+/// use provide::{lazy::LazyProvider, ProvideExt};
+///
+/// let count = Cell::new(0);
+/// let provider = LazyProvider::new(|| {
+///     count.set(count.get() + 1);
+///     String::from("expensive")
+/// });
+/// assert_eq!(count.get(), 0);
+///
+/// assert_eq!(provider.get_dep::<str>(), "expensive");
+/// assert_eq!(provider.get_dep::<[u8]>(), b"expensive");
+/// assert_eq!(count.get(), 1);
+/// ```
+pub struct LazyProvider<P, F = fn() -> P> {
+    provider: OnceCell<P>,
+    init: Cell<Option<F>>,
+    pending: Cell<bool>,
+}
+
+impl<P, F> LazyProvider<P, F>
+where
+    F: FnOnce() -> P,
+{
+    /// Creates new provider from the function which creates the sub-provider.
+    pub const fn new(init: F) -> Self {
+        Self {
+            provider: OnceCell::new(),
+            init: Cell::new(Some(init)),
+            pending: Cell::new(false),
+        }
+    }
+
+    /// Materializes the sub-provider if it was not done yet,
+    /// returning shared reference to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if previous materialization of the sub-provider has panicked,
+    /// or if the sub-provider is requested recursively while it is materialized.
+    /// See [`try_force`](LazyProvider::try_force) for the non-panicking version.
+    pub fn force(&self) -> &P {
+        self.try_force().unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Materializes the sub-provider if it was not done yet,
+    /// returning shared reference to it.
+    ///
+    /// Returns [poisoned](ForceError::Poisoned) error if previous materialization
+    /// of the sub-provider has panicked, or [reentrant](ForceError::Reentrant) error
+    /// if the sub-provider is requested recursively while it is materialized.
+    pub fn try_force(&self) -> Result<&P, ForceError> {
+        let Self {
+            provider,
+            init,
+            pending,
+        } = self;
+        if let Some(provider) = provider.get() {
+            return Ok(provider);
+        }
+        if pending.get() {
+            return Err(ForceError::Reentrant);
+        }
+        let init = init.take().ok_or(ForceError::Poisoned)?;
+        pending.set(true);
+        let reset = Reset(pending);
+        let materialized = init();
+        drop(reset);
+        Ok(provider.get_or_init(|| materialized))
+    }
+
+    /// Materializes the sub-provider if it was not done yet,
+    /// returning unique reference to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if previous materialization of the sub-provider has panicked.
+    /// See [`try_force_mut`](LazyProvider::try_force_mut) for the non-panicking version.
+    pub fn force_mut(&mut self) -> &mut P {
+        self.try_force_mut()
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Materializes the sub-provider if it was not done yet,
+    /// returning unique reference to it,
+    /// or [poisoned](ForceError::Poisoned) error if previous materialization
+    /// of the sub-provider has panicked.
+    pub fn try_force_mut(&mut self) -> Result<&mut P, ForceError> {
+        self.try_force()?;
+        let Self { provider, .. } = self;
+        let provider = provider
+            .get_mut()
+            .expect("sub-provider should be materialized");
+        Ok(provider)
+    }
+
+    /// Returns the sub-provider if it was materialized,
+    /// or the function which creates it otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if previous materialization of the sub-provider has panicked.
+    /// See [`try_into_inner`](LazyProvider::try_into_inner) for the non-panicking version.
+    pub fn into_inner(self) -> Result<P, F> {
+        self.try_into_inner()
+            .expect("sub-provider materialization has previously panicked")
+    }
+
+    /// Returns the sub-provider if it was materialized,
+    /// or the function which creates it otherwise,
+    /// or [`None`] if previous materialization of the sub-provider has panicked.
+    ///
+    /// Unlike [`try_force`](LazyProvider::try_force) method, this method cannot observe
+    /// the sub-provider being materialized, because it consumes the provider,
+    /// so [`None`] always means that the materialization has panicked.
+    pub fn try_into_inner(self) -> Option<Result<P, F>> {
+        let Self { provider, init, .. } = self;
+        match (provider.into_inner(), init.into_inner()) {
+            (Some(provider), _) => Some(Ok(provider)),
+            (None, Some(init)) => Some(Err(init)),
+            (None, None) => None,
+        }
+    }
+}
+
+impl<P, F> Debug for LazyProvider<P, F>
+where
+    P: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { provider, .. } = self;
+        let mut tuple = f.debug_tuple("LazyProvider");
+        match provider.get() {
+            Some(provider) => tuple.field(provider),
+            None => tuple.field(&format_args!("<unmaterialized>")),
+        };
+        tuple.finish()
+    }
+}
+
+impl<T, P, F> AsRef<T> for LazyProvider<P, F>
+where
+    T: ?Sized,
+    P: AsRef<T>,
+    F: FnOnce() -> P,
+{
+    fn as_ref(&self) -> &T {
+        self.force().as_ref()
+    }
+}
+
+impl<T, P, F> AsMut<T> for LazyProvider<P, F>
+where
+    T: ?Sized,
+    P: AsMut<T>,
+    F: FnOnce() -> P,
+{
+    fn as_mut(&mut self) -> &mut T {
+        self.force_mut().as_mut()
+    }
+}
+
+/// Dependency which is constructed lazily on the first provision.
+///
+/// Value of the dependency is created with the carried function when it is requested
+/// for the first time, and then it is stored, so all the subsequent provisions reuse it.
+/// This is the lazy singleton pattern for the individual dependencies held by the provider,
+/// while [`LazyProvider`] wraps the whole sub-provider instead.
+///
+/// Dependency is provided from this wrapper with the
+/// [`LazyDependencyWith`](crate::context::lazy::LazyDependencyWith) context
+/// if it is constructed with a function pointer (which is the default),
+/// or explicitly with [`force`](Lazy::force) and [`force_mut`](Lazy::force_mut) methods.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{context::lazy::LazyDependency, lazy::Lazy, with::ProvideRefWith};
+///
+/// struct Provider {
+///     config: Lazy<String>,
+/// }
+///
+/// impl AsRef<Lazy<String>> for Provider {
+///     fn as_ref(&self) -> &Lazy<String> {
+///         let Self { config } = self;
+///         config
+///     }
+/// }
+///
+/// let provider = Provider {
+///     config: Lazy::new(|| String::from("expensive")),
+/// };
+/// assert_eq!(provider.config.get(), None);
+///
+/// let config: &String = provider.provide_ref_with(LazyDependency::default());
+/// assert_eq!(config, "expensive");
+/// assert_eq!(provider.config.get().map(String::as_str), Some("expensive"));
+/// ```
+pub struct Lazy<T, F = fn() -> T> {
+    value: OnceCell<T>,
+    init: Cell<Option<F>>,
+    pending: Cell<bool>,
+}
+
+impl<T, F> Lazy<T, F> {
+    /// Returns shared reference to the value of the dependency if it was constructed.
+    pub fn get(&self) -> Option<&T> {
+        let Self { value, .. } = self;
+        value.get()
+    }
+}
+
+impl<T, F> Lazy<T, F>
+where
+    F: FnOnce() -> T,
+{
+    /// Creates new dependency from the function which constructs its value.
+    pub const fn new(init: F) -> Self {
+        Self {
+            value: OnceCell::new(),
+            init: Cell::new(Some(init)),
+            pending: Cell::new(false),
+        }
+    }
+
+    /// Constructs the value of the dependency if it was not done yet,
+    /// returning shared reference to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if previous construction of the dependency has panicked,
+    /// or if the dependency is requested recursively while it is constructed.
+    /// See [`try_force`](Lazy::try_force) for the non-panicking version.
+    pub fn force(&self) -> &T {
+        self.try_force().unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Constructs the value of the dependency if it was not done yet,
+    /// returning shared reference to it.
+    ///
+    /// Returns [poisoned](ForceError::Poisoned) error if previous construction
+    /// of the dependency has panicked, or [reentrant](ForceError::Reentrant) error
+    /// if the dependency is requested recursively while it is constructed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::lazy::{ForceError, Lazy};
+    ///
+    /// thread_local! {
+    ///     static VALUE: Lazy<u32> = const { Lazy::new(init) };
+    /// }
+    ///
+    /// fn init() -> u32 {
+    ///     let result = VALUE.with(|value| value.try_force().copied());
+    ///     assert_eq!(result, Err(ForceError::Reentrant));
+    ///     42
+    /// }
+    ///
+    /// let result = VALUE.with(|value| value.try_force().copied());
+    /// assert_eq!(result, Ok(42));
+    /// ```
+    pub fn try_force(&self) -> Result<&T, ForceError> {
+        let Self {
+            value,
+            init,
+            pending,
+        } = self;
+        if let Some(value) = value.get() {
+            return Ok(value);
+        }
+        if pending.get() {
+            return Err(ForceError::Reentrant);
+        }
+        let init = init.take().ok_or(ForceError::Poisoned)?;
+        pending.set(true);
+        let reset = Reset(pending);
+        let constructed = init();
+        drop(reset);
+        Ok(value.get_or_init(|| constructed))
+    }
+
+    /// Constructs the value of the dependency if it was not done yet,
+    /// returning unique reference to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if previous construction of the dependency has panicked.
+    /// See [`try_force_mut`](Lazy::try_force_mut) for the non-panicking version.
+    pub fn force_mut(&mut self) -> &mut T {
+        self.try_force_mut()
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Constructs the value of the dependency if it was not done yet,
+    /// returning unique reference to it,
+    /// or [poisoned](ForceError::Poisoned) error if previous construction
+    /// of the dependency has panicked.
+    pub fn try_force_mut(&mut self) -> Result<&mut T, ForceError> {
+        self.try_force()?;
+        let Self { value, .. } = self;
+        let value = value.get_mut().expect("dependency should be constructed");
+        Ok(value)
+    }
+
+    /// Returns the value of the dependency if it was constructed,
+    /// or the function which constructs it otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if previous construction of the dependency has panicked.
+    /// See [`try_into_inner`](Lazy::try_into_inner) for the non-panicking version.
+    pub fn into_inner(self) -> Result<T, F> {
+        self.try_into_inner()
+            .expect("dependency construction has previously panicked")
+    }
+
+    /// Returns the value of the dependency if it was constructed,
+    /// or the function which constructs it otherwise,
+    /// or [`None`] if previous construction of the dependency has panicked.
+    ///
+    /// Unlike [`try_force`](Lazy::try_force) method, this method cannot observe
+    /// the dependency being constructed, because it consumes the dependency,
+    /// so [`None`] always means that the construction has panicked.
+    pub fn try_into_inner(self) -> Option<Result<T, F>> {
+        let Self { value, init, .. } = self;
+        match (value.into_inner(), init.into_inner()) {
+            (Some(value), _) => Some(Ok(value)),
+            (None, Some(init)) => Some(Err(init)),
+            (None, None) => None,
+        }
+    }
+}
+
+impl<T, F> Debug for Lazy<T, F>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { value, .. } = self;
+        let mut tuple = f.debug_tuple("Lazy");
+        match value.get() {
+            Some(value) => tuple.field(value),
+            None => tuple.field(&format_args!("<uninitialized>")),
+        };
+        tuple.finish()
+    }
+}
+
+/// The error type returned when [lazy provider](LazyProvider)
+/// or [lazy dependency](Lazy) is forced fallibly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ForceError {
+    /// Previous initialization has panicked.
+    Poisoned,
+    /// Value was requested recursively while it was initialized.
+    Reentrant,
+}
+
+impl Display for ForceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Poisoned => f.write_str("lazy initialization has previously panicked"),
+            Self::Reentrant => {
+                f.write_str("lazy value was requested recursively while initializing")
+            }
+        }
+    }
+}
+
+impl Error for ForceError {}
+
+/// The error type returned when dependency is provided out of [lazy](Lazy) dependency
+/// with [`LazyDependencyWith`](crate::context::lazy::LazyDependencyWith) context fallibly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum LazyError<E> {
+    /// Previous construction of the dependency has panicked.
+    Poisoned,
+    /// Dependency was requested recursively while it was constructed.
+    Reentrant,
+    /// Lazy dependency itself was not provided with the inner context.
+    Provide(E),
+}
+
+impl<E> Display for LazyError<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Poisoned => f.write_str("dependency construction has previously panicked"),
+            Self::Reentrant => {
+                f.write_str("dependency was requested recursively while constructing")
+            }
+            Self::Provide(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl<E> Error for LazyError<E>
+where
+    E: Error,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Poisoned | Self::Reentrant => None,
+            Self::Provide(error) => error.source(),
+        }
+    }
+}
+
+impl<E> From<ForceError> for LazyError<E> {
+    fn from(error: ForceError) -> Self {
+        match error {
+            ForceError::Poisoned => Self::Poisoned,
+            ForceError::Reentrant => Self::Reentrant,
+        }
+    }
+}
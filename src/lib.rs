@@ -5,18 +5,124 @@
 //! - **context** types represent different ways to provide some dependency
 //!
 //! // TODO better documentation
+//!
+//! # Features
+//!
+//! This crate is `no_std` and has no dependencies by default.
+//! Subsystems and integrations are opt-in with the following features:
+//! - `alloc` — contexts and providers which need heap allocation
+//! - `std` — implementations for types of the standard library (implies `alloc`)
+//! - `async` — asynchronous provider traits and construction
+//! - `parking_lot`, `tokio`, `async-lock` — lock contexts for types of these crates
+//! - `futures` — channel contexts for types of `futures` crate
+//! - `pool` — contexts which acquire dependencies from connection pools
+//! - `test_util` — fake clocks and timers, and provider assertions for tests
+//! - `bench` — providers which hide provisions from the optimizer for benchmarks
+//! - `defmt` — [`defmt::Format`](https://docs.rs/defmt) implementations for contexts and errors
+//! - `compact_names` — short hashes instead of full type names in [`Debug`](core::fmt::Debug)
+//!   and `defmt::Format` implementations of contexts, for embedded logs
+//! - `tuples_32` — implementations for tuples of up to 32 elements instead of 12
+//! - `full` — all the subsystems and integrations above,
+//!   except for `test_util`, `bench`, `defmt`, `compact_names` and `tuples_32`
+//!
+//! # Panics
+//!
+//! Provision may panic only where it is documented in the `# Panics` section
+//! of the context or provider, such as [lazy](lazy::Lazy) dependency whose construction
+//! has previously panicked. Each such context implements fallible provider traits
+//! ([`TryProvide`], [`TryProvideRef`], [`TryProvideMut`] and their `*With` counterparts)
+//! which report the same conditions as errors instead of panicking.
+//! Providers which are accessed through the dedicated context,
+//! such as [memoized](memoize::Memoized) provider, report them with that context the same way.
+//!
+//! Conditions which are not specific to the provision are never reported with panics,
+//! but fallible provision still reports them as errors:
+//! for example, infallible provision of locks from the standard library ignores poisoning,
+//! while fallible provision reports it with `LockError` and `TryLockError`
+//! errors of lock contexts, available with `std` feature enabled.
+//!
+//! Internal assertions of the crate, such as `expect` calls on values
+//! which were provided just before, only guard invariants
+//! which cannot be violated through the public interface.
 
 #![warn(clippy::all)]
 #![warn(missing_docs)]
 #![forbid(unsafe_code)]
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+/// Invokes provided macro with generic parameters of the largest supported tuple.
+///
+/// Maximum arity of tuples is 12 by default, and 32 with `tuples_32` feature enabled,
+/// so larger tuples are supported only by those who opt into their compile-time cost.
+macro_rules! for_tuples {
+    ($macro:ident) => {
+        #[cfg(not(feature = "tuples_32"))]
+        $macro!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
+        #[cfg(feature = "tuples_32")]
+        $macro!(
+            T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17, T18, T19,
+            T20, T21, T22, T23, T24, T25, T26, T27, T28, T29, T30, T31, T32
+        );
+    };
+}
+
+#[cfg(feature = "async")]
+pub use self::provide::{
+    ProvideAsync, ProvideMutAsync, ProvideRefAsync, TryProvideAsync, TryProvideMutAsync,
+    TryProvideRefAsync,
+};
 pub use self::{
-    provide::{Provide, ProvideMut, ProvideRef, TryProvide, TryProvideMut, TryProvideRef},
-    with::With,
+    provide::{
+        Provide, ProvideAll, ProvideAllError, ProvideExt, ProvideHandle, ProvideMut, ProvideRef,
+        ProvidedFn, TryProvide, TryProvideAll, TryProvideMut, TryProvideRef, TryProvideRefEach,
+    },
+    with::{TryWith, With, WithBack, WithFront},
 };
 
+pub mod balance;
+pub mod best_effort;
+#[cfg(feature = "bench")]
+pub mod black_box;
+pub mod bundle;
+#[cfg(feature = "alloc")]
+pub mod checkout;
+pub mod clock;
+#[cfg(feature = "async")]
+pub mod construct;
 pub mod context;
+pub mod describe;
+pub mod downgrade;
+pub mod failpoint;
+pub mod generic;
+pub mod graph;
+pub mod intercept;
+pub mod lazy;
+pub mod lifecycle;
+pub mod memoize;
+pub mod merge;
+pub mod named;
+pub mod observe;
+pub mod pair;
+pub mod pending;
+#[cfg(feature = "pool")]
+pub mod pool;
+pub mod profile;
+pub mod project;
+#[cfg(feature = "alloc")]
+pub mod spy;
+#[cfg(all(feature = "tokio", feature = "std"))]
+pub mod task_scope;
+#[cfg(feature = "test_util")]
+pub mod test_util;
+#[cfg(feature = "std")]
+pub mod throttle;
+pub mod variant;
 pub mod with;
 
+mod name;
 mod provide;
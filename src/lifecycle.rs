@@ -0,0 +1,207 @@
+//! Lifecycle hooks of dependencies bound to the [scope](Scope).
+//!
+//! See [crate] documentation for more.
+
+use core::ops::{Deref, DerefMut};
+
+use crate::pair::Pair;
+
+/// Type of dependency which should be initialized when the [scope](Scope) is created.
+///
+/// This trait is implemented for [pairs](Pair) and tuples of up to 12 elements
+/// (or 32 elements with `tuples_32` feature enabled),
+/// which initialize all of their dependencies in order.
+///
+/// See [crate] documentation for more.
+pub trait Initialize {
+    /// Initializes the dependency, for example, warms up the connection.
+    fn initialize(&mut self);
+}
+
+/// Type of dependency which should be shut down when the [scope](Scope) ends.
+///
+/// This trait is implemented for [pairs](Pair) and tuples of up to 12 elements
+/// (or 32 elements with `tuples_32` feature enabled),
+/// which shut down all of their dependencies in *reverse* order,
+/// so dependencies are shut down before dependencies initialized earlier.
+///
+/// See [crate] documentation for more.
+pub trait Shutdown {
+    /// Shuts down the dependency, for example, gracefully closes the connection.
+    fn shutdown(&mut self);
+}
+
+/// Scope which drives the lifecycle of dependencies of the provider.
+///
+/// Dependencies are [initialized](Initialize) when the scope is created
+/// and [shut down](Shutdown) when the scope is dropped.
+/// The provider is accessible through [`Deref`] and [`DerefMut`] traits.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::RefCell;
+///
+/// use provide::lifecycle::{Initialize, Scope, Shutdown};
+///
+/// struct Connection<'a> {
+///     name: &'static str,
+///     log: &'a RefCell<Vec<String>>,
+/// }
+///
+/// impl Initialize for Connection<'_> {
+///     fn initialize(&mut self) {
+///         self.log.borrow_mut().push(format!("open {}", self.name));
+///     }
+/// }
+///
+/// impl Shutdown for Connection<'_> {
+///     fn shutdown(&mut self) {
+///         self.log.borrow_mut().push(format!("close {}", self.name));
+///     }
+/// }
+///
+/// let log = RefCell::new(Vec::new());
+/// let scope = Scope::new((
+///     Connection { name: "database", log: &log },
+///     Connection { name: "cache", log: &log },
+/// ));
+/// drop(scope);
+///
+/// let log = log.into_inner();
+/// assert_eq!(log, ["open database", "open cache", "close cache", "close database"]);
+/// ```
+#[derive(Debug)]
+pub struct Scope<P>(P)
+where
+    P: Shutdown;
+
+impl<P> Scope<P>
+where
+    P: Shutdown,
+{
+    /// Creates new scope from the provider, initializing all of its dependencies.
+    pub fn new(mut provider: P) -> Self
+    where
+        P: Initialize,
+    {
+        provider.initialize();
+        Self(provider)
+    }
+}
+
+impl<P> Deref for Scope<P>
+where
+    P: Shutdown,
+{
+    type Target = P;
+
+    fn deref(&self) -> &Self::Target {
+        let Self(provider) = self;
+        provider
+    }
+}
+
+impl<P> DerefMut for Scope<P>
+where
+    P: Shutdown,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        let Self(provider) = self;
+        provider
+    }
+}
+
+impl<P> Drop for Scope<P>
+where
+    P: Shutdown,
+{
+    fn drop(&mut self) {
+        let Self(provider) = self;
+        provider.shutdown();
+    }
+}
+
+impl Initialize for () {
+    fn initialize(&mut self) {}
+}
+
+impl Shutdown for () {
+    fn shutdown(&mut self) {}
+}
+
+impl<T> Initialize for &mut T
+where
+    T: Initialize + ?Sized,
+{
+    fn initialize(&mut self) {
+        T::initialize(self);
+    }
+}
+
+impl<T> Shutdown for &mut T
+where
+    T: Shutdown + ?Sized,
+{
+    fn shutdown(&mut self) {
+        T::shutdown(self);
+    }
+}
+
+impl<H, T> Initialize for Pair<H, T>
+where
+    H: Initialize,
+    T: Initialize,
+{
+    fn initialize(&mut self) {
+        self.head_mut().initialize();
+        self.tail_mut().initialize();
+    }
+}
+
+impl<H, T> Shutdown for Pair<H, T>
+where
+    H: Shutdown,
+    T: Shutdown,
+{
+    fn shutdown(&mut self) {
+        self.tail_mut().shutdown();
+        self.head_mut().shutdown();
+    }
+}
+
+macro_rules! impl_lifecycle {
+    () => {};
+    ($head:ident $(, $tail:ident)* $(,)?) => {
+        impl<$head, $($tail),*> Initialize for ($head, $($tail,)*)
+        where
+            $head: Initialize,
+            $($tail: Initialize,)*
+        {
+            #[allow(non_snake_case)]
+            fn initialize(&mut self) {
+                let ($head, $($tail,)*) = self;
+                $head.initialize();
+                $($tail.initialize();)*
+            }
+        }
+
+        impl<$head, $($tail),*> Shutdown for ($head, $($tail,)*)
+        where
+            $head: Shutdown,
+            $($tail: Shutdown,)*
+        {
+            #[allow(non_snake_case)]
+            fn shutdown(&mut self) {
+                let ($head, $($tail,)*) = self;
+                // shut down remaining dependencies first to reverse order of initialization
+                ($($tail,)*).shutdown();
+                $head.shutdown();
+            }
+        }
+
+        impl_lifecycle!($($tail),*);
+    };
+}
+
+for_tuples!(impl_lifecycle);
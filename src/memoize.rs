@@ -0,0 +1,273 @@
+//! Providers which memoize dependencies provided from the inner provider.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    cell::{Cell, OnceCell},
+    error::Error,
+    fmt::{self, Display},
+};
+
+use crate::{
+    context::Empty,
+    with::{ProvideRefWith, TryProvideRefWith},
+};
+
+/// Provider which memoizes the dependency of type `T` provided from the inner provider.
+///
+/// Dependency is provided from the inner provider by shared reference
+/// with the carried context when it is requested for the first time, and then it is cached,
+/// so all the subsequent provisions return shared reference to the cached dependency.
+/// This allows to memoize expensive dependencies without requiring them to be [`Clone`].
+///
+/// Cached dependency is provided by [shared](crate::with::ProvideRefWith)
+/// or [unique](crate::with::ProvideMutWith) reference
+/// with [`Memoize`](crate::context::memoize::Memoize) context.
+///
+/// # Panics
+///
+/// Infallible provision panics if the inner provider provides the dependency
+/// out of this provider recursively while it is not cached yet.
+/// Fallible provision reports [reentrant](MemoizeError::Reentrant) provision as an error instead.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+///
+/// use provide::{context::memoize::Memoize, memoize::Memoized, with::ProvideRefWith, ProvideRef};
+///
+/// struct Config(String);
+///
+/// #[derive(Default)]
+/// struct Provider {
+///     loads: Cell<u32>,
+/// }
+///
+/// impl<'me> ProvideRef<'me, Config> for Provider {
+///     fn provide_ref(&'me self) -> Config {
+///         let Self { loads } = self;
+///         loads.set(loads.get() + 1);
+///         Config(String::from("expensive"))
+///     }
+/// }
+///
+/// let provider: Memoized<_, Config> = Memoized::new(Provider::default(), ());
+///
+/// let Config(first) = provider.provide_ref_with(Memoize);
+/// let Config(second) = provider.provide_ref_with(Memoize);
+/// assert_eq!((first.as_str(), second.as_str()), ("expensive", "expensive"));
+///
+/// let (provider, _) = provider.into_inner();
+/// assert_eq!(provider.loads.get(), 1);
+/// ```
+#[derive(Debug)]
+pub struct Memoized<P, T, C = Empty> {
+    provider: P,
+    context: C,
+    cache: OnceCell<T>,
+    pending: Cell<bool>,
+}
+
+impl<P, T, C> Memoized<P, T, C> {
+    /// Creates new provider from the inner provider and the context
+    /// to provide dependency from the inner provider with.
+    pub const fn new(provider: P, context: C) -> Self {
+        Self {
+            provider,
+            context,
+            cache: OnceCell::new(),
+            pending: Cell::new(false),
+        }
+    }
+
+    /// Returns shared reference to the cached dependency, if any.
+    pub fn cached(&self) -> Option<&T> {
+        let Self { cache, .. } = self;
+        cache.get()
+    }
+
+    /// Resets the cache of this provider, returning previously cached dependency, if any,
+    /// so it will be provided from the inner provider again on the next provision.
+    pub fn reset(&mut self) -> Option<T> {
+        let Self { cache, .. } = self;
+        cache.take()
+    }
+
+    /// Returns the inner provider and the cached dependency, if any.
+    pub fn into_inner(self) -> (P, Option<T>) {
+        let Self {
+            provider, cache, ..
+        } = self;
+        (provider, cache.into_inner())
+    }
+}
+
+impl<P, T, C> Memoized<P, T, C>
+where
+    P: for<'me> ProvideRefWith<'me, T, C>,
+    C: Clone,
+{
+    /// Provides dependency from the inner provider if it was not cached yet,
+    /// returning shared reference to the cached dependency.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the inner provider provides the dependency out of this provider recursively.
+    pub fn force(&self) -> &T {
+        let Self {
+            provider,
+            context,
+            cache,
+            ..
+        } = self;
+        cache.get_or_init(|| provider.provide_ref_with(context.clone()))
+    }
+
+    /// Provides dependency from the inner provider if it was not cached yet,
+    /// returning unique reference to the cached dependency.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the inner provider provides the dependency out of this provider recursively.
+    pub fn force_mut(&mut self) -> &mut T {
+        self.force();
+        let Self { cache, .. } = self;
+        cache.get_mut().expect("dependency should be cached")
+    }
+}
+
+impl<P, T, C, E> Memoized<P, T, C>
+where
+    P: for<'me> TryProvideRefWith<'me, T, C, Error = E>,
+    C: Clone,
+{
+    /// Attempts to provide dependency from the inner provider if it was not cached yet,
+    /// returning shared reference to the cached dependency.
+    ///
+    /// Unlike [`force`](Memoized::force) method, this method does not panic
+    /// if the inner provider provides the dependency out of this provider recursively,
+    /// returning [reentrant](MemoizeError::Reentrant) error instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    ///
+    /// use provide::{context::cell::TakeCell, memoize::Memoized};
+    ///
+    /// let provider: Memoized<_, i32, _> = Memoized::new(Box::new(Cell::new(1)), TakeCell::default());
+    /// assert_eq!(provider.try_force(), Ok(&1));
+    /// assert_eq!(provider.try_force(), Ok(&1));
+    /// ```
+    pub fn try_force(&self) -> Result<&T, MemoizeError<E>> {
+        let Self {
+            provider,
+            context,
+            cache,
+            pending,
+        } = self;
+        if let Some(dependency) = cache.get() {
+            return Ok(dependency);
+        }
+        if pending.replace(true) {
+            return Err(MemoizeError::Reentrant);
+        }
+        let reset = Reset(pending);
+        let result = provider.try_provide_ref_with(context.clone());
+        drop(reset);
+        let dependency = result.map_err(MemoizeError::Provide)?;
+        Ok(cache.get_or_init(|| dependency))
+    }
+
+    /// Attempts to provide dependency from the inner provider if it was not cached yet,
+    /// returning unique reference to the cached dependency.
+    ///
+    /// Unlike [`force_mut`](Memoized::force_mut) method, this method does not panic
+    /// if the inner provider provides the dependency out of this provider recursively,
+    /// returning [reentrant](MemoizeError::Reentrant) error instead.
+    pub fn try_force_mut(&mut self) -> Result<&mut T, MemoizeError<E>> {
+        self.try_force()?;
+        let Self { cache, .. } = self;
+        let dependency = cache.get_mut().expect("dependency should be cached");
+        Ok(dependency)
+    }
+}
+
+impl<P, T, C> Clone for Memoized<P, T, C>
+where
+    P: Clone,
+    T: Clone,
+    C: Clone,
+{
+    fn clone(&self) -> Self {
+        let Self {
+            provider,
+            context,
+            cache,
+            ..
+        } = self;
+        // The clone is not providing the dependency, even if this provider is right now.
+        Self {
+            provider: provider.clone(),
+            context: context.clone(),
+            cache: cache.clone(),
+            pending: Cell::new(false),
+        }
+    }
+}
+
+impl<P, T, C> Default for Memoized<P, T, C>
+where
+    P: Default,
+    C: Default,
+{
+    fn default() -> Self {
+        Self::new(P::default(), C::default())
+    }
+}
+
+/// Resets the pending flag even if the provision or initialization panics.
+pub(crate) struct Reset<'a>(pub(crate) &'a Cell<bool>);
+
+impl Drop for Reset<'_> {
+    fn drop(&mut self) {
+        let Self(pending) = self;
+        pending.set(false);
+    }
+}
+
+/// The error type returned when dependency is provided
+/// out of the [memoized](Memoized) provider fallibly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum MemoizeError<E> {
+    /// Dependency was requested recursively while it was provided from the inner provider.
+    Reentrant,
+    /// Dependency was not provided from the inner provider.
+    Provide(E),
+}
+
+impl<E> Display for MemoizeError<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Reentrant => f.write_str("dependency was requested recursively while memoizing"),
+            Self::Provide(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl<E> Error for MemoizeError<E>
+where
+    E: Error,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Reentrant => None,
+            Self::Provide(error) => error.source(),
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Providers which combine two independently built providers into one.
+//!
+//! See [crate] documentation for more.
+
+use core::marker::PhantomData;
+
+/// Provider which combines two providers into one composite.
+///
+/// Dependencies are provided with the [`SelectWith`](crate::context::merge::SelectWith) context,
+/// which delegates provision to whichever side of the composite can satisfy the request.
+/// Side is selected by the [index](Left) which is inferred by the compiler,
+/// so dependency can be requested by its type only.
+/// If both sides can provide the same dependency, index cannot be inferred
+/// and should be specified explicitly.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{context::merge::Select, merge::Merge, with::ProvideRefWith};
+///
+/// let library = String::from("library");
+/// let application = vec![1, 2, 3];
+/// let provider = library.merge(application);
+///
+/// let name: &str = provider.provide_ref_with(Select::default());
+/// let items: &[i32] = provider.provide_ref_with(Select::default());
+/// assert_eq!((name, items), ("library", [1, 2, 3].as_slice()));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Merged<L, R> {
+    left: L,
+    right: R,
+}
+
+impl<L, R> Merged<L, R> {
+    /// Creates new provider from the left and the right providers.
+    pub const fn new(left: L, right: R) -> Self {
+        Self { left, right }
+    }
+
+    /// Returns shared reference to the left provider.
+    pub const fn left(&self) -> &L {
+        &self.left
+    }
+
+    /// Returns unique reference to the left provider.
+    pub fn left_mut(&mut self) -> &mut L {
+        &mut self.left
+    }
+
+    /// Returns shared reference to the right provider.
+    pub const fn right(&self) -> &R {
+        &self.right
+    }
+
+    /// Returns unique reference to the right provider.
+    pub fn right_mut(&mut self) -> &mut R {
+        &mut self.right
+    }
+
+    /// Returns the left and the right providers.
+    pub fn into_inner(self) -> (L, R) {
+        let Self { left, right } = self;
+        (left, right)
+    }
+}
+
+/// Type of provider which can be merged with another provider into [composite](Merged).
+///
+/// This trait is implemented for all the sized types.
+pub trait Merge: Sized {
+    /// Merges this provider with another one, placing this provider on the left side.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::merge::{Merge, Merged};
+    ///
+    /// let provider = 1.merge("hello");
+    /// assert_eq!(provider, Merged::new(1, "hello"));
+    /// ```
+    #[must_use]
+    fn merge<R>(self, other: R) -> Merged<Self, R> {
+        Merged::new(self, other)
+    }
+}
+
+impl<P> Merge for P {}
+
+/// Index which points into the left side of the [composite](Merged) provider.
+///
+/// Inner index `I` points further into the left provider if it is composite too,
+/// or is [`Here`](crate::pair::Here) if the left provider provides dependency itself.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Left<I>(PhantomData<I>);
+
+/// Index which points into the right side of the [composite](Merged) provider.
+///
+/// Inner index `I` points further into the right provider if it is composite too,
+/// or is [`Here`](crate::pair::Here) if the right provider provides dependency itself.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Right<I>(PhantomData<I>);
@@ -0,0 +1,69 @@
+//! Names of types used by `Debug` and `defmt::Format` implementations.
+
+use core::{
+    any::type_name,
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
+
+/// Name of the type which is formatted either in full,
+/// or as the short hash of the full name with `compact_names` feature enabled.
+///
+/// Formatting never allocates, so it is suitable for embedded logs.
+pub(crate) struct TypeName<T>(PhantomData<fn() -> *const T>)
+where
+    T: ?Sized;
+
+/// Returns the name of the type `T` to be formatted.
+pub(crate) const fn type_name_of<T>() -> TypeName<T>
+where
+    T: ?Sized,
+{
+    TypeName(PhantomData)
+}
+
+#[cfg(feature = "compact_names")]
+impl<T> TypeName<T>
+where
+    T: ?Sized,
+{
+    /// Returns 32-bit FNV-1a hash of the full name of the type.
+    fn hash(&self) -> u32 {
+        type_name::<T>().bytes().fold(0x811c_9dc5, |hash, byte| {
+            (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+        })
+    }
+}
+
+impl<T> Debug for TypeName<T>
+where
+    T: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "compact_names")]
+        {
+            write!(f, "#{:08x}", self.hash())
+        }
+        #[cfg(not(feature = "compact_names"))]
+        {
+            f.write_str(type_name::<T>())
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<T> defmt::Format for TypeName<T>
+where
+    T: ?Sized,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        #[cfg(feature = "compact_names")]
+        {
+            defmt::write!(f, "#{=u32:08x}", self.hash());
+        }
+        #[cfg(not(feature = "compact_names"))]
+        {
+            defmt::write!(f, "{=str}", type_name::<T>());
+        }
+    }
+}
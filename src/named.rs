@@ -0,0 +1,185 @@
+//! Dependencies distinguished by human-readable names.
+//!
+//! Newtypes are the usual way to register several dependencies of the same type
+//! in one provider, but declaring a newtype for each of them is verbose.
+//! [`Named`] is a single generic newtype distinguished by the const generic name,
+//! which can be written as the string with [`name`] function:
+//! `Named<u16, { name("primary") }>`.
+//!
+//! Ideally, names would be const generic parameters of type `&'static str`,
+//! but such parameters are not supported in stable Rust yet,
+//! so names are packed into [`u128`] integers instead.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    fmt::{self, Debug},
+    str,
+};
+
+use crate::context::alias::Alias;
+
+/// Packs the name of at most 16 bytes into the integer
+/// to be used as const generic parameter of [`Named`].
+///
+/// Name can be recovered back with [`Named::name`] method
+/// and is shown in [`Debug`] implementation of [`Named`].
+///
+/// # Panics
+///
+/// Panics if the name is longer than 16 bytes or contains null bytes.
+/// This function is intended to be called in const context,
+/// so such panics are reported at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use provide::named::{name, Named};
+///
+/// const PRIMARY: u128 = name("primary");
+///
+/// let port = Named::<u16, PRIMARY>::new(5432);
+/// assert_eq!(port.name(), "primary");
+/// ```
+pub const fn name(name: &str) -> u128 {
+    let bytes = name.as_bytes();
+    assert!(bytes.len() <= 16, "name must be at most 16 bytes long");
+
+    let mut packed = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        assert!(bytes[i] != 0, "name must not contain null bytes");
+        packed |= (bytes[i] as u128) << (i * 8);
+        i += 1;
+    }
+    packed
+}
+
+/// Returns the name packed with [`name`] function,
+/// or [`None`] if the integer was not created by it.
+fn unpack(packed: &[u8; 16]) -> Option<&str> {
+    let len = packed.iter().position(|&byte| byte == 0).unwrap_or(16);
+    let (name, rest) = packed.split_at(len);
+    if rest.iter().any(|&byte| byte != 0) {
+        return None;
+    }
+    str::from_utf8(name).ok()
+}
+
+/// Newtype which distinguishes the dependency of type `T` by the name `NAME`
+/// packed with [`name`] function.
+///
+/// This newtype is [aliased](Alias) to the inner dependency,
+/// so it can be selected by name with
+/// [`NamedDependencyWith`](crate::context::alias::NamedDependencyWith) context.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::alias::NamedDependency,
+///     named::{name, Named},
+///     with::ProvideRefWith,
+/// };
+///
+/// const PRIMARY: u128 = name("primary");
+/// const REPLICA: u128 = name("replica");
+///
+/// struct Config {
+///     primary: Named<u16, PRIMARY>,
+///     replica: Named<u16, REPLICA>,
+/// }
+///
+/// impl AsRef<Named<u16, PRIMARY>> for Config {
+///     fn as_ref(&self) -> &Named<u16, PRIMARY> {
+///         let Self { primary, .. } = self;
+///         primary
+///     }
+/// }
+///
+/// impl AsRef<Named<u16, REPLICA>> for Config {
+///     fn as_ref(&self) -> &Named<u16, REPLICA> {
+///         let Self { replica, .. } = self;
+///         replica
+///     }
+/// }
+///
+/// let config = Config {
+///     primary: Named::new(5432),
+///     replica: Named::new(5433),
+/// };
+///
+/// let port: &u16 = config.provide_ref_with(NamedDependency::<u16, REPLICA>::default());
+/// assert_eq!(*port, 5433);
+///
+/// let port: &u16 = config.provide_ref_with(NamedDependency::<u16, { name("primary") }>::default());
+/// assert_eq!(*port, 5432);
+///
+/// assert_eq!(
+///     format!("{:?}", config.primary),
+///     r#"Named { name: "primary", value: 5432 }"#,
+/// );
+/// ```
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Named<T, const NAME: u128>(pub T);
+
+impl<T, const NAME: u128> Named<T, NAME> {
+    /// Creates new named dependency from the inner dependency.
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns the inner dependency.
+    pub fn into_inner(self) -> T {
+        let Self(value) = self;
+        value
+    }
+
+    /// Returns the name of this dependency,
+    /// or an empty string if `NAME` was not created by [`name`] function.
+    pub fn name(&self) -> &'static str {
+        unpack(const { &NAME.to_le_bytes() }).unwrap_or_default()
+    }
+}
+
+impl<T, const NAME: u128> Alias for Named<T, NAME> {
+    type Target = T;
+
+    fn into_target(self) -> Self::Target {
+        self.into_inner()
+    }
+
+    fn target(&self) -> &Self::Target {
+        let Self(value) = self;
+        value
+    }
+
+    fn target_mut(&mut self) -> &mut Self::Target {
+        let Self(value) = self;
+        value
+    }
+}
+
+impl<T, const NAME: u128> Debug for Named<T, NAME>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(value) = self;
+        f.debug_struct("Named")
+            .field("name", &self.name())
+            .field("value", value)
+            .finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<T, const NAME: u128> defmt::Format for Named<T, NAME>
+where
+    T: defmt::Format,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        let Self(value) = self;
+        defmt::write!(f, "Named {{ name: {=str}, value: {} }}", self.name(), value,);
+    }
+}
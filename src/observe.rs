@@ -0,0 +1,116 @@
+//! Lightweight hooks which observe provision of dependencies.
+//!
+//! See [crate] documentation for more.
+
+use core::any::type_name;
+
+/// Type of hook which is notified about each provision of dependency.
+///
+/// Unlike [interceptor](crate::intercept::Interceptor), this trait is object safe,
+/// so observer can be stored as a function pointer or a trait object.
+/// This allows to instrument providers even on `no_std` targets
+/// without any tracing infrastructure.
+///
+/// Observer can be attached to the provider with the [`Observed`] wrapper.
+///
+/// See [crate] documentation for more.
+pub trait Observer {
+    /// Runs before provision of dependency with provided type name.
+    fn on_provide(&self, type_name: &str);
+}
+
+impl Observer for fn(&str) {
+    fn on_provide(&self, type_name: &str) {
+        self(type_name);
+    }
+}
+
+impl<O> Observer for &O
+where
+    O: Observer + ?Sized,
+{
+    fn on_provide(&self, type_name: &str) {
+        O::on_provide(self, type_name);
+    }
+}
+
+/// Provider which notifies [observer](Observer) about each provision
+/// of dependency by reference from the inner provider.
+///
+/// This wrapper implements [`AsRef`] and [`AsMut`] traits,
+/// so it provides dependencies by [shared](crate::ProvideRef)
+/// or [unique](crate::ProvideMut) reference with all the contexts based on them.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+///
+/// use provide::{
+///     observe::{Observed, Observer},
+///     ProvideExt,
+/// };
+///
+/// #[derive(Default)]
+/// struct Counter {
+///     count: Cell<u32>,
+/// }
+///
+/// impl Observer for Counter {
+///     fn on_provide(&self, _: &str) {
+///         self.count.set(self.count.get() + 1);
+///     }
+/// }
+///
+/// let counter = Counter::default();
+/// let observer: &dyn Observer = &counter;
+///
+/// let provider = Observed::new(String::from("hello"), observer);
+/// assert_eq!(provider.get_dep::<str>(), "hello");
+/// assert_eq!(provider.get_dep::<[u8]>(), b"hello");
+/// assert_eq!(counter.count.get(), 2);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Observed<P, O> {
+    provider: P,
+    observer: O,
+}
+
+impl<P, O> Observed<P, O> {
+    /// Creates new provider from the inner provider and the observer.
+    pub const fn new(provider: P, observer: O) -> Self {
+        Self { provider, observer }
+    }
+
+    /// Returns the inner provider and the observer.
+    pub fn into_inner(self) -> (P, O) {
+        let Self { provider, observer } = self;
+        (provider, observer)
+    }
+}
+
+impl<T, P, O> AsRef<T> for Observed<P, O>
+where
+    T: ?Sized,
+    P: AsRef<T>,
+    O: Observer,
+{
+    fn as_ref(&self) -> &T {
+        let Self { provider, observer } = self;
+        observer.on_provide(type_name::<T>());
+        provider.as_ref()
+    }
+}
+
+impl<T, P, O> AsMut<T> for Observed<P, O>
+where
+    T: ?Sized,
+    P: AsMut<T>,
+    O: Observer,
+{
+    fn as_mut(&mut self) -> &mut T {
+        let Self { provider, observer } = self;
+        observer.on_provide(type_name::<T>());
+        provider.as_mut()
+    }
+}
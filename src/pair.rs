@@ -0,0 +1,625 @@
+//! Cons-cell type used to represent remaining parts of providers.
+//!
+//! Unlike raw nested tuples, [`Pair`] always has the same shape:
+//! the *head* dependency and the *tail*, which is either another [`Pair`]
+//! or [empty tuple](()) at the end. This makes remainder types predictable
+//! and allows to manipulate them generically.
+//!
+//! Combinators of this crate deliberately keep their [remainder](crate::Provide::Remainder) types
+//! and do not return [`Pair`]. None of them composes remainders out of separate dependencies:
+//! they pass the remaining part of the inner provider through,
+//! at most wrapping it into the combinator itself, so there is no list to build.
+//! Switching them to [`Pair`] would change every public remainder type
+//! without making any of them more predictable.
+//!
+//! Instead, this type is meant for providers which build their own remainders,
+//! while [`Normalize`] trait converts remainders of other shapes,
+//! such as nested tuples, into it.
+//!
+//! See [crate] documentation for more.
+
+use core::{convert::Infallible, marker::PhantomData};
+
+use crate::with::{TryWith, With, WithBack, WithFront};
+
+/// Cons-cell of the *head* dependency and the *tail*
+/// which holds all the remaining dependencies.
+///
+/// The tail is either another [`Pair`] or [empty tuple](()) at the end.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{pair::Pair, with::With};
+///
+/// let pair = Pair::new(1, ()).with("hello").with(2.0);
+/// assert_eq!(pair, Pair::new(2.0, Pair::new("hello", Pair::new(1, ()))));
+/// assert_eq!(*pair.head(), 2.0);
+/// assert_eq!(*pair.tail().head(), "hello");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pair<H, T> {
+    head: H,
+    tail: T,
+}
+
+impl<H, T> Pair<H, T> {
+    /// Creates new pair from the head dependency and the tail.
+    pub const fn new(head: H, tail: T) -> Self {
+        Self { head, tail }
+    }
+
+    /// Returns shared reference to the head dependency.
+    pub const fn head(&self) -> &H {
+        let Self { head, .. } = self;
+        head
+    }
+
+    /// Returns unique reference to the head dependency.
+    pub fn head_mut(&mut self) -> &mut H {
+        let Self { head, .. } = self;
+        head
+    }
+
+    /// Returns shared reference to the tail.
+    pub const fn tail(&self) -> &T {
+        let Self { tail, .. } = self;
+        tail
+    }
+
+    /// Returns unique reference to the tail.
+    pub fn tail_mut(&mut self) -> &mut T {
+        let Self { tail, .. } = self;
+        tail
+    }
+
+    /// Returns the head dependency and the tail.
+    pub fn into_inner(self) -> (H, T) {
+        let Self { head, tail } = self;
+        (head, tail)
+    }
+}
+
+impl<H, T, D> With<D> for Pair<H, T> {
+    type Output = Pair<D, Self>;
+
+    fn with(self, dependency: D) -> Self::Output {
+        Pair::new(dependency, self)
+    }
+}
+
+impl<H, T, D> TryWith<D> for Pair<H, T> {
+    type Output = Pair<D, Self>;
+
+    type Error = Infallible;
+
+    fn try_with(self, dependency: D) -> Result<Self::Output, Self::Error> {
+        Ok(self.with(dependency))
+    }
+}
+
+impl<H, T, D> WithFront<D> for Pair<H, T> {
+    type Output = Pair<D, Self>;
+
+    fn with_front(self, dependency: D) -> Self::Output {
+        Pair::new(dependency, self)
+    }
+}
+
+impl<H, T, D> WithBack<D> for Pair<H, T>
+where
+    Self: Append<D>,
+{
+    type Output = <Self as Append<D>>::Output;
+
+    fn with_back(self, dependency: D) -> Self::Output {
+        self.append(dependency)
+    }
+}
+
+/// Type of [pair](Pair) list which can be extended with the dependency at the end.
+///
+/// This trait is implemented for [`Pair`] and [empty tuple](()) at the end of the list.
+pub trait Append<D>: Sized {
+    /// Type of the list with provided dependency at the end.
+    type Output;
+
+    /// Creates new list from the self and provided dependency placed at the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::pair::{Append, Pair};
+    ///
+    /// let pair = Pair::new(1, ()).append(2);
+    /// assert_eq!(pair, Pair::new(1, Pair::new(2, ())));
+    /// ```
+    #[must_use]
+    fn append(self, dependency: D) -> Self::Output;
+}
+
+impl<D> Append<D> for () {
+    type Output = Pair<D, ()>;
+
+    fn append(self, dependency: D) -> Self::Output {
+        Pair::new(dependency, self)
+    }
+}
+
+impl<H, T, D> Append<D> for Pair<H, T>
+where
+    T: Append<D>,
+{
+    type Output = Pair<H, T::Output>;
+
+    fn append(self, dependency: D) -> Self::Output {
+        let Self { head, tail } = self;
+        Pair::new(head, tail.append(dependency))
+    }
+}
+
+/// Type of [pair](Pair) list with length known at compile time.
+///
+/// This trait is implemented for [`Pair`] and [empty tuple](()) at the end of the list.
+pub trait Len {
+    /// Count of dependencies in the list.
+    const LEN: usize;
+
+    /// Returns count of dependencies in the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::pair::{Len, Pair};
+    ///
+    /// let pair = Pair::new(1, Pair::new("hello", ()));
+    /// assert_eq!(pair.len(), 2);
+    /// assert!(().is_empty());
+    /// ```
+    fn len(&self) -> usize {
+        Self::LEN
+    }
+
+    /// Returns `true` if the list contains no dependencies.
+    fn is_empty(&self) -> bool {
+        Self::LEN == 0
+    }
+}
+
+impl Len for () {
+    const LEN: usize = 0;
+}
+
+impl<H, T> Len for Pair<H, T>
+where
+    T: Len,
+{
+    const LEN: usize = 1 + T::LEN;
+}
+
+/// Index which points to the head of the [pair](Pair).
+///
+/// Used by the [`Find`] trait to search dependency by its type.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Here;
+
+/// Index which points somewhere into the tail of the [pair](Pair).
+///
+/// Used by the [`Find`] trait to search dependency by its type.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct There<I>(PhantomData<I>);
+
+/// Type of [pair](Pair) list which contains dependency of type `D` at index `I`.
+///
+/// Index is inferred by the compiler, so dependency can be searched by its type only.
+/// If there are many dependencies of the same type, index cannot be inferred
+/// and should be specified explicitly with [`Here`] and [`There`] types.
+///
+/// # Examples
+///
+/// ```
+/// use provide::pair::{Find, Here, Pair, There};
+///
+/// let pair = Pair::new(1, Pair::new("hello", Pair::new(2, ())));
+/// let dependency = <_ as Find<i32, There<There<Here>>>>::find(&pair);
+/// assert_eq!(*dependency, 2);
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "dependency of type `{D}` cannot be found in `{Self}`",
+    label = "dependency `{D}` cannot be found",
+    note = "dependency must be contained in the list exactly once to be found by its type",
+    note = "if there are many dependencies of type `{D}`, specify index explicitly with `Here` and `There` types"
+)]
+pub trait Find<D, I> {
+    /// Returns shared reference to the found dependency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::pair::{Find, Pair};
+    ///
+    /// let pair = Pair::new(1, Pair::new("hello", ()));
+    /// let dependency: &&str = pair.find();
+    /// assert_eq!(*dependency, "hello");
+    /// ```
+    fn find(&self) -> &D;
+
+    /// Returns unique reference to the found dependency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::pair::{Find, Pair};
+    ///
+    /// let mut pair = Pair::new(1, Pair::new("hello", ()));
+    /// let dependency: &mut i32 = pair.find_mut();
+    /// *dependency = 2;
+    /// assert_eq!(pair, Pair::new(2, Pair::new("hello", ())));
+    /// ```
+    fn find_mut(&mut self) -> &mut D;
+}
+
+impl<D, T> Find<D, Here> for Pair<D, T> {
+    fn find(&self) -> &D {
+        self.head()
+    }
+
+    fn find_mut(&mut self) -> &mut D {
+        self.head_mut()
+    }
+}
+
+impl<D, H, T, I> Find<D, There<I>> for Pair<H, T>
+where
+    T: Find<D, I>,
+{
+    fn find(&self) -> &D {
+        self.tail().find()
+    }
+
+    fn find_mut(&mut self) -> &mut D {
+        self.tail_mut().find_mut()
+    }
+}
+
+/// Marker of the provider which contains dependency of type `D` at index `I`.
+///
+/// This trait is implemented for [pair](Pair) lists and tuples of up to 12 elements
+/// (or 32 elements with `tuples_32` feature enabled), so it can be used in where-clauses
+/// to require some dependency to be contained in the provider at compile time.
+/// Index is inferred by the compiler in the same way as for the [`Find`] trait.
+///
+/// # Examples
+///
+/// ```
+/// use provide::pair::{Contains, Pair};
+///
+/// fn requires_name<P, I>(_: &P)
+/// where
+///     P: Contains<&'static str, I>,
+/// {
+/// }
+///
+/// requires_name(&(1, "hello", 2.0));
+/// requires_name(&Pair::new("hello", ()));
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not contain dependency of type `{D}`",
+    label = "dependency `{D}` is missing",
+    note = "if there are many dependencies of type `{D}`, specify index explicitly with `Here` and `There` types"
+)]
+pub trait Contains<D, I> {}
+
+impl<D, T> Contains<D, Here> for Pair<D, T> {}
+
+impl<D, H, T, I> Contains<D, There<I>> for Pair<H, T> where T: Contains<D, I> {}
+
+/// Type of [pair](Pair) list which contains dependency of type `D` at index `I`
+/// and can be split into this dependency and the list without it.
+///
+/// Index is inferred by the compiler in the same way as for the [`Find`] trait.
+/// [Output](Remove::Output) type of the list is guaranteed not to own the removed dependency
+/// (unless there were many dependencies of the same type),
+/// so APIs can require providers which no longer hold some dependency.
+#[diagnostic::on_unimplemented(
+    message = "dependency of type `{D}` cannot be removed from `{Self}`",
+    label = "dependency `{D}` cannot be found",
+    note = "if there are many dependencies of type `{D}`, specify index explicitly with `Here` and `There` types"
+)]
+pub trait Remove<D, I> {
+    /// Type of the list without the removed dependency.
+    type Output;
+
+    /// Removes the dependency from the list,
+    /// returning it together with the remaining list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::pair::{Pair, Remove};
+    ///
+    /// let pair = Pair::new(1, Pair::new("hello", Pair::new(2.0, ())));
+    /// let (dependency, rest): (&str, _) = pair.remove();
+    /// assert_eq!(dependency, "hello");
+    /// assert_eq!(rest, Pair::new(1, Pair::new(2.0, ())));
+    /// ```
+    #[must_use = "this call returns removed dependency and the remaining list"]
+    fn remove(self) -> (D, Self::Output);
+}
+
+impl<D, T> Remove<D, Here> for Pair<D, T> {
+    type Output = T;
+
+    fn remove(self) -> (D, Self::Output) {
+        self.into_inner()
+    }
+}
+
+impl<D, H, T, I> Remove<D, There<I>> for Pair<H, T>
+where
+    T: Remove<D, I>,
+{
+    type Output = Pair<H, T::Output>;
+
+    fn remove(self) -> (D, Self::Output) {
+        let Self { head, tail } = self;
+        let (dependency, tail) = tail.remove();
+        (dependency, Pair::new(head, tail))
+    }
+}
+
+/// Type of [pair](Pair) list which can be flattened into the tuple.
+///
+/// This trait is implemented for lists of up to 12 dependencies
+/// (or 32 dependencies with `tuples_32` feature enabled).
+/// Tuples can be converted back into the list with the [`From`] trait.
+pub trait Flatten {
+    /// Type of the flat tuple.
+    type Output;
+
+    /// Converts the list into the flat tuple of dependencies in the same order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::pair::{Flatten, Pair};
+    ///
+    /// let pair = Pair::new(1, Pair::new("hello", ()));
+    /// assert_eq!(pair.flatten(), (1, "hello"));
+    /// assert_eq!(Pair::from((1, "hello")), pair);
+    /// ```
+    #[must_use]
+    fn flatten(self) -> Self::Output;
+}
+
+impl Flatten for () {
+    type Output = ();
+
+    fn flatten(self) -> Self::Output {}
+}
+
+macro_rules! pair_type {
+    () => { () };
+    ($head:ident $(, $tail:ident)*) => { Pair<$head, pair_type!($($tail),*)> };
+}
+
+macro_rules! pair_value {
+    () => { () };
+    ($head:ident $(, $tail:ident)*) => { Pair { head: $head, tail: pair_value!($($tail),*) } };
+}
+
+macro_rules! impl_flatten {
+    () => {};
+    ($head:ident $(, $tail:ident)* $(,)?) => {
+        impl<$head, $($tail),*> Flatten for pair_type!($head $(, $tail)*) {
+            type Output = ($head, $($tail,)*);
+
+            #[allow(non_snake_case)]
+            fn flatten(self) -> Self::Output {
+                let pair_value!($head $(, $tail)*) = self;
+                ($head, $($tail,)*)
+            }
+        }
+
+        impl<$head, $($tail),*> From<($head, $($tail,)*)> for pair_type!($head $(, $tail)*) {
+            #[allow(non_snake_case)]
+            fn from(tuple: ($head, $($tail,)*)) -> Self {
+                let ($head, $($tail,)*) = tuple;
+                pair_value!($head $(, $tail)*)
+            }
+        }
+
+        impl_flatten!($($tail),*);
+    };
+}
+
+for_tuples!(impl_flatten);
+
+macro_rules! impl_contains {
+    () => {};
+    ($head:ident $(, $tail:ident)* $(,)?) => {
+        impl<D, I, $head, $($tail),*> Contains<D, I> for ($head, $($tail,)*)
+        where
+            pair_type!($head $(, $tail)*): Contains<D, I>,
+        {
+        }
+
+        impl_contains!($($tail),*);
+    };
+}
+
+for_tuples!(impl_contains);
+
+/// Type of [pair](Pair) list which can be concatenated with another list.
+///
+/// This trait is implemented for [`Pair`] and [empty tuple](()) at the end of the list.
+pub trait Concat<R>: Sized {
+    /// Type of the concatenated list.
+    type Output;
+
+    /// Creates new list with dependencies of the self followed by dependencies of provided list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::pair::{Concat, Pair};
+    ///
+    /// let pair = Pair::from((1, 2)).concat(Pair::from((3,)));
+    /// assert_eq!(pair, Pair::from((1, 2, 3)));
+    /// ```
+    #[must_use]
+    fn concat(self, rest: R) -> Self::Output;
+}
+
+impl<R> Concat<R> for () {
+    type Output = R;
+
+    fn concat(self, rest: R) -> Self::Output {
+        rest
+    }
+}
+
+impl<H, T, R> Concat<R> for Pair<H, T>
+where
+    T: Concat<R>,
+{
+    type Output = Pair<H, T::Output>;
+
+    fn concat(self, rest: R) -> Self::Output {
+        let Self { head, tail } = self;
+        Pair::new(head, tail.concat(rest))
+    }
+}
+
+/// Marker of the type which is never flattened by the [`Normalize`] trait.
+///
+/// This trait is implemented for primitive types, references and many common types.
+/// Implement it for your own dependency types to be able to [normalize](Normalize)
+/// remainders which contain them.
+pub trait Leaf {}
+
+macro_rules! impl_leaf {
+    ($($ty:ty),* $(,)?) => {$(
+        impl Leaf for $ty {}
+    )*};
+}
+
+impl_leaf!(bool, char, f32, f64, core::time::Duration);
+impl_leaf!(u8, u16, u32, u64, u128, usize);
+impl_leaf!(i8, i16, i32, i64, i128, isize);
+
+impl<T> Leaf for &T where T: ?Sized {}
+
+impl<T> Leaf for &mut T where T: ?Sized {}
+
+impl<T> Leaf for Option<T> {}
+
+impl<T, E> Leaf for Result<T, E> {}
+
+impl<T, const N: usize> Leaf for [T; N] {}
+
+impl<T> Leaf for PhantomData<T> where T: ?Sized {}
+
+impl<T> Leaf for core::cell::Cell<T> where T: ?Sized {}
+
+impl<T> Leaf for core::cell::RefCell<T> where T: ?Sized {}
+
+#[cfg(feature = "alloc")]
+impl_leaf!(alloc::string::String);
+
+#[cfg(feature = "alloc")]
+impl<T> Leaf for alloc::boxed::Box<T> where T: ?Sized {}
+
+#[cfg(feature = "alloc")]
+impl<T> Leaf for alloc::vec::Vec<T> {}
+
+#[cfg(feature = "alloc")]
+impl<T> Leaf for alloc::rc::Rc<T> where T: ?Sized {}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T> Leaf for alloc::sync::Arc<T> where T: ?Sized {}
+
+/// Type of remainder which can be converted into the canonical form.
+///
+/// Canonical form is the [pair](Pair) list of all the [leaf](Leaf) dependencies
+/// in the same order, regardless of how they were nested in tuples and pairs.
+/// This way, equivalent shapes of remainders (such as `((A,), B)`, `(A, (B,))` and `(A, B)`)
+/// are converted into the same type, so different composition orders unify in the type system.
+///
+/// Conversion only moves values around, so it has no runtime cost.
+/// This trait is implemented for [`Pair`], tuples of up to 12 elements
+/// (or 32 elements with `tuples_32` feature enabled) and [leaf](Leaf) types.
+pub trait Normalize {
+    /// Canonical form of the remainder.
+    type Output;
+
+    /// Converts the remainder into the canonical form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::pair::{Normalize, Pair};
+    ///
+    /// let expected = Pair::new(1, Pair::new(2.0, ()));
+    /// assert_eq!(((1,), 2.0).normalize(), expected);
+    /// assert_eq!((1, (2.0,)).normalize(), expected);
+    /// assert_eq!((1, 2.0).normalize(), expected);
+    /// ```
+    #[must_use]
+    fn normalize(self) -> Self::Output;
+}
+
+impl<T> Normalize for T
+where
+    T: Leaf,
+{
+    type Output = Pair<T, ()>;
+
+    fn normalize(self) -> Self::Output {
+        Pair::new(self, ())
+    }
+}
+
+impl Normalize for () {
+    type Output = ();
+
+    fn normalize(self) -> Self::Output {}
+}
+
+impl<H, T> Normalize for Pair<H, T>
+where
+    H: Normalize,
+    T: Normalize,
+    H::Output: Concat<T::Output>,
+{
+    type Output = <H::Output as Concat<T::Output>>::Output;
+
+    fn normalize(self) -> Self::Output {
+        let Self { head, tail } = self;
+        head.normalize().concat(tail.normalize())
+    }
+}
+
+macro_rules! impl_normalize {
+    () => {};
+    ($head:ident $(, $tail:ident)* $(,)?) => {
+        impl<$head, $($tail),*> Normalize for ($head, $($tail,)*)
+        where
+            $head: Normalize,
+            ($($tail,)*): Normalize,
+            $head::Output: Concat<<($($tail,)*) as Normalize>::Output>,
+        {
+            type Output = <$head::Output as Concat<<($($tail,)*) as Normalize>::Output>>::Output;
+
+            #[allow(non_snake_case)]
+            fn normalize(self) -> Self::Output {
+                let ($head, $($tail,)*) = self;
+                $head.normalize().concat(($($tail,)*).normalize())
+            }
+        }
+
+        impl_normalize!($($tail),*);
+    };
+}
+
+for_tuples!(impl_normalize);
@@ -0,0 +1,165 @@
+//! Handles which defer provision of dependencies until they are requested.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
+
+use crate::{
+    context::Empty,
+    name::type_name_of,
+    with::{ProvideRefWith, ProvideWith, TryProvideRefWith, TryProvideWith},
+};
+
+/// Handle which captures the provider and the context
+/// to provide dependency of type `T` with, deferring the provision itself
+/// until the dependency is requested.
+///
+/// This allows to describe the wiring eagerly at the composition root,
+/// while dependencies are constructed only at their first use.
+/// Dependency can be provided either by value, consuming the handle,
+/// or by reference from the captured provider any number of times.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+///
+/// use provide::{pending::Pending, ProvideRef};
+///
+/// struct Connection(u32);
+///
+/// #[derive(Default)]
+/// struct Provider {
+///     connections: Cell<u32>,
+/// }
+///
+/// impl<'me> ProvideRef<'me, Connection> for Provider {
+///     fn provide_ref(&'me self) -> Connection {
+///         let Self { connections } = self;
+///         connections.set(connections.get() + 1);
+///         Connection(connections.get())
+///     }
+/// }
+///
+/// let pending: Pending<Connection, _> = Pending::new(Provider::default(), ());
+///
+/// let Connection(id) = pending.get_ref();
+/// assert_eq!(id, 1);
+///
+/// let (provider, _) = pending.into_inner();
+/// assert_eq!(provider.connections.get(), 1);
+/// ```
+pub struct Pending<T, P, C = Empty> {
+    provider: P,
+    context: C,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T, P, C> Pending<T, P, C> {
+    /// Creates new handle from the provider and the context to provide dependency with.
+    pub const fn new(provider: P, context: C) -> Self {
+        Self {
+            provider,
+            context,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the captured provider and context without providing the dependency.
+    pub fn into_inner(self) -> (P, C) {
+        let Self {
+            provider, context, ..
+        } = self;
+        (provider, context)
+    }
+
+    /// Provides the dependency by value, consuming this handle,
+    /// also returning [remaining part](ProvideWith::Remainder) of the provider.
+    pub fn get(self) -> (T, P::Remainder)
+    where
+        P: ProvideWith<T, C>,
+    {
+        let (provider, context) = self.into_inner();
+        provider.provide_with(context)
+    }
+
+    /// Tries to provide the dependency by value, consuming this handle,
+    /// also returning [remaining part](TryProvideWith::Remainder) of the provider.
+    pub fn try_get(self) -> Result<(T, P::Remainder), P::Error>
+    where
+        P: TryProvideWith<T, C>,
+    {
+        let (provider, context) = self.into_inner();
+        provider.try_provide_with(context)
+    }
+
+    /// Provides the dependency by reference from the captured provider
+    /// with the clone of the captured context.
+    ///
+    /// Dependency is provided anew on each call.
+    pub fn get_ref<'me>(&'me self) -> T
+    where
+        P: ProvideRefWith<'me, T, C>,
+        C: Clone,
+    {
+        let Self {
+            provider, context, ..
+        } = self;
+        provider.provide_ref_with(context.clone())
+    }
+
+    /// Tries to provide the dependency by reference from the captured provider
+    /// with the clone of the captured context.
+    ///
+    /// Dependency is provided anew on each call.
+    pub fn try_get_ref<'me>(&'me self) -> Result<T, P::Error>
+    where
+        P: TryProvideRefWith<'me, T, C>,
+        C: Clone,
+    {
+        let Self {
+            provider, context, ..
+        } = self;
+        provider.try_provide_ref_with(context.clone())
+    }
+}
+
+impl<T, P, C> Debug for Pending<T, P, C>
+where
+    P: Debug,
+    C: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            provider, context, ..
+        } = self;
+        f.debug_struct("Pending")
+            .field("dependency", &type_name_of::<T>())
+            .field("provider", provider)
+            .field("context", context)
+            .finish()
+    }
+}
+
+impl<T, P, C> Clone for Pending<T, P, C>
+where
+    P: Clone,
+    C: Clone,
+{
+    fn clone(&self) -> Self {
+        let Self {
+            provider, context, ..
+        } = self;
+        Self::new(provider.clone(), context.clone())
+    }
+}
+
+impl<T, P, C> Copy for Pending<T, P, C>
+where
+    P: Copy,
+    C: Copy,
+{
+}
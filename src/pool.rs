@@ -0,0 +1,134 @@
+//! Providers which acquire dependencies from the pool of resources.
+//!
+//! This module is available with `pool` feature enabled.
+//!
+//! See [crate] documentation for more.
+
+#[cfg(feature = "async")]
+use core::future::Future;
+
+/// Type of pool which hands out guards of pooled resources.
+///
+/// This trait matches the API of pools such as [`r2d2`](https://docs.rs/r2d2),
+/// where the resource is returned into the pool when its guard is dropped.
+pub trait Pool {
+    /// Guard of the pooled resource.
+    type Guard;
+
+    /// The type returned in the event of an error.
+    type Error;
+
+    /// Acquires the guard of the pooled resource.
+    fn get(&self) -> Result<Self::Guard, Self::Error>;
+}
+
+impl<P> Pool for &P
+where
+    P: Pool + ?Sized,
+{
+    type Guard = P::Guard;
+
+    type Error = P::Error;
+
+    fn get(&self) -> Result<Self::Guard, Self::Error> {
+        P::get(self)
+    }
+}
+
+/// Type of pool which hands out guards of pooled resources asynchronously.
+///
+/// This trait matches the API of pools such as [`deadpool`](https://docs.rs/deadpool)
+/// or [`bb8`](https://docs.rs/bb8),
+/// where the resource is returned into the pool when its guard is dropped.
+#[cfg(feature = "async")]
+pub trait AsyncPool {
+    /// Guard of the pooled resource.
+    type Guard;
+
+    /// The type returned in the event of an error.
+    type Error;
+
+    /// Acquires the guard of the pooled resource asynchronously.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    fn get(&self) -> impl Future<Output = Result<Self::Guard, Self::Error>>;
+}
+
+#[cfg(feature = "async")]
+impl<P> AsyncPool for &P
+where
+    P: AsyncPool + ?Sized,
+{
+    type Guard = P::Guard;
+
+    type Error = P::Error;
+
+    fn get(&self) -> impl Future<Output = Result<Self::Guard, Self::Error>> {
+        P::get(self)
+    }
+}
+
+/// Provider which acquires dependencies from the [pool](Pool).
+///
+/// Dependencies are provided with the [`AcquireWith`](crate::context::pool::AcquireWith) context,
+/// which acquires the guard of the pooled resource and then provides dependency out of it.
+/// This allows to inject pooled connections as ordinary dependencies.
+///
+/// # Examples
+///
+/// ```
+/// use std::{cell::Cell, convert::Infallible};
+///
+/// use provide::{
+///     context::pool::Acquire,
+///     pool::{Pool, PoolProvider},
+///     with::TryProvideRefWith,
+/// };
+///
+/// struct Connection {
+///     id: u32,
+/// }
+///
+/// #[derive(Default)]
+/// struct ConnectionPool {
+///     next: Cell<u32>,
+/// }
+///
+/// impl Pool for ConnectionPool {
+///     type Guard = Connection;
+///     type Error = Infallible;
+///
+///     fn get(&self) -> Result<Self::Guard, Self::Error> {
+///         let id = self.next.get();
+///         self.next.set(id + 1);
+///         Ok(Connection { id })
+///     }
+/// }
+///
+/// let provider = PoolProvider::new(ConnectionPool::default());
+///
+/// let first: Result<Connection, _> = provider.try_provide_ref_with(Acquire::default());
+/// let second: Result<Connection, _> = provider.try_provide_ref_with(Acquire::default());
+/// assert_eq!(first.map(|connection| connection.id), Ok(0));
+/// assert_eq!(second.map(|connection| connection.id), Ok(1));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PoolProvider<P>(P);
+
+impl<P> PoolProvider<P> {
+    /// Creates new provider from the pool.
+    pub const fn new(pool: P) -> Self {
+        Self(pool)
+    }
+
+    /// Returns shared reference to the pool.
+    pub const fn pool(&self) -> &P {
+        let Self(pool) = self;
+        pool
+    }
+
+    /// Returns the pool.
+    pub fn into_inner(self) -> P {
+        let Self(pool) = self;
+        pool
+    }
+}
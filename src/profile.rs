@@ -0,0 +1,158 @@
+//! Providers which are active only for some deployment profile.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+/// Deployment profile of the application.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Profile {
+    /// Profile used during development.
+    #[default]
+    Dev,
+    /// Profile used while running tests.
+    Test,
+    /// Profile used in production.
+    Prod,
+}
+
+impl Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Dev => "dev",
+            Self::Test => "test",
+            Self::Prod => "prod",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Provider which is active only for the carried [profile](Profile).
+///
+/// Dependencies are provided with the [`ProfileWith`](crate::context::profile::ProfileWith)
+/// context which carries the active profile. If it does not match the profile of the provider,
+/// provision fails with [inactive](GatedError::Inactive) error.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     context::profile::Profiled,
+///     profile::{GatedError, Profile, ProfileGated},
+///     with::TryProvideRefWith,
+/// };
+///
+/// let provider = ProfileGated::new(Box::new("postgres://prod"), Profile::Prod);
+///
+/// let url: Result<&&str, _> = provider.try_provide_ref_with(Profiled::new(Profile::Prod, ()));
+/// assert_eq!(url, Ok(&"postgres://prod"));
+///
+/// let url: Result<&&str, _> = provider.try_provide_ref_with(Profiled::new(Profile::Dev, ()));
+/// assert_eq!(
+///     url,
+///     Err(GatedError::Inactive {
+///         required: Profile::Prod,
+///         active: Profile::Dev,
+///     }),
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ProfileGated<P> {
+    provider: P,
+    profile: Profile,
+}
+
+impl<P> ProfileGated<P> {
+    /// Creates new provider which is active only for provided profile.
+    pub const fn new(provider: P, profile: Profile) -> Self {
+        Self { provider, profile }
+    }
+
+    /// Returns the profile this provider is active for.
+    pub const fn profile(&self) -> Profile {
+        self.profile
+    }
+
+    /// Returns the inner provider and the profile it is active for.
+    pub fn into_inner(self) -> (P, Profile) {
+        let Self { provider, profile } = self;
+        (provider, profile)
+    }
+
+    pub(crate) fn check(&self, active: Profile) -> Result<(), InactiveProfile> {
+        let Self { profile, .. } = *self;
+        if profile == active {
+            return Ok(());
+        }
+        Err(InactiveProfile {
+            required: profile,
+            active,
+        })
+    }
+
+    pub(crate) fn provider(&self) -> &P {
+        &self.provider
+    }
+
+    pub(crate) fn provider_mut(&mut self) -> &mut P {
+        &mut self.provider
+    }
+}
+
+pub(crate) struct InactiveProfile {
+    required: Profile,
+    active: Profile,
+}
+
+impl<E> From<InactiveProfile> for GatedError<E> {
+    fn from(error: InactiveProfile) -> Self {
+        let InactiveProfile { required, active } = error;
+        Self::Inactive { required, active }
+    }
+}
+
+/// The error type returned when [profile-gated](ProfileGated) provider fails to provide dependency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum GatedError<E> {
+    /// Profile of the provider is not active.
+    Inactive {
+        /// Profile the provider is active for.
+        required: Profile,
+        /// Profile which is currently active.
+        active: Profile,
+    },
+    /// Provider is active, but it failed to provide dependency.
+    Provide(E),
+}
+
+impl<E> Display for GatedError<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Inactive { required, active } => write!(
+                f,
+                "provider is active only for `{required}` profile, but `{active}` is active",
+            ),
+            Self::Provide(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl<E> Error for GatedError<E>
+where
+    E: Error,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Inactive { .. } => None,
+            Self::Provide(error) => error.source(),
+        }
+    }
+}
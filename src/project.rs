@@ -0,0 +1,81 @@
+//! Providers which expose parts of other providers through projection functions.
+//!
+//! See [crate] documentation for more.
+
+/// Provider which exposes some part of the inner provider
+/// by shared reference through the projection function.
+///
+/// This wrapper implements [`AsRef`] trait, so it provides projected dependency
+/// by [shared reference](crate::ProvideRef) with all the contexts based on it,
+/// without the need to implement any provider trait for the inner provider.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{project::Project, ProvideRef};
+///
+/// struct Config {
+///     database: Database,
+/// }
+///
+/// struct Database {
+///     url: String,
+/// }
+///
+/// let config = Config {
+///     database: Database {
+///         url: String::from("localhost"),
+///     },
+/// };
+/// let provider = Project::new(config, |config: &Config| config.database.url.as_str());
+///
+/// let url: &str = provider.provide_ref();
+/// assert_eq!(url, "localhost");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Project<P, F> {
+    provider: P,
+    projection: F,
+}
+
+impl<P, F> Project<P, F> {
+    /// Creates new provider from the inner provider and the projection function.
+    pub const fn new<T>(provider: P, projection: F) -> Self
+    where
+        T: ?Sized,
+        F: Fn(&P) -> &T,
+    {
+        Self {
+            provider,
+            projection,
+        }
+    }
+
+    /// Returns shared reference to the inner provider.
+    pub const fn provider(&self) -> &P {
+        &self.provider
+    }
+
+    /// Returns the inner provider and the projection function.
+    pub fn into_inner(self) -> (P, F) {
+        let Self {
+            provider,
+            projection,
+        } = self;
+        (provider, projection)
+    }
+}
+
+impl<T, P, F> AsRef<T> for Project<P, F>
+where
+    T: ?Sized,
+    F: Fn(&P) -> &T,
+{
+    fn as_ref(&self) -> &T {
+        let Self {
+            provider,
+            projection,
+        } = self;
+        projection(provider)
+    }
+}
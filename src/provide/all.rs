@@ -0,0 +1,322 @@
+use core::{
+    convert::Infallible,
+    error::Error,
+    fmt::{self, Display},
+};
+
+use crate::{Provide, TryProvide, TryProvideRef};
+
+/// Type of provider which provides many dependencies by value at once.
+///
+/// This trait is implemented for tuples of dependencies, each of which is provided
+/// by the [`Provide`] trait in order, threading [remaining part](Provide::Remainder)
+/// of the provider into the provision of the next dependency.
+///
+/// See [crate] documentation for more.
+pub trait ProvideAll<T>: Sized {
+    /// Remaining part of the provider after providing all the dependencies by value.
+    type Remainder;
+
+    /// Provides all the dependencies by value, also returning
+    /// [remaining part](ProvideAll::Remainder) of the provider.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::{Provide, ProvideAll};
+    ///
+    /// struct Provider {
+    ///     foo: i32,
+    ///     bar: f32,
+    /// }
+    ///
+    /// struct Remainder {
+    ///     bar: f32,
+    /// }
+    ///
+    /// impl Provide<i32> for Provider {
+    ///     type Remainder = Remainder;
+    ///
+    ///     fn provide(self) -> (i32, Self::Remainder) {
+    ///         let Self { foo, bar } = self;
+    ///         (foo, Remainder { bar })
+    ///     }
+    /// }
+    ///
+    /// impl Provide<f32> for Remainder {
+    ///     type Remainder = ();
+    ///
+    ///     fn provide(self) -> (f32, Self::Remainder) {
+    ///         let Self { bar } = self;
+    ///         (bar, ())
+    ///     }
+    /// }
+    ///
+    /// let provider = Provider { foo: 1, bar: 2.0 };
+    /// let ((foo, bar), _): ((i32, f32), _) = provider.provide_all();
+    /// assert_eq!(foo, 1);
+    /// assert_eq!(bar, 2.0);
+    /// ```
+    #[must_use = "this call returns dependencies and remaining part of the provider"]
+    fn provide_all(self) -> (T, Self::Remainder);
+}
+
+/// Type of provider which can provide many dependencies by value at once or fail.
+///
+/// This trait is implemented for tuples of dependencies, each of which is provided
+/// by the [`TryProvide`] trait in order, threading [remaining part](TryProvide::Remainder)
+/// of the provider into the provision of the next dependency.
+///
+/// Provision fails fast: nothing is provided after the first error,
+/// and the [error](ProvideAllError) holds all the dependencies provided before it.
+///
+/// See [crate] documentation for more.
+pub trait TryProvideAll<T>: Sized {
+    /// Remaining part of the provider after providing all the dependencies by value.
+    type Remainder;
+
+    /// The type returned in the event of an error.
+    type Error;
+
+    /// Tries to provide all the dependencies by value, also returning
+    /// [remaining part](TryProvideAll::Remainder) of the provider on success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::{Provide, ProvideAllError, TryProvide, TryProvideAll};
+    ///
+    /// struct Provider {
+    ///     foo: i32,
+    ///     bar: Option<f32>,
+    /// }
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Remainder {
+    ///     bar: Option<f32>,
+    /// }
+    ///
+    /// impl Provide<i32> for Provider {
+    ///     type Remainder = Remainder;
+    ///
+    ///     fn provide(self) -> (i32, Self::Remainder) {
+    ///         let Self { foo, bar } = self;
+    ///         (foo, Remainder { bar })
+    ///     }
+    /// }
+    ///
+    /// impl TryProvide<f32> for Remainder {
+    ///     type Remainder = ();
+    ///
+    ///     type Error = Self;
+    ///
+    ///     fn try_provide(self) -> Result<(f32, Self::Remainder), Self::Error> {
+    ///         match self {
+    ///             Self { bar: Some(bar) } => Ok((bar, ())),
+    ///             remainder => Err(remainder),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let provider = Provider { foo: 1, bar: Some(2.0) };
+    /// let result: Result<((i32, f32), _), _> = provider.try_provide_all();
+    /// assert!(matches!(result, Ok(((1, bar), ())) if bar == 2.0));
+    ///
+    /// // Error contains `foo` which was provided before the failure
+    /// // and remaining part of the provider returned by the failed provision.
+    /// let provider = Provider { foo: 1, bar: None };
+    /// let result: Result<((i32, f32), _), _> = provider.try_provide_all();
+    /// let Err(ProvideAllError::Tail(foo, ProvideAllError::Head(remainder))) = result else {
+    ///     unreachable!()
+    /// };
+    /// assert_eq!(foo, 1);
+    /// assert_eq!(remainder, Remainder { bar: None });
+    /// ```
+    fn try_provide_all(self) -> Result<(T, Self::Remainder), Self::Error>;
+}
+
+/// Type of provider which can provide many dependencies by reference at once,
+/// attempting to provide each of them regardless of failures of others.
+///
+/// This trait is implemented for tuples of dependencies, each of which is provided
+/// by the [`TryProvideRef`] trait. Unlike [`TryProvideAll`], which stops on the first error,
+/// provision of every dependency is attempted, so all the errors can be reported at once.
+/// This is possible because providing by reference does not consume the provider.
+///
+/// See [crate] documentation for more.
+pub trait TryProvideRefEach<'me, T> {
+    /// Tuple of results of provision of each dependency, in the same order.
+    type Output;
+
+    /// Tries to provide each dependency by reference,
+    /// returning results of provision of all of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::{TryProvideRef, TryProvideRefEach};
+    ///
+    /// struct Provider {
+    ///     foo: Option<i32>,
+    ///     bar: Option<f32>,
+    /// }
+    ///
+    /// impl<'me> TryProvideRef<'me, &'me i32> for Provider {
+    ///     type Error = &'static str;
+    ///
+    ///     fn try_provide_ref(&'me self) -> Result<&'me i32, Self::Error> {
+    ///         let Self { foo, .. } = self;
+    ///         foo.as_ref().ok_or("foo is missing")
+    ///     }
+    /// }
+    ///
+    /// impl<'me> TryProvideRef<'me, &'me f32> for Provider {
+    ///     type Error = &'static str;
+    ///
+    ///     fn try_provide_ref(&'me self) -> Result<&'me f32, Self::Error> {
+    ///         let Self { bar, .. } = self;
+    ///         bar.as_ref().ok_or("bar is missing")
+    ///     }
+    /// }
+    ///
+    /// let provider = Provider { foo: None, bar: None };
+    /// let (foo, bar) = TryProvideRefEach::<(&i32, &f32)>::try_provide_ref_each(&provider);
+    /// assert_eq!(foo, Err("foo is missing"));
+    /// assert_eq!(bar, Err("bar is missing"));
+    /// ```
+    #[must_use = "this call returns results of provision of the dependencies"]
+    fn try_provide_ref_each(&'me self) -> Self::Output;
+}
+
+/// The error type returned when [fallible provision](TryProvideAll) of many dependencies fails.
+///
+/// The error is nested in the same way as dependencies are provided:
+/// either provision of the first (*head*) dependency `D` fails with an error `E`,
+/// or it succeeds but provision of the remaining (*tail*) dependencies fails with an error `R`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ProvideAllError<D, E, R> {
+    /// Provision of the first dependency has failed.
+    Head(E),
+    /// The first dependency was provided,
+    /// but provision of some of the remaining dependencies has failed.
+    Tail(D, R),
+}
+
+impl<D, E, R> Display for ProvideAllError<D, E, R>
+where
+    E: Display,
+    R: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Head(error) => Display::fmt(error, f),
+            Self::Tail(_, error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl<D, E, R> Error for ProvideAllError<D, E, R>
+where
+    D: fmt::Debug,
+    E: Error,
+    R: Error,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Head(error) => error.source(),
+            Self::Tail(_, error) => error.source(),
+        }
+    }
+}
+
+impl<P> ProvideAll<()> for P {
+    type Remainder = P;
+
+    fn provide_all(self) -> ((), Self::Remainder) {
+        ((), self)
+    }
+}
+
+impl<P> TryProvideAll<()> for P {
+    type Remainder = P;
+
+    type Error = Infallible;
+
+    fn try_provide_all(self) -> Result<((), Self::Remainder), Self::Error> {
+        Ok(((), self))
+    }
+}
+
+macro_rules! impl_all {
+    () => {};
+    ($head:ident $(, $tail:ident)* $(,)?) => {
+        impl<P, $head, $($tail),*> ProvideAll<($head, $($tail,)*)> for P
+        where
+            P: Provide<$head>,
+            P::Remainder: ProvideAll<($($tail,)*)>,
+        {
+            type Remainder = <P::Remainder as ProvideAll<($($tail,)*)>>::Remainder;
+
+            #[allow(non_snake_case)]
+            fn provide_all(self) -> (($head, $($tail,)*), Self::Remainder) {
+                let ($head, remainder) = self.provide();
+                let (($($tail,)*), remainder) = remainder.provide_all();
+                (($head, $($tail,)*), remainder)
+            }
+        }
+
+        impl<P, $head, $($tail),*> TryProvideAll<($head, $($tail,)*)> for P
+        where
+            P: TryProvide<$head>,
+            P::Remainder: TryProvideAll<($($tail,)*)>,
+        {
+            type Remainder = <P::Remainder as TryProvideAll<($($tail,)*)>>::Remainder;
+
+            type Error = ProvideAllError<
+                $head,
+                P::Error,
+                <P::Remainder as TryProvideAll<($($tail,)*)>>::Error,
+            >;
+
+            #[allow(non_snake_case)]
+            fn try_provide_all(self) -> Result<(($head, $($tail,)*), Self::Remainder), Self::Error> {
+                let ($head, remainder) = self.try_provide().map_err(ProvideAllError::Head)?;
+                match remainder.try_provide_all() {
+                    Ok((($($tail,)*), remainder)) => Ok((($head, $($tail,)*), remainder)),
+                    Err(error) => Err(ProvideAllError::Tail($head, error)),
+                }
+            }
+        }
+
+        impl_all!($($tail),*);
+    };
+}
+
+for_tuples!(impl_all);
+
+macro_rules! impl_each {
+    () => {};
+    ($head:ident $(, $tail:ident)* $(,)?) => {
+        impl<'me, P, $head, $($tail),*> TryProvideRefEach<'me, ($head, $($tail,)*)> for P
+        where
+            P: TryProvideRef<'me, $head> $(+ TryProvideRef<'me, $tail>)* + ?Sized,
+        {
+            type Output = (
+                Result<$head, <P as TryProvideRef<'me, $head>>::Error>,
+                $(Result<$tail, <P as TryProvideRef<'me, $tail>>::Error>,)*
+            );
+
+            fn try_provide_ref_each(&'me self) -> Self::Output {
+                (
+                    TryProvideRef::<$head>::try_provide_ref(self),
+                    $(TryProvideRef::<$tail>::try_provide_ref(self),)*
+                )
+            }
+        }
+
+        impl_each!($($tail),*);
+    };
+}
+
+for_tuples!(impl_each);
@@ -0,0 +1,9 @@
+pub use self::{
+    owned::{ProvideAsync, TryProvideAsync},
+    r#mut::{ProvideMutAsync, TryProvideMutAsync},
+    r#ref::{ProvideRefAsync, TryProvideRefAsync},
+};
+
+mod r#mut;
+mod owned;
+mod r#ref;
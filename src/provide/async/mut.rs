@@ -0,0 +1,125 @@
+use core::{
+    convert::Infallible,
+    future::{ready, Future},
+};
+
+use crate::ProvideMut;
+
+/// Type of provider which provides dependency by mutable reference asynchronously.
+///
+/// This trait is very similar to [`ProvideMut`] trait.
+/// However, dependency is provided by the [future](Future) which should be awaited,
+/// so it is possible to perform some I/O or wait for some event during provision.
+///
+/// See [crate] documentation for more.
+pub trait ProvideMutAsync<'me, T> {
+    /// Provides dependency by mutable reference asynchronously.
+    ///
+    /// # Examples
+    ///
+    /// You can implement this trait for your provider
+    /// to provide dependency by mutable reference asynchronously:
+    ///
+    /// ```
+    /// use core::future::Future;
+    ///
+    /// use futures::executor::block_on;
+    /// use provide::ProvideMutAsync;
+    ///
+    /// struct Provider {
+    ///     foo: i32,
+    /// }
+    ///
+    /// impl<'me> ProvideMutAsync<'me, &'me mut i32> for Provider {
+    ///     fn provide_mut_async(&'me mut self) -> impl Future<Output = &'me mut i32> {
+    ///         let Self { foo } = self;
+    ///         async move { foo }
+    ///     }
+    /// }
+    ///
+    /// let mut provider = Provider { foo: 1 };
+    /// let dependency: &mut i32 = block_on(provider.provide_mut_async());
+    /// assert_eq!(dependency, &mut 1);
+    /// ```
+    ///
+    /// Also, this trait is implemented for all types which implement [`ProvideMut`],
+    /// so such providers are ready immediately:
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use provide::ProvideMutAsync;
+    ///
+    /// let mut provider = vec![1, 2, 3];
+    /// let dependency: &mut [i32] = block_on(provider.provide_mut_async());
+    /// assert_eq!(dependency, [1, 2, 3]);
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    fn provide_mut_async(&'me mut self) -> impl Future<Output = T>;
+}
+
+impl<'me, T, U> ProvideMutAsync<'me, T> for U
+where
+    U: ProvideMut<'me, T> + ?Sized,
+{
+    fn provide_mut_async(&'me mut self) -> impl Future<Output = T> {
+        ready(self.provide_mut())
+    }
+}
+
+/// Type of provider which can provide dependency by mutable reference asynchronously or fail.
+///
+/// This trait is very similar to [`TryProvideMut`](crate::TryProvideMut) trait.
+/// However, dependency is provided by the [future](Future) which should be awaited,
+/// so it is possible to perform some I/O or wait for some event during provision.
+///
+/// See [crate] documentation for more.
+pub trait TryProvideMutAsync<'me, T> {
+    /// The type returned in the event of an error.
+    type Error;
+
+    /// Tries to provide dependency by mutable reference asynchronously.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::future::Future;
+    ///
+    /// use futures::executor::block_on;
+    /// use provide::TryProvideMutAsync;
+    ///
+    /// struct Provider {
+    ///     foo: Option<i32>,
+    /// }
+    ///
+    /// impl<'me> TryProvideMutAsync<'me, &'me mut i32> for Provider {
+    ///     type Error = ();
+    ///
+    ///     fn try_provide_mut_async(&'me mut self) -> impl Future<Output = Result<&'me mut i32, ()>> {
+    ///         let Self { foo } = self;
+    ///         async move { foo.as_mut().ok_or(()) }
+    ///     }
+    /// }
+    ///
+    /// let mut provider = Provider { foo: Some(1) };
+    /// let dependency: Result<&mut i32, _> = block_on(provider.try_provide_mut_async());
+    /// assert_eq!(dependency, Ok(&mut 1));
+    ///
+    /// let mut provider = Provider { foo: None };
+    /// let dependency: Result<&mut i32, _> = block_on(provider.try_provide_mut_async());
+    /// assert_eq!(dependency, Err(()));
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    fn try_provide_mut_async(&'me mut self) -> impl Future<Output = Result<T, Self::Error>>;
+}
+
+impl<'me, T, U> TryProvideMutAsync<'me, T> for U
+where
+    U: ProvideMutAsync<'me, T> + ?Sized,
+{
+    type Error = Infallible;
+
+    async fn try_provide_mut_async(&'me mut self) -> Result<T, Self::Error> {
+        let provide_mut_async = self.provide_mut_async().await;
+        Ok(provide_mut_async)
+    }
+}
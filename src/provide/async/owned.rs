@@ -0,0 +1,142 @@
+use core::{
+    convert::Infallible,
+    future::{ready, Future},
+};
+
+use crate::Provide;
+
+/// Type of provider which provides dependency by value asynchronously.
+///
+/// This trait is very similar to [`Provide`] trait.
+/// However, dependency is provided by the [future](Future) which should be awaited,
+/// so it is possible to perform some I/O or wait for some event during provision.
+///
+/// See [crate] documentation for more.
+pub trait ProvideAsync<T>: Sized {
+    /// Remaining part of the provider after providing dependency by value.
+    type Remainder;
+
+    /// Provides dependency by value asynchronously, also returning
+    /// [remaining part](ProvideAsync::Remainder) of the provider.
+    ///
+    /// # Examples
+    ///
+    /// You can implement this trait for your provider
+    /// to provide dependency by value asynchronously:
+    ///
+    /// ```
+    /// use core::future::Future;
+    ///
+    /// use futures::executor::block_on;
+    /// use provide::ProvideAsync;
+    ///
+    /// struct Provider {
+    ///     foo: i32,
+    ///     bar: f32,
+    /// }
+    ///
+    /// impl ProvideAsync<i32> for Provider {
+    ///     type Remainder = f32;
+    ///
+    ///     fn provide_async(self) -> impl Future<Output = (i32, Self::Remainder)> {
+    ///         let Self { foo, bar } = self;
+    ///         async move { (foo, bar) }
+    ///     }
+    /// }
+    ///
+    /// let provider = Provider { foo: 1, bar: 2.0 };
+    /// let (dependency, _) = block_on(ProvideAsync::<i32>::provide_async(provider));
+    /// assert_eq!(dependency, 1);
+    /// ```
+    ///
+    /// Also, this trait is implemented for all types which implement [`Provide`],
+    /// so such providers are ready immediately:
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use provide::ProvideAsync;
+    ///
+    /// let provider: i32 = 1;
+    /// let (dependency, _): (i64, _) = block_on(provider.provide_async());
+    /// assert_eq!(dependency, 1);
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    fn provide_async(self) -> impl Future<Output = (T, Self::Remainder)>;
+}
+
+impl<T, U> ProvideAsync<T> for U
+where
+    U: Provide<T>,
+{
+    type Remainder = U::Remainder;
+
+    fn provide_async(self) -> impl Future<Output = (T, Self::Remainder)> {
+        ready(self.provide())
+    }
+}
+
+/// Type of provider which can provide dependency by value asynchronously or fail.
+///
+/// This trait is very similar to [`TryProvide`](crate::TryProvide) trait.
+/// However, dependency is provided by the [future](Future) which should be awaited,
+/// so it is possible to perform some I/O or wait for some event during provision.
+///
+/// See [crate] documentation for more.
+pub trait TryProvideAsync<T>: Sized {
+    /// Remaining part of the provider after providing dependency by value.
+    type Remainder;
+
+    /// The type returned in the event of an error.
+    type Error;
+
+    /// Tries to provide dependency by value asynchronously, also returning
+    /// [remaining part](TryProvideAsync::Remainder) of the provider on success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::future::Future;
+    ///
+    /// use futures::executor::block_on;
+    /// use provide::TryProvideAsync;
+    ///
+    /// struct Provider {
+    ///     foo: Option<i32>,
+    /// }
+    ///
+    /// impl TryProvideAsync<i32> for Provider {
+    ///     type Remainder = ();
+    ///
+    ///     type Error = ();
+    ///
+    ///     fn try_provide_async(self) -> impl Future<Output = Result<(i32, ()), ()>> {
+    ///         let Self { foo } = self;
+    ///         async move { foo.map(|foo| (foo, ())).ok_or(()) }
+    ///     }
+    /// }
+    ///
+    /// let provider = Provider { foo: Some(1) };
+    /// let result = block_on(TryProvideAsync::<i32>::try_provide_async(provider));
+    /// assert_eq!(result, Ok((1, ())));
+    ///
+    /// let provider = Provider { foo: None };
+    /// let result = block_on(TryProvideAsync::<i32>::try_provide_async(provider));
+    /// assert_eq!(result, Err(()));
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    fn try_provide_async(self) -> impl Future<Output = Result<(T, Self::Remainder), Self::Error>>;
+}
+
+impl<T, U> TryProvideAsync<T> for U
+where
+    U: ProvideAsync<T>,
+{
+    type Remainder = U::Remainder;
+
+    type Error = Infallible;
+
+    async fn try_provide_async(self) -> Result<(T, Self::Remainder), Self::Error> {
+        let provide_async = self.provide_async().await;
+        Ok(provide_async)
+    }
+}
@@ -0,0 +1,125 @@
+use core::{
+    convert::Infallible,
+    future::{ready, Future},
+};
+
+use crate::ProvideRef;
+
+/// Type of provider which provides dependency by reference asynchronously.
+///
+/// This trait is very similar to [`ProvideRef`] trait.
+/// However, dependency is provided by the [future](Future) which should be awaited,
+/// so it is possible to perform some I/O or wait for some event during provision.
+///
+/// See [crate] documentation for more.
+pub trait ProvideRefAsync<'me, T> {
+    /// Provides dependency by reference asynchronously.
+    ///
+    /// # Examples
+    ///
+    /// You can implement this trait for your provider
+    /// to provide dependency by reference asynchronously:
+    ///
+    /// ```
+    /// use core::future::Future;
+    ///
+    /// use futures::executor::block_on;
+    /// use provide::ProvideRefAsync;
+    ///
+    /// struct Provider {
+    ///     foo: i32,
+    /// }
+    ///
+    /// impl<'me> ProvideRefAsync<'me, &'me i32> for Provider {
+    ///     fn provide_ref_async(&'me self) -> impl Future<Output = &'me i32> {
+    ///         let Self { foo } = self;
+    ///         async move { foo }
+    ///     }
+    /// }
+    ///
+    /// let provider = Provider { foo: 1 };
+    /// let dependency: &i32 = block_on(provider.provide_ref_async());
+    /// assert_eq!(dependency, &1);
+    /// ```
+    ///
+    /// Also, this trait is implemented for all types which implement [`ProvideRef`],
+    /// so such providers are ready immediately:
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use provide::ProvideRefAsync;
+    ///
+    /// let provider = vec![1, 2, 3];
+    /// let dependency: &[i32] = block_on(provider.provide_ref_async());
+    /// assert_eq!(dependency, [1, 2, 3]);
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    fn provide_ref_async(&'me self) -> impl Future<Output = T>;
+}
+
+impl<'me, T, U> ProvideRefAsync<'me, T> for U
+where
+    U: ProvideRef<'me, T> + ?Sized,
+{
+    fn provide_ref_async(&'me self) -> impl Future<Output = T> {
+        ready(self.provide_ref())
+    }
+}
+
+/// Type of provider which can provide dependency by reference asynchronously or fail.
+///
+/// This trait is very similar to [`TryProvideRef`](crate::TryProvideRef) trait.
+/// However, dependency is provided by the [future](Future) which should be awaited,
+/// so it is possible to perform some I/O or wait for some event during provision.
+///
+/// See [crate] documentation for more.
+pub trait TryProvideRefAsync<'me, T> {
+    /// The type returned in the event of an error.
+    type Error;
+
+    /// Tries to provide dependency by reference asynchronously.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::future::Future;
+    ///
+    /// use futures::executor::block_on;
+    /// use provide::TryProvideRefAsync;
+    ///
+    /// struct Provider {
+    ///     foo: Option<i32>,
+    /// }
+    ///
+    /// impl<'me> TryProvideRefAsync<'me, &'me i32> for Provider {
+    ///     type Error = ();
+    ///
+    ///     fn try_provide_ref_async(&'me self) -> impl Future<Output = Result<&'me i32, ()>> {
+    ///         let Self { foo } = self;
+    ///         async move { foo.as_ref().ok_or(()) }
+    ///     }
+    /// }
+    ///
+    /// let provider = Provider { foo: Some(1) };
+    /// let dependency: Result<&i32, _> = block_on(provider.try_provide_ref_async());
+    /// assert_eq!(dependency, Ok(&1));
+    ///
+    /// let provider = Provider { foo: None };
+    /// let dependency: Result<&i32, _> = block_on(provider.try_provide_ref_async());
+    /// assert_eq!(dependency, Err(()));
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    fn try_provide_ref_async(&'me self) -> impl Future<Output = Result<T, Self::Error>>;
+}
+
+impl<'me, T, U> TryProvideRefAsync<'me, T> for U
+where
+    U: ProvideRefAsync<'me, T> + ?Sized,
+{
+    type Error = Infallible;
+
+    async fn try_provide_ref_async(&'me self) -> Result<T, Self::Error> {
+        let provide_ref_async = self.provide_ref_async().await;
+        Ok(provide_ref_async)
+    }
+}
@@ -0,0 +1,202 @@
+use core::array;
+
+use crate::{Provide, ProvideMut, ProvideRef, ProvidedFn};
+
+/// Extension trait which adds convenient methods to all the providers.
+///
+/// Methods of this trait accept type of the dependency as the only generic parameter,
+/// so it is possible to specify it with the turbofish syntax
+/// instead of annotating the type of the result.
+/// Their names are distinct from inherent methods of common types, such as [`slice::get`],
+/// so bringing this trait into scope never shadows them.
+///
+/// See [crate] documentation for more.
+pub trait ProvideExt {
+    /// Provides dependency of type `T` by shared reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::ProvideExt;
+    ///
+    /// let provider = String::from("hello");
+    /// let dependency = provider.get_dep::<str>();
+    /// assert_eq!(dependency, "hello");
+    /// ```
+    fn get_dep<'me, T>(&'me self) -> &'me T
+    where
+        Self: ProvideRef<'me, &'me T>,
+        T: ?Sized + 'me,
+    {
+        self.provide_ref()
+    }
+
+    /// Provides dependency of type `T` by unique reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::ProvideExt;
+    ///
+    /// let mut provider = vec![1, 2, 3];
+    /// provider.get_dep_mut::<[i32]>()[0] = 0;
+    /// assert_eq!(provider, [0, 2, 3]);
+    /// ```
+    fn get_dep_mut<'me, T>(&'me mut self) -> &'me mut T
+    where
+        Self: ProvideMut<'me, &'me mut T>,
+        T: ?Sized + 'me,
+    {
+        self.provide_mut()
+    }
+
+    /// Provides dependency of type `T` by value
+    /// by cloning the dependency provided by shared reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::ProvideExt;
+    ///
+    /// struct Provider {
+    ///     foo: String,
+    /// }
+    ///
+    /// impl AsRef<String> for Provider {
+    ///     fn as_ref(&self) -> &String {
+    ///         let Self { foo } = self;
+    ///         foo
+    ///     }
+    /// }
+    ///
+    /// let provider = Provider { foo: String::from("hello") };
+    /// let dependency = provider.provide_cloned::<String>();
+    /// assert_eq!(dependency, provider.foo);
+    /// ```
+    fn provide_cloned<'me, T>(&'me self) -> T
+    where
+        Self: ProvideRef<'me, &'me T>,
+        T: Clone + 'me,
+    {
+        self.provide_ref().clone()
+    }
+
+    /// Converts provided function into a function without arguments
+    /// which provides all of the arguments by reference from this provider on each call.
+    ///
+    /// This allows to register callbacks in external libraries
+    /// capturing only the provider instead of each of the dependencies.
+    /// Arguments are provided at call time, so each call observes
+    /// the current state of the provider.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    ///
+    /// use provide::{ProvideExt, ProvideRef};
+    ///
+    /// struct Provider {
+    ///     name: String,
+    ///     visits: Cell<u32>,
+    /// }
+    ///
+    /// impl AsRef<String> for Provider {
+    ///     fn as_ref(&self) -> &String {
+    ///         let Self { name, .. } = self;
+    ///         name
+    ///     }
+    /// }
+    ///
+    /// impl ProvideRef<'_, u32> for Provider {
+    ///     fn provide_ref(&self) -> u32 {
+    ///         let Self { visits, .. } = self;
+    ///         visits.set(visits.get() + 1);
+    ///         visits.get()
+    ///     }
+    /// }
+    ///
+    /// let provider = Provider {
+    ///     name: String::from("world"),
+    ///     visits: Cell::new(0),
+    /// };
+    /// let greet = provider.provide_map(|name: &String, visit: u32| format!("hello, {name} #{visit}"));
+    ///
+    /// assert_eq!(greet(), "hello, world #1");
+    /// assert_eq!(greet(), "hello, world #2");
+    /// ```
+    fn provide_map<'me, F, Args>(&'me self, function: F) -> impl Fn() -> F::Output + 'me
+    where
+        F: ProvidedFn<'me, Self, Args> + 'me,
+    {
+        move || function.call_provided(self)
+    }
+
+    /// Provides dependency of type `T` by value,
+    /// also returning [remaining part](Provide::Remainder) of the provider.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::ProvideExt;
+    ///
+    /// let provider = 'a';
+    /// let (dependency, _) = provider.take_dep::<u32>();
+    /// assert_eq!(dependency, 97);
+    /// ```
+    fn take_dep<T>(self) -> (T, <Self as Provide<T>>::Remainder)
+    where
+        Self: Provide<T>,
+    {
+        self.provide()
+    }
+
+    /// Provides array of `N` dependencies of type `T` by value,
+    /// providing each next dependency from the [remaining part](Provide::Remainder)
+    /// of the previous provision, and returns the last remaining part of the provider.
+    ///
+    /// This is useful to wire arrays of workers or shards
+    /// where each element needs its own provided instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::{Provide, ProvideExt};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Shard(u32);
+    ///
+    /// struct Provider {
+    ///     next: u32,
+    /// }
+    ///
+    /// impl Provide<Shard> for Provider {
+    ///     type Remainder = Self;
+    ///
+    ///     fn provide(self) -> (Shard, Self::Remainder) {
+    ///         let Self { next } = self;
+    ///         (Shard(next), Self { next: next + 1 })
+    ///     }
+    /// }
+    ///
+    /// let (shards, provider) = Provider { next: 0 }.take_array::<Shard, 3>();
+    /// assert_eq!(shards, [Shard(0), Shard(1), Shard(2)]);
+    /// assert_eq!(provider.next, 3);
+    /// ```
+    fn take_array<T, const N: usize>(self) -> ([T; N], Self)
+    where
+        Self: Provide<T, Remainder = Self> + Sized,
+    {
+        let mut provider = Some(self);
+        let dependencies = array::from_fn(|_| {
+            let current = provider.take().expect("provider should be returned back");
+            let (dependency, remainder) = current.provide();
+            provider = Some(remainder);
+            dependency
+        });
+        let provider = provider.expect("provider should be returned back");
+        (dependencies, provider)
+    }
+}
+
+impl<P> ProvideExt for P where P: ?Sized {}
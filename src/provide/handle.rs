@@ -0,0 +1,65 @@
+/// Type of provider which provides cheap handles to its dependencies.
+///
+/// Handles are small [`Copy`] values which identify dependencies
+/// rather than being full dependencies themselves,
+/// such as indices, keys or `&'static` references.
+/// Unlike [`ProvideRef`](crate::ProvideRef), provision of handle never borrows the provider,
+/// so handles can be resolved once and then used freely in performance-critical inner loops.
+///
+/// This trait is not implemented for any provider automatically,
+/// so each handle is provided only the way its provider explicitly chooses.
+///
+/// See [crate] documentation for more.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot provide handle of type `{H}`",
+    label = "handle `{H}` cannot be provided",
+    note = "consider implementing `ProvideHandle<{H}>` trait for `{Self}`"
+)]
+pub trait ProvideHandle<H>
+where
+    H: Copy,
+{
+    /// Provides handle to the dependency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::ProvideHandle;
+    ///
+    /// #[derive(Clone, Copy)]
+    /// struct UserId(usize);
+    ///
+    /// struct Provider {
+    ///     users: Vec<String>,
+    ///     current: usize,
+    ///     greeting: &'static str,
+    /// }
+    ///
+    /// impl ProvideHandle<UserId> for Provider {
+    ///     fn provide_handle(&self) -> UserId {
+    ///         let Self { current, .. } = self;
+    ///         UserId(*current)
+    ///     }
+    /// }
+    ///
+    /// impl ProvideHandle<&'static str> for Provider {
+    ///     fn provide_handle(&self) -> &'static str {
+    ///         let Self { greeting, .. } = self;
+    ///         greeting
+    ///     }
+    /// }
+    ///
+    /// let provider = Provider {
+    ///     users: vec![String::from("alice"), String::from("bob")],
+    ///     current: 1,
+    ///     greeting: "hello",
+    /// };
+    ///
+    /// let UserId(user) = provider.provide_handle();
+    /// let greeting: &str = provider.provide_handle();
+    /// for _ in 0..3 {
+    ///     assert_eq!(format!("{greeting}, {}", provider.users[user]), "hello, bob");
+    /// }
+    /// ```
+    fn provide_handle(&self) -> H;
+}
@@ -0,0 +1,91 @@
+use crate::ProvideRef;
+
+/// Type of function which arguments can be provided by reference from provider `P`.
+///
+/// This trait is implemented for all the functions (including closures)
+/// with up to 12 arguments (or up to 32 with `tuples_32` feature),
+/// each of which is provided by the [`ProvideRef`] trait.
+/// Arguments are represented as a tuple `Args` to allow implementations for many arities.
+///
+/// Arguments can be references to the dependencies held by the provider,
+/// or owned values if the provider constructs or clones them on each provision.
+///
+/// See [`ProvideExt::provide_map`](crate::ProvideExt::provide_map) for more.
+pub trait ProvidedFn<'me, P, Args>
+where
+    P: ?Sized,
+{
+    /// Type of the value returned by the function.
+    type Output;
+
+    /// Calls this function, providing all of its arguments by reference from the provider.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::ProvidedFn;
+    ///
+    /// struct Provider {
+    ///     foo: i32,
+    ///     bar: String,
+    /// }
+    ///
+    /// impl AsRef<i32> for Provider {
+    ///     fn as_ref(&self) -> &i32 {
+    ///         let Self { foo, .. } = self;
+    ///         foo
+    ///     }
+    /// }
+    ///
+    /// impl AsRef<String> for Provider {
+    ///     fn as_ref(&self) -> &String {
+    ///         let Self { bar, .. } = self;
+    ///         bar
+    ///     }
+    /// }
+    ///
+    /// let provider = Provider {
+    ///     foo: 1,
+    ///     bar: String::from("bar"),
+    /// };
+    /// let function = |foo: &i32, bar: &String| format!("{bar}{foo}");
+    /// assert_eq!(function.call_provided(&provider), "bar1");
+    /// ```
+    fn call_provided(&self, provider: &'me P) -> Self::Output;
+}
+
+impl<'me, P, F, R> ProvidedFn<'me, P, ()> for F
+where
+    P: ?Sized,
+    F: Fn() -> R,
+{
+    type Output = R;
+
+    fn call_provided(&self, _: &'me P) -> Self::Output {
+        self()
+    }
+}
+
+macro_rules! impl_provided_fn {
+    () => {};
+    ($head:ident $(, $tail:ident)* $(,)?) => {
+        impl<'me, P, F, R, $head, $($tail),*> ProvidedFn<'me, P, ($head, $($tail,)*)> for F
+        where
+            P: ProvideRef<'me, $head> $(+ ProvideRef<'me, $tail>)* + ?Sized,
+            F: Fn($head, $($tail),*) -> R,
+        {
+            type Output = R;
+
+            fn call_provided(&self, provider: &'me P) -> Self::Output {
+                self(
+                    ProvideRef::<$head>::provide_ref(provider),
+                    $(ProvideRef::<$tail>::provide_ref(provider),)*
+                )
+            }
+        }
+
+        impl_provided_fn!($($tail),*);
+    };
+}
+
+for_tuples!(impl_provided_fn);
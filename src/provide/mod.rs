@@ -1,9 +1,24 @@
+#[cfg(feature = "async")]
+pub use self::r#async::{
+    ProvideAsync, ProvideMutAsync, ProvideRefAsync, TryProvideAsync, TryProvideMutAsync,
+    TryProvideRefAsync,
+};
 pub use self::{
+    all::{ProvideAll, ProvideAllError, TryProvideAll, TryProvideRefEach},
+    ext::ProvideExt,
+    handle::ProvideHandle,
+    map::ProvidedFn,
     owned::{Provide, TryProvide},
     r#mut::{ProvideMut, TryProvideMut},
     r#ref::{ProvideRef, TryProvideRef},
 };
 
+mod all;
+#[cfg(feature = "async")]
+mod r#async;
+mod ext;
+mod handle;
+mod map;
 mod r#mut;
 mod owned;
 mod r#ref;
@@ -5,7 +5,15 @@ use core::convert::Infallible;
 /// This trait can be interpreted as an extension of [`AsMut`] trait
 /// but with the ability to return not only plain mutable references.
 ///
+/// Referenced dependency may be unsized, such as [`str`], slice or trait object:
+/// this trait is implemented for `&mut T` where `T: ?Sized` through [`AsMut`] trait.
+///
 /// See [crate] documentation for more.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot provide dependency of type `{T}` by mutable reference",
+    label = "dependency `{T}` cannot be provided by mutable reference",
+    note = "consider implementing `ProvideMut<'_, {T}>` or `AsMut` trait for `{Self}`"
+)]
 pub trait ProvideMut<'me, T> {
     /// Provides dependency by mutable reference.
     ///
@@ -130,10 +138,43 @@ pub trait TryProvideMut<'me, T> {
     ///
     /// # Examples
     ///
+    /// You can implement this trait for your provider
+    /// to provide dependency by mutable reference or fail:
+    ///
+    /// ```
+    /// use provide::TryProvideMut;
+    ///
+    /// struct Provider {
+    ///     foo: Option<i32>,
+    /// }
+    ///
+    /// impl<'me> TryProvideMut<'me, &'me mut i32> for Provider {
+    ///     type Error = ();
+    ///
+    ///     fn try_provide_mut(&'me mut self) -> Result<&'me mut i32, Self::Error> {
+    ///         let Self { foo } = self;
+    ///         foo.as_mut().ok_or(())
+    ///     }
+    /// }
+    ///
+    /// let mut provider = Provider { foo: Some(1) };
+    /// let dependency: Result<&mut i32, _> = provider.try_provide_mut();
+    /// assert_eq!(dependency, Ok(&mut 1));
+    ///
+    /// let mut provider = Provider { foo: None };
+    /// let dependency: Result<&mut i32, _> = provider.try_provide_mut();
+    /// assert_eq!(dependency, Err(()));
     /// ```
-    /// use provide::ProvideMut;
     ///
-    /// todo!()
+    /// Also, this trait is implemented for all types which implement [`ProvideMut`],
+    /// so such providers never fail:
+    ///
+    /// ```
+    /// use provide::TryProvideMut;
+    ///
+    /// let mut provider = vec![1, 2, 3];
+    /// let dependency: Result<&mut [i32], _> = provider.try_provide_mut();
+    /// assert_eq!(dependency, Ok([1, 2, 3].as_mut_slice()));
     /// ```
     fn try_provide_mut(&'me mut self) -> Result<T, Self::Error>;
 }
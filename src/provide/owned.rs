@@ -7,6 +7,11 @@ use core::convert::Infallible;
 /// or in chain to retrieve more dependencies.
 ///
 /// See [crate] documentation for more.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot provide dependency of type `{T}` by value",
+    label = "dependency `{T}` cannot be provided by value",
+    note = "consider implementing `Provide<{T}>` or `Into<{T}>` trait for `{Self}`"
+)]
 pub trait Provide<T>: Sized {
     /// Remaining part of the provider after providing dependency by value.
     type Remainder;
@@ -52,6 +57,31 @@ pub trait Provide<T>: Sized {
     /// assert_eq!(dependency, 1);
     /// ```
     ///
+    /// So if your provider is consumed entirely, you don't need to implement this trait
+    /// and name its remainder: implement [`From`] trait for the dependency instead,
+    /// and the provider will provide it with [empty](()) remainder:
+    ///
+    /// ```
+    /// use provide::Provide;
+    ///
+    /// struct Provider {
+    ///     foo: i32,
+    /// }
+    ///
+    /// struct Dependency(i32);
+    ///
+    /// impl From<Provider> for Dependency {
+    ///     fn from(provider: Provider) -> Self {
+    ///         let Provider { foo } = provider;
+    ///         Self(foo)
+    ///     }
+    /// }
+    ///
+    /// let provider = Provider { foo: 1 };
+    /// let (Dependency(dependency), ()) = provider.provide();
+    /// assert_eq!(dependency, 1);
+    /// ```
+    ///
     /// However, due to this blanket implementation, you cannot do this:
     ///
     /// ```compile_fail
@@ -138,10 +168,48 @@ pub trait TryProvide<T>: Sized {
     ///
     /// # Examples
     ///
+    /// You can implement this trait for your provider
+    /// to provide dependency by value or fail:
+    ///
     /// ```
     /// use provide::TryProvide;
     ///
-    /// todo!()
+    /// struct Provider {
+    ///     foo: Option<i32>,
+    ///     bar: f32,
+    /// }
+    ///
+    /// impl TryProvide<i32> for Provider {
+    ///     type Remainder = f32;
+    ///
+    ///     type Error = Self;
+    ///
+    ///     fn try_provide(self) -> Result<(i32, Self::Remainder), Self::Error> {
+    ///         match self {
+    ///             Self { foo: Some(foo), bar } => Ok((foo, bar)),
+    ///             provider => Err(provider),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let provider = Provider { foo: Some(1), bar: 2.0 };
+    /// let result: Result<(i32, _), _> = provider.try_provide();
+    /// assert!(matches!(result, Ok((1, _))));
+    ///
+    /// let provider = Provider { foo: None, bar: 2.0 };
+    /// let result: Result<(i32, _), _> = provider.try_provide();
+    /// assert!(result.is_err());
+    /// ```
+    ///
+    /// Also, this trait is implemented for all types which implement [`Provide`],
+    /// so such providers never fail:
+    ///
+    /// ```
+    /// use provide::TryProvide;
+    ///
+    /// let provider: i32 = 1;
+    /// let result: Result<(i64, _), _> = provider.try_provide();
+    /// assert_eq!(result, Ok((1, ())));
     /// ```
     fn try_provide(self) -> Result<(T, Self::Remainder), Self::Error>;
 }
@@ -5,7 +5,15 @@ use core::convert::Infallible;
 /// This trait can be interpreted as an extension of [`AsRef`] trait
 /// but with the ability to return not only plain references.
 ///
+/// Referenced dependency may be unsized, such as [`str`], slice or trait object:
+/// this trait is implemented for `&T` where `T: ?Sized` through [`AsRef`] trait.
+///
 /// See [crate] documentation for more.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot provide dependency of type `{T}` by reference",
+    label = "dependency `{T}` cannot be provided by reference",
+    note = "consider implementing `ProvideRef<'_, {T}>` or `AsRef` trait for `{Self}`"
+)]
 pub trait ProvideRef<'me, T> {
     /// Provides dependency by reference.
     ///
@@ -130,10 +138,43 @@ pub trait TryProvideRef<'me, T> {
     ///
     /// # Examples
     ///
+    /// You can implement this trait for your provider
+    /// to provide dependency by reference or fail:
+    ///
+    /// ```
+    /// use provide::TryProvideRef;
+    ///
+    /// struct Provider {
+    ///     foo: Option<i32>,
+    /// }
+    ///
+    /// impl<'me> TryProvideRef<'me, &'me i32> for Provider {
+    ///     type Error = ();
+    ///
+    ///     fn try_provide_ref(&'me self) -> Result<&'me i32, Self::Error> {
+    ///         let Self { foo } = self;
+    ///         foo.as_ref().ok_or(())
+    ///     }
+    /// }
+    ///
+    /// let provider = Provider { foo: Some(1) };
+    /// let dependency: Result<&i32, _> = provider.try_provide_ref();
+    /// assert_eq!(dependency, Ok(&1));
+    ///
+    /// let provider = Provider { foo: None };
+    /// let dependency: Result<&i32, _> = provider.try_provide_ref();
+    /// assert_eq!(dependency, Err(()));
+    /// ```
+    ///
+    /// Also, this trait is implemented for all types which implement [`ProvideRef`],
+    /// so such providers never fail:
+    ///
     /// ```
     /// use provide::TryProvideRef;
     ///
-    /// todo!()
+    /// let provider = vec![1, 2, 3];
+    /// let dependency: Result<&[i32], _> = provider.try_provide_ref();
+    /// assert_eq!(dependency, Ok([1, 2, 3].as_slice()));
     /// ```
     fn try_provide_ref(&'me self) -> Result<T, Self::Error>;
 }
@@ -0,0 +1,161 @@
+//! Providers which record dependencies requested from them.
+//!
+//! This module is available with `alloc` feature enabled.
+//!
+//! See [crate] documentation for more.
+
+use alloc::vec::Vec;
+use core::{any::type_name, cell::RefCell};
+
+/// Way in which dependency was requested from the [spy provider](SpyProvider).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Flavor {
+    /// Dependency was requested by [value](crate::Provide).
+    Value,
+    /// Dependency was requested by [shared reference](crate::ProvideRef).
+    Ref,
+    /// Dependency was requested by [unique reference](crate::ProvideMut).
+    Mut,
+}
+
+/// Dependency requested from the [spy provider](SpyProvider).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Request {
+    type_name: &'static str,
+    flavor: Flavor,
+}
+
+impl Request {
+    /// Creates new request of dependency of type `T` in provided way.
+    pub fn new<T>(flavor: Flavor) -> Self
+    where
+        T: ?Sized,
+    {
+        Self {
+            type_name: type_name::<T>(),
+            flavor,
+        }
+    }
+
+    /// Returns name of the type of requested dependency.
+    pub const fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns the way in which dependency was requested.
+    pub const fn flavor(&self) -> Flavor {
+        self.flavor
+    }
+}
+
+/// Provider which records the ordered list of dependencies requested
+/// from the inner provider, so it can be queried after the test.
+///
+/// This wrapper implements [`AsRef`] and [`AsMut`] traits,
+/// so it provides dependencies by [shared](crate::ProvideRef)
+/// or [unique](crate::ProvideMut) reference with all the contexts based on them.
+/// Requests of any [flavor](Flavor), including requests by value
+/// and requests with contexts of the inner provider, are recorded
+/// with the [`SpyWith`](crate::context::spy::SpyWith) context.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{
+///     spy::{Flavor, Request, SpyProvider},
+///     ProvideExt,
+/// };
+///
+/// let mut provider = SpyProvider::new(String::from("hello"));
+/// assert_eq!(provider.get_dep::<str>(), "hello");
+/// provider.get_dep_mut::<str>().make_ascii_uppercase();
+///
+/// let (inner, requests) = provider.into_inner();
+/// assert_eq!(inner, "HELLO");
+/// assert_eq!(
+///     requests,
+///     [Request::new::<str>(Flavor::Ref), Request::new::<str>(Flavor::Mut)],
+/// );
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct SpyProvider<P> {
+    provider: P,
+    requests: RefCell<Vec<Request>>,
+}
+
+impl<P> SpyProvider<P> {
+    /// Creates new provider which records requests to the inner provider.
+    pub const fn new(provider: P) -> Self {
+        Self {
+            provider,
+            requests: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Returns all the requests recorded so far in order.
+    pub fn requests(&self) -> Vec<Request> {
+        let Self { requests, .. } = self;
+        requests.borrow().clone()
+    }
+
+    /// Forgets all the requests recorded so far.
+    pub fn clear(&mut self) {
+        let Self { requests, .. } = self;
+        requests.get_mut().clear();
+    }
+
+    /// Returns the inner provider and all the requests recorded in order.
+    pub fn into_inner(self) -> (P, Vec<Request>) {
+        let Self { provider, requests } = self;
+        (provider, requests.into_inner())
+    }
+
+    pub(crate) fn record<T>(&self, flavor: Flavor) -> &P
+    where
+        T: ?Sized,
+    {
+        let Self { provider, requests } = self;
+        requests.borrow_mut().push(Request::new::<T>(flavor));
+        provider
+    }
+
+    pub(crate) fn record_mut<T>(&mut self, flavor: Flavor) -> &mut P
+    where
+        T: ?Sized,
+    {
+        let Self { provider, requests } = self;
+        requests.get_mut().push(Request::new::<T>(flavor));
+        provider
+    }
+
+    pub(crate) fn with_requests(provider: P, requests: Vec<Request>) -> Self {
+        Self {
+            provider,
+            requests: RefCell::new(requests),
+        }
+    }
+}
+
+impl<T, P> AsRef<T> for SpyProvider<P>
+where
+    T: ?Sized,
+    P: AsRef<T>,
+{
+    fn as_ref(&self) -> &T {
+        let Self { provider, requests } = self;
+        requests.borrow_mut().push(Request::new::<T>(Flavor::Ref));
+        provider.as_ref()
+    }
+}
+
+impl<T, P> AsMut<T> for SpyProvider<P>
+where
+    T: ?Sized,
+    P: AsMut<T>,
+{
+    fn as_mut(&mut self) -> &mut T {
+        let Self { provider, requests } = self;
+        requests.get_mut().push(Request::new::<T>(Flavor::Mut));
+        provider.as_mut()
+    }
+}
@@ -0,0 +1,145 @@
+//! Ambient providers which are installed into the scope of the [`tokio`] task.
+//!
+//! Provider installed with [`with`] function can be resolved with [`current`]
+//! or [`try_with`] functions anywhere inside the future, without passing it
+//! through every function of the asynchronous call chain.
+//!
+//! Task-local values are not inherited by spawned tasks,
+//! so the provider should be installed into each of them explicitly:
+//! the [`current`] provider can be passed into the spawned task with [`with_arc`] function.
+//!
+//! This module is available with both `tokio` and `std` features enabled.
+//!
+//! Scopes can be nested: the innermost provider of the requested type is resolved,
+//! and providers of the outer scopes are still available if their types differ.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    any::Any,
+    error::Error,
+    fmt::{self, Display},
+    future::Future,
+};
+use std::sync::Arc;
+
+::tokio::task_local! {
+    static SCOPE: Arc<Scope>;
+}
+
+struct Scope {
+    provider: Arc<dyn Any + Send + Sync>,
+    parent: Option<Arc<Scope>>,
+}
+
+impl Scope {
+    fn find<P>(self: &Arc<Self>) -> Option<Arc<P>>
+    where
+        P: Any + Send + Sync,
+    {
+        let mut scope = self;
+        loop {
+            let Self { provider, parent } = &**scope;
+            if let Ok(provider) = Arc::clone(provider).downcast() {
+                return Some(provider);
+            }
+            scope = parent.as_ref()?;
+        }
+    }
+}
+
+/// Runs provided future with the provider installed into the scope of the current task.
+///
+/// # Examples
+///
+/// ```
+/// use futures::executor::block_on;
+/// use provide::task_scope;
+///
+/// struct Config {
+///     url: &'static str,
+/// }
+///
+/// async fn connect() -> &'static str {
+///     task_scope::try_with(|config: &Config| config.url).unwrap()
+/// }
+///
+/// let config = Config { url: "localhost" };
+/// let url = block_on(task_scope::with(config, connect()));
+/// assert_eq!(url, "localhost");
+/// ```
+pub fn with<P, F>(provider: P, future: F) -> impl Future<Output = F::Output>
+where
+    P: Any + Send + Sync,
+    F: Future,
+{
+    with_arc(Arc::new(provider), future)
+}
+
+/// Runs provided future with the shared provider installed into the scope of the current task.
+///
+/// # Examples
+///
+/// ```
+/// use futures::executor::block_on;
+/// use provide::task_scope;
+///
+/// let url = block_on(task_scope::with("localhost", async {
+///     let provider = task_scope::current::<&str>().unwrap();
+///     task_scope::with_arc(provider, async { *task_scope::current::<&str>().unwrap() }).await
+/// }));
+/// assert_eq!(url, "localhost");
+/// ```
+pub fn with_arc<P, F>(provider: Arc<P>, future: F) -> impl Future<Output = F::Output>
+where
+    P: Any + Send + Sync,
+    F: Future,
+{
+    let scope = Scope {
+        provider,
+        parent: SCOPE.try_with(Arc::clone).ok(),
+    };
+    SCOPE.scope(Arc::new(scope), future)
+}
+
+/// Returns the innermost provider of type `P` installed into the scope of the current task.
+///
+/// # Errors
+///
+/// Returns [`NotInScope`] error if there is no provider of type `P`
+/// in the scope of the current task.
+pub fn current<P>() -> Result<Arc<P>, NotInScope>
+where
+    P: Any + Send + Sync,
+{
+    SCOPE.try_with(Scope::find).ok().flatten().ok_or(NotInScope)
+}
+
+/// Calls provided function with the innermost provider of type `P`
+/// installed into the scope of the current task.
+///
+/// # Errors
+///
+/// Returns [`NotInScope`] error if there is no provider of type `P`
+/// in the scope of the current task.
+pub fn try_with<P, R, F>(f: F) -> Result<R, NotInScope>
+where
+    P: Any + Send + Sync,
+    F: FnOnce(&P) -> R,
+{
+    let provider = current::<P>()?;
+    Ok(f(&provider))
+}
+
+/// The error type returned when there is no provider of requested type
+/// in the scope of the current task.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NotInScope;
+
+impl Display for NotInScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("no provider of requested type in the scope of the current task")
+    }
+}
+
+impl Error for NotInScope {}
@@ -0,0 +1,362 @@
+//! Deterministic implementations of dependencies for tests.
+//!
+//! This module is available with `test_util` feature enabled.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    any::type_name,
+    cell::Cell,
+    fmt::{self, Debug, Display},
+    time::Duration,
+};
+
+use crate::{
+    clock::{Clock, Timer},
+    describe::Exhausted,
+    pair::{Leaf, Pair},
+};
+
+/// [Clock] which advances only when told.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use provide::{clock::Clock, test_util::FakeClock, ProvideExt};
+///
+/// struct Provider {
+///     clock: FakeClock,
+/// }
+///
+/// impl AsRef<FakeClock> for Provider {
+///     fn as_ref(&self) -> &FakeClock {
+///         let Self { clock } = self;
+///         clock
+///     }
+/// }
+///
+/// fn uptime(clock: &impl Clock, started: Duration) -> Duration {
+///     clock.now() - started
+/// }
+///
+/// let provider = Provider {
+///     clock: FakeClock::default(),
+/// };
+/// let clock = provider.get_dep::<FakeClock>();
+/// let started = clock.now();
+///
+/// clock.advance(Duration::from_secs(5));
+/// assert_eq!(uptime(clock, started), Duration::from_secs(5));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FakeClock {
+    now: Cell<Duration>,
+}
+
+impl FakeClock {
+    /// Creates new clock which tells provided time until it is advanced.
+    pub const fn new(now: Duration) -> Self {
+        Self {
+            now: Cell::new(now),
+        }
+    }
+
+    /// Advances the clock by provided duration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the time of the clock overflows.
+    pub fn advance(&self, by: Duration) {
+        let Self { now } = self;
+        let next = now
+            .get()
+            .checked_add(by)
+            .expect("time of the fake clock should not overflow");
+        now.set(next);
+    }
+
+    /// Sets the time of the clock, which may also move it backwards.
+    pub fn set(&self, time: Duration) {
+        let Self { now } = self;
+        now.set(time);
+    }
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> Duration {
+        let Self { now } = self;
+        now.get()
+    }
+}
+
+/// [Timer] which expires only when told.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{clock::Timer, test_util::ManualTimer};
+///
+/// let timer = ManualTimer::default();
+/// assert!(!timer.is_expired());
+///
+/// timer.expire();
+/// assert!(timer.is_expired());
+///
+/// timer.reset();
+/// assert!(!timer.is_expired());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ManualTimer {
+    expired: Cell<bool>,
+}
+
+impl ManualTimer {
+    /// Creates new timer which has not expired yet.
+    pub const fn new() -> Self {
+        Self {
+            expired: Cell::new(false),
+        }
+    }
+
+    /// Expires the timer.
+    pub fn expire(&self) {
+        let Self { expired } = self;
+        expired.set(true);
+    }
+
+    /// Resets the timer, so it has not expired anymore.
+    pub fn reset(&self) {
+        let Self { expired } = self;
+        expired.set(false);
+    }
+}
+
+impl Timer for ManualTimer {
+    fn is_expired(&self) -> bool {
+        let Self { expired } = self;
+        expired.get()
+    }
+}
+
+/// Type of provider which can be compared with another provider dependency-by-dependency.
+///
+/// This trait is implemented for [pairs](Pair), tuples of up to 12 elements
+/// (or 32 elements with `tuples_32` feature enabled),
+/// [leaf](Leaf) types and [exhausted](Exhausted) markers,
+/// so composition roots built out of them can be compared out of the box.
+/// Implement it for your own providers to compare them in the same way.
+///
+/// See [`assert_provider_eq!`](crate::assert_provider_eq) macro
+/// to assert equality of providers in tests.
+pub trait ProviderEq {
+    /// Compares all the dependencies of this provider
+    /// with the dependencies of another provider in the comparer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::test_util::{Comparer, ProviderEq};
+    ///
+    /// struct Provider {
+    ///     port: u16,
+    ///     host: &'static str,
+    /// }
+    ///
+    /// impl ProviderEq for Provider {
+    ///     fn compare_into(&self, other: &Self, comparer: &mut Comparer<'_, '_>) {
+    ///         comparer
+    ///             .dependency(&self.port, &other.port)
+    ///             .dependency(&self.host, &other.host);
+    ///     }
+    /// }
+    ///
+    /// let left = Provider { port: 5432, host: "localhost" };
+    /// let right = Provider { port: 5433, host: "localhost" };
+    /// assert!(left.provider_eq(&left));
+    /// assert!(!left.provider_eq(&right));
+    /// assert_eq!(left.diff(&right).to_string(), "\n  u16: left = 5432, right = 5433");
+    /// ```
+    fn compare_into(&self, other: &Self, comparer: &mut Comparer<'_, '_>);
+
+    /// Returns `true` if all the dependencies of both providers are equal.
+    fn provider_eq(&self, other: &Self) -> bool {
+        let mut comparer = Comparer {
+            sink: None,
+            mismatches: 0,
+            result: Ok(()),
+        };
+        self.compare_into(other, &mut comparer);
+        comparer.mismatches == 0
+    }
+
+    /// Returns difference between both providers which can be printed with [`Display`],
+    /// with one line per each mismatching dependency.
+    fn diff<'a>(&'a self, other: &'a Self) -> Diff<'a, Self> {
+        Diff {
+            left: self,
+            right: other,
+        }
+    }
+}
+
+/// Comparer of dependencies of two [providers](ProviderEq).
+pub struct Comparer<'a, 'b: 'a> {
+    sink: Option<&'a mut fmt::Formatter<'b>>,
+    mismatches: usize,
+    result: fmt::Result,
+}
+
+impl Comparer<'_, '_> {
+    /// Compares dependency of type `T` of the left provider with the one of the right provider.
+    pub fn dependency<T>(&mut self, left: &T, right: &T) -> &mut Self
+    where
+        T: PartialEq + Debug + ?Sized,
+    {
+        if left == right {
+            return self;
+        }
+        self.mismatches += 1;
+        let Self { sink, result, .. } = self;
+        if let (Some(f), Ok(())) = (sink, &result) {
+            *result = write!(
+                f,
+                "\n  {}: left = {left:?}, right = {right:?}",
+                type_name::<T>(),
+            );
+        }
+        self
+    }
+}
+
+/// Difference between two [providers](ProviderEq) which can be printed with [`Display`].
+///
+/// This struct is created by the [`diff`](ProviderEq::diff) method.
+pub struct Diff<'a, P>
+where
+    P: ?Sized,
+{
+    left: &'a P,
+    right: &'a P,
+}
+
+impl<P> Display for Diff<'_, P>
+where
+    P: ProviderEq + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { left, right } = *self;
+        let mut comparer = Comparer {
+            sink: Some(f),
+            mismatches: 0,
+            result: Ok(()),
+        };
+        left.compare_into(right, &mut comparer);
+        comparer.result
+    }
+}
+
+/// Asserts that two [providers](ProviderEq) are equal dependency-by-dependency.
+///
+/// This function is used by [`assert_provider_eq!`](crate::assert_provider_eq) macro.
+///
+/// # Panics
+///
+/// Panics if some dependencies of providers are not equal,
+/// listing all the mismatching dependencies in the message.
+#[track_caller]
+pub fn assert_provider_eq<P>(left: &P, right: &P)
+where
+    P: ProviderEq + ?Sized,
+{
+    if !left.provider_eq(right) {
+        panic!(
+            "assertion `left == right` failed: providers differ{}",
+            left.diff(right),
+        );
+    }
+}
+
+/// Asserts that two [providers](crate::test_util::ProviderEq) are equal dependency-by-dependency.
+///
+/// On failure, message of the panic lists all the mismatching dependencies
+/// along with their values in both providers.
+///
+/// This macro is available with `test_util` feature enabled.
+///
+/// # Examples
+///
+/// ```should_panic
+/// use provide::{assert_provider_eq, pair::Pair};
+///
+/// let expected = Pair::new(5432_u16, Pair::new("localhost", ()));
+/// let actual = Pair::new(5433_u16, Pair::new("localhost", ()));
+/// assert_provider_eq!(actual, expected);
+/// ```
+#[macro_export]
+macro_rules! assert_provider_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::test_util::assert_provider_eq(&$left, &$right)
+    };
+}
+
+impl<T> ProviderEq for Exhausted<T>
+where
+    T: ?Sized,
+{
+    fn compare_into(&self, _: &Self, _: &mut Comparer<'_, '_>) {}
+}
+
+impl<T> ProviderEq for T
+where
+    T: Leaf + PartialEq + Debug,
+{
+    fn compare_into(&self, other: &Self, comparer: &mut Comparer<'_, '_>) {
+        comparer.dependency(self, other);
+    }
+}
+
+impl ProviderEq for () {
+    fn compare_into(&self, _: &Self, _: &mut Comparer<'_, '_>) {}
+}
+
+impl<H, T> ProviderEq for Pair<H, T>
+where
+    H: ProviderEq,
+    T: ProviderEq,
+{
+    fn compare_into(&self, other: &Self, comparer: &mut Comparer<'_, '_>) {
+        self.head().compare_into(other.head(), comparer);
+        self.tail().compare_into(other.tail(), comparer);
+    }
+}
+
+/// Implements [`ProviderEq`] for all the tuples which are prefixes of provided list
+/// of element types paired with their indices.
+macro_rules! impl_provider_eq {
+    ([$($ty:ident . $idx:tt),*]) => {};
+    ([$($ty:ident . $idx:tt),*] $next:ident . $next_idx:tt $(, $rest:ident . $rest_idx:tt)* $(,)?) => {
+        impl<$($ty,)* $next> ProviderEq for ($($ty,)* $next,)
+        where
+            $($ty: ProviderEq,)*
+            $next: ProviderEq,
+        {
+            fn compare_into(&self, other: &Self, comparer: &mut Comparer<'_, '_>) {
+                $(self.$idx.compare_into(&other.$idx, comparer);)*
+                self.$next_idx.compare_into(&other.$next_idx, comparer);
+            }
+        }
+
+        impl_provider_eq!([$($ty . $idx,)* $next . $next_idx] $($rest . $rest_idx),*);
+    };
+}
+
+#[cfg(not(feature = "tuples_32"))]
+impl_provider_eq!([] T1.0, T2.1, T3.2, T4.3, T5.4, T6.5, T7.6, T8.7, T9.8, T10.9, T11.10, T12.11);
+#[cfg(feature = "tuples_32")]
+impl_provider_eq!(
+    [] T1.0, T2.1, T3.2, T4.3, T5.4, T6.5, T7.6, T8.7, T9.8, T10.9, T11.10, T12.11, T13.12,
+    T14.13, T15.14, T16.15, T17.16, T18.17, T19.18, T20.19, T21.20, T22.21, T23.22, T24.23,
+    T25.24, T26.25, T27.26, T28.27, T29.28, T30.29, T31.30, T32.31
+);
@@ -0,0 +1,282 @@
+//! Providers which limit the rate of provisions.
+//!
+//! This module is available with `std` feature enabled.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    cell::Cell,
+    error::Error,
+    fmt::{self, Display},
+    time::Duration,
+};
+
+use crate::clock::{Clock, SystemClock};
+
+/// Provider which limits the rate of provisions from the inner provider
+/// with the token bucket algorithm.
+///
+/// Bucket holds up to `capacity` tokens and starts full.
+/// Each successful provision takes one token out of the bucket,
+/// and one token is put back into the bucket after each `interval` of time,
+/// measured with the [clock](Clock) of type `K`.
+/// This allows to protect scarce injected resources, such as API clients or licenses,
+/// at the wiring layer.
+///
+/// Dependencies are provided with the [`ThrottleWith`](crate::context::throttle::ThrottleWith)
+/// context, which fails provision with [throttled](ThrottleError::Throttled) error
+/// if the bucket is empty. Failed provision from the inner provider does not take a token.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use provide::{
+///     context::throttle::Throttle,
+///     throttle::{ThrottleError, Throttled},
+///     with::TryProvideRefWith,
+/// };
+///
+/// let provider = Throttled::new(Box::new("api client"), 1, Duration::from_secs(60));
+///
+/// let client: Result<&&str, _> = provider.try_provide_ref_with(Throttle::default());
+/// assert_eq!(client, Ok(&"api client"));
+///
+/// let client: Result<&&str, _> = provider.try_provide_ref_with(Throttle::default());
+/// assert!(matches!(client, Err(ThrottleError::Throttled { .. })));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Throttled<P, K = SystemClock> {
+    provider: P,
+    bucket: Bucket<K>,
+}
+
+impl<P> Throttled<P> {
+    /// Creates new provider from the inner provider, the capacity of the bucket
+    /// and the interval of time after which one token is put back into the bucket.
+    ///
+    /// Time is measured with the [system clock](SystemClock).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interval is zero.
+    pub fn new(provider: P, capacity: u32, interval: Duration) -> Self {
+        Self::with_clock(provider, capacity, interval, SystemClock::new())
+    }
+}
+
+impl<P, K> Throttled<P, K> {
+    /// Creates new provider from the inner provider, the capacity of the bucket,
+    /// the interval of time after which one token is put back into the bucket
+    /// and the clock to measure time with.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interval is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "test_util")] {
+    /// use std::time::Duration;
+    ///
+    /// use provide::{
+    ///     context::throttle::Throttle, test_util::FakeClock, throttle::Throttled,
+    ///     with::TryProvideRefWith,
+    /// };
+    ///
+    /// let clock = FakeClock::default();
+    /// let provider = Throttled::with_clock(Box::new(1), 1, Duration::from_secs(60), &clock);
+    ///
+    /// let result: Result<&i32, _> = provider.try_provide_ref_with(Throttle::default());
+    /// assert!(result.is_ok());
+    /// assert_eq!(provider.available(), 0);
+    ///
+    /// clock.advance(Duration::from_secs(60));
+    /// assert_eq!(provider.available(), 1);
+    /// # }
+    /// ```
+    pub fn with_clock(provider: P, capacity: u32, interval: Duration, clock: K) -> Self
+    where
+        K: Clock,
+    {
+        assert!(
+            !interval.is_zero(),
+            "interval of the throttle should not be zero"
+        );
+        let refilled = Cell::new(clock.now());
+        let bucket = Bucket {
+            clock,
+            capacity,
+            interval,
+            tokens: Cell::new(capacity),
+            refilled,
+        };
+        Self { provider, bucket }
+    }
+
+    /// Returns the capacity of the bucket.
+    pub const fn capacity(&self) -> u32 {
+        self.bucket.capacity
+    }
+
+    /// Returns the interval of time after which one token is put back into the bucket.
+    pub const fn interval(&self) -> Duration {
+        self.bucket.interval
+    }
+
+    /// Returns the clock which time is measured with.
+    pub const fn clock(&self) -> &K {
+        &self.bucket.clock
+    }
+
+    /// Returns count of tokens currently available in the bucket.
+    pub fn available(&self) -> u32
+    where
+        K: Clock,
+    {
+        let Self { bucket, .. } = self;
+        bucket.refill(bucket.clock.now());
+        bucket.tokens.get()
+    }
+
+    /// Returns the inner provider.
+    pub fn into_inner(self) -> P {
+        let Self { provider, .. } = self;
+        provider
+    }
+
+    pub(crate) fn parts(&self) -> (&P, &Bucket<K>) {
+        let Self { provider, bucket } = self;
+        (provider, bucket)
+    }
+
+    pub(crate) fn parts_mut(&mut self) -> (&mut P, &Bucket<K>) {
+        let Self { provider, bucket } = self;
+        (provider, bucket)
+    }
+}
+
+/// Token bucket of the [throttled](Throttled) provider.
+#[derive(Debug, Clone)]
+pub(crate) struct Bucket<K> {
+    clock: K,
+    capacity: u32,
+    interval: Duration,
+    tokens: Cell<u32>,
+    refilled: Cell<Duration>,
+}
+
+impl<K> Bucket<K>
+where
+    K: Clock,
+{
+    /// Checks that the bucket has a token available,
+    /// returning the time of the check to [take](Self::take) the token at.
+    pub(crate) fn check(&self) -> Result<Duration, Exhausted> {
+        let Self {
+            clock,
+            interval,
+            tokens,
+            refilled,
+            ..
+        } = self;
+        let now = clock.now();
+        self.refill(now);
+        if tokens.get() == 0 {
+            let next = refilled.get() + *interval;
+            let retry_after = next.saturating_sub(now);
+            return Err(Exhausted { retry_after });
+        }
+        Ok(now)
+    }
+
+    /// Takes one token out of the bucket after the successful provision.
+    pub(crate) fn take(&self, now: Duration) {
+        let Self {
+            capacity,
+            tokens,
+            refilled,
+            ..
+        } = self;
+        if tokens.get() == *capacity {
+            refilled.set(now);
+        }
+        tokens.set(tokens.get().saturating_sub(1));
+    }
+
+    fn refill(&self, now: Duration) {
+        let Self {
+            capacity,
+            interval,
+            tokens,
+            refilled,
+            ..
+        } = self;
+        let elapsed = now.saturating_sub(refilled.get());
+        let intervals = elapsed.as_nanos() / interval.as_nanos();
+        if intervals == 0 {
+            return;
+        }
+        let added = u32::try_from(intervals).unwrap_or(u32::MAX);
+        let available = tokens.get().saturating_add(added).min(*capacity);
+        tokens.set(available);
+        if available == *capacity {
+            refilled.set(now);
+        } else {
+            refilled.set(refilled.get() + *interval * added);
+        }
+    }
+}
+
+pub(crate) struct Exhausted {
+    retry_after: Duration,
+}
+
+impl<E> From<Exhausted> for ThrottleError<E> {
+    fn from(error: Exhausted) -> Self {
+        let Exhausted { retry_after } = error;
+        Self::Throttled { retry_after }
+    }
+}
+
+/// The error type returned when [throttled](Throttled) provider fails to provide dependency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ThrottleError<E> {
+    /// Rate of provisions has exceeded the limit.
+    Throttled {
+        /// Time after which the next provision is allowed.
+        retry_after: Duration,
+    },
+    /// Rate limit is not exceeded, but the inner provider failed to provide dependency.
+    Provide(E),
+}
+
+impl<E> Display for ThrottleError<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Throttled { retry_after } => write!(
+                f,
+                "rate of provisions has exceeded the limit, retry after {retry_after:?}",
+            ),
+            Self::Provide(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl<E> Error for ThrottleError<E>
+where
+    E: Error,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Throttled { .. } => None,
+            Self::Provide(error) => error.source(),
+        }
+    }
+}
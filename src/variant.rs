@@ -0,0 +1,226 @@
+//! Providers which are enums declared with the [`variants!`](crate::variants!) macro.
+//!
+//! See [crate] documentation for more.
+
+use core::{
+    error::Error,
+    fmt::{self, Debug, Display},
+};
+
+/// Type of provider which is an enum, each variant of which holds one dependency.
+///
+/// This trait is implemented by the [`variants!`](crate::variants!) macro.
+///
+/// See [crate] documentation for more.
+pub trait Variants {
+    /// Names of all the variants of the enum in the order of declaration.
+    const VARIANTS: &'static [&'static str];
+
+    /// Returns the name of the active variant.
+    fn variant(&self) -> &'static str;
+}
+
+/// The error type returned when dependency is requested from the [enum provider](Variants)
+/// whose active variant does not hold it.
+///
+/// Provider (or reference to it) is returned back inside of this error,
+/// so it is not lost on failed provision by value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct WrongVariant<P> {
+    expected: &'static str,
+    provider: P,
+}
+
+impl<P> WrongVariant<P> {
+    /// Creates new error from the name of the variant which holds requested dependency
+    /// and the provider.
+    pub const fn new(expected: &'static str, provider: P) -> Self {
+        Self { expected, provider }
+    }
+
+    /// Returns the name of the variant which holds requested dependency.
+    pub const fn expected(&self) -> &'static str {
+        self.expected
+    }
+
+    /// Returns the provider which failed to provide dependency.
+    pub fn into_provider(self) -> P {
+        let Self { provider, .. } = self;
+        provider
+    }
+}
+
+impl<P> WrongVariant<P>
+where
+    P: Variants,
+{
+    /// Returns the name of the active variant of the provider.
+    pub fn actual(&self) -> &'static str {
+        let Self { provider, .. } = self;
+        provider.variant()
+    }
+}
+
+impl<P> Display for WrongVariant<P>
+where
+    P: Variants,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { expected, .. } = self;
+        let actual = self.actual();
+        write!(f, "expected `{expected}` variant, but `{actual}` is active")
+    }
+}
+
+impl<P> Error for WrongVariant<P> where P: Variants + Debug {}
+
+impl<P> Variants for &P
+where
+    P: Variants + ?Sized,
+{
+    const VARIANTS: &'static [&'static str] = P::VARIANTS;
+
+    fn variant(&self) -> &'static str {
+        P::variant(self)
+    }
+}
+
+impl<P> Variants for &mut P
+where
+    P: Variants + ?Sized,
+{
+    const VARIANTS: &'static [&'static str] = P::VARIANTS;
+
+    fn variant(&self) -> &'static str {
+        P::variant(self)
+    }
+}
+
+/// Declares the enum provider, each variant of which holds one dependency.
+///
+/// Each variant is declared with exactly one unnamed field of the type of the dependency.
+/// Types of the dependencies must be distinct, so the variant can be selected
+/// by the type of requested dependency.
+///
+/// The macro generates:
+/// - the enum itself;
+/// - implementations of [`TryProvide`](crate::TryProvide),
+///   [`TryProvideRef`](crate::TryProvideRef) and [`TryProvideMut`](crate::TryProvideMut) traits
+///   for every dependency, which succeed only if the active variant holds it,
+///   and fail with [`WrongVariant`](crate::variant::WrongVariant) error otherwise;
+/// - implementation of the [`Variants`](crate::variant::Variants) trait.
+///
+/// This allows to model state machines as providers:
+/// dependencies of the current state are available, while others are not.
+///
+/// # Examples
+///
+/// ```
+/// use provide::{variant::Variants, variants, TryProvide, TryProvideRef};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Config(&'static str);
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Connection(u32);
+///
+/// variants! {
+///     /// State of the client.
+///     #[derive(Debug, PartialEq)]
+///     pub enum State {
+///         Idle(Config),
+///         Connected(Connection),
+///     }
+/// }
+///
+/// let state = State::Connected(Connection(1));
+/// assert_eq!(State::VARIANTS, ["Idle", "Connected"]);
+///
+/// let connection: Result<&Connection, _> = state.try_provide_ref();
+/// assert_eq!(connection, Ok(&Connection(1)));
+///
+/// let config: Result<&Config, _> = state.try_provide_ref();
+/// let error = config.unwrap_err();
+/// assert_eq!(error.to_string(), "expected `Idle` variant, but `Connected` is active");
+///
+/// let result: Result<(Config, _), _> = state.try_provide();
+/// let state = result.unwrap_err().into_provider();
+/// assert!(matches!(state, State::Connected(_)));
+/// ```
+#[macro_export]
+macro_rules! variants {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident($ty:ty)
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant($ty),
+            )*
+        }
+
+        impl $crate::variant::Variants for $name {
+            const VARIANTS: &'static [&'static str] = &[$(::core::stringify!($variant)),*];
+
+            fn variant(&self) -> &'static str {
+                match self {
+                    $(Self::$variant(_) => ::core::stringify!($variant),)*
+                }
+            }
+        }
+
+        $(
+            impl $crate::TryProvide<$ty> for $name {
+                type Remainder = ();
+
+                type Error = $crate::variant::WrongVariant<Self>;
+
+                #[allow(unreachable_patterns)]
+                fn try_provide(self) -> ::core::result::Result<($ty, Self::Remainder), Self::Error> {
+                    match self {
+                        Self::$variant(dependency) => ::core::result::Result::Ok((dependency, ())),
+                        provider => ::core::result::Result::Err(
+                            $crate::variant::WrongVariant::new(::core::stringify!($variant), provider),
+                        ),
+                    }
+                }
+            }
+
+            impl<'me> $crate::TryProvideRef<'me, &'me $ty> for $name {
+                type Error = $crate::variant::WrongVariant<&'me Self>;
+
+                #[allow(unreachable_patterns)]
+                fn try_provide_ref(&'me self) -> ::core::result::Result<&'me $ty, Self::Error> {
+                    match self {
+                        Self::$variant(dependency) => ::core::result::Result::Ok(dependency),
+                        provider => ::core::result::Result::Err(
+                            $crate::variant::WrongVariant::new(::core::stringify!($variant), provider),
+                        ),
+                    }
+                }
+            }
+
+            impl<'me> $crate::TryProvideMut<'me, &'me mut $ty> for $name {
+                type Error = $crate::variant::WrongVariant<&'me mut Self>;
+
+                #[allow(unreachable_patterns)]
+                fn try_provide_mut(&'me mut self) -> ::core::result::Result<&'me mut $ty, Self::Error> {
+                    match self {
+                        Self::$variant(dependency) => ::core::result::Result::Ok(dependency),
+                        provider => ::core::result::Result::Err(
+                            $crate::variant::WrongVariant::new(::core::stringify!($variant), provider),
+                        ),
+                    }
+                }
+            }
+        )*
+    };
+}
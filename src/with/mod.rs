@@ -2,12 +2,17 @@
 //!
 //! See [crate] documentation for more.
 
+#[cfg(feature = "async")]
+pub use self::provide::{
+    ProvideAsyncWith, ProvideMutAsyncWith, ProvideRefAsyncWith, TryProvideAsyncWith,
+    TryProvideMutAsyncWith, TryProvideRefAsyncWith,
+};
 pub use self::{
     provide::{
         ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideMutWith, TryProvideRefWith,
         TryProvideWith,
     },
-    with::With,
+    with::{TryWith, With, WithBack, WithFront},
 };
 
 mod provide;
@@ -0,0 +1,9 @@
+pub use self::{
+    owned::{ProvideAsyncWith, TryProvideAsyncWith},
+    r#mut::{ProvideMutAsyncWith, TryProvideMutAsyncWith},
+    r#ref::{ProvideRefAsyncWith, TryProvideRefAsyncWith},
+};
+
+mod r#mut;
+mod owned;
+mod r#ref;
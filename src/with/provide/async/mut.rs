@@ -0,0 +1,90 @@
+use core::future::Future;
+
+use crate::{context::Empty, ProvideMutAsync, TryProvideMutAsync};
+
+/// Type of provider which provides dependency by *unique reference* asynchronously,
+/// but with additional context provided by the caller.
+///
+/// This trait is very similar to [`ProvideMutAsync`](crate::ProvideMutAsync) trait.
+/// However, this trait allows to retrieve additional context provided by the caller,
+/// so it is possible to *define many ways* of how dependency can be provided.
+///
+/// See [crate] documentation for more.
+pub trait ProvideMutAsyncWith<'me, T, C> {
+    /// Provides dependency by *unique reference* asynchronously
+    /// with additional context provided by the caller.
+    ///
+    /// # Examples
+    ///
+    /// With [empty context](crate::context::Empty), this trait
+    /// falls back to the implementation of [`ProvideMutAsync`](crate::ProvideMutAsync) trait:
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use provide::with::ProvideMutAsyncWith;
+    ///
+    /// let mut provider = vec![1, 2, 3];
+    /// let dependency: &mut [i32] = block_on(provider.provide_mut_async_with(()));
+    /// assert_eq!(dependency, [1, 2, 3]);
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    fn provide_mut_async_with(&'me mut self, context: C) -> impl Future<Output = T>;
+}
+
+impl<'me, T, U> ProvideMutAsyncWith<'me, T, Empty> for U
+where
+    U: ProvideMutAsync<'me, T> + ?Sized,
+{
+    fn provide_mut_async_with(&'me mut self, _: Empty) -> impl Future<Output = T> {
+        self.provide_mut_async()
+    }
+}
+
+/// Type of provider which can provide dependency by *unique reference* asynchronously,
+/// but with additional context provided by the caller, or fail.
+///
+/// This trait is very similar to [`TryProvideMutAsync`](crate::TryProvideMutAsync) trait.
+/// However, this trait allows to retrieve additional context provided by the caller,
+/// so it is possible to *define many ways* of how dependency can be provided.
+///
+/// See [crate] documentation for more.
+pub trait TryProvideMutAsyncWith<'me, T, C> {
+    /// The type returned in the event of an error.
+    type Error;
+
+    /// Tries to provide dependency by *unique reference* asynchronously
+    /// with additional context provided by the caller.
+    ///
+    /// # Examples
+    ///
+    /// With [empty context](crate::context::Empty), this trait
+    /// falls back to the implementation of [`TryProvideMutAsync`](crate::TryProvideMutAsync) trait:
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use provide::with::TryProvideMutAsyncWith;
+    ///
+    /// let mut provider = vec![1, 2, 3];
+    /// let dependency: Result<&mut [i32], _> = block_on(provider.try_provide_mut_async_with(()));
+    /// assert_eq!(dependency, Ok([1, 2, 3].as_mut_slice()));
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    fn try_provide_mut_async_with(
+        &'me mut self,
+        context: C,
+    ) -> impl Future<Output = Result<T, Self::Error>>;
+}
+
+impl<'me, T, U> TryProvideMutAsyncWith<'me, T, Empty> for U
+where
+    U: TryProvideMutAsync<'me, T> + ?Sized,
+{
+    type Error = U::Error;
+
+    fn try_provide_mut_async_with(
+        &'me mut self,
+        _: Empty,
+    ) -> impl Future<Output = Result<T, Self::Error>> {
+        self.try_provide_mut_async()
+    }
+}
@@ -0,0 +1,102 @@
+use core::future::Future;
+
+use crate::{context::Empty, ProvideAsync, TryProvideAsync};
+
+/// Type of provider which provides dependency by *value* asynchronously,
+/// but with additional context provided by the caller.
+///
+/// This trait is very similar to [`ProvideAsync`](crate::ProvideAsync) trait.
+/// However, this trait allows to retrieve additional context provided by the caller,
+/// so it is possible to *define many ways* of how dependency can be provided.
+///
+/// See [crate] documentation for more.
+pub trait ProvideAsyncWith<T, C>: Sized {
+    /// Remaining part of the provider after providing dependency by value.
+    type Remainder;
+
+    /// Provides dependency by *value* asynchronously
+    /// with additional context provided by the caller,
+    /// also returning [remaining part](ProvideAsyncWith::Remainder) of the provider.
+    ///
+    /// # Examples
+    ///
+    /// With [empty context](crate::context::Empty), this trait
+    /// falls back to the implementation of [`ProvideAsync`](crate::ProvideAsync) trait:
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use provide::with::ProvideAsyncWith;
+    ///
+    /// let provider: i32 = 1;
+    /// let (dependency, _): (i64, _) = block_on(provider.provide_async_with(()));
+    /// assert_eq!(dependency, 1);
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    fn provide_async_with(self, context: C) -> impl Future<Output = (T, Self::Remainder)>;
+}
+
+impl<T, U> ProvideAsyncWith<T, Empty> for U
+where
+    U: ProvideAsync<T>,
+{
+    type Remainder = U::Remainder;
+
+    fn provide_async_with(self, _: Empty) -> impl Future<Output = (T, Self::Remainder)> {
+        self.provide_async()
+    }
+}
+
+/// Type of provider which can provide dependency by *value* asynchronously,
+/// but with additional context provided by the caller, or fail.
+///
+/// This trait is very similar to [`TryProvideAsync`](crate::TryProvideAsync) trait.
+/// However, this trait allows to retrieve additional context provided by the caller,
+/// so it is possible to *define many ways* of how dependency can be provided.
+///
+/// See [crate] documentation for more.
+pub trait TryProvideAsyncWith<T, C>: Sized {
+    /// Remaining part of the provider after providing dependency by value.
+    type Remainder;
+
+    /// The type returned in the event of an error.
+    type Error;
+
+    /// Tries to provide dependency by *value* asynchronously
+    /// with additional context provided by the caller,
+    /// also returning [remaining part](TryProvideAsyncWith::Remainder) of the provider on success.
+    ///
+    /// # Examples
+    ///
+    /// With [empty context](crate::context::Empty), this trait
+    /// falls back to the implementation of [`TryProvideAsync`](crate::TryProvideAsync) trait:
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use provide::with::TryProvideAsyncWith;
+    ///
+    /// let provider: i32 = 1;
+    /// let result: Result<(i64, _), _> = block_on(provider.try_provide_async_with(()));
+    /// assert_eq!(result, Ok((1, ())));
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    fn try_provide_async_with(
+        self,
+        context: C,
+    ) -> impl Future<Output = Result<(T, Self::Remainder), Self::Error>>;
+}
+
+impl<T, U> TryProvideAsyncWith<T, Empty> for U
+where
+    U: TryProvideAsync<T>,
+{
+    type Remainder = U::Remainder;
+
+    type Error = U::Error;
+
+    fn try_provide_async_with(
+        self,
+        _: Empty,
+    ) -> impl Future<Output = Result<(T, Self::Remainder), Self::Error>> {
+        self.try_provide_async()
+    }
+}
@@ -0,0 +1,90 @@
+use core::future::Future;
+
+use crate::{context::Empty, ProvideRefAsync, TryProvideRefAsync};
+
+/// Type of provider which provides dependency by *shared reference* asynchronously,
+/// but with additional context provided by the caller.
+///
+/// This trait is very similar to [`ProvideRefAsync`](crate::ProvideRefAsync) trait.
+/// However, this trait allows to retrieve additional context provided by the caller,
+/// so it is possible to *define many ways* of how dependency can be provided.
+///
+/// See [crate] documentation for more.
+pub trait ProvideRefAsyncWith<'me, T, C> {
+    /// Provides dependency by *shared reference* asynchronously
+    /// with additional context provided by the caller.
+    ///
+    /// # Examples
+    ///
+    /// With [empty context](crate::context::Empty), this trait
+    /// falls back to the implementation of [`ProvideRefAsync`](crate::ProvideRefAsync) trait:
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use provide::with::ProvideRefAsyncWith;
+    ///
+    /// let provider = vec![1, 2, 3];
+    /// let dependency: &[i32] = block_on(provider.provide_ref_async_with(()));
+    /// assert_eq!(dependency, [1, 2, 3]);
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    fn provide_ref_async_with(&'me self, context: C) -> impl Future<Output = T>;
+}
+
+impl<'me, T, U> ProvideRefAsyncWith<'me, T, Empty> for U
+where
+    U: ProvideRefAsync<'me, T> + ?Sized,
+{
+    fn provide_ref_async_with(&'me self, _: Empty) -> impl Future<Output = T> {
+        self.provide_ref_async()
+    }
+}
+
+/// Type of provider which can provide dependency by *shared reference* asynchronously,
+/// but with additional context provided by the caller, or fail.
+///
+/// This trait is very similar to [`TryProvideRefAsync`](crate::TryProvideRefAsync) trait.
+/// However, this trait allows to retrieve additional context provided by the caller,
+/// so it is possible to *define many ways* of how dependency can be provided.
+///
+/// See [crate] documentation for more.
+pub trait TryProvideRefAsyncWith<'me, T, C> {
+    /// The type returned in the event of an error.
+    type Error;
+
+    /// Tries to provide dependency by *shared reference* asynchronously
+    /// with additional context provided by the caller.
+    ///
+    /// # Examples
+    ///
+    /// With [empty context](crate::context::Empty), this trait
+    /// falls back to the implementation of [`TryProvideRefAsync`](crate::TryProvideRefAsync) trait:
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use provide::with::TryProvideRefAsyncWith;
+    ///
+    /// let provider = vec![1, 2, 3];
+    /// let dependency: Result<&[i32], _> = block_on(provider.try_provide_ref_async_with(()));
+    /// assert_eq!(dependency, Ok([1, 2, 3].as_slice()));
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    fn try_provide_ref_async_with(
+        &'me self,
+        context: C,
+    ) -> impl Future<Output = Result<T, Self::Error>>;
+}
+
+impl<'me, T, U> TryProvideRefAsyncWith<'me, T, Empty> for U
+where
+    U: TryProvideRefAsync<'me, T> + ?Sized,
+{
+    type Error = U::Error;
+
+    fn try_provide_ref_async_with(
+        &'me self,
+        _: Empty,
+    ) -> impl Future<Output = Result<T, Self::Error>> {
+        self.try_provide_ref_async()
+    }
+}
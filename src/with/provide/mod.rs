@@ -1,9 +1,16 @@
+#[cfg(feature = "async")]
+pub use self::r#async::{
+    ProvideAsyncWith, ProvideMutAsyncWith, ProvideRefAsyncWith, TryProvideAsyncWith,
+    TryProvideMutAsyncWith, TryProvideRefAsyncWith,
+};
 pub use self::{
     owned::{ProvideWith, TryProvideWith},
     r#mut::{ProvideMutWith, TryProvideMutWith},
     r#ref::{ProvideRefWith, TryProvideRefWith},
 };
 
+#[cfg(feature = "async")]
+mod r#async;
 mod r#mut;
 mod owned;
 mod r#ref;
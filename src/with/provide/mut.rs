@@ -1,6 +1,7 @@
-use core::convert::Infallible;
-
-use crate::{context::Empty, ProvideMut};
+use crate::{
+    context::{Empty, IntoContext},
+    ProvideMut, TryProvideMut,
+};
 
 /// Type of provider which provides dependency by *unique reference*,
 /// but with additional context provided by the caller.
@@ -16,12 +17,54 @@ pub trait ProvideMutWith<'me, T, C> {
     ///
     /// # Examples
     ///
+    /// You can implement this trait for your provider
+    /// to provide dependency by unique reference in many ways:
+    ///
     /// ```
     /// use provide::with::ProvideMutWith;
     ///
-    /// todo!()
+    /// struct Provider {
+    ///     foo: i32,
+    /// }
+    ///
+    /// // Context which increments value before providing it.
+    /// struct Increment;
+    ///
+    /// impl<'me> ProvideMutWith<'me, &'me mut i32, Increment> for Provider {
+    ///     fn provide_mut_with(&'me mut self, _: Increment) -> &'me mut i32 {
+    ///         let Self { foo } = self;
+    ///         *foo += 1;
+    ///         foo
+    ///     }
+    /// }
+    ///
+    /// let mut provider = Provider { foo: 1 };
+    /// let dependency: &mut i32 = provider.provide_mut_with(Increment);
+    /// assert_eq!(dependency, &mut 2);
+    /// ```
+    ///
+    /// With [empty context](crate::context::Empty), this trait
+    /// falls back to the implementation of [`ProvideMut`](crate::ProvideMut) trait:
+    ///
+    /// ```
+    /// use provide::with::ProvideMutWith;
+    ///
+    /// let mut provider = vec![1, 2, 3];
+    /// let dependency: &mut [i32] = provider.provide_mut_with(());
+    /// assert_eq!(dependency, [1, 2, 3]);
     /// ```
     fn provide_mut_with(&'me mut self, context: C) -> T;
+
+    /// Provides dependency by *unique reference*
+    /// with the context converted from provided value.
+    ///
+    /// See [`IntoContext`] trait for more.
+    fn provide_mut_with_into<I, M>(&'me mut self, context: I) -> T
+    where
+        I: IntoContext<M, Context = C>,
+    {
+        self.provide_mut_with(context.into_context())
+    }
 }
 
 impl<'me, T, U> ProvideMutWith<'me, T, Empty> for U
@@ -40,6 +83,13 @@ where
 /// However, this trait allows to retrieve additional context provided by the caller,
 /// so it is possible to *define many ways* of how dependency can be provided.
 ///
+/// Unlike [`TryProvideMut`](crate::TryProvideMut) trait, this trait is not implemented
+/// for each provider which implements [`ProvideMutWith`] trait with the same context.
+/// Contexts which can fail on their own, such as [`FailAfterWith`](crate::context::failpoint::FailAfterWith),
+/// implement this trait with their own error, which such blanket implementation would conflict with.
+/// Providers which implement [`ProvideMutWith`] trait with custom context
+/// should implement this trait with the same context too.
+///
 /// See [crate] documentation for more.
 pub trait TryProvideMutWith<'me, T, C> {
     /// The type returned in the event of an error.
@@ -53,19 +103,58 @@ pub trait TryProvideMutWith<'me, T, C> {
     /// ```
     /// use provide::with::TryProvideMutWith;
     ///
-    /// todo!()
+    /// struct Provider {
+    ///     foo: Option<i32>,
+    /// }
+    ///
+    /// // Context which takes value out of the provider.
+    /// struct Take;
+    ///
+    /// impl TryProvideMutWith<'_, i32, Take> for Provider {
+    ///     type Error = ();
+    ///
+    ///     fn try_provide_mut_with(&mut self, _: Take) -> Result<i32, Self::Error> {
+    ///         let Self { foo } = self;
+    ///         foo.take().ok_or(())
+    ///     }
+    /// }
+    ///
+    /// let mut provider = Provider { foo: Some(1) };
+    /// assert_eq!(provider.try_provide_mut_with(Take), Ok(1));
+    /// assert_eq!(provider.try_provide_mut_with(Take), Err(()));
+    /// ```
+    ///
+    /// With [empty context](crate::context::Empty), this trait
+    /// falls back to the implementation of [`TryProvideMut`](crate::TryProvideMut) trait:
+    ///
+    /// ```
+    /// use provide::with::TryProvideMutWith;
+    ///
+    /// let mut provider = vec![1, 2, 3];
+    /// let dependency: Result<&mut [i32], _> = provider.try_provide_mut_with(());
+    /// assert_eq!(dependency, Ok([1, 2, 3].as_mut_slice()));
     /// ```
     fn try_provide_mut_with(&'me mut self, context: C) -> Result<T, Self::Error>;
+
+    /// Tries to provide dependency by *unique reference*
+    /// with the context converted from provided value on success.
+    ///
+    /// See [`IntoContext`] trait for more.
+    fn try_provide_mut_with_into<I, M>(&'me mut self, context: I) -> Result<T, Self::Error>
+    where
+        I: IntoContext<M, Context = C>,
+    {
+        self.try_provide_mut_with(context.into_context())
+    }
 }
 
-impl<'me, T, U, C> TryProvideMutWith<'me, T, C> for U
+impl<'me, T, U> TryProvideMutWith<'me, T, Empty> for U
 where
-    U: ProvideMutWith<'me, T, C> + ?Sized,
+    U: TryProvideMut<'me, T> + ?Sized,
 {
-    type Error = Infallible;
+    type Error = U::Error;
 
-    fn try_provide_mut_with(&'me mut self, context: C) -> Result<T, Self::Error> {
-        let provide_mut_with = self.provide_mut_with(context);
-        Ok(provide_mut_with)
+    fn try_provide_mut_with(&'me mut self, _: Empty) -> Result<T, Self::Error> {
+        self.try_provide_mut()
     }
 }
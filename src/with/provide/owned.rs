@@ -1,6 +1,7 @@
-use core::convert::Infallible;
-
-use crate::{context::Empty, Provide};
+use crate::{
+    context::{Empty, IntoContext},
+    Provide, TryProvide,
+};
 
 /// Type of provider which provides dependency by *value*,
 /// but with additional context provided by the caller.
@@ -20,13 +21,57 @@ pub trait ProvideWith<T, C>: Sized {
     ///
     /// # Examples
     ///
+    /// You can implement this trait for your provider
+    /// to provide dependency by value in many ways:
+    ///
     /// ```
     /// use provide::with::ProvideWith;
     ///
-    /// todo!()
+    /// struct Provider {
+    ///     foo: i32,
+    ///     bar: f32,
+    /// }
+    ///
+    /// // Context which provides sum of all the fields.
+    /// struct Sum;
+    ///
+    /// impl ProvideWith<f32, Sum> for Provider {
+    ///     type Remainder = ();
+    ///
+    ///     fn provide_with(self, _: Sum) -> (f32, Self::Remainder) {
+    ///         let Self { foo, bar } = self;
+    ///         (foo as f32 + bar, ())
+    ///     }
+    /// }
+    ///
+    /// let provider = Provider { foo: 1, bar: 2.0 };
+    /// let (dependency, _) = provider.provide_with(Sum);
+    /// assert_eq!(dependency, 3.0);
+    /// ```
+    ///
+    /// With [empty context](crate::context::Empty), this trait
+    /// falls back to the implementation of [`Provide`](crate::Provide) trait:
+    ///
+    /// ```
+    /// use provide::with::ProvideWith;
+    ///
+    /// let provider: i32 = 1;
+    /// let (dependency, _): (i64, _) = provider.provide_with(());
+    /// assert_eq!(dependency, 1);
     /// ```
     #[must_use = "this call returns dependency and remaining part of the provider"]
     fn provide_with(self, context: C) -> (T, Self::Remainder);
+
+    /// Provides dependency by *value*
+    /// with the context converted from provided value.
+    ///
+    /// See [`IntoContext`] trait for more.
+    fn provide_with_into<I, M>(self, context: I) -> (T, Self::Remainder)
+    where
+        I: IntoContext<M, Context = C>,
+    {
+        self.provide_with(context.into_context())
+    }
 }
 
 impl<T, U> ProvideWith<T, Empty> for U
@@ -47,6 +92,13 @@ where
 /// However, this trait allows to retrieve additional context provided by the caller,
 /// so it is possible to *define many ways* of how dependency can be provided.
 ///
+/// Unlike [`TryProvide`](crate::TryProvide) trait, this trait is not implemented
+/// for each provider which implements [`ProvideWith`] trait with the same context.
+/// Contexts which can fail on their own, such as [`FailAfterWith`](crate::context::failpoint::FailAfterWith),
+/// implement this trait with their own error, which such blanket implementation would conflict with.
+/// Providers which implement [`ProvideWith`] trait with custom context
+/// should implement this trait with the same context too.
+///
 /// See [crate] documentation for more.
 pub trait TryProvideWith<T, C>: Sized {
     /// Remaining part of the provider after providing dependency by value.
@@ -64,21 +116,68 @@ pub trait TryProvideWith<T, C>: Sized {
     /// ```
     /// use provide::with::TryProvideWith;
     ///
-    /// todo!()
+    /// struct Provider {
+    ///     foo: i32,
+    /// }
+    ///
+    /// // Context which provides only positive values.
+    /// struct Positive;
+    ///
+    /// impl TryProvideWith<u32, Positive> for Provider {
+    ///     type Remainder = ();
+    ///
+    ///     type Error = Self;
+    ///
+    ///     fn try_provide_with(self, _: Positive) -> Result<(u32, Self::Remainder), Self::Error> {
+    ///         match u32::try_from(self.foo) {
+    ///             Ok(foo) if foo > 0 => Ok((foo, ())),
+    ///             _ => Err(self),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let provider = Provider { foo: 1 };
+    /// let result = provider.try_provide_with(Positive);
+    /// assert!(matches!(result, Ok((1, ()))));
+    ///
+    /// let provider = Provider { foo: -1 };
+    /// let result = provider.try_provide_with(Positive);
+    /// assert!(result.is_err());
+    /// ```
+    ///
+    /// With [empty context](crate::context::Empty), this trait
+    /// falls back to the implementation of [`TryProvide`](crate::TryProvide) trait:
+    ///
+    /// ```
+    /// use provide::with::TryProvideWith;
+    ///
+    /// let provider: i32 = 1;
+    /// let result: Result<(i64, _), _> = provider.try_provide_with(());
+    /// assert_eq!(result, Ok((1, ())));
     /// ```
     fn try_provide_with(self, context: C) -> Result<(T, Self::Remainder), Self::Error>;
+
+    /// Tries to provide dependency by *value*
+    /// with the context converted from provided value on success.
+    ///
+    /// See [`IntoContext`] trait for more.
+    fn try_provide_with_into<I, M>(self, context: I) -> Result<(T, Self::Remainder), Self::Error>
+    where
+        I: IntoContext<M, Context = C>,
+    {
+        self.try_provide_with(context.into_context())
+    }
 }
 
-impl<T, U, C> TryProvideWith<T, C> for U
+impl<T, U> TryProvideWith<T, Empty> for U
 where
-    U: ProvideWith<T, C>,
+    U: TryProvide<T>,
 {
     type Remainder = U::Remainder;
 
-    type Error = Infallible;
+    type Error = U::Error;
 
-    fn try_provide_with(self, context: C) -> Result<(T, Self::Remainder), Self::Error> {
-        let provide_with = self.provide_with(context);
-        Ok(provide_with)
+    fn try_provide_with(self, _: Empty) -> Result<(T, Self::Remainder), Self::Error> {
+        self.try_provide()
     }
 }
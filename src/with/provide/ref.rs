@@ -1,6 +1,7 @@
-use core::convert::Infallible;
-
-use crate::{context::Empty, ProvideRef};
+use crate::{
+    context::{Empty, IntoContext},
+    ProvideRef, TryProvideRef,
+};
 
 /// Type of provider which provides dependency by *shared reference*,
 /// but with additional context provided by the caller.
@@ -16,12 +17,79 @@ pub trait ProvideRefWith<'me, T, C> {
     ///
     /// # Examples
     ///
+    /// You can implement this trait for your provider
+    /// to provide dependency by shared reference in many ways:
+    ///
     /// ```
     /// use provide::with::ProvideRefWith;
     ///
-    /// todo!()
+    /// struct Provider {
+    ///     foo: i32,
+    /// }
+    ///
+    /// // Context which provides doubled value.
+    /// struct Doubled;
+    ///
+    /// impl ProvideRefWith<'_, i32, Doubled> for Provider {
+    ///     fn provide_ref_with(&self, _: Doubled) -> i32 {
+    ///         let Self { foo } = self;
+    ///         foo * 2
+    ///     }
+    /// }
+    ///
+    /// let provider = Provider { foo: 1 };
+    /// let dependency: i32 = provider.provide_ref_with(Doubled);
+    /// assert_eq!(dependency, 2);
+    /// ```
+    ///
+    /// With [empty context](crate::context::Empty), this trait
+    /// falls back to the implementation of [`ProvideRef`](crate::ProvideRef) trait:
+    ///
+    /// ```
+    /// use provide::with::ProvideRefWith;
+    ///
+    /// let provider = vec![1, 2, 3];
+    /// let dependency: &[i32] = provider.provide_ref_with(());
+    /// assert_eq!(dependency, [1, 2, 3]);
     /// ```
     fn provide_ref_with(&'me self, context: C) -> T;
+
+    /// Provides dependency by *shared reference*
+    /// with the context converted from provided value.
+    ///
+    /// See [`IntoContext`] trait for more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::{context::Context, with::ProvideRefWith};
+    ///
+    /// struct Provider {
+    ///     foo: i32,
+    /// }
+    ///
+    /// // Context which provides doubled value.
+    /// struct Doubled;
+    ///
+    /// impl Context for Doubled {}
+    ///
+    /// impl ProvideRefWith<'_, i32, Doubled> for Provider {
+    ///     fn provide_ref_with(&self, _: Doubled) -> i32 {
+    ///         let Self { foo } = self;
+    ///         foo * 2
+    ///     }
+    /// }
+    ///
+    /// let provider = Provider { foo: 1 };
+    /// let dependency: i32 = provider.provide_ref_with_into(Doubled);
+    /// assert_eq!(dependency, 2);
+    /// ```
+    fn provide_ref_with_into<I, M>(&'me self, context: I) -> T
+    where
+        I: IntoContext<M, Context = C>,
+    {
+        self.provide_ref_with(context.into_context())
+    }
 }
 
 impl<'me, T, U> ProvideRefWith<'me, T, Empty> for U
@@ -40,6 +108,13 @@ where
 /// However, this trait allows to retrieve additional context provided by the caller,
 /// so it is possible to *define many ways* of how dependency can be provided.
 ///
+/// Unlike [`TryProvideRef`](crate::TryProvideRef) trait, this trait is not implemented
+/// for each provider which implements [`ProvideRefWith`] trait with the same context.
+/// Contexts which can fail on their own, such as [`FailAfterWith`](crate::context::failpoint::FailAfterWith),
+/// implement this trait with their own error, which such blanket implementation would conflict with.
+/// Providers which implement [`ProvideRefWith`] trait with custom context
+/// should implement this trait with the same context too.
+///
 /// See [crate] documentation for more.
 pub trait TryProvideRefWith<'me, T, C> {
     /// The type returned in the event of an error.
@@ -53,19 +128,84 @@ pub trait TryProvideRefWith<'me, T, C> {
     /// ```
     /// use provide::with::TryProvideRefWith;
     ///
-    /// todo!()
+    /// struct Provider {
+    ///     foo: i32,
+    /// }
+    ///
+    /// // Context which provides only positive values.
+    /// struct Positive;
+    ///
+    /// impl TryProvideRefWith<'_, u32, Positive> for Provider {
+    ///     type Error = ();
+    ///
+    ///     fn try_provide_ref_with(&self, _: Positive) -> Result<u32, Self::Error> {
+    ///         let Self { foo } = self;
+    ///         u32::try_from(*foo).ok().filter(|foo| *foo > 0).ok_or(())
+    ///     }
+    /// }
+    ///
+    /// let provider = Provider { foo: 1 };
+    /// assert_eq!(provider.try_provide_ref_with(Positive), Ok(1));
+    ///
+    /// let provider = Provider { foo: -1 };
+    /// assert_eq!(provider.try_provide_ref_with(Positive), Err(()));
+    /// ```
+    ///
+    /// With [empty context](crate::context::Empty), this trait
+    /// falls back to the implementation of [`TryProvideRef`](crate::TryProvideRef) trait:
+    ///
+    /// ```
+    /// use provide::with::TryProvideRefWith;
+    ///
+    /// let provider = vec![1, 2, 3];
+    /// let dependency: Result<&[i32], _> = provider.try_provide_ref_with(());
+    /// assert_eq!(dependency, Ok([1, 2, 3].as_slice()));
     /// ```
     fn try_provide_ref_with(&'me self, context: C) -> Result<T, Self::Error>;
+
+    /// Tries to provide dependency by *shared reference*
+    /// with the context converted from provided value on success.
+    ///
+    /// See [`IntoContext`] trait for more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "parking_lot")] {
+    /// use std::{sync::Arc, time::Duration};
+    ///
+    /// use parking_lot::{Mutex, MutexGuard};
+    /// use provide::{
+    ///     context::lock::{TimedOut, TryAcquireError},
+    ///     with::TryProvideRefWith,
+    /// };
+    ///
+    /// let provider = Arc::new(Mutex::new(1));
+    /// let timeout = Duration::from_millis(10);
+    ///
+    /// // Timeout is converted into the context of lock acquisition.
+    /// let guard: Result<MutexGuard<i32>, _> = provider.try_provide_ref_with_into(timeout);
+    /// assert_eq!(guard.as_deref(), Ok(&1));
+    ///
+    /// let other: Result<MutexGuard<i32>, _> = provider.try_provide_ref_with_into(timeout);
+    /// assert_eq!(other.err(), Some(TryAcquireError::Acquire(TimedOut)));
+    /// # }
+    /// ```
+    fn try_provide_ref_with_into<I, M>(&'me self, context: I) -> Result<T, Self::Error>
+    where
+        I: IntoContext<M, Context = C>,
+    {
+        self.try_provide_ref_with(context.into_context())
+    }
 }
 
-impl<'me, T, U, C> TryProvideRefWith<'me, T, C> for U
+impl<'me, T, U> TryProvideRefWith<'me, T, Empty> for U
 where
-    U: ProvideRefWith<'me, T, C> + ?Sized,
+    U: TryProvideRef<'me, T> + ?Sized,
 {
-    type Error = Infallible;
+    type Error = U::Error;
 
-    fn try_provide_ref_with(&'me self, context: C) -> Result<T, Self::Error> {
-        let provide_ref_with = self.provide_ref_with(context);
-        Ok(provide_ref_with)
+    fn try_provide_ref_with(&'me self, _: Empty) -> Result<T, Self::Error> {
+        self.try_provide_ref()
     }
 }
@@ -1,5 +1,7 @@
 #![allow(clippy::module_inception)]
 
+use core::convert::Infallible;
+
 /// Type of provider which can be created from provided dependency.
 ///
 /// This trait can be used to emulate extension of self type with dependency type,
@@ -17,7 +19,8 @@ pub trait With<T>: Sized {
     /// ```
     /// use provide::with::With;
     ///
-    /// todo!()
+    /// let provider = ().with(1);
+    /// assert_eq!(provider, 1);
     /// ```
     #[must_use]
     fn with(self, dependency: T) -> Self::Output;
@@ -30,3 +33,175 @@ impl<T> With<T> for () {
         dependency
     }
 }
+
+/// Type of provider which can be created from provided dependency or fail.
+///
+/// This trait is the fallible counterpart of [`With`] trait:
+/// it allows to reject provided dependency, for example,
+/// if the capacity of the provider is exceeded or some invariant is violated.
+/// Fallible provision which reassembles the remainder with provided dependency,
+/// such as provision with [`RestoreWith`](crate::context::restore::RestoreWith) context,
+/// relies on this trait to report such rejection as an error.
+///
+/// Unlike fallible provider traits, this trait is not implemented
+/// for each type which implements [`With`] trait, so such types could reject dependencies
+/// which they otherwise accept infallibly. Types of this crate which never reject
+/// dependencies implement this trait with [`Infallible`] error.
+///
+/// See [crate] documentation for more.
+pub trait TryWith<T>: Sized {
+    /// Type of new provider with provided dependency.
+    type Output;
+
+    /// The type returned in the event of an error.
+    type Error;
+
+    /// Tries to create new provider from the self and provided dependency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::with::TryWith;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Workers(Vec<&'static str>);
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct CapacityExceeded(&'static str);
+    ///
+    /// impl TryWith<&'static str> for Workers {
+    ///     type Output = Self;
+    ///
+    ///     type Error = CapacityExceeded;
+    ///
+    ///     fn try_with(self, dependency: &'static str) -> Result<Self::Output, Self::Error> {
+    ///         let Self(mut workers) = self;
+    ///         if workers.len() >= 2 {
+    ///             return Err(CapacityExceeded(dependency));
+    ///         }
+    ///         workers.push(dependency);
+    ///         Ok(Self(workers))
+    ///     }
+    /// }
+    ///
+    /// let provider = Workers(vec![]).try_with("first").unwrap().try_with("second").unwrap();
+    /// assert_eq!(provider.try_with("third"), Err(CapacityExceeded("third")));
+    ///
+    /// let provider = ().try_with(1);
+    /// assert_eq!(provider, Ok(1));
+    /// ```
+    fn try_with(self, dependency: T) -> Result<Self::Output, Self::Error>;
+}
+
+impl<T> TryWith<T> for () {
+    type Output = T;
+
+    type Error = Infallible;
+
+    fn try_with(self, dependency: T) -> Result<Self::Output, Self::Error> {
+        Ok(dependency)
+    }
+}
+
+/// Type of provider which can be created from provided dependency
+/// by placing it at the *front* of the tuple.
+///
+/// Position of the dependency matters because dependencies are usually
+/// provided and destructured from the remainders in order.
+/// Use [`WithBack`] trait to place dependency at the back of the tuple instead.
+///
+/// See [crate] documentation for more.
+pub trait WithFront<T>: Sized {
+    /// Type of new provider with provided dependency at the front.
+    type Output;
+
+    /// Creates new provider from the self and provided dependency placed at the front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::with::WithFront;
+    ///
+    /// let provider = (2, 3).with_front(1);
+    /// assert_eq!(provider, (1, 2, 3));
+    /// ```
+    #[must_use]
+    fn with_front(self, dependency: T) -> Self::Output;
+}
+
+/// Type of provider which can be created from provided dependency
+/// by placing it at the *back* of the tuple.
+///
+/// Position of the dependency matters because dependencies are usually
+/// provided and destructured from the remainders in order.
+/// Use [`WithFront`] trait to place dependency at the front of the tuple instead.
+///
+/// See [crate] documentation for more.
+pub trait WithBack<T>: Sized {
+    /// Type of new provider with provided dependency at the back.
+    type Output;
+
+    /// Creates new provider from the self and provided dependency placed at the back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use provide::with::WithBack;
+    ///
+    /// let provider = (1, 2).with_back(3);
+    /// assert_eq!(provider, (1, 2, 3));
+    /// ```
+    #[must_use]
+    fn with_back(self, dependency: T) -> Self::Output;
+}
+
+macro_rules! impl_with_front_back {
+    () => {
+        impl<T> WithFront<T> for () {
+            type Output = (T,);
+
+            fn with_front(self, dependency: T) -> Self::Output {
+                (dependency,)
+            }
+        }
+
+        impl<T> WithBack<T> for () {
+            type Output = (T,);
+
+            fn with_back(self, dependency: T) -> Self::Output {
+                (dependency,)
+            }
+        }
+    };
+    ($head:ident $(, $tail:ident)* $(,)?) => {
+        impl<T, $head, $($tail),*> WithFront<T> for ($head, $($tail,)*) {
+            type Output = (T, $head, $($tail,)*);
+
+            #[allow(non_snake_case)]
+            fn with_front(self, dependency: T) -> Self::Output {
+                let ($head, $($tail,)*) = self;
+                (dependency, $head, $($tail,)*)
+            }
+        }
+
+        impl<T, $head, $($tail),*> WithBack<T> for ($head, $($tail,)*) {
+            type Output = ($head, $($tail,)* T);
+
+            #[allow(non_snake_case)]
+            fn with_back(self, dependency: T) -> Self::Output {
+                let ($head, $($tail,)*) = self;
+                ($head, $($tail,)* dependency)
+            }
+        }
+
+        impl_with_front_back!($($tail),*);
+    };
+}
+
+#[cfg(not(feature = "tuples_32"))]
+impl_with_front_back!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
+#[cfg(feature = "tuples_32")]
+impl_with_front_back!(
+    T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17, T18, T19, T20, T21,
+    T22, T23, T24, T25, T26, T27, T28, T29, T30, T31
+);
@@ -0,0 +1,72 @@
+use provide::{
+    alias,
+    context::{alias::AliasDependency, Context},
+    with::{ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideRefWith},
+};
+
+#[derive(Debug, PartialEq)]
+struct Port(u16);
+
+#[derive(Debug, PartialEq)]
+struct Host(String);
+
+alias!(Port => u16, Host => String);
+
+struct Config {
+    port: Port,
+    host: Host,
+}
+
+impl AsRef<Port> for Config {
+    fn as_ref(&self) -> &Port {
+        let Self { port, .. } = self;
+        port
+    }
+}
+
+impl AsRef<Host> for Config {
+    fn as_ref(&self) -> &Host {
+        let Self { host, .. } = self;
+        host
+    }
+}
+
+impl AsMut<Host> for Config {
+    fn as_mut(&mut self) -> &mut Host {
+        let Self { host, .. } = self;
+        host
+    }
+}
+
+#[test]
+fn by_value() {
+    let (port, ()): (u16, _) = Port(8080).provide_with(AliasDependency::<Port>::default());
+    assert_eq!(port, 8080);
+}
+
+#[test]
+fn by_ref() {
+    let config = Config {
+        port: Port(5432),
+        host: Host(String::from("localhost")),
+    };
+
+    let port: &u16 = config.provide_ref_with(AliasDependency::<Port>::default());
+    assert_eq!(*port, 5432);
+
+    let host: Result<Option<&String>, _> =
+        config.try_provide_ref_with(().then_alias::<Host>().then_some());
+    assert_eq!(host, Ok(Some(&String::from("localhost"))));
+}
+
+#[test]
+fn by_mut() {
+    let mut config = Config {
+        port: Port(5432),
+        host: Host(String::from("localhost")),
+    };
+
+    let host: &mut String = config.provide_mut_with(AliasDependency::<Host>::default());
+    host.push_str(":5432");
+    assert_eq!(config.host, Host(String::from("localhost:5432")));
+}
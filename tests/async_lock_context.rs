@@ -0,0 +1,101 @@
+#![cfg(any(feature = "tokio", feature = "async-lock"))]
+
+#[cfg(feature = "tokio")]
+mod tokio {
+    use std::sync::Arc;
+
+    use futures::executor::block_on;
+    use provide::{
+        context::lock::{Lock, Read, TryAcquireError, TryLock, TryWrite, WouldBlock, Write},
+        with::{ProvideMutAsyncWith, ProvideRefAsyncWith, TryProvideRefWith},
+    };
+    use tokio::sync::{Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+    #[test]
+    fn mutex() {
+        block_on(async {
+            let provider = Arc::new(Mutex::new(1));
+
+            let mut guard: MutexGuard<i32> = provider.provide_ref_async_with(Lock::default()).await;
+            *guard += 1;
+
+            let other: Result<MutexGuard<i32>, _> =
+                provider.try_provide_ref_with(TryLock::default());
+            assert_eq!(other.err(), Some(TryAcquireError::Acquire(WouldBlock)));
+
+            drop(guard);
+            let guard: MutexGuard<i32> = provider.provide_ref_async_with(Lock::default()).await;
+            assert_eq!(*guard, 2);
+        });
+    }
+
+    #[test]
+    fn rw_lock() {
+        block_on(async {
+            let mut provider = Box::new(RwLock::new(1));
+
+            let mut writer: RwLockWriteGuard<i32> =
+                provider.provide_mut_async_with(Write::default()).await;
+            *writer += 1;
+            drop(writer);
+
+            let guard: RwLockReadGuard<i32> =
+                provider.provide_ref_async_with(Read::default()).await;
+            assert_eq!(*guard, 2);
+
+            let writer: Result<RwLockWriteGuard<i32>, _> =
+                provider.try_provide_ref_with(TryWrite::default());
+            assert_eq!(writer.err(), Some(TryAcquireError::Acquire(WouldBlock)));
+        });
+    }
+}
+
+#[cfg(feature = "async-lock")]
+mod async_lock {
+    use std::sync::Arc;
+
+    use async_lock::{Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard};
+    use futures::executor::block_on;
+    use provide::{
+        context::lock::{Lock, Read, TryAcquireError, TryLock, TryWrite, WouldBlock, Write},
+        with::{ProvideMutAsyncWith, ProvideRefAsyncWith, TryProvideRefWith},
+    };
+
+    #[test]
+    fn mutex() {
+        block_on(async {
+            let provider = Arc::new(Mutex::new(1));
+
+            let mut guard: MutexGuard<i32> = provider.provide_ref_async_with(Lock::default()).await;
+            *guard += 1;
+
+            let other: Result<MutexGuard<i32>, _> =
+                provider.try_provide_ref_with(TryLock::default());
+            assert!(matches!(other, Err(TryAcquireError::Acquire(WouldBlock))));
+
+            drop(guard);
+            let guard: MutexGuard<i32> = provider.provide_ref_async_with(Lock::default()).await;
+            assert_eq!(*guard, 2);
+        });
+    }
+
+    #[test]
+    fn rw_lock() {
+        block_on(async {
+            let mut provider = Box::new(RwLock::new(1));
+
+            let mut writer: RwLockWriteGuard<i32> =
+                provider.provide_mut_async_with(Write::default()).await;
+            *writer += 1;
+            drop(writer);
+
+            let guard: RwLockReadGuard<i32> =
+                provider.provide_ref_async_with(Read::default()).await;
+            assert_eq!(*guard, 2);
+
+            let writer: Result<RwLockWriteGuard<i32>, _> =
+                provider.try_provide_ref_with(TryWrite::default());
+            assert!(matches!(writer, Err(TryAcquireError::Acquire(WouldBlock))));
+        });
+    }
+}
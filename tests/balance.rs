@@ -0,0 +1,84 @@
+use provide::{
+    balance::{NoProviders, RoundRobin},
+    context::{balance::Rotate, Context},
+    with::{ProvideMutWith, ProvideRefWith},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Client {
+    host: &'static str,
+    requests: u32,
+}
+
+impl AsRef<Client> for Client {
+    fn as_ref(&self) -> &Client {
+        self
+    }
+}
+
+impl AsMut<Client> for Client {
+    fn as_mut(&mut self) -> &mut Client {
+        self
+    }
+}
+
+#[test]
+fn rotation() {
+    let clients = [
+        Client {
+            host: "a",
+            requests: 0,
+        },
+        Client {
+            host: "b",
+            requests: 0,
+        },
+        Client {
+            host: "c",
+            requests: 0,
+        },
+    ];
+    let mut provider = RoundRobin::new(clients).unwrap();
+
+    for _ in 0..4 {
+        let client: &mut Client = provider.provide_mut_with(Rotate::default());
+        client.requests += 1;
+    }
+    let client: &Client = provider.provide_ref_with(().then_rotate());
+    assert_eq!(client.host, "b");
+
+    let hosts = [(); 3].map(|()| provider.next_provider().host);
+    assert_eq!(hosts, ["c", "a", "b"]);
+
+    let clients = provider.into_inner();
+    assert_eq!(clients.map(|client| client.requests), [2, 1, 1]);
+}
+
+#[test]
+fn empty() {
+    let provider = RoundRobin::new([] as [Client; 0]);
+    assert_eq!(provider.err(), Some(NoProviders));
+
+    let provider = RoundRobin::new(Vec::<Client>::new());
+    assert_eq!(provider.err(), Some(NoProviders));
+}
+
+#[test]
+fn slice() {
+    let clients = [
+        Client {
+            host: "a",
+            requests: 0,
+        },
+        Client {
+            host: "b",
+            requests: 0,
+        },
+    ];
+    let provider: &RoundRobin<[Client]> = &RoundRobin::new(clients).unwrap();
+
+    let client: &Client = provider.provide_ref_with(Rotate::default());
+    assert_eq!(client.host, "a");
+    let client: &Client = provider.provide_ref_with(Rotate::default());
+    assert_eq!(client.host, "b");
+}
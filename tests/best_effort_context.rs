@@ -0,0 +1,100 @@
+use core::convert::Infallible;
+
+use provide::{
+    best_effort::Degraded,
+    context::{best_effort::BestEffort, failpoint::FailAfter, Context},
+    failpoint::{Failpoint, FailpointError},
+    with::{ProvideMutWith, ProvideRefWith, ProvideWith},
+    ProvideRef,
+};
+
+#[derive(Debug, Default, PartialEq)]
+struct Config {
+    retries: u32,
+}
+
+struct Provider {
+    config: Config,
+}
+
+impl AsRef<Config> for Provider {
+    fn as_ref(&self) -> &Config {
+        let Self { config } = self;
+        config
+    }
+}
+
+impl AsMut<Config> for Provider {
+    fn as_mut(&mut self) -> &mut Config {
+        let Self { config } = self;
+        config
+    }
+}
+
+impl ProvideRef<'_, u32> for Provider {
+    fn provide_ref(&self) -> u32 {
+        let Self { config } = self;
+        config.retries
+    }
+}
+
+#[test]
+fn by_value() {
+    let provider = Failpoint::new(Config { retries: 3 });
+    let (config, remainder): (Degraded<Config, _>, _) =
+        provider.provide_with(().then_fail_after(usize::MAX).then_best_effort());
+    assert_eq!(config, Degraded::full(Config { retries: 3 }));
+    assert_eq!(remainder, Some(()));
+
+    let provider = Failpoint::new(Config { retries: 3 });
+    let (config, remainder): (Degraded<Config, _>, _) =
+        provider.provide_with(().then_fail_after(0).then_best_effort());
+    assert_eq!(
+        config,
+        Degraded::fallback(Config::default(), FailpointError::Injected),
+    );
+    assert_eq!(remainder, None);
+}
+
+#[test]
+fn by_ref() {
+    let provider = Provider {
+        config: Config { retries: 3 },
+    };
+    let retries: Degraded<u32, Infallible> = provider.provide_ref_with(BestEffort::default());
+    assert_eq!(retries, Degraded::full(3));
+
+    let mut provider = Failpoint::new(provider);
+
+    let config: Degraded<Option<&Config>, _> = provider.provide_ref_with(
+        FailAfter::new(usize::MAX, ())
+            .then_some()
+            .then_best_effort(),
+    );
+    assert!(!config.is_degraded());
+    assert_eq!(config.into_inner(), Some(&Config { retries: 3 }));
+
+    let config: Degraded<Option<&mut Config>, _> = provider.provide_mut_with(
+        FailAfter::new(usize::MAX, ())
+            .then_some()
+            .then_best_effort(),
+    );
+    assert!(!config.is_degraded());
+}
+
+#[test]
+fn by_ref_degraded() {
+    let mut provider = Failpoint::new(Provider {
+        config: Config { retries: 3 },
+    });
+
+    let config: Degraded<Option<&Config>, _> =
+        provider.provide_ref_with(().then_fail_after(0).then_some().then_best_effort());
+    assert!(config.is_degraded());
+    assert_eq!(config.dependency(), &None);
+    assert_eq!(config.error(), Some(&FailpointError::Injected));
+
+    let config: Degraded<Option<&mut Config>, _> =
+        provider.provide_mut_with(().then_fail_after(0).then_some().then_best_effort());
+    assert_eq!(config.into_parts(), (None, Some(FailpointError::Injected)));
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "bench")]
+
+use provide::{
+    black_box::BlackBox,
+    context::{black_box::BlackBoxDependency, Context},
+    with::{
+        ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideMutWith, TryProvideRefWith,
+        TryProvideWith,
+    },
+};
+
+#[test]
+fn by_value() {
+    let provider = BlackBox::new(42_u8);
+
+    let (dependency, ()): (u16, _) = provider.provide_with(BlackBoxDependency::default());
+    assert_eq!(dependency, 42);
+
+    let result: Result<(u32, _), _> = provider.try_provide_with(().then_black_box());
+    assert!(matches!(result, Ok((42, ()))));
+}
+
+#[test]
+fn by_ref() {
+    let provider = BlackBox::new(Box::new(42));
+
+    let dependency: &i32 = provider.provide_ref_with(BlackBoxDependency::default());
+    assert_eq!(*dependency, 42);
+
+    let dependency: Option<&i32> = provider.provide_ref_with(().then_some().then_black_box());
+    assert_eq!(dependency, Some(&42));
+
+    let result: Result<&i32, _> = provider.try_provide_ref_with(BlackBoxDependency::default());
+    assert_eq!(result, Ok(&42));
+    assert_eq!(**provider.get_ref(), 42);
+}
+
+#[test]
+fn by_mut() {
+    let mut provider = BlackBox::new(Box::new(42));
+
+    let dependency: &mut i32 = provider.provide_mut_with(BlackBoxDependency::default());
+    *dependency += 1;
+
+    let result: Result<&mut i32, _> = provider.try_provide_mut_with(().then_black_box());
+    assert_eq!(result, Ok(&mut 43));
+
+    **provider.get_mut() += 1;
+    assert_eq!(*provider.into_inner(), 44);
+}
@@ -0,0 +1,53 @@
+use std::path::{Path, PathBuf};
+
+use provide::{
+    context::{borrow::BorrowDependency, Context},
+    with::{ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideRefWith},
+};
+
+struct Config {
+    name: String,
+    root: PathBuf,
+}
+
+impl AsRef<String> for Config {
+    fn as_ref(&self) -> &String {
+        let Self { name, .. } = self;
+        name
+    }
+}
+
+impl AsMut<String> for Config {
+    fn as_mut(&mut self) -> &mut String {
+        let Self { name, .. } = self;
+        name
+    }
+}
+
+impl AsRef<PathBuf> for Config {
+    fn as_ref(&self) -> &PathBuf {
+        let Self { root, .. } = self;
+        root
+    }
+}
+
+#[test]
+fn borrow() {
+    let mut provider = Config {
+        name: String::from("service"),
+        root: PathBuf::from("/srv"),
+    };
+
+    let name: &str = provider.provide_ref_with(BorrowDependency::<String>::default());
+    assert_eq!(name, "service");
+
+    let root: Result<&Path, _> = provider.try_provide_ref_with(().then_borrow::<PathBuf>());
+    assert_eq!(root, Ok(Path::new("/srv")));
+
+    let name: &mut str = provider.provide_mut_with(().then_borrow::<String>());
+    name.make_ascii_uppercase();
+    assert_eq!(provider.name, "SERVICE");
+
+    let (name, _): (&str, _) = (&provider.name).provide_with(().then_borrow::<String>());
+    assert_eq!(name, "SERVICE");
+}
@@ -0,0 +1,87 @@
+#![cfg(feature = "alloc")]
+
+use provide::{
+    context::{
+        bridge::{BorrowBytes, BorrowStr, OwnBytes, OwnStr},
+        Context,
+    },
+    with::{ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideRefWith},
+};
+
+struct Config {
+    name: Box<str>,
+    url: String,
+    key: Vec<u8>,
+}
+
+impl AsRef<str> for Config {
+    fn as_ref(&self) -> &str {
+        let Self { name, .. } = self;
+        name
+    }
+}
+
+impl AsRef<String> for Config {
+    fn as_ref(&self) -> &String {
+        let Self { url, .. } = self;
+        url
+    }
+}
+
+impl AsMut<String> for Config {
+    fn as_mut(&mut self) -> &mut String {
+        let Self { url, .. } = self;
+        url
+    }
+}
+
+impl AsRef<Vec<u8>> for Config {
+    fn as_ref(&self) -> &Vec<u8> {
+        let Self { key, .. } = self;
+        key
+    }
+}
+
+#[test]
+fn strings() {
+    let mut provider = Config {
+        name: Box::from("service"),
+        url: String::from("postgres://localhost"),
+        key: b"secret".to_vec(),
+    };
+
+    let name: String = provider.provide_ref_with(OwnStr::default());
+    assert_eq!(name, "service");
+
+    let url: &str = provider.provide_ref_with(BorrowStr::default());
+    assert_eq!(url, "postgres://localhost");
+
+    let url: Result<String, _> = provider.try_provide_ref_with(().then_borrow_str().then_own_str());
+    assert_eq!(url.as_deref(), Ok("postgres://localhost"));
+
+    let url: &mut str = provider.provide_mut_with(().then_borrow_str());
+    url.make_ascii_uppercase();
+    assert_eq!(provider.url, "POSTGRES://LOCALHOST");
+
+    let (url, _): (&str, _) = (&provider.url).provide_with(().then_borrow_str());
+    assert_eq!(url, "POSTGRES://LOCALHOST");
+}
+
+#[test]
+fn bytes() {
+    let provider = Config {
+        name: Box::from("service"),
+        url: String::from("postgres://localhost"),
+        key: b"secret".to_vec(),
+    };
+
+    let key: &[u8] = provider.provide_ref_with(BorrowBytes::default());
+    assert_eq!(key, b"secret");
+
+    let key: Vec<u8> = provider.provide_ref_with(().then_borrow_bytes().then_own_bytes());
+    assert_eq!(key, b"secret");
+
+    let mut bytes: Box<[u8]> = Box::new(*b"public");
+    let key: Vec<u8> = bytes.provide_mut_with(OwnBytes::default());
+    assert_eq!(key, b"public");
+}
@@ -0,0 +1,72 @@
+use provide::{bundle, bundle::Bundle, Provide};
+
+#[derive(Debug, PartialEq)]
+struct Config(&'static str);
+
+#[derive(Debug, PartialEq)]
+struct Database(u16);
+
+#[derive(Debug, PartialEq)]
+struct Cache(u32);
+
+struct Provider {
+    config: Config,
+    database: Database,
+    cache: Cache,
+}
+
+struct WithoutConfig {
+    database: Database,
+    cache: Cache,
+}
+
+impl Provide<Config> for Provider {
+    type Remainder = WithoutConfig;
+
+    fn provide(self) -> (Config, Self::Remainder) {
+        let Self {
+            config,
+            database,
+            cache,
+        } = self;
+        (config, WithoutConfig { database, cache })
+    }
+}
+
+impl Provide<Database> for WithoutConfig {
+    type Remainder = Cache;
+
+    fn provide(self) -> (Database, Self::Remainder) {
+        let Self { database, cache } = self;
+        (database, cache)
+    }
+}
+
+bundle! {
+    #[derive(Debug)]
+    struct Storage {
+        config: Config,
+        database: Database,
+    }
+}
+
+bundle! {
+    struct Nothing {}
+}
+
+#[test]
+fn provided_in_order() {
+    let provider = Provider {
+        config: Config("localhost"),
+        database: Database(5432),
+        cache: Cache(64),
+    };
+
+    let (storage, remainder) = Storage::provide_bundle(provider);
+    assert_eq!(storage.config, Config("localhost"));
+    assert_eq!(storage.database, Database(5432));
+    assert_eq!(remainder, Cache(64));
+
+    let (Nothing {}, remainder) = Nothing::provide_bundle(remainder);
+    assert_eq!(remainder, Cache(64));
+}
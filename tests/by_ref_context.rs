@@ -0,0 +1,77 @@
+use provide::{
+    context::{by_ref::ByRef, Context},
+    failpoint::{Failpoint, FailpointError},
+    with::{ProvideWith, TryProvideWith},
+};
+
+struct Provider {
+    name: String,
+}
+
+impl AsRef<str> for Provider {
+    fn as_ref(&self) -> &str {
+        let Self { name } = self;
+        name
+    }
+}
+
+impl AsMut<String> for Provider {
+    fn as_mut(&mut self) -> &mut String {
+        let Self { name } = self;
+        name
+    }
+}
+
+/// Accepts any provider by value, so borrowed providers need [`ByRef`] context.
+fn provide_twice<'a, P>(provider: P) -> [&'a str; 2]
+where
+    P: ProvideWith<&'a str, ByRef>,
+    P::Remainder: ProvideWith<&'a str, ByRef>,
+{
+    let (first, remainder) = provider.provide_with(ByRef::default());
+    let (second, _) = remainder.provide_with(ByRef::default());
+    [first, second]
+}
+
+#[test]
+fn by_shared() {
+    let provider = Provider {
+        name: String::from("hello"),
+    };
+
+    assert_eq!(provide_twice(&provider), ["hello", "hello"]);
+
+    let (name, remainder): (Option<&str>, _) =
+        (&provider).provide_with(().then_some().then_by_ref());
+    assert_eq!(name, Some("hello"));
+    assert!(core::ptr::eq(remainder, &provider));
+}
+
+#[test]
+fn by_unique() {
+    let mut provider = Provider {
+        name: String::from("hello"),
+    };
+
+    let (name, ()): (&mut String, _) = (&mut provider).provide_with(ByRef::default());
+    name.push_str(", world");
+    assert_eq!(provider.name, "hello, world");
+}
+
+#[test]
+fn fallible() {
+    let mut provider = Failpoint::new(Provider {
+        name: String::from("hello"),
+    });
+
+    let result: Result<(&str, _), _> =
+        (&provider).try_provide_with(().then_fail_after(usize::MAX).then_by_ref());
+    assert_eq!(result.map(|(name, _)| name), Ok("hello"));
+    let result: Result<(&str, _), _> =
+        (&provider).try_provide_with(().then_fail_after(0).then_by_ref());
+    assert_eq!(result.map(|(name, _)| name), Err(FailpointError::Injected));
+
+    let result: Result<(&mut String, ()), _> =
+        (&mut provider).try_provide_with(().then_fail_after(0).then_by_ref());
+    assert_eq!(result, Err(FailpointError::Injected));
+}
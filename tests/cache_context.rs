@@ -0,0 +1,79 @@
+use std::cell::Cell;
+
+use provide::{
+    context::{cache::CachedDependency, Context},
+    failpoint::{Failpoint, FailpointError},
+    with::{ProvideMutWith, ProvideRefWith, TryProvideRefWith},
+    ProvideMut, ProvideRef,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Config(u32);
+
+#[derive(Default)]
+struct Provider {
+    loads: Cell<u32>,
+}
+
+impl<'me> ProvideRef<'me, Config> for Provider {
+    fn provide_ref(&'me self) -> Config {
+        let Self { loads } = self;
+        loads.set(loads.get() + 1);
+        Config(loads.get())
+    }
+}
+
+impl<'me> ProvideMut<'me, Config> for Provider {
+    fn provide_mut(&'me mut self) -> Config {
+        self.provide_ref()
+    }
+}
+
+#[test]
+fn cached() {
+    let mut provider = Provider::default();
+    let mut context = CachedDependency::default();
+    assert_eq!(context.cached(), None);
+
+    let dependency: Config = provider.provide_ref_with(&mut context);
+    assert_eq!(dependency, Config(1));
+    let dependency: Config = provider.provide_mut_with(&mut context);
+    assert_eq!(dependency, Config(1));
+    assert_eq!(context.cached(), Some(&Config(1)));
+    assert_eq!(provider.loads.get(), 1);
+
+    assert_eq!(context.reset(), Some(Config(1)));
+    let dependency: Config = provider.provide_ref_with(&mut context);
+    assert_eq!(dependency, Config(2));
+
+    let (cached, ()) = context.into_inner();
+    assert_eq!(cached, Some(Config(2)));
+}
+
+#[test]
+fn inner_context() {
+    let provider = Provider::default();
+    let mut context = ().then_some().then_cache();
+
+    for _ in 0..3 {
+        let dependency: Option<Config> = provider.provide_ref_with(&mut context);
+        assert_eq!(dependency, Some(Config(1)));
+    }
+    assert_eq!(provider.loads.get(), 1);
+}
+
+#[test]
+fn not_cached_on_error() {
+    let provider = Failpoint::new(Provider::default());
+
+    let mut context = ().then_fail_after(0).then_cache();
+    let dependency: Result<Config, _> = provider.try_provide_ref_with(&mut context);
+    assert_eq!(dependency, Err(FailpointError::Injected));
+    assert_eq!(context.cached(), None);
+
+    let mut context = ().then_fail_after(1).then_cache();
+    let dependency: Result<Config, _> = provider.try_provide_ref_with(&mut context);
+    assert_eq!(dependency, Ok(Config(1)));
+    let dependency: Result<Config, _> = provider.try_provide_ref_with(&mut context);
+    assert_eq!(dependency, Ok(Config(1)));
+}
@@ -0,0 +1,63 @@
+use core::cell::Cell;
+
+use provide::{
+    context::{
+        cell::{ReplaceCell, TakeCell},
+        Context,
+    },
+    failpoint::{Failpoint, FailpointError},
+    with::{ProvideRefWith, TryProvideRefWith},
+};
+
+struct Provider {
+    counter: Cell<u32>,
+}
+
+impl AsRef<Cell<u32>> for Provider {
+    fn as_ref(&self) -> &Cell<u32> {
+        let Self { counter } = self;
+        counter
+    }
+}
+
+#[test]
+fn replace() {
+    let provider = Provider {
+        counter: Cell::new(1),
+    };
+
+    let previous: u32 = provider.provide_ref_with(ReplaceCell::new(2, ()));
+    assert_eq!(previous, 1);
+    let previous: u32 = provider.provide_ref_with(().then_replace_cell(3));
+    assert_eq!(previous, 2);
+    assert_eq!(provider.counter.get(), 3);
+}
+
+#[test]
+fn take() {
+    let provider = Provider {
+        counter: Cell::new(1),
+    };
+
+    let previous: u32 = provider.provide_ref_with(TakeCell::default());
+    assert_eq!(previous, 1);
+    let previous: u32 = provider.provide_ref_with(().then_take_cell());
+    assert_eq!(previous, 0);
+}
+
+#[test]
+fn fallible() {
+    let provider = Failpoint::new(Provider {
+        counter: Cell::new(1),
+    });
+
+    let previous: Result<u32, _> =
+        provider.try_provide_ref_with(().then_fail_after(0).then_take_cell());
+    assert_eq!(previous, Err(FailpointError::Injected));
+    let previous: Result<u32, _> =
+        provider.try_provide_ref_with(().then_fail_after(usize::MAX).then_replace_cell(2));
+    assert_eq!(previous, Ok(1));
+
+    let provider = provider.into_inner();
+    assert_eq!(provider.counter.get(), 2);
+}
@@ -0,0 +1,81 @@
+#![cfg(any(feature = "futures", feature = "tokio"))]
+
+#[cfg(feature = "futures")]
+mod futures {
+    use futures::{channel::oneshot, executor::block_on};
+    use provide::{
+        context::{channel::Receive, Context},
+        with::TryProvideAsyncWith,
+    };
+
+    #[test]
+    fn oneshot() {
+        block_on(async {
+            let (sender, receiver) = oneshot::channel();
+            sender.send(1).unwrap();
+
+            let result: Result<(i32, _), _> =
+                receiver.try_provide_async_with(().then_receive()).await;
+            assert_eq!(result, Ok((1, ())));
+        });
+    }
+
+    #[test]
+    fn oneshot_canceled() {
+        block_on(async {
+            let (sender, receiver) = oneshot::channel::<i32>();
+            drop(sender);
+
+            let result: Result<(i32, _), _> =
+                receiver.try_provide_async_with(Receive::default()).await;
+            assert!(result.is_err());
+        });
+    }
+}
+
+#[cfg(feature = "tokio")]
+mod tokio {
+    use futures::executor::block_on;
+    use provide::{
+        context::channel::{Changed, Latest},
+        with::{ProvideRefAsyncWith, TryProvideMutAsyncWith},
+    };
+    use tokio::sync::watch::{self, Ref};
+
+    #[test]
+    fn watch_latest() {
+        block_on(async {
+            let (sender, receiver) = watch::channel(1);
+            let provider = Box::new(receiver);
+
+            let latest: Ref<i32> = provider.provide_ref_async_with(Latest::default()).await;
+            assert_eq!(*latest, 1);
+            drop(latest);
+
+            sender.send(2).unwrap();
+            let latest: Ref<i32> = provider.provide_ref_async_with(Latest::default()).await;
+            assert_eq!(*latest, 2);
+        });
+    }
+
+    #[test]
+    fn watch_changed() {
+        block_on(async {
+            let (sender, receiver) = watch::channel(1);
+            let mut provider = Box::new(receiver);
+
+            sender.send(2).unwrap();
+            let changed: Result<Ref<i32>, _> = provider
+                .try_provide_mut_async_with(Changed::default())
+                .await;
+            assert_eq!(changed.as_deref().ok(), Some(&2));
+            drop(changed);
+
+            drop(sender);
+            let changed: Result<Ref<i32>, _> = provider
+                .try_provide_mut_async_with(Changed::default())
+                .await;
+            assert!(changed.is_err());
+        });
+    }
+}
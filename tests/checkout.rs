@@ -0,0 +1,58 @@
+#![cfg(feature = "alloc")]
+
+use provide::{checkout::Checkout, context::checkout::Lend, with::TryProvideWith};
+
+#[derive(Debug, PartialEq)]
+struct License(u32);
+
+#[test]
+fn check_out() {
+    let provider = Checkout::new(vec![License(1), License(2)]);
+    assert_eq!((provider.available(), provider.checked_out()), (2, 0));
+
+    let (first, provider): (License, _) = provider.try_provide_with(Lend).unwrap();
+    let (second, provider): (License, _) = provider.try_provide_with(Lend).unwrap();
+    assert_eq!((first, second), (License(2), License(1)));
+    assert_eq!((provider.available(), provider.checked_out()), (0, 2));
+}
+
+#[test]
+fn return_value() {
+    let provider: Checkout<_> = [License(1)].into_iter().collect();
+
+    let (license, provider): (License, _) = provider.try_provide_with(Lend).unwrap();
+    let result: Result<(License, _), _> = provider.try_provide_with(Lend);
+    let error = result.unwrap_err();
+    assert_eq!(error.to_string(), "all 1 instances are checked out");
+
+    let mut provider = error.into_inner();
+    provider.return_value(license).unwrap();
+    assert_eq!((provider.available(), provider.checked_out()), (1, 0));
+
+    let (license, provider): (License, _) = provider.try_provide_with(Lend).unwrap();
+    assert_eq!(license, License(1));
+    assert_eq!(provider.into_inner(), []);
+}
+
+#[test]
+fn empty() {
+    let provider = Checkout::<License>::new(Vec::new());
+
+    let result: Result<(License, _), _> = provider.try_provide_with(Lend);
+    let error = result.unwrap_err();
+    assert_eq!(error.to_string(), "there are no instances to check out");
+    assert_eq!(error.into_inner(), Checkout::new(Vec::new()));
+}
+
+#[test]
+fn return_foreign_value() {
+    let mut provider = Checkout::new(vec![License(1)]);
+
+    let error = provider.return_value(License(2)).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "there are no checked out instances to return"
+    );
+    assert_eq!(error.into_inner(), License(2));
+    assert_eq!((provider.available(), provider.checked_out()), (1, 0));
+}
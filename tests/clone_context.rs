@@ -0,0 +1,35 @@
+use provide::{
+    context::{clone::CloneMut, Context},
+    with::{ProvideMutWith, TryProvideMutWith},
+    ProvideMut,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Token(u32);
+
+struct Issuer {
+    token: Token,
+}
+
+impl<'me> ProvideMut<'me, &'me mut Token> for Issuer {
+    fn provide_mut(&'me mut self) -> &'me mut Token {
+        let Self { token } = self;
+        token.0 += 1;
+        token
+    }
+}
+
+#[test]
+fn clone_mut() {
+    let mut provider = Issuer { token: Token(0) };
+
+    let token: Token = provider.provide_mut_with(CloneMut::default());
+    assert_eq!(token, Token(1));
+
+    let token: Result<Token, _> = provider.try_provide_mut_with(().then_clone_mut());
+    assert_eq!(token, Ok(Token(2)));
+
+    let token: Option<Token> = provider.provide_mut_with(().then_clone_mut().then_some());
+    assert_eq!(token, Some(Token(3)));
+    assert_eq!(provider.token, Token(3));
+}
@@ -0,0 +1,80 @@
+use provide::{
+    context::{
+        collect::{CloneN, Collect},
+        Context,
+    },
+    with::{ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideRefWith},
+    Provide, ProvideExt, ProvideMut, ProvideRef,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Id(u32);
+
+#[derive(Debug, Default, PartialEq)]
+struct Sequence {
+    next: u32,
+}
+
+impl Provide<Id> for Sequence {
+    type Remainder = Self;
+
+    fn provide(self) -> (Id, Self::Remainder) {
+        let Self { next } = self;
+        (Id(next), Self { next: next + 1 })
+    }
+}
+
+impl ProvideMut<'_, Id> for Sequence {
+    fn provide_mut(&mut self) -> Id {
+        let Self { next } = self;
+        *next += 1;
+        Id(*next - 1)
+    }
+}
+
+impl ProvideRef<'_, Id> for Sequence {
+    fn provide_ref(&self) -> Id {
+        let Self { next } = *self;
+        Id(next)
+    }
+}
+
+#[test]
+fn by_value() {
+    let (ids, provider) = Sequence::default().take_array::<Id, 3>();
+    assert_eq!(ids, [Id(0), Id(1), Id(2)]);
+    assert_eq!(provider, Sequence { next: 3 });
+
+    let (ids, provider) = provider.take_array::<Id, 0>();
+    assert_eq!(ids, []);
+    assert_eq!(provider, Sequence { next: 3 });
+}
+
+#[test]
+fn by_ref() {
+    let provider = Sequence { next: 7 };
+    let ids: [Id; 2] = provider.provide_ref_with(Collect::default());
+    assert_eq!(ids, [Id(7), Id(7)]);
+
+    let ids: Result<[Id; 2], _> = provider.try_provide_ref_with(().then_collect());
+    assert_eq!(ids, Ok([Id(7), Id(7)]));
+}
+
+#[test]
+fn clone_n() {
+    let provider = String::from("shard");
+
+    let (shards, ()): ([String; 3], _) = provider.clone().provide_with(CloneN::default());
+    assert_eq!(shards, ["shard", "shard", "shard"]);
+
+    let shards: [&str; 2] = provider.provide_ref_with(().then_clone_n());
+    assert_eq!(shards, ["shard", "shard"]);
+
+    let mut sequence = Sequence { next: 4 };
+    let ids: [Id; 2] = sequence.provide_mut_with(CloneN::default());
+    assert_eq!(ids, [Id(4), Id(4)]);
+    assert_eq!(sequence, Sequence { next: 5 });
+
+    let (empty, ()): ([String; 0], _) = provider.provide_with(().then_clone_n());
+    assert_eq!(empty, <[String; 0]>::default());
+}
@@ -0,0 +1,30 @@
+use provide::context::{alias::AliasDependency, convert::TryFromDependency};
+
+#[test]
+#[cfg(not(feature = "compact_names"))]
+fn full_names() {
+    let context = TryFromDependency::<i64>::default();
+    assert_eq!(
+        format!("{context:?}"),
+        "TryFromDependencyWith { type: i64, context: () }"
+    );
+
+    let context = AliasDependency::<Option<u8>>::default();
+    let debug = format!("{context:?}");
+    assert!(debug.starts_with("AliasDependencyWith { type: "));
+    assert!(debug.ends_with("Option<u8>, context: () }"));
+}
+
+#[test]
+#[cfg(feature = "compact_names")]
+fn compact_names() {
+    let context = TryFromDependency::<i64>::default();
+    assert_eq!(
+        format!("{context:?}"),
+        "TryFromDependencyWith { type: #c69b2266, context: () }"
+    );
+
+    let context = AliasDependency::<Option<u8>>::default();
+    let other = AliasDependency::<Option<u16>>::default();
+    assert_ne!(format!("{context:?}"), format!("{other:?}"));
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "async")]
+
+use core::cell::Cell;
+
+use futures::executor::block_on;
+use provide::{async_construct, construct::AsyncConstruct, ProvideRefAsync};
+
+struct Handshake {
+    order: u32,
+}
+
+struct Provider {
+    handshakes: Cell<u32>,
+}
+
+impl<'me> ProvideRefAsync<'me, Handshake> for Provider {
+    async fn provide_ref_async(&'me self) -> Handshake {
+        let Self { handshakes } = self;
+        let order = handshakes.get() + 1;
+        handshakes.set(order);
+        Handshake { order }
+    }
+}
+
+async_construct! {
+    struct Service {
+        first: Handshake,
+        second: Handshake,
+    }
+}
+
+#[test]
+fn construct_in_order() {
+    let provider = Provider {
+        handshakes: Cell::new(0),
+    };
+    let service = block_on(Service::construct_async(&provider));
+    assert_eq!(service.first.order, 1);
+    assert_eq!(service.second.order, 2);
+    assert_eq!(provider.handshakes.get(), 2);
+}
+
+#[test]
+fn construct_tuple() {
+    let provider = Provider {
+        handshakes: Cell::new(0),
+    };
+    let (first, second) = block_on(<(Handshake, Handshake)>::construct_async(&provider));
+    assert_eq!((first.order, second.order), (1, 2));
+}
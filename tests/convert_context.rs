@@ -0,0 +1,145 @@
+use core::{error::Error, num::TryFromIntError};
+
+use provide::{
+    context::{
+        convert::{TryFromDependency, TryFromDependencyMut, TryFromDependencyRef, TryFromError},
+        Context,
+    },
+    failpoint::{Failpoint, FailpointError},
+    with::{TryProvideMutWith, TryProvideRefWith, TryProvideWith},
+    ProvideMut, ProvideRef,
+};
+
+#[derive(Debug, PartialEq)]
+struct Port(u16);
+
+impl TryFrom<i64> for Port {
+    type Error = TryFromIntError;
+
+    fn try_from(port: i64) -> Result<Self, Self::Error> {
+        u16::try_from(port).map(Self)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Host<'a>(&'a str);
+
+impl<'a> TryFrom<&'a str> for Host<'a> {
+    type Error = InvalidHost;
+
+    fn try_from(host: &'a str) -> Result<Self, Self::Error> {
+        if host.is_empty() || host.contains(char::is_whitespace) {
+            return Err(InvalidHost);
+        }
+        Ok(Self(host))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct InvalidHost;
+
+struct Config {
+    port: i64,
+}
+
+struct Registry {
+    host: String,
+}
+
+impl AsRef<str> for Registry {
+    fn as_ref(&self) -> &str {
+        let Self { host } = self;
+        host
+    }
+}
+
+impl ProvideRef<'_, i64> for Config {
+    fn provide_ref(&self) -> i64 {
+        let Self { port } = self;
+        *port
+    }
+}
+
+impl ProvideMut<'_, i64> for Config {
+    fn provide_mut(&mut self) -> i64 {
+        let Self { port } = self;
+        *port += 1;
+        *port
+    }
+}
+
+#[test]
+fn by_value() {
+    let result: Result<(Port, _), _> = 80_i64.try_provide_with(TryFromDependency::<i64>::default());
+    assert!(matches!(result, Ok((Port(80), ()))));
+
+    let result: Result<(Port, _), _> =
+        (-80_i64).try_provide_with(TryFromDependency::<i64>::new(()));
+    assert!(result.is_err());
+}
+
+#[test]
+fn by_ref() {
+    let provider = Config { port: 8080 };
+    let port: Result<Port, _> = provider.try_provide_ref_with(TryFromDependency::<i64>::default());
+    assert_eq!(port, Ok(Port(8080)));
+
+    let provider = Config { port: 65536 };
+    let port: Result<Port, _> = provider.try_provide_ref_with(().then_try_from::<i64>());
+    assert!(matches!(port, Err(TryFromError::Convert(_))));
+}
+
+#[test]
+fn by_mut() {
+    let mut provider = Config { port: 65534 };
+    let port: Result<Port, _> = provider.try_provide_mut_with(TryFromDependency::<i64>::default());
+    assert_eq!(port, Ok(Port(65535)));
+
+    let port: Result<Port, _> = provider.try_provide_mut_with(TryFromDependency::<i64>::default());
+    assert!(port.is_err());
+
+    let mut provider = vec![1_u8, 2, 3, 4];
+    let bytes: Result<&mut [u8; 4], _> =
+        provider.try_provide_mut_with(TryFromDependencyMut::<[u8]>::default());
+    assert_eq!(bytes.ok(), Some(&mut [1, 2, 3, 4]));
+
+    let bytes: Result<Option<&mut [u8; 2]>, _> =
+        provider.try_provide_mut_with(().then_try_from_mut::<[u8]>().then_some());
+    assert!(matches!(bytes, Err(TryFromError::Convert(_))));
+}
+
+#[test]
+fn from_ref() {
+    let registry = Registry {
+        host: String::from("localhost"),
+    };
+    let host: Result<Host, _> =
+        registry.try_provide_ref_with(TryFromDependencyRef::<str>::default());
+    assert_eq!(host, Ok(Host("localhost")));
+
+    let registry = Failpoint::new(Registry {
+        host: String::from("local host"),
+    });
+    let host: Result<Host, _> =
+        registry.try_provide_ref_with(().then_fail_after(usize::MAX).then_try_from_ref::<str>());
+    assert_eq!(host, Err(TryFromError::Convert(InvalidHost)));
+
+    let host: Result<Host, _> =
+        registry.try_provide_ref_with(().then_fail_after(0).then_try_from_ref::<str>());
+    assert_eq!(host, Err(TryFromError::Provide(FailpointError::Injected)));
+}
+
+#[test]
+fn error() {
+    let provider: i64 = -1;
+    let error = provider
+        .try_provide_with(TryFromDependency::<i64>::default())
+        .map(|(port, ()): (u16, _)| port)
+        .unwrap_err();
+    assert_eq!(error.to_string(), "failed to convert dependency");
+    let source = error.source().map(ToString::to_string);
+    assert_eq!(
+        source.as_deref(),
+        Some("out of range integral type conversion attempted")
+    );
+}
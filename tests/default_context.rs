@@ -0,0 +1,87 @@
+use core::convert::Infallible;
+
+use provide::{
+    context::{
+        default::DefaultDependency,
+        failpoint::{FailAfter, FailFor},
+        Context,
+    },
+    failpoint::{Failpoint, FailpointError},
+    variants,
+    with::{
+        ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideMutWith, TryProvideRefWith,
+        TryProvideWith,
+    },
+};
+
+#[derive(Debug, Default, PartialEq)]
+struct Config {
+    retries: u32,
+}
+
+struct Provider;
+
+#[test]
+fn by_value() {
+    let (config, Provider): (Config, _) = Provider.provide_with(DefaultDependency::default());
+    assert_eq!(config, Config::default());
+}
+
+#[test]
+fn by_ref() {
+    let config: Config = Provider.provide_ref_with(().then_default());
+    assert_eq!(config, Config { retries: 0 });
+
+    let config: Result<Vec<Config>, Infallible> =
+        Provider.try_provide_ref_with(DefaultDependency::default());
+    assert_eq!(config, Ok(Vec::new()));
+}
+
+#[test]
+fn by_mut() {
+    let config: Option<Config> = Provider.provide_mut_with(DefaultDependency::default());
+    assert_eq!(config, None);
+
+    let config: Option<Config> = Provider.provide_mut_with(().then_default().then_some());
+    assert_eq!(config, Some(Config::default()));
+}
+
+variants! {
+    #[derive(Debug, PartialEq)]
+    enum Stage {
+        Configured(Config),
+        Unconfigured(()),
+    }
+}
+
+#[test]
+fn or_default_by_value() {
+    let provider = Failpoint::new(3_u32);
+    let result: Result<(u32, _), Infallible> =
+        provider.try_provide_with(FailFor::<i32>::default().then_or_default());
+    assert_eq!(result, Ok((3, Ok(()))));
+
+    let provider = Failpoint::new(3_u32);
+    let result: Result<(u32, _), Infallible> =
+        provider.try_provide_with(FailFor::<u32>::default().then_or_default());
+    let (retries, remainder) = result.unwrap();
+    assert_eq!(retries, 0);
+    assert!(matches!(remainder, Err(FailpointError::Injected)));
+}
+
+#[test]
+fn or_default_by_ref() {
+    let mut provider = Failpoint::new(Stage::Configured(Config { retries: 3 }));
+
+    let config: Result<Option<&Config>, Infallible> =
+        provider.try_provide_ref_with(().then_fail_after(usize::MAX).then_some().then_or_default());
+    assert_eq!(config, Ok(Some(&Config { retries: 3 })));
+
+    let config: Result<Option<&mut Config>, Infallible> =
+        provider.try_provide_mut_with(().then_fail_after(0).then_some().then_or_default());
+    assert_eq!(config, Ok(None));
+
+    let config: Result<&Config, FailpointError<_>> =
+        provider.try_provide_ref_with(FailAfter::new(0, ()));
+    assert_eq!(config, Err(FailpointError::Injected));
+}
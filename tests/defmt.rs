@@ -0,0 +1,172 @@
+#![cfg(feature = "defmt")]
+
+use provide::{
+    balance::NoProviders,
+    best_effort::Degraded,
+    context::{
+        alias::AliasDependency,
+        balance::Rotate,
+        best_effort::BestEffort,
+        borrow::BorrowDependency,
+        by_ref::ByRef,
+        cache::CachedDependency,
+        cell::{ReplaceCell, TakeCell},
+        clone::CloneMut,
+        collect::Collect,
+        convert::{TryFromDependency, TryFromDependencyMut, TryFromDependencyRef},
+        default::{DefaultDependency, OrDefaultDependency},
+        depth::DepthGuard,
+        deref::DerefDependency,
+        downgrade::Downgrade,
+        either::{Either, EitherDependency, SelectDependency},
+        failpoint::{FailAfter, FailFor},
+        function::FnDependency,
+        graph::Rebuild,
+        inspect::InspectDependency,
+        intercept::{Intercept, InterceptError},
+        lazy::LazyDependency,
+        memoize::Memoize,
+        merge::Select,
+        or_else::OrElse,
+        profile::Profiled,
+        restore::{Restore, RestoreError},
+        take::{ReplaceDependency, TakeDependency},
+        type_name::{TypeName, WithTypeName},
+        validate::{ValidateDependency, ValidateError},
+        zip::ZipDependency,
+    },
+    downgrade::DowngradeError,
+    failpoint::FailpointError,
+    lazy::{ForceError, LazyError},
+    memoize::MemoizeError,
+    merge::Left,
+    pair::Here,
+    profile::{GatedError, Profile},
+    ProvideAllError,
+};
+
+#[cfg(feature = "bench")]
+use provide::context::black_box::BlackBoxDependency;
+#[cfg(feature = "alloc")]
+use provide::context::checkout::Lend;
+#[cfg(feature = "std")]
+use provide::context::env::FromEnvOrDefault;
+#[cfg(any(
+    feature = "std",
+    feature = "parking_lot",
+    feature = "tokio",
+    feature = "async-lock",
+))]
+use provide::context::lock::{Lock, WouldBlock};
+#[cfg(feature = "std")]
+use provide::context::lock::{LockError, TryLockError};
+#[cfg(feature = "parking_lot")]
+use provide::context::lock::{TimedOut, TryLockFor};
+#[cfg(feature = "pool")]
+use provide::context::pool::{Acquire, AcquireError};
+#[cfg(feature = "alloc")]
+use provide::context::spy::Spy;
+#[cfg(feature = "alloc")]
+use provide::context::to_owned::ToOwnedDependency;
+
+fn assert_format<T>()
+where
+    T: defmt::Format + ?Sized,
+{
+}
+
+#[test]
+fn contexts() {
+    #[cfg(any(
+        feature = "std",
+        feature = "parking_lot",
+        feature = "tokio",
+        feature = "async-lock",
+    ))]
+    assert_format::<Lock>();
+    #[cfg(feature = "parking_lot")]
+    assert_format::<TryLockFor>();
+    assert_format::<ReplaceCell<i32>>();
+    assert_format::<TakeCell>();
+    assert_format::<Profiled>();
+    assert_format::<FailAfter>();
+    assert_format::<FailFor<str>>();
+    assert_format::<Select<Left<Here>>>();
+    assert_format::<OrElse<(), FailAfter>>();
+    assert_format::<Downgrade>();
+    assert_format::<Intercept>();
+    #[cfg(feature = "alloc")]
+    assert_format::<Spy>();
+    assert_format::<EitherDependency<(), FailAfter>>();
+    assert_format::<SelectDependency<(), FailAfter>>();
+    assert_format::<ZipDependency<(), TypeName>>();
+    assert_format::<Collect<3>>();
+    assert_format::<TypeName>();
+    assert_format::<CloneMut>();
+    assert_format::<Restore>();
+    assert_format::<BorrowDependency<str>>();
+    assert_format::<DefaultDependency>();
+    assert_format::<Either<TypeName, Profiled>>();
+    #[cfg(feature = "alloc")]
+    assert_format::<Lend>();
+    assert_format::<AliasDependency<u8>>();
+    assert_format::<FnDependency<fn(u8) -> u16, u8>>();
+    assert_format::<DepthGuard<4>>();
+    assert_format::<InspectDependency<u8>>();
+    assert_format::<ValidateDependency<u8>>();
+    assert_format::<CachedDependency<u8>>();
+    #[cfg(feature = "std")]
+    assert_format::<FromEnvOrDefault>();
+    assert_format::<LazyDependency>();
+    assert_format::<TakeDependency>();
+    assert_format::<ByRef>();
+    assert_format::<ReplaceDependency<u8>>();
+    assert_format::<OrDefaultDependency>();
+    assert_format::<BestEffort>();
+    assert_format::<Degraded<u8, Profile>>();
+    assert_format::<TryFromDependency<u8>>();
+    assert_format::<TryFromDependencyRef<str>>();
+    assert_format::<TryFromDependencyMut<[u8]>>();
+    #[cfg(feature = "alloc")]
+    assert_format::<ToOwnedDependency<&str>>();
+    assert_format::<DerefDependency<&u8>>();
+    #[cfg(feature = "bench")]
+    assert_format::<BlackBoxDependency>();
+    assert_format::<Rotate>();
+    assert_format::<Memoize>();
+    assert_format::<Rebuild>();
+    #[cfg(feature = "pool")]
+    assert_format::<Acquire>();
+}
+
+#[test]
+fn errors() {
+    #[cfg(any(
+        feature = "std",
+        feature = "parking_lot",
+        feature = "tokio",
+        feature = "async-lock",
+    ))]
+    assert_format::<WouldBlock>();
+    #[cfg(feature = "parking_lot")]
+    assert_format::<TimedOut>();
+    #[cfg(feature = "std")]
+    assert_format::<LockError<Profile>>();
+    #[cfg(feature = "std")]
+    assert_format::<TryLockError>();
+    assert_format::<Profile>();
+    assert_format::<GatedError<Profile>>();
+    assert_format::<FailpointError<Profile>>();
+    assert_format::<ProvideAllError<i32, Profile, Profile>>();
+    assert_format::<WithTypeName<Profile>>();
+    assert_format::<ForceError>();
+    assert_format::<LazyError<Profile>>();
+    assert_format::<MemoizeError<Profile>>();
+    assert_format::<DowngradeError<Profile>>();
+    assert_format::<ValidateError<Profile, Profile>>();
+    assert_format::<RestoreError<Profile, Profile>>();
+    assert_format::<InterceptError<Profile, Profile>>();
+    assert_format::<NoProviders>();
+    #[cfg(feature = "pool")]
+    assert_format::<AcquireError<Profile, Profile>>();
+}
@@ -0,0 +1,61 @@
+use provide::{
+    context::{
+        depth::{Depth, DepthGuard},
+        Context,
+    },
+    with::{ProvideRefWith, ProvideWith, TryProvideMutWith},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Database(i32);
+
+struct Provider(Database);
+
+impl AsRef<Database> for Provider {
+    fn as_ref(&self) -> &Database {
+        let Self(database) = self;
+        database
+    }
+}
+
+impl AsMut<Database> for Provider {
+    fn as_mut(&mut self) -> &mut Database {
+        let Self(database) = self;
+        database
+    }
+}
+
+#[test]
+fn depth() {
+    assert_eq!(<()>::DEPTH, 0);
+    assert_eq!(DepthGuard::<0>::DEPTH, 1);
+    assert_eq!(DepthGuard::<0>::default().depth(), 0);
+    assert_eq!(().then_some().then_depth_guard::<1>().depth(), 1);
+    assert_eq!(().then_some().then_ok().then_depth_guard::<2>().depth(), 2);
+}
+
+#[test]
+fn within_limit() {
+    let mut provider = Provider(Database(1));
+
+    let dependency: Option<&Database> =
+        provider.provide_ref_with(().then_some().then_depth_guard::<1>());
+    assert_eq!(dependency, Some(&Database(1)));
+
+    let dependency: Result<&mut Database, _> =
+        provider.try_provide_mut_with(DepthGuard::<0>::default());
+    assert_eq!(dependency, Ok(&mut Database(1)));
+
+    let (dependency, _): (Option<Option<i32>>, _) =
+        1.provide_with(().then_some().then_some().then_depth_guard::<2>());
+    assert_eq!(dependency, Some(Some(1)));
+}
+
+#[test]
+fn branches() {
+    let context = ().then_some().then_some().then_zip(().then_ok());
+    assert_eq!(context.then_depth_guard::<3>().depth(), 3);
+
+    let context = ().then_ok().then_or_else(().then_some().then_some());
+    assert_eq!(context.then_depth_guard::<3>().depth(), 3);
+}
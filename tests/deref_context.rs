@@ -0,0 +1,69 @@
+use std::{rc::Rc, sync::Arc};
+
+use provide::{
+    context::{
+        deref::{DerefDependency, DerefDependencyWith},
+        Context,
+    },
+    failpoint::{Failpoint, FailpointError},
+    with::{ProvideRefWith, ProvideWith, TryProvideRefWith, TryProvideWith},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Config {
+    retries: u32,
+}
+
+struct Provider {
+    config: Arc<Config>,
+    name: Rc<str>,
+}
+
+impl AsRef<Arc<Config>> for Provider {
+    fn as_ref(&self) -> &Arc<Config> {
+        let Self { config, .. } = self;
+        config
+    }
+}
+
+impl AsRef<Rc<str>> for Provider {
+    fn as_ref(&self) -> &Rc<str> {
+        let Self { name, .. } = self;
+        name
+    }
+}
+
+#[test]
+fn by_value() {
+    let config = Arc::new(Config { retries: 3 });
+
+    let (dependency, ()): (Config, _) =
+        Arc::clone(&config).provide_with(DerefDependency::<Arc<Config>>::default());
+    assert_eq!(dependency, *config);
+
+    let result: Result<(Config, _), _> =
+        Box::new(Config { retries: 5 }).try_provide_with(DerefDependency::<Box<Config>>::default());
+    assert!(matches!(result, Ok((Config { retries: 5 }, ()))));
+}
+
+#[test]
+fn by_ref() {
+    let provider = Provider {
+        config: Arc::new(Config { retries: 3 }),
+        name: Rc::from("server"),
+    };
+
+    let config: &Config = provider.provide_ref_with(DerefDependency::<Arc<Config>>::default());
+    assert_eq!(config.retries, 3);
+
+    let name: &str = provider.provide_ref_with(DerefDependencyWith::<Rc<str>, _>::new(()));
+    assert_eq!(name, "server");
+
+    let provider = Failpoint::new(provider);
+    let config: Result<&Config, _> =
+        provider.try_provide_ref_with(().then_fail_after(1).then_deref::<Arc<Config>>());
+    assert_eq!(config, Ok(&Config { retries: 3 }));
+    let config: Result<&Config, _> =
+        provider.try_provide_ref_with(().then_fail_after(1).then_deref::<Arc<Config>>());
+    assert_eq!(config, Err(FailpointError::Injected));
+}
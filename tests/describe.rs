@@ -0,0 +1,25 @@
+use provide::{
+    describe::{DescribeProvider, Exhausted},
+    pair::Pair,
+};
+
+#[test]
+fn describe_remainder() {
+    let remainder = (Exhausted::<i32>::new(), Pair::from(("hello", 2.0)));
+    let description = format!("{:?}", remainder.describe());
+    assert_eq!(description, "[i32 (exhausted), &str, f64]");
+}
+
+#[test]
+fn describe_empty() {
+    let description = format!("{:?}", ().describe());
+    assert_eq!(description, "[]");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn provided_type_names() {
+    let remainder = (Exhausted::<i32>::new(), Pair::from(("hello", 2.0)));
+    assert_eq!(remainder.provided_type_names(), ["&str", "f64"]);
+    assert!(().provided_type_names().is_empty());
+}
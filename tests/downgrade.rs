@@ -0,0 +1,48 @@
+use provide::{
+    context::{downgrade::Downgrade, Context},
+    downgrade::{DowngradeError, Downgraded},
+    failpoint::{Failpoint, FailpointError},
+    with::{ProvideRefWith, TryProvideRefWith},
+    ProvideMut,
+};
+
+#[derive(Debug, Default, PartialEq)]
+struct Sequence {
+    next: u32,
+}
+
+impl ProvideMut<'_, u32> for Sequence {
+    fn provide_mut(&mut self) -> u32 {
+        let Self { next } = self;
+        *next += 1;
+        *next
+    }
+}
+
+#[test]
+fn by_ref() {
+    let mut provider = Downgraded::new(Sequence::default());
+
+    let id: u32 = provider.provide_ref_with(Downgrade::default());
+    assert_eq!(id, 1);
+
+    let id: Option<u32> = provider.provide_ref_with(().then_downgrade().then_some());
+    assert_eq!(id, Some(2));
+
+    let id: Option<u32> = provider.provide_ref_with(().then_some().then_downgrade());
+    assert_eq!(id, Some(3));
+
+    provider.get_mut().next = 10;
+    assert_eq!(provider.into_inner(), Sequence { next: 10 });
+}
+
+#[test]
+fn by_ref_fallible() {
+    let provider = Downgraded::new(Failpoint::new(Sequence::default()));
+
+    let id: Result<u32, _> = provider.try_provide_ref_with(().then_fail_after(1).then_downgrade());
+    assert_eq!(id, Ok(1));
+
+    let id: Result<u32, _> = provider.try_provide_ref_with(().then_fail_after(1).then_downgrade());
+    assert_eq!(id, Err(DowngradeError::Provide(FailpointError::Injected)));
+}
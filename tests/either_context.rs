@@ -0,0 +1,152 @@
+use core::convert::Infallible;
+
+use provide::{
+    context::{
+        default::DefaultDependency,
+        either::{Either, EitherDependency, SelectDependency},
+        failpoint::FailAfter,
+        wrap::OptionDependency,
+        Context,
+    },
+    failpoint::{Failpoint, FailpointError},
+    with::{ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideRefWith},
+    ProvideRef,
+};
+
+struct Config {
+    retries: u32,
+}
+
+impl AsRef<u32> for Config {
+    fn as_ref(&self) -> &u32 {
+        let Self { retries } = self;
+        retries
+    }
+}
+
+impl AsMut<u32> for Config {
+    fn as_mut(&mut self) -> &mut u32 {
+        let Self { retries } = self;
+        retries
+    }
+}
+
+struct Retries(u32);
+
+impl ProvideRef<'_, u32> for Retries {
+    fn provide_ref(&self) -> u32 {
+        let Self(retries) = *self;
+        retries
+    }
+}
+
+#[test]
+fn by_value() {
+    let context = Either::<(), DefaultDependency>::Right(().then_default());
+    let (dependency, remainder): (u32, _) = 1_u32.provide_with(context);
+    assert_eq!(dependency, 0);
+    assert!(matches!(remainder, Either::Right(1)));
+
+    let context = Either::<(), DefaultDependency>::Left(());
+    let (dependency, remainder): (u32, _) = 1_u32.provide_with(context);
+    assert_eq!(dependency, 1);
+    assert!(matches!(remainder, Either::Left(())));
+}
+
+#[test]
+fn by_ref() {
+    let provider = Config { retries: 3 };
+
+    let context = Either::<OptionDependency, DefaultDependency>::Left(().then_some());
+    let retries: Option<&u32> = provider.provide_ref_with(context);
+    assert_eq!(retries, Some(&3));
+
+    let context = Either::<OptionDependency, DefaultDependency>::Right(().then_default());
+    let retries: Result<Option<&u32>, Either<Infallible, Infallible>> =
+        provider.try_provide_ref_with(context);
+    assert_eq!(retries, Ok(None));
+}
+
+#[test]
+fn by_mut() {
+    let mut provider = Config { retries: 3 };
+
+    for context in [
+        Either::Left(().then_some()),
+        Either::Right(().then_default()),
+    ] {
+        let retries: Option<&mut u32> = provider.provide_mut_with(context);
+        if let Some(retries) = retries {
+            *retries += 1;
+        }
+    }
+    assert_eq!(provider.retries, 4);
+}
+
+#[test]
+fn option() {
+    let provider = Retries(3);
+
+    let retries: u32 = provider.provide_ref_with(None::<DefaultDependency>);
+    assert_eq!(retries, 3);
+    let retries: u32 = provider.provide_ref_with(Some(().then_default()));
+    assert_eq!(retries, 0);
+
+    let (retries, remainder): (u32, _) = 1_u32.provide_with(Some(DefaultDependency::default()));
+    assert_eq!(retries, 0);
+    assert!(matches!(remainder, Either::Right(1)));
+
+    let (retries, remainder): (u32, _) = 1_u32.provide_with(None::<DefaultDependency>);
+    assert_eq!(retries, 1);
+    assert!(matches!(remainder, Either::Left(())));
+}
+
+#[test]
+fn either_dependency() {
+    let config = Config { retries: 3 };
+    let retries: Result<Either<&u32, Option<&u32>>, _> =
+        config.try_provide_ref_with(EitherDependency::new((), ().then_some()));
+    assert_eq!(retries, Ok(Either::Left(&3)));
+
+    let provider = Failpoint::new(config);
+    let retries: Result<Either<&u32, Option<&u32>>, _> = provider.try_provide_ref_with(
+        ().then_fail_after(0)
+            .then_either(().then_fail_after(usize::MAX).then_some()),
+    );
+    assert_eq!(retries, Ok(Either::Right(Some(&3))));
+
+    let retries: Result<Either<&u32, &u32>, _> =
+        provider.try_provide_ref_with(().then_fail_after(0).then_either(FailAfter::new(0, ())));
+    assert_eq!(retries, Err(FailpointError::Injected));
+}
+
+#[test]
+fn select_dependency() {
+    let mut provider = Config { retries: 3 };
+
+    for (enabled, expected) in [(true, Some(3)), (false, None)] {
+        let context = ().then_some().then_select_if(enabled, ().then_default());
+        let retries: Option<&u32> = provider.provide_ref_with(context);
+        assert_eq!(retries, expected.as_ref());
+
+        let retries: Option<&mut u32> = provider.provide_mut_with(context);
+        assert_eq!(retries.is_some(), enabled);
+    }
+
+    let context = SelectDependency::new(false, (), DefaultDependency::default());
+    let (dependency, remainder): (u32, _) = 1_u32.provide_with(context);
+    assert_eq!(dependency, 0);
+    assert!(matches!(remainder, Either::Right(1)));
+
+    let provider = Failpoint::new(Retries(3));
+    let retries: Result<u32, _> = provider.try_provide_ref_with(
+        ().then_fail_after(0)
+            .then_select_if(false, ().then_default()),
+    );
+    assert_eq!(retries, Ok(0));
+    let retries: Result<u32, _> = provider.try_provide_ref_with(
+        ().then_fail_after(0)
+            .then_select_if(true, ().then_default()),
+    );
+    assert_eq!(retries, Err(Either::Left(FailpointError::Injected)));
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "std")]
+
+use std::{env, num::ParseIntError};
+
+use provide::{
+    context::{
+        env::{FromEnvError, FromEnvOrDefault},
+        Context,
+    },
+    failpoint::{Failpoint, FailpointError},
+    with::{TryProvideMutWith, TryProvideRefWith},
+    ProvideMut, ProvideRef,
+};
+
+struct Provider {
+    port: u16,
+}
+
+impl<'me> ProvideRef<'me, u16> for Provider {
+    fn provide_ref(&'me self) -> u16 {
+        let Self { port } = self;
+        *port
+    }
+}
+
+impl<'me> ProvideMut<'me, u16> for Provider {
+    fn provide_mut(&'me mut self) -> u16 {
+        self.provide_ref()
+    }
+}
+
+#[test]
+fn or_default() {
+    const KEY: &str = "PROVIDE_TEST_OR_DEFAULT";
+    let context = FromEnvOrDefault::new(KEY, Default::default());
+
+    let timeout: Result<u64, _> = Provider { port: 80 }.try_provide_ref_with(context);
+    assert_eq!(timeout, Ok(0));
+
+    env::set_var(KEY, "30");
+    let timeout: Result<u64, _> = Provider { port: 80 }.try_provide_ref_with(context);
+    assert_eq!(timeout, Ok(30));
+
+    env::set_var(KEY, "thirty");
+    let timeout: Result<u64, _> = Provider { port: 80 }.try_provide_ref_with(context);
+    let error = timeout.unwrap_err();
+    assert!(matches!(error, FromEnvError::Parse { key: KEY, .. }));
+    assert_eq!(
+        error.to_string(),
+        "failed to parse value of environment variable `PROVIDE_TEST_OR_DEFAULT`",
+    );
+}
+
+#[test]
+fn or_inner_context() {
+    const KEY: &str = "PROVIDE_TEST_OR_INNER_CONTEXT";
+    let mut provider = Provider { port: 80 };
+    let context = ().then_from_env(KEY);
+
+    let port: Result<u16, FromEnvError<_, ParseIntError>> = provider.try_provide_mut_with(context);
+    assert_eq!(port, Ok(80));
+
+    env::set_var(KEY, "443");
+    let port: Result<u16, FromEnvError<_, ParseIntError>> = provider.try_provide_ref_with(context);
+    assert_eq!(port, Ok(443));
+}
+
+#[test]
+fn provide_error() {
+    const KEY: &str = "PROVIDE_TEST_PROVIDE_ERROR";
+    let provider = Failpoint::new(Provider { port: 80 });
+    let context = ().then_fail_after(0).then_from_env(KEY);
+
+    let port: Result<u16, _> = provider.try_provide_ref_with(context);
+    assert_eq!(port, Err(FromEnvError::Provide(FailpointError::Injected)));
+}
@@ -0,0 +1,97 @@
+use provide::{
+    context::{failpoint::FailFor, Context},
+    failpoint::{Failpoint, FailpointError},
+    with::{TryProvideMutWith, TryProvideRefWith, TryProvideWith},
+};
+
+struct Cache(i32);
+
+struct Database(i32);
+
+struct Provider {
+    cache: Cache,
+    database: Database,
+}
+
+impl AsRef<Cache> for Provider {
+    fn as_ref(&self) -> &Cache {
+        let Self { cache, .. } = self;
+        cache
+    }
+}
+
+impl AsRef<Database> for Provider {
+    fn as_ref(&self) -> &Database {
+        let Self { database, .. } = self;
+        database
+    }
+}
+
+impl AsMut<Database> for Provider {
+    fn as_mut(&mut self) -> &mut Database {
+        let Self { database, .. } = self;
+        database
+    }
+}
+
+#[test]
+fn fail_for() {
+    let provider = Failpoint::new(Provider {
+        cache: Cache(1),
+        database: Database(2),
+    });
+
+    let cache: Result<&Cache, _> = provider.try_provide_ref_with(FailFor::<&Cache>::default());
+    assert!(matches!(cache, Err(FailpointError::Injected)));
+
+    let database: Result<&Database, _> =
+        provider.try_provide_ref_with(FailFor::<&Cache>::default());
+    assert!(matches!(database, Ok(Database(2))));
+
+    let cache: Result<&Cache, _> = provider.try_provide_ref_with(().then_fail_for::<&Database>());
+    assert!(matches!(cache, Ok(Cache(1))));
+}
+
+#[test]
+fn fail_after() {
+    let provider = Failpoint::new(Provider {
+        cache: Cache(1),
+        database: Database(2),
+    });
+
+    let cache: Result<&Cache, _> = provider.try_provide_ref_with(().then_fail_after(1));
+    assert!(matches!(cache, Ok(Cache(1))));
+    assert_eq!(provider.successes(), 1);
+
+    let database: Result<&Database, _> = provider.try_provide_ref_with(().then_fail_after(1));
+    assert!(matches!(database, Err(FailpointError::Injected)));
+
+    provider.reset();
+    let database: Result<&Database, _> = provider.try_provide_ref_with(().then_fail_after(1));
+    assert!(matches!(database, Ok(Database(2))));
+}
+
+#[test]
+fn by_mut() {
+    let mut provider = Failpoint::new(Provider {
+        cache: Cache(1),
+        database: Database(2),
+    });
+    let database: Result<&mut Database, _> = provider.try_provide_mut_with(().then_fail_after(1));
+    database.unwrap().0 = 3;
+
+    let database: Result<&mut Database, _> = provider.try_provide_mut_with(().then_fail_after(1));
+    assert!(matches!(database, Err(FailpointError::Injected)));
+    assert_eq!(provider.into_inner().database.0, 3);
+}
+
+#[test]
+fn by_value() {
+    let provider = Failpoint::new(1);
+    let result: Result<(i32, _), _> = provider.try_provide_with(FailFor::<i32>::default());
+    assert!(matches!(result, Err(FailpointError::Injected)));
+
+    let provider = Failpoint::new(1);
+    let result: Result<(i64, _), _> = provider.try_provide_with(FailFor::<i32>::default());
+    assert!(matches!(result, Ok((1, ()))));
+}
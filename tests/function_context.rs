@@ -0,0 +1,118 @@
+use provide::{
+    context::{function::FnDependency, Context},
+    failpoint::{Failpoint, FailpointError},
+    with::{ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideRefWith},
+};
+
+#[derive(Debug, PartialEq)]
+struct Db(&'static str);
+
+#[derive(Debug, PartialEq)]
+struct Service {
+    url: &'static str,
+}
+
+impl Service {
+    fn new(db: &Db) -> Self {
+        let Db(url) = *db;
+        Self { url }
+    }
+}
+
+struct Provider {
+    db: Db,
+}
+
+impl AsRef<Db> for Provider {
+    fn as_ref(&self) -> &Db {
+        let Self { db } = self;
+        db
+    }
+}
+
+impl AsMut<Db> for Provider {
+    fn as_mut(&mut self) -> &mut Db {
+        let Self { db } = self;
+        db
+    }
+}
+
+#[test]
+fn by_value() {
+    let (service, ()): (Service, _) =
+        Db("sqlite::memory:").provide_with(FnDependency::new(|db: Db| Service::new(&db), ()));
+    assert_eq!(
+        service,
+        Service {
+            url: "sqlite::memory:"
+        }
+    );
+}
+
+#[test]
+fn by_ref() {
+    let provider = Provider {
+        db: Db("postgres://"),
+    };
+
+    let service: Service = provider.provide_ref_with(FnDependency::new(Service::new, ()));
+    assert_eq!(service, Service { url: "postgres://" });
+
+    let service: Result<Option<Service>, _> =
+        provider.try_provide_ref_with(().then_map_new(Service::new).then_some());
+    assert_eq!(service, Ok(Some(Service { url: "postgres://" })));
+
+    let url: Option<&str> = provider.provide_ref_with(
+        ().then_some()
+            .then_map_new(|db: Option<&Db>| db.map(|db| db.0)),
+    );
+    assert_eq!(url, Some("postgres://"));
+}
+
+#[test]
+fn by_ref_error() {
+    let provider = Failpoint::new(Provider {
+        db: Db("postgres://"),
+    });
+
+    let service: Result<Service, _> =
+        provider.try_provide_ref_with(().then_fail_after(usize::MAX).then_map_new(Service::new));
+    assert_eq!(service, Ok(Service { url: "postgres://" }));
+
+    let service: Result<Service, _> =
+        provider.try_provide_ref_with(().then_fail_after(0).then_map_new(Service::new));
+    assert_eq!(service, Err(FailpointError::Injected));
+}
+
+#[test]
+fn by_mut() {
+    let mut provider = Provider {
+        db: Db("postgres://"),
+    };
+
+    let service: Service = provider.provide_mut_with(().then_map_new(|db: &mut Db| {
+        db.0 = "mysql://";
+        Service::new(db)
+    }));
+    assert_eq!(service, Service { url: "mysql://" });
+    assert_eq!(provider.db, Db("mysql://"));
+}
+
+#[test]
+fn map() {
+    let provider = Provider {
+        db: Db("postgres://"),
+    };
+
+    let url: &str = provider.provide_ref_with(().then_map_new(|Db(url): &Db| *url));
+    assert_eq!(url, "postgres://");
+
+    let len: Result<usize, _> =
+        provider.try_provide_ref_with(().then_map_new(|Db(url): &Db| *url).then_map_new(str::len));
+    assert_eq!(len, Ok(11));
+
+    let provider = Failpoint::new(provider);
+    let url: Result<&str, _> =
+        provider.try_provide_ref_with(().then_fail_after(0).then_map_new(|Db(url): &Db| *url));
+    assert_eq!(url, Err(FailpointError::Injected));
+}
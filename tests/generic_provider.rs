@@ -0,0 +1,96 @@
+use provide::{
+    generic::{Dependency, GenericProvider},
+    with::{ProvideMutWith, ProvideRefWith, ProvideWith},
+    Provide, ProvideMut, ProvideRef,
+};
+
+#[test]
+fn dependency() {
+    let mut provider = GenericProvider::new(vec![1, 2, 3]);
+
+    let Dependency(dependency): Dependency<&mut Vec<i32>> = provider.provide_mut();
+    dependency.push(4);
+
+    let Dependency(dependency): Dependency<&Vec<i32>> = provider.provide_ref();
+    assert_eq!(dependency, &[1, 2, 3, 4]);
+
+    let (Dependency(dependency), ()) = provider.provide();
+    assert_eq!(dependency, [1, 2, 3, 4]);
+}
+
+#[test]
+fn by_value() {
+    #[derive(Default)]
+    struct WrapOptionWith<C>(C)
+    where
+        C: ?Sized;
+
+    impl<T, U, C> ProvideWith<Option<T>, WrapOptionWith<C>> for GenericProvider<U>
+    where
+        U: ProvideWith<T, C>,
+    {
+        type Remainder = U::Remainder;
+
+        fn provide_with(self, context: WrapOptionWith<C>) -> (Option<T>, Self::Remainder) {
+            let WrapOptionWith(context) = context;
+            let (dependency, remainder) = self.into_inner().provide_with(context);
+            (Some(dependency), remainder)
+        }
+    }
+
+    let provider = GenericProvider::new(1);
+    // Built-in contexts apply to any provider, so inner context must be named explicitly.
+    let context = WrapOptionWith::<()>::default();
+    let (dependency, _) = provider.provide_with(context);
+    assert_eq!(dependency, Some(1));
+}
+
+#[test]
+fn by_ref() {
+    #[derive(Default)]
+    struct WrapOptionWith<C>(C)
+    where
+        C: ?Sized;
+
+    impl<'me, T, U, C> ProvideRefWith<'me, Option<T>, WrapOptionWith<C>> for GenericProvider<U>
+    where
+        U: ProvideRefWith<'me, T, C> + ?Sized,
+    {
+        fn provide_ref_with(&'me self, context: WrapOptionWith<C>) -> Option<T> {
+            let WrapOptionWith(context) = context;
+            let dependency = self.inner().provide_ref_with(context);
+            Some(dependency)
+        }
+    }
+
+    let provider = GenericProvider::new("hello");
+    // Built-in contexts apply to any provider, so inner context must be named explicitly.
+    let context = WrapOptionWith::<()>::default();
+    let dependency = provider.provide_ref_with(context);
+    assert_eq!(dependency, Some("hello"));
+}
+
+#[test]
+fn by_mut() {
+    #[derive(Default)]
+    struct WrapOptionWith<C>(C)
+    where
+        C: ?Sized;
+
+    impl<'me, T, U, C> ProvideMutWith<'me, Option<T>, WrapOptionWith<C>> for GenericProvider<U>
+    where
+        U: ProvideMutWith<'me, T, C> + ?Sized,
+    {
+        fn provide_mut_with(&'me mut self, context: WrapOptionWith<C>) -> Option<T> {
+            let WrapOptionWith(context) = context;
+            let dependency = self.inner_mut().provide_mut_with(context);
+            Some(dependency)
+        }
+    }
+
+    let mut provider = GenericProvider::new([1, 2, 3, 4, 5]);
+    // Built-in contexts apply to any provider, so inner context must be named explicitly.
+    let context = WrapOptionWith::<()>::default();
+    let dependency = provider.provide_mut_with(context);
+    assert_eq!(dependency, Some([1, 2, 3, 4, 5].as_mut_slice()));
+}
@@ -0,0 +1,83 @@
+use core::cell::Cell;
+
+use provide::{
+    context::{cell::TakeCell, graph::Rebuild},
+    graph::Graph,
+    ordered_graph,
+    with::ProvideRefWith,
+    ProvideExt,
+};
+
+struct Counter {
+    count: Cell<u32>,
+}
+
+#[derive(Clone)]
+struct Label {
+    text: String,
+}
+
+ordered_graph! {
+    struct Counted {
+        counter: Counter = () => Counter { count: Cell::new(1) },
+        label: Label = (counter) => Label {
+            text: format!("count is {}", counter.count.get()),
+        },
+    }
+}
+
+#[test]
+fn build() {
+    let mut graph = Counted::build();
+    assert_eq!(Counted::NODES, ["counter", "label"]);
+    assert_eq!(graph.get_dep::<Label>().text, "count is 1");
+
+    graph.get_dep_mut::<Label>().text.push('!');
+    assert_eq!(graph.get_dep::<Label>().text, "count is 1!");
+}
+
+ordered_graph! {
+    struct Shuffled {
+        labels: Vec<Label> = (counter, label) => vec![label.clone(); counter.count.get() as usize],
+        label: Label = (counter) => Label {
+            text: format!("count is {}", counter.count.get()),
+        },
+        counter: Counter = () => Counter { count: Cell::new(2) },
+    }
+}
+
+#[test]
+fn topological_order() {
+    let graph = Shuffled::build();
+    assert_eq!(Shuffled::NODES, ["counter", "label", "labels"]);
+
+    let labels = graph.get_dep::<Vec<Label>>();
+    assert_eq!(labels.len(), 2);
+    assert!(labels.iter().all(|label| label.text == "count is 2"));
+}
+
+#[test]
+fn rebuild() {
+    let graph = Shuffled::build();
+    graph.get_dep::<Counter>().count.set(3);
+
+    let label: Label = graph.provide_ref_with(Rebuild);
+    assert_eq!(label.text, "count is 3");
+    assert_eq!(graph.get_dep::<Label>().text, "count is 2");
+}
+
+#[test]
+fn with_context() {
+    struct Provider(Counted);
+
+    impl AsRef<Cell<u32>> for Provider {
+        fn as_ref(&self) -> &Cell<u32> {
+            let Self(graph) = self;
+            &graph.get_dep::<Counter>().count
+        }
+    }
+
+    let provider = Provider(Counted::build());
+    let dependency: u32 = provider.provide_ref_with(TakeCell::default());
+    assert_eq!(dependency, 1);
+}
@@ -0,0 +1,46 @@
+use provide::ProvideHandle;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Index(usize);
+
+struct Registry {
+    names: [&'static str; 3],
+    default: usize,
+}
+
+impl ProvideHandle<Index> for Registry {
+    fn provide_handle(&self) -> Index {
+        let Self { default, .. } = self;
+        Index(*default)
+    }
+}
+
+impl ProvideHandle<&'static str> for Registry {
+    fn provide_handle(&self) -> &'static str {
+        let Self { names, default } = self;
+        names[*default]
+    }
+}
+
+fn resolve<P>(provider: &P) -> (Index, &'static str)
+where
+    P: ProvideHandle<Index> + ProvideHandle<&'static str>,
+{
+    (provider.provide_handle(), provider.provide_handle())
+}
+
+#[test]
+fn handles() {
+    let mut registry = Registry {
+        names: ["foo", "bar", "baz"],
+        default: 2,
+    };
+
+    let (index, name) = resolve(&registry);
+    registry.default = 0;
+    assert_eq!((index, name), (Index(2), "baz"));
+
+    let Index(index) = registry.provide_handle();
+    let name: &str = registry.provide_handle();
+    assert_eq!(registry.names[index], name);
+}
@@ -0,0 +1,66 @@
+use std::cell::Cell;
+
+use provide::{
+    context::{inspect::InspectDependency, Context},
+    failpoint::{Failpoint, FailpointError},
+    with::{ProvideMutWith, ProvideWith, TryProvideRefWith},
+};
+
+#[derive(Debug, PartialEq)]
+struct Database(i32);
+
+struct Provider(Database);
+
+impl AsRef<Database> for Provider {
+    fn as_ref(&self) -> &Database {
+        let Self(database) = self;
+        database
+    }
+}
+
+impl AsMut<Database> for Provider {
+    fn as_mut(&mut self) -> &mut Database {
+        let Self(database) = self;
+        database
+    }
+}
+
+#[test]
+fn inspected() {
+    let mut provider = Provider(Database(1));
+    let calls = Cell::new(0);
+
+    let dependency: &mut Database = provider.provide_mut_with(InspectDependency::new(
+        |Database(id): &&mut Database| {
+            assert_eq!(*id, 1);
+            calls.set(calls.get() + 1);
+        },
+        (),
+    ));
+    dependency.0 = 2;
+
+    let (dependency, _): (Option<Database>, _) =
+        Database(3).provide_with(().then_some().then_inspect(|database: &Option<Database>| {
+            assert_eq!(database, &Some(Database(3)));
+            calls.set(calls.get() + 1);
+        }));
+    assert_eq!(dependency, Some(Database(3)));
+    assert_eq!(calls.get(), 2);
+    assert_eq!(provider.0, Database(2));
+}
+
+#[test]
+fn not_called_on_error() {
+    let provider = Failpoint::new(Provider(Database(1)));
+    let calls = Cell::new(0);
+    let inspect = |_: &&Database| calls.set(calls.get() + 1);
+
+    let dependency: Result<&Database, _> =
+        provider.try_provide_ref_with(().then_fail_after(usize::MAX).then_inspect(inspect));
+    assert_eq!(dependency, Ok(&Database(1)));
+
+    let dependency: Result<&Database, _> =
+        provider.try_provide_ref_with(().then_fail_after(0).then_inspect(inspect));
+    assert_eq!(dependency, Err(FailpointError::Injected));
+    assert_eq!(calls.get(), 1);
+}
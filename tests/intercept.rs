@@ -0,0 +1,213 @@
+use core::cell::{Cell, RefCell};
+use std::{any::type_name, convert::Infallible};
+
+use provide::{
+    context::{
+        cell::TakeCell,
+        intercept::{Intercept, InterceptError},
+        Context,
+    },
+    failpoint::{Failpoint, FailpointError},
+    intercept::{Intercepted, Interceptor},
+    with::{ProvideRefWith, ProvideWith, TryProvideMutWith, TryProvideRefWith, TryProvideWith},
+    ProvideExt, ProvideRef,
+};
+
+#[derive(Default)]
+struct Logger {
+    log: RefCell<Vec<String>>,
+}
+
+impl Interceptor for Logger {
+    type Error = Infallible;
+
+    fn before<T: ?Sized>(&self) -> Result<(), Self::Error> {
+        self.log
+            .borrow_mut()
+            .push(format!("before {}", type_name::<T>()));
+        Ok(())
+    }
+
+    fn after<T: ?Sized>(&self) {
+        self.log
+            .borrow_mut()
+            .push(format!("after {}", type_name::<T>()));
+    }
+}
+
+#[test]
+fn intercept_context() {
+    let logger = Logger::default();
+    let provider = Intercepted::new(Box::new(Cell::new(1)), &logger);
+
+    let dependency: i32 = provider.provide_ref_with(TakeCell::default());
+    assert_eq!(dependency, 1);
+    assert_eq!(
+        logger.log.into_inner(),
+        [
+            "before core::cell::Cell<i32>",
+            "after core::cell::Cell<i32>"
+        ],
+    );
+}
+
+#[test]
+fn intercept_mut() {
+    let logger = Logger::default();
+    let mut provider = Intercepted::new(vec![1, 2, 3], &logger);
+
+    provider.get_dep_mut::<[i32]>()[0] = 0;
+    assert_eq!(provider.get_dep::<[i32]>(), [0, 2, 3]);
+    assert_eq!(
+        logger.log.into_inner(),
+        ["before [i32]", "after [i32]", "before [i32]", "after [i32]"],
+    );
+}
+
+struct Registry {
+    entries: Vec<&'static str>,
+}
+
+impl<'me> ProvideRef<'me, usize> for Registry {
+    fn provide_ref(&'me self) -> usize {
+        let Self { entries } = self;
+        entries.len()
+    }
+}
+
+impl AsRef<[&'static str]> for Registry {
+    fn as_ref(&self) -> &[&'static str] {
+        let Self { entries } = self;
+        entries
+    }
+}
+
+#[test]
+fn intercept_with_context() {
+    let logger = Logger::default();
+    let provider = Intercepted::new(
+        Registry {
+            entries: vec!["alpha"],
+        },
+        &logger,
+    );
+
+    let count: usize = provider.provide_ref_with(Intercept::default());
+    assert_eq!(count, 1);
+    assert_eq!(logger.log.take(), ["before usize", "after usize"]);
+
+    let (dependency, remainder): (Option<Registry>, _) =
+        provider.provide_with(().then_some().then_intercept());
+    assert!(dependency.is_some());
+    let ((), _) = remainder.into_inner();
+    assert_eq!(
+        logger.log.take(),
+        [
+            "before core::option::Option<intercept::Registry>",
+            "after core::option::Option<intercept::Registry>",
+        ],
+    );
+
+    let provider = Intercepted::new(
+        Failpoint::new(Registry {
+            entries: vec!["alpha"],
+        }),
+        &logger,
+    );
+
+    let entries: Result<&[&str], _> =
+        provider.try_provide_ref_with(().then_fail_after(usize::MAX).then_intercept());
+    assert_eq!(entries, Ok(&["alpha"][..]));
+    let entries: Result<&[&str], _> =
+        provider.try_provide_ref_with(().then_fail_after(0).then_intercept());
+    assert_eq!(
+        entries,
+        Err(InterceptError::Provide(FailpointError::Injected))
+    );
+    assert_eq!(
+        logger.log.into_inner(),
+        [
+            "before &[&str]",
+            "after &[&str]",
+            "before &[&str]",
+            "after &[&str]"
+        ],
+    );
+}
+
+#[test]
+fn intercept_with_context_mut() {
+    let logger = Logger::default();
+    let mut provider = Intercepted::new(vec![1, 2, 3], &logger);
+
+    let result: Result<Option<&mut [i32]>, _> =
+        provider.try_provide_mut_with(().then_some().then_intercept());
+    result.unwrap().unwrap()[0] = 0;
+    let (dependency, _): (Vec<i32>, _) = provider.try_provide_with(Intercept::default()).unwrap();
+    assert_eq!(dependency, [0, 2, 3]);
+    assert_eq!(
+        logger.log.into_inner(),
+        [
+            "before core::option::Option<&mut [i32]>",
+            "after core::option::Option<&mut [i32]>",
+            "before alloc::vec::Vec<i32>",
+            "after alloc::vec::Vec<i32>",
+        ],
+    );
+}
+
+/// Interceptor which denies provision of dependencies of some type.
+struct Deny {
+    denied: &'static str,
+    log: Logger,
+}
+
+impl Interceptor for Deny {
+    type Error = &'static str;
+
+    fn before<T: ?Sized>(&self) -> Result<(), Self::Error> {
+        let Self { denied, log } = self;
+        if type_name::<T>() == *denied {
+            return Err(*denied);
+        }
+        log.before::<T>().map_err(|error| match error {})
+    }
+
+    fn after<T: ?Sized>(&self) {
+        let Self { log, .. } = self;
+        log.after::<T>();
+    }
+}
+
+#[test]
+fn intercept_denied() {
+    let interceptor = Deny {
+        denied: "&str",
+        log: Logger::default(),
+    };
+    let mut provider = Intercepted::new(String::from("hello"), interceptor);
+
+    let result: Result<&str, _> = provider.try_provide_ref_with(Intercept::default());
+    assert_eq!(result, Err(InterceptError::Denied("&str")));
+    let result: Result<&[u8], _> = provider.try_provide_ref_with(Intercept::default());
+    assert_eq!(result, Ok(&b"hello"[..]));
+    let result: Result<&mut str, _> = provider.try_provide_mut_with(Intercept::default());
+    assert_eq!(result.unwrap(), "hello");
+
+    let result: Result<(Box<str>, _), _> = provider.try_provide_with(Intercept::default());
+    let (dependency, remainder) = result.unwrap();
+    assert_eq!(&*dependency, "hello");
+
+    let ((), interceptor) = remainder.into_inner();
+    assert_eq!(
+        interceptor.log.log.into_inner(),
+        [
+            "before &[u8]",
+            "after &[u8]",
+            "before &mut str",
+            "after &mut str",
+            "before alloc::boxed::Box<str>",
+            "after alloc::boxed::Box<str>",
+        ],
+    );
+}
@@ -0,0 +1,106 @@
+use provide::{
+    context::Context,
+    failpoint::{Failpoint, FailpointError},
+    with::{ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideRefWith},
+};
+
+#[derive(Debug, PartialEq)]
+struct Db(&'static str);
+
+#[derive(Debug, PartialEq)]
+struct Service {
+    url: &'static str,
+}
+
+impl Service {
+    fn new(db: &Db) -> Self {
+        let Db(url) = *db;
+        Self { url }
+    }
+}
+
+struct Provider {
+    db: Db,
+}
+
+impl AsRef<Db> for Provider {
+    fn as_ref(&self) -> &Db {
+        let Self { db } = self;
+        db
+    }
+}
+
+impl AsMut<Db> for Provider {
+    fn as_mut(&mut self) -> &mut Db {
+        let Self { db } = self;
+        db
+    }
+}
+
+#[test]
+fn context() {
+    let provider = Provider {
+        db: Db("postgres://"),
+    };
+
+    let db: Option<&Db> = provider.provide_ref_with_into(().then_some());
+    assert_eq!(db, Some(&Db("postgres://")));
+}
+
+#[test]
+fn function() {
+    let (service, ()): (Service, _) =
+        Db("sqlite::memory:").provide_with_into(|db: Db| Service::new(&db));
+    assert_eq!(
+        service,
+        Service {
+            url: "sqlite::memory:"
+        }
+    );
+
+    let mut provider = Provider {
+        db: Db("postgres://"),
+    };
+
+    let service: Service = provider.provide_ref_with_into(Service::new);
+    assert_eq!(service, Service { url: "postgres://" });
+
+    let url: &str = provider.provide_mut_with_into(|db: &mut Db| {
+        db.0 = "mysql://";
+        db.0
+    });
+    assert_eq!(url, "mysql://");
+}
+
+#[test]
+fn function_error() {
+    let provider = Failpoint::new(Provider {
+        db: Db("postgres://"),
+    });
+
+    let service: Result<Service, _> = provider
+        .try_provide_ref_with_into(().then_fail_after(usize::MAX).then_map_new(Service::new));
+    assert_eq!(service, Ok(Service { url: "postgres://" }));
+
+    let service: Result<Service, _> =
+        provider.try_provide_ref_with_into(().then_fail_after(0).then_map_new(Service::new));
+    assert_eq!(service, Err(FailpointError::Injected));
+}
+
+#[cfg(feature = "parking_lot")]
+#[test]
+fn timeout() {
+    use std::{sync::Arc, time::Duration};
+
+    use parking_lot::{Mutex, MutexGuard};
+    use provide::context::lock::{TimedOut, TryAcquireError};
+
+    let provider = Arc::new(Mutex::new(1));
+    let timeout = Duration::from_millis(10);
+
+    let guard: Result<MutexGuard<i32>, _> = provider.try_provide_ref_with_into(timeout);
+    assert_eq!(guard.as_deref(), Ok(&1));
+
+    let other: Result<MutexGuard<i32>, _> = provider.try_provide_ref_with_into(timeout);
+    assert_eq!(other.err(), Some(TryAcquireError::Acquire(TimedOut)));
+}
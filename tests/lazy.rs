@@ -0,0 +1,197 @@
+use core::{cell::Cell, convert::Infallible, error::Error};
+use std::panic::{self, AssertUnwindSafe};
+
+use provide::{
+    context::{lazy::LazyDependency, Context},
+    failpoint::{Failpoint, FailpointError},
+    graph::Graph,
+    lazy::{ForceError, Lazy, LazyError, LazyProvider},
+    ordered_graph,
+    with::{ProvideMutWith, ProvideRefWith, TryProvideMutWith, TryProvideRefWith},
+    ProvideExt,
+};
+
+thread_local! {
+    static BUILT: Cell<u32> = const { Cell::new(0) };
+}
+
+struct Pool {
+    size: u32,
+}
+
+struct Repository {
+    pool_size: u32,
+}
+
+ordered_graph! {
+    struct Storage {
+        pool: Pool = () => {
+            BUILT.with(|built| built.set(built.get() + 1));
+            Pool { size: 4 }
+        },
+        repository: Repository = (pool) => Repository { pool_size: pool.size },
+    }
+}
+
+#[test]
+fn lazy_subgraph() {
+    let provider = LazyProvider::new(Storage::build);
+    assert!(provider.into_inner().is_err());
+    assert_eq!(BUILT.with(Cell::get), 0);
+
+    let mut provider = LazyProvider::new(Storage::build);
+    assert_eq!(provider.get_dep::<Repository>().pool_size, 4);
+    provider.get_dep_mut::<Pool>().size = 8;
+    assert_eq!(provider.get_dep::<Pool>().size, 8);
+    assert_eq!(BUILT.with(Cell::get), 1);
+
+    let storage = provider.into_inner().ok().unwrap();
+    assert_eq!(storage.get_dep::<Repository>().pool_size, 4);
+}
+
+#[test]
+fn lazy_subgraph_poisoned() {
+    let provider = LazyProvider::new(|| -> Storage { panic!("storage is unavailable") });
+    let result = panic::catch_unwind(AssertUnwindSafe(|| provider.force().pool.size));
+    assert!(result.is_err());
+
+    assert_eq!(provider.try_force().err(), Some(ForceError::Poisoned));
+    assert!(provider.try_into_inner().is_none());
+}
+
+thread_local! {
+    static STORAGE: LazyProvider<Storage> = const { LazyProvider::new(reentrant_storage) };
+}
+
+fn reentrant_storage() -> Storage {
+    let error = STORAGE.with(|provider| provider.try_force().err());
+    assert_eq!(error, Some(ForceError::Reentrant));
+    let result = STORAGE
+        .with(|provider| panic::catch_unwind(AssertUnwindSafe(|| provider.force().pool.size)));
+    assert!(result.is_err());
+    Storage::build()
+}
+
+#[test]
+fn lazy_subgraph_reentrant() {
+    STORAGE.with(|provider| {
+        assert_eq!(provider.get_dep::<Repository>().pool_size, 4);
+        assert!(provider.try_force().is_ok());
+    });
+
+    let error = ForceError::Reentrant;
+    assert_eq!(
+        error.to_string(),
+        "lazy value was requested recursively while initializing",
+    );
+    assert!(error.source().is_none());
+}
+
+struct Provider {
+    pool: Lazy<Pool>,
+}
+
+impl AsRef<Lazy<Pool>> for Provider {
+    fn as_ref(&self) -> &Lazy<Pool> {
+        let Self { pool } = self;
+        pool
+    }
+}
+
+impl AsMut<Lazy<Pool>> for Provider {
+    fn as_mut(&mut self) -> &mut Lazy<Pool> {
+        let Self { pool } = self;
+        pool
+    }
+}
+
+fn pool() -> Pool {
+    Pool { size: 2 }
+}
+
+#[test]
+fn lazy_dependency() {
+    let mut provider = Provider {
+        pool: Lazy::new(pool),
+    };
+    assert!(provider.pool.get().is_none());
+
+    let pool: &Pool = provider.provide_ref_with(LazyDependency::default());
+    assert_eq!(pool.size, 2);
+    let pool: &mut Pool = provider.provide_mut_with(().then_lazy());
+    pool.size = 3;
+    assert_eq!(provider.pool.get().map(|pool| pool.size), Some(3));
+    let pool: Result<&mut Pool, _> = provider.try_provide_mut_with(().then_lazy());
+    assert_eq!(pool.map(|pool| pool.size).ok(), Some(3));
+
+    let pool = provider.pool.into_inner().ok().unwrap();
+    assert_eq!(pool.size, 3);
+}
+
+#[test]
+fn lazy_dependency_error() {
+    let provider = Failpoint::new(Provider {
+        pool: Lazy::new(pool),
+    });
+
+    let result: Result<&Pool, _> = provider.try_provide_ref_with(().then_fail_after(0).then_lazy());
+    let error = result.err().unwrap();
+    assert_eq!(error, LazyError::Provide(FailpointError::Injected));
+    assert_eq!(error.to_string(), "error was injected by the failpoint");
+    assert!(error.source().is_none());
+
+    let result: Result<&Pool, _> =
+        provider.try_provide_ref_with(().then_fail_after(usize::MAX).then_lazy());
+    assert_eq!(result.map(|pool| pool.size).ok(), Some(2));
+}
+
+#[test]
+fn lazy_dependency_poisoned() {
+    let mut provider = Provider {
+        pool: Lazy::new(|| panic!("pool is unavailable")),
+    };
+    let result = panic::catch_unwind(AssertUnwindSafe(|| provider.pool.force().size));
+    assert!(result.is_err());
+
+    let result: Result<&Pool, _> = provider.try_provide_ref_with(LazyDependency::default());
+    assert_eq!(result.err(), Some(LazyError::Poisoned));
+    let result: Result<&mut Pool, _> = provider.try_provide_mut_with(LazyDependency::default());
+    assert_eq!(result.err(), Some(LazyError::Poisoned));
+    assert_eq!(
+        provider.pool.try_force_mut().err(),
+        Some(ForceError::Poisoned)
+    );
+    assert!(provider.pool.try_into_inner().is_none());
+}
+
+thread_local! {
+    static PROVIDER: Provider = const {
+        Provider {
+            pool: Lazy::new(reentrant_pool),
+        }
+    };
+}
+
+fn reentrant_pool() -> Pool {
+    let error = PROVIDER.with(|provider| {
+        let result: Result<&Pool, _> = provider.try_provide_ref_with(LazyDependency::default());
+        result.err()
+    });
+    assert_eq!(error, Some(LazyError::Reentrant));
+    pool()
+}
+
+#[test]
+fn lazy_dependency_reentrant() {
+    PROVIDER.with(|provider| {
+        let result: Result<&Pool, _> = provider.try_provide_ref_with(LazyDependency::default());
+        assert_eq!(result.map(|pool| pool.size).ok(), Some(2));
+    });
+
+    let error = LazyError::<Infallible>::Reentrant;
+    assert_eq!(
+        error.to_string(),
+        "dependency was requested recursively while constructing",
+    );
+    assert!(error.source().is_none());
+}
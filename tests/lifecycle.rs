@@ -0,0 +1,69 @@
+use core::cell::RefCell;
+
+use provide::{
+    lifecycle::{Initialize, Scope, Shutdown},
+    pair::Pair,
+};
+
+struct Service<'a> {
+    id: u32,
+    log: &'a RefCell<Vec<(&'static str, u32)>>,
+}
+
+impl Initialize for Service<'_> {
+    fn initialize(&mut self) {
+        self.log.borrow_mut().push(("initialize", self.id));
+    }
+}
+
+impl Shutdown for Service<'_> {
+    fn shutdown(&mut self) {
+        self.log.borrow_mut().push(("shutdown", self.id));
+    }
+}
+
+#[test]
+fn tuple_scope() {
+    let log = RefCell::new(Vec::new());
+    {
+        let scope = Scope::new((
+            Service { id: 1, log: &log },
+            Service { id: 2, log: &log },
+            Service { id: 3, log: &log },
+        ));
+        let (first, ..) = &*scope;
+        assert_eq!(first.id, 1);
+    }
+    let log = log.into_inner();
+    assert_eq!(
+        log,
+        [
+            ("initialize", 1),
+            ("initialize", 2),
+            ("initialize", 3),
+            ("shutdown", 3),
+            ("shutdown", 2),
+            ("shutdown", 1),
+        ],
+    );
+}
+
+#[test]
+fn pair_scope() {
+    let log = RefCell::new(Vec::new());
+    let scope = Scope::new(Pair::new(
+        Service { id: 1, log: &log },
+        Pair::new(Service { id: 2, log: &log }, ()),
+    ));
+    drop(scope);
+    let log = log.into_inner();
+    assert_eq!(
+        log,
+        [
+            ("initialize", 1),
+            ("initialize", 2),
+            ("shutdown", 2),
+            ("shutdown", 1),
+        ],
+    );
+}
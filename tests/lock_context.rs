@@ -0,0 +1,213 @@
+#![cfg(any(feature = "std", feature = "parking_lot"))]
+
+#[cfg(feature = "std")]
+mod std {
+    use std::sync::{Arc, Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+    use provide::{
+        context::{
+            failpoint::FailAfter,
+            lock::{
+                Lock, LockError, Read, TryAcquireError, TryLock, TryLockError, TryLockWith,
+                TryRead, TryWrite, Write,
+            },
+        },
+        failpoint::{Failpoint, FailpointError},
+        with::{ProvideMutWith, ProvideRefWith, TryProvideMutWith, TryProvideRefWith},
+    };
+
+    #[test]
+    fn mutex() {
+        let provider = Arc::new(Mutex::new(1));
+
+        let mut guard: MutexGuard<i32> = provider.provide_ref_with(Lock::default());
+        *guard += 1;
+
+        let other: Result<MutexGuard<i32>, _> = provider.try_provide_ref_with(TryLock::default());
+        assert_eq!(
+            other.err(),
+            Some(TryAcquireError::Acquire(TryLockError::WouldBlock))
+        );
+
+        drop(guard);
+        let guard: Result<MutexGuard<i32>, _> = provider.try_provide_ref_with(TryLock::default());
+        assert_eq!(guard.as_deref(), Ok(&2));
+    }
+
+    #[test]
+    fn poisoned_mutex() {
+        let provider = Arc::new(Mutex::new(1));
+        let clone = Arc::clone(&provider);
+        let _ = std::thread::spawn(move || {
+            let _guard = clone.lock();
+            panic!("poison the mutex");
+        })
+        .join();
+        assert!(provider.is_poisoned());
+
+        let guard: MutexGuard<i32> = provider.provide_ref_with(Lock::default());
+        assert_eq!(*guard, 1);
+        drop(guard);
+
+        let guard: Result<MutexGuard<i32>, _> = provider.try_provide_ref_with(TryLock::default());
+        assert_eq!(
+            guard.err(),
+            Some(TryAcquireError::Acquire(TryLockError::Poisoned))
+        );
+
+        let guard: Result<MutexGuard<i32>, _> = provider.try_provide_ref_with(Lock::default());
+        assert_eq!(guard.err(), Some(LockError::Poisoned));
+    }
+
+    #[test]
+    fn failed_mutex() {
+        let provider = Failpoint::new(Arc::new(Mutex::new(1)));
+
+        let guard: Result<MutexGuard<i32>, _> =
+            provider.try_provide_ref_with(TryLockWith::new(FailAfter::new(0, ())));
+        assert_eq!(
+            guard.err(),
+            Some(TryAcquireError::Provide(FailpointError::Injected)),
+        );
+    }
+
+    #[test]
+    fn rw_lock() {
+        let provider = Arc::new(RwLock::new(1));
+
+        let guard: RwLockReadGuard<i32> = provider.provide_ref_with(Read::default());
+        let other: Result<RwLockReadGuard<i32>, _> =
+            provider.try_provide_ref_with(TryRead::default());
+        assert_eq!(other.as_deref(), Ok(&1));
+
+        let writer: Result<RwLockWriteGuard<i32>, _> =
+            provider.try_provide_ref_with(TryWrite::default());
+        assert_eq!(
+            writer.err(),
+            Some(TryAcquireError::Acquire(TryLockError::WouldBlock))
+        );
+
+        drop((guard, other));
+        let mut writer: RwLockWriteGuard<i32> = provider.provide_ref_with(Write::default());
+        *writer += 1;
+        assert_eq!(*writer, 2);
+    }
+
+    #[test]
+    fn by_mut() {
+        let mut provider = Box::new(Mutex::new(1));
+
+        let mut guard: MutexGuard<i32> = provider.provide_mut_with(Lock::default());
+        *guard += 1;
+        drop(guard);
+
+        let guard: Result<MutexGuard<i32>, _> = provider.try_provide_mut_with(TryLock::default());
+        assert_eq!(guard.as_deref(), Ok(&2));
+    }
+}
+
+#[cfg(feature = "parking_lot")]
+mod parking_lot {
+    use std::{sync::Arc, thread, time::Duration};
+
+    use parking_lot::{Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard};
+    use provide::{
+        context::{
+            failpoint::FailAfter,
+            lock::{
+                Lock, Read, TimedOut, TryAcquireError, TryLock, TryLockFor, TryLockForWith,
+                TryRead, TryReadFor, TryWrite, TryWriteFor, WouldBlock, Write,
+            },
+        },
+        failpoint::{Failpoint, FailpointError},
+        with::{ProvideRefWith, TryProvideMutWith, TryProvideRefWith},
+    };
+
+    #[test]
+    fn mutex() {
+        let provider = Arc::new(Mutex::new(1));
+
+        let mut guard: MutexGuard<i32> = provider.provide_ref_with(Lock::default());
+        *guard += 1;
+
+        let other: Result<MutexGuard<i32>, _> = provider.try_provide_ref_with(TryLock::default());
+        assert_eq!(other.err(), Some(TryAcquireError::Acquire(WouldBlock)));
+
+        drop(guard);
+        let guard: Result<MutexGuard<i32>, _> = provider.try_provide_ref_with(TryLock::default());
+        assert_eq!(guard.as_deref(), Ok(&2));
+    }
+
+    #[test]
+    fn rw_lock() {
+        let provider = Arc::new(RwLock::new(1));
+
+        let guard: RwLockReadGuard<i32> = provider.provide_ref_with(Read::default());
+        let other: Result<RwLockReadGuard<i32>, _> =
+            provider.try_provide_ref_with(TryRead::default());
+        assert_eq!(other.as_deref(), Ok(&1));
+
+        let writer: Result<RwLockWriteGuard<i32>, _> =
+            provider.try_provide_ref_with(TryWrite::default());
+        assert_eq!(writer.err(), Some(TryAcquireError::Acquire(WouldBlock)));
+
+        drop((guard, other));
+        let mut writer: RwLockWriteGuard<i32> = provider.provide_ref_with(Write::default());
+        *writer += 1;
+        assert_eq!(*writer, 2);
+    }
+
+    #[test]
+    fn timeout() {
+        let provider = Arc::new(Mutex::new(1));
+        let timeout = Duration::from_millis(10);
+
+        let guard: MutexGuard<i32> = provider.provide_ref_with(Lock::default());
+        let other: Result<MutexGuard<i32>, _> =
+            provider.try_provide_ref_with(TryLockFor::new(timeout, ()));
+        assert_eq!(other.err(), Some(TryAcquireError::Acquire(TimedOut)));
+
+        let clone = Arc::clone(&provider);
+        let handle = thread::spawn(move || {
+            let timeout = Duration::from_secs(60);
+            let guard: Result<MutexGuard<i32>, _> =
+                clone.try_provide_ref_with(TryLockFor::new(timeout, ()));
+            guard.map(|guard| *guard)
+        });
+        drop(guard);
+        assert_eq!(handle.join().unwrap(), Ok(1));
+    }
+
+    #[test]
+    fn rw_lock_timeout() {
+        let mut provider = Box::new(RwLock::new(1));
+        let timeout = Duration::from_millis(10);
+
+        let guard: Result<RwLockWriteGuard<i32>, _> =
+            provider.try_provide_mut_with(TryWriteFor::new(timeout, ()));
+        assert_eq!(guard.as_deref(), Ok(&1));
+        drop(guard);
+
+        let guard: RwLockReadGuard<i32> = provider.provide_ref_with(Read::default());
+        let other: Result<RwLockReadGuard<i32>, _> =
+            provider.try_provide_ref_with(TryReadFor::new(timeout, ()));
+        assert_eq!(other.as_deref(), Ok(&1));
+
+        let writer: Result<RwLockWriteGuard<i32>, _> =
+            provider.try_provide_ref_with(TryWriteFor::new(timeout, ()));
+        assert_eq!(writer.err(), Some(TryAcquireError::Acquire(TimedOut)));
+        drop((guard, other));
+    }
+    #[test]
+    fn failed_timeout() {
+        let provider = Failpoint::new(Arc::new(Mutex::new(1)));
+        let timeout = Duration::from_millis(10);
+
+        let guard: Result<MutexGuard<i32>, _> =
+            provider.try_provide_ref_with(TryLockForWith::new(timeout, FailAfter::new(0, ())));
+        assert_eq!(
+            guard.err(),
+            Some(TryAcquireError::Provide(FailpointError::Injected)),
+        );
+    }
+}
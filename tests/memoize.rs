@@ -0,0 +1,143 @@
+use core::convert::Infallible;
+use std::{
+    cell::{Cell, RefCell},
+    rc::{Rc, Weak},
+};
+
+use provide::{
+    context::{memoize::Memoize, wrap::OptionDependency},
+    memoize::{MemoizeError, Memoized},
+    with::{ProvideMutWith, ProvideRefWith, TryProvideMutWith, TryProvideRefWith},
+    ProvideRef, TryProvideRef,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Config(u32);
+
+#[derive(Default)]
+struct Provider {
+    loads: Cell<u32>,
+}
+
+impl<'me> ProvideRef<'me, Config> for Provider {
+    fn provide_ref(&'me self) -> Config {
+        let Self { loads } = self;
+        loads.set(loads.get() + 1);
+        Config(loads.get())
+    }
+}
+
+#[test]
+fn cached() {
+    let mut provider: Memoized<_, Config> = Memoized::new(Provider::default(), ());
+    assert_eq!(provider.cached(), None);
+
+    let first: &Config = provider.provide_ref_with(Memoize);
+    let second: Result<&Config, _> = provider.try_provide_ref_with(Memoize);
+    assert!(core::ptr::eq(first, second.unwrap()));
+    assert_eq!(provider.cached(), Some(&Config(1)));
+
+    let dependency: &mut Config = provider.provide_mut_with(Memoize);
+    dependency.0 += 10;
+    assert_eq!(provider.reset(), Some(Config(11)));
+
+    let dependency: Result<&mut Config, _> = provider.try_provide_mut_with(Memoize);
+    assert_eq!(dependency, Ok(&mut Config(2)));
+
+    let (provider, cached) = provider.into_inner();
+    assert_eq!(provider.loads.get(), 2);
+    assert_eq!(cached, Some(Config(2)));
+}
+
+#[test]
+fn inner_context() {
+    let provider: Memoized<_, Option<Config>, _> =
+        Memoized::new(Provider::default(), OptionDependency::default());
+    let dependency: &Option<Config> = provider.provide_ref_with(Memoize);
+    assert_eq!(dependency, &Some(Config(1)));
+
+    let dependency: Result<&Option<Config>, _> = provider.try_provide_ref_with(Memoize);
+    assert_eq!(dependency, Ok(&Some(Config(1))));
+
+    let (provider, _) = provider.into_inner();
+    assert_eq!(provider.loads.get(), 1);
+}
+
+#[derive(Debug, PartialEq)]
+struct Cycle;
+
+struct Recursive {
+    this: Weak<Memoized<Recursive, Config>>,
+}
+
+impl<'me> TryProvideRef<'me, Config> for Recursive {
+    type Error = Cycle;
+
+    fn try_provide_ref(&'me self) -> Result<Config, Self::Error> {
+        let Self { this } = self;
+        let this = this.upgrade().ok_or(Cycle)?;
+        match this.try_force() {
+            Ok(Config(value)) => Ok(Config(value + 1)),
+            Err(MemoizeError::Reentrant) => Err(Cycle),
+            Err(MemoizeError::Provide(error)) => Err(error),
+        }
+    }
+}
+
+#[test]
+fn reentrant() {
+    let provider = Rc::new_cyclic(|this| {
+        let this = Weak::clone(this);
+        Memoized::new(Recursive { this }, ())
+    });
+    assert_eq!(provider.try_force(), Err(MemoizeError::Provide(Cycle)));
+    let dependency: Result<&Config, _> = Memoized::try_provide_ref_with(&provider, Memoize);
+    assert_eq!(dependency, Err(MemoizeError::Provide(Cycle)));
+    assert_eq!(provider.cached(), None);
+
+    let provider: Memoized<_, Config> = Memoized::new(Provider::default(), ());
+    assert_eq!(provider.try_force(), Ok(&Config(1)));
+    assert_eq!(provider.try_force(), Ok(&Config(1)));
+}
+
+#[derive(Clone)]
+struct Snapshot {
+    this: Weak<Memoized<Snapshot, Config>>,
+    clone: Rc<RefCell<Option<Memoized<Snapshot, Config>>>>,
+}
+
+impl<'me> TryProvideRef<'me, Config> for Snapshot {
+    type Error = Infallible;
+
+    fn try_provide_ref(&'me self) -> Result<Config, Self::Error> {
+        let Self { this, clone } = self;
+        if let Some(this) = this.upgrade() {
+            clone.replace(Some(Memoized::clone(&this)));
+        }
+        Ok(Config(1))
+    }
+}
+
+#[test]
+fn clone_while_pending() {
+    let clone = Rc::default();
+    let provider = Rc::new_cyclic(|this| {
+        let this = Weak::clone(this);
+        let clone = Rc::clone(&clone);
+        Memoized::new(Snapshot { this, clone }, ())
+    });
+    assert_eq!(provider.try_force(), Ok(&Config(1)));
+
+    let clone = clone.take().unwrap();
+    assert_eq!(clone.cached(), None);
+    assert_eq!(clone.try_force(), Ok(&Config(1)));
+}
+
+#[test]
+fn default() {
+    let provider: Memoized<Provider, Config> = Memoized::default();
+    assert_eq!(provider.cached(), None);
+
+    let dependency: &Config = provider.provide_ref_with(Memoize);
+    assert_eq!(dependency, &Config(1));
+}
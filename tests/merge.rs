@@ -0,0 +1,95 @@
+use provide::{
+    context::{merge::Select, Context},
+    merge::{Left, Merge, Merged, Right},
+    pair::Here,
+    with::{ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideRefWith},
+};
+
+struct Library {
+    name: String,
+}
+
+impl AsRef<str> for Library {
+    fn as_ref(&self) -> &str {
+        let Self { name } = self;
+        name
+    }
+}
+
+struct Application {
+    items: Vec<i32>,
+}
+
+impl AsRef<[i32]> for Application {
+    fn as_ref(&self) -> &[i32] {
+        let Self { items } = self;
+        items
+    }
+}
+
+impl AsMut<[i32]> for Application {
+    fn as_mut(&mut self) -> &mut [i32] {
+        let Self { items } = self;
+        items
+    }
+}
+
+#[test]
+fn by_ref() {
+    let library = Library {
+        name: String::from("library"),
+    };
+    let application = Application {
+        items: vec![1, 2, 3],
+    };
+    let provider = library.merge(application);
+
+    let name: &str = provider.provide_ref_with(Select::default());
+    let items: Result<&[i32], _> = provider.try_provide_ref_with(().then_select());
+    assert_eq!((name, items), ("library", Ok([1, 2, 3].as_slice())));
+}
+
+#[test]
+fn by_mut() {
+    let library = Library {
+        name: String::from("library"),
+    };
+    let application = Application {
+        items: vec![1, 2, 3],
+    };
+    let mut provider = library.merge(application);
+
+    let items: &mut [i32] = provider.provide_mut_with(Select::default());
+    items[0] = 0;
+    assert_eq!(provider.right().items, [0, 2, 3]);
+}
+
+#[test]
+fn by_value() {
+    let provider = 1_i32.merge("hello");
+
+    let (dependency, remainder): (i64, _) = provider.provide_with(Select::default());
+    assert_eq!(dependency, 1);
+    assert_eq!(remainder, Merged::new((), "hello"));
+}
+
+#[test]
+fn nested() {
+    let library = Library {
+        name: String::from("library"),
+    };
+    let application = Application {
+        items: vec![1, 2, 3],
+    };
+    let provider = Box::new(1)
+        .merge(Box::new(2))
+        .merge(library.merge(application));
+
+    let first: &i32 = provider.provide_ref_with(Select::<Left<Left<Here>>>::default());
+    let second: &i32 = provider.provide_ref_with(Select::<Left<Right<Here>>>::default());
+    assert_eq!((*first, *second), (1, 2));
+
+    let name: &str = provider.provide_ref_with(Select::default());
+    let items: &[i32] = provider.provide_ref_with(Select::<Right<Right<Here>>>::default());
+    assert_eq!((name, items), ("library", [1, 2, 3].as_slice()));
+}
@@ -1,4 +1,9 @@
-use provide::with::{ProvideMutWith, ProvideRefWith, ProvideWith};
+use core::cell::Cell;
+
+use provide::{
+    context::Context,
+    with::{ProvideMutWith, ProvideRefWith, ProvideWith},
+};
 
 #[test]
 fn by_value() {
@@ -26,7 +31,7 @@ fn by_value() {
     }
 
     let provider = GenericProvider(1);
-    let context = WrapOptionWith::default();
+    let context = WrapOptionWith::<()>::default();
     let (dependency, _) = provider.provide_with(context);
     assert_eq!(dependency, Some(1));
 }
@@ -55,7 +60,7 @@ fn by_ref() {
     }
 
     let provider = GenericProvider("hello");
-    let context = WrapOptionWith::default();
+    let context = WrapOptionWith::<()>::default();
     let dependency = provider.provide_ref_with(context);
     assert_eq!(dependency, Some("hello"));
 }
@@ -84,7 +89,72 @@ fn by_mut() {
     }
 
     let mut provider = GenericProvider([1, 2, 3, 4, 5]);
-    let context = WrapOptionWith::default();
+    let context = WrapOptionWith::<()>::default();
     let dependency = provider.provide_mut_with(context);
     assert_eq!(dependency, Some([1, 2, 3, 4, 5].as_mut_slice()));
 }
+
+#[test]
+fn then() {
+    struct GenericProvider<T>(T)
+    where
+        T: ?Sized;
+
+    struct WrapOptionWith<C>(C)
+    where
+        C: ?Sized;
+
+    impl<C> Context for WrapOptionWith<C> {}
+
+    impl<'me, T, U, C> ProvideRefWith<'me, Option<T>, WrapOptionWith<C>> for GenericProvider<U>
+    where
+        U: ProvideRefWith<'me, T, C> + ?Sized,
+    {
+        fn provide_ref_with(&'me self, context: WrapOptionWith<C>) -> Option<T> {
+            let Self(provider) = self;
+            let WrapOptionWith(context) = context;
+            let dependency = provider.provide_ref_with(context);
+            Some(dependency)
+        }
+    }
+
+    let provider = GenericProvider(Box::new(Cell::new(1)));
+    let context = ().then_take_cell().then(WrapOptionWith);
+    let dependency: Option<i32> = provider.provide_ref_with(context);
+    assert_eq!(dependency, Some(1));
+}
+
+#[test]
+fn stateful() {
+    struct Provider {
+        replicas: Vec<i32>,
+    }
+
+    #[derive(Default)]
+    struct RoundRobin {
+        next: usize,
+    }
+
+    impl Context for RoundRobin {}
+
+    impl<'me> ProvideRefWith<'me, &'me i32, &mut RoundRobin> for Provider {
+        fn provide_ref_with(&'me self, context: &mut RoundRobin) -> &'me i32 {
+            let Self { replicas } = self;
+            let RoundRobin { next } = context;
+            let replica = &replicas[*next % replicas.len()];
+            *next += 1;
+            replica
+        }
+    }
+
+    let provider = Provider {
+        replicas: vec![1, 2],
+    };
+    let mut context = RoundRobin::default();
+
+    let dependency: &i32 = provider.provide_ref_with(&mut context);
+    assert_eq!(*dependency, 1);
+    let dependency: Option<&i32> = provider.provide_ref_with((&mut context).then_some());
+    assert_eq!(dependency, Some(&2));
+    assert_eq!(context.next, 2);
+}
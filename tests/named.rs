@@ -0,0 +1,82 @@
+use provide::{
+    context::{
+        alias::{NamedDependency, NamedDependencyWith},
+        Context,
+    },
+    named::{name, Named},
+    with::{ProvideMutWith, ProvideRefWith, ProvideWith},
+};
+
+const PRIMARY: u128 = name("primary");
+const REPLICA: u128 = name("replica");
+
+struct Hosts {
+    primary: Named<String, PRIMARY>,
+    replica: Named<String, REPLICA>,
+}
+
+impl AsRef<Named<String, PRIMARY>> for Hosts {
+    fn as_ref(&self) -> &Named<String, PRIMARY> {
+        let Self { primary, .. } = self;
+        primary
+    }
+}
+
+impl AsRef<Named<String, REPLICA>> for Hosts {
+    fn as_ref(&self) -> &Named<String, REPLICA> {
+        let Self { replica, .. } = self;
+        replica
+    }
+}
+
+impl AsMut<Named<String, REPLICA>> for Hosts {
+    fn as_mut(&mut self) -> &mut Named<String, REPLICA> {
+        let Self { replica, .. } = self;
+        replica
+    }
+}
+
+#[test]
+fn names() {
+    assert_eq!(name(""), 0);
+    assert_ne!(PRIMARY, REPLICA);
+    assert_eq!(Named::<(), PRIMARY>::default().name(), "primary");
+    assert_eq!(
+        Named::<(), { name("sixteen bytes!!!") }>::default().name(),
+        "sixteen bytes!!!"
+    );
+    assert_eq!(Named::<(), { u128::MAX }>::default().name(), "");
+    assert_eq!(
+        format!("{:?}", Named::<_, REPLICA>::new(1)),
+        r#"Named { name: "replica", value: 1 }"#,
+    );
+}
+
+#[test]
+#[should_panic = "name must be at most 16 bytes long"]
+fn too_long() {
+    name("seventeen bytes!!");
+}
+
+#[test]
+fn selected_by_name() {
+    let mut hosts = Hosts {
+        primary: Named::new(String::from("db-1")),
+        replica: Named::new(String::from("db-2")),
+    };
+
+    let host: &String = hosts.provide_ref_with(NamedDependency::<String, PRIMARY>::default());
+    assert_eq!(host, "db-1");
+
+    let host: Option<&String> =
+        hosts.provide_ref_with(NamedDependencyWith::<String, REPLICA, _>::new(()).then_some());
+    assert_eq!(host.map(String::as_str), Some("db-2"));
+
+    let host: &mut String = hosts.provide_mut_with(().then_alias::<Named<String, REPLICA>>());
+    host.push_str("-backup");
+    assert_eq!(hosts.replica.into_inner(), "db-2-backup");
+
+    let (port, ()): (u16, _) = Named::<u16, { name("port") }>::new(5432)
+        .provide_with(NamedDependency::<u16, { name("port") }>::default());
+    assert_eq!(port, 5432);
+}
@@ -0,0 +1,44 @@
+use core::cell::{Cell, RefCell};
+
+use provide::{
+    context::cell::TakeCell,
+    observe::{Observed, Observer},
+    with::ProvideRefWith,
+    ProvideExt,
+};
+
+#[derive(Default)]
+struct Recorder {
+    names: RefCell<Vec<String>>,
+}
+
+impl Observer for Recorder {
+    fn on_provide(&self, type_name: &str) {
+        self.names.borrow_mut().push(type_name.to_owned());
+    }
+}
+
+#[test]
+fn trait_object() {
+    let recorder = Recorder::default();
+    let mut provider = Observed::new(vec![1, 2, 3], &recorder as &dyn Observer);
+
+    provider.get_dep_mut::<[i32]>()[0] = 0;
+    assert_eq!(provider.get_dep::<Vec<i32>>(), &[0, 2, 3]);
+    assert_eq!(
+        recorder.names.into_inner(),
+        ["[i32]", "alloc::vec::Vec<i32>"]
+    );
+}
+
+#[test]
+fn function_pointer() {
+    fn ignore(_: &str) {}
+
+    let provider = Observed::new(Box::new(Cell::new(1)), ignore as fn(&str));
+    let dependency: i32 = provider.provide_ref_with(TakeCell::default());
+    assert_eq!(dependency, 1);
+
+    let (inner, _) = provider.into_inner();
+    assert_eq!(Cell::get(&inner), 0);
+}
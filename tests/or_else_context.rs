@@ -0,0 +1,89 @@
+use core::num::TryFromIntError;
+
+use provide::{
+    context::{failpoint::FailAfter, or_else::OrElse, Context},
+    failpoint::{Failpoint, FailpointError},
+    with::TryProvideRefWith,
+    ProvideRef,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Port(u16);
+
+impl TryFrom<i64> for Port {
+    type Error = TryFromIntError;
+
+    fn try_from(port: i64) -> Result<Self, Self::Error> {
+        u16::try_from(port).map(Self)
+    }
+}
+
+struct Settings {
+    cached: Port,
+    raw: i64,
+}
+
+impl ProvideRef<'_, Port> for Settings {
+    fn provide_ref(&self) -> Port {
+        let Self { cached, .. } = self;
+        *cached
+    }
+}
+
+impl ProvideRef<'_, i64> for Settings {
+    fn provide_ref(&self) -> i64 {
+        let Self { raw, .. } = self;
+        *raw
+    }
+}
+
+#[test]
+fn first_succeeds() {
+    let settings = Failpoint::new(Settings {
+        cached: Port(80),
+        raw: -1,
+    });
+
+    let context = OrElse::new(
+        FailAfter::new(usize::MAX, ()),
+        ().then_fail_after(usize::MAX).then_try_from::<i64>(),
+    );
+    let port: Result<Port, _> = settings.try_provide_ref_with(context);
+    assert_eq!(port, Ok(Port(80)));
+}
+
+#[test]
+fn fallback() {
+    let settings = Failpoint::new(Settings {
+        cached: Port(80),
+        raw: 8080,
+    });
+    let context = ()
+        .then_fail_after(0)
+        .then_or_else(().then_fail_after(usize::MAX).then_try_from::<i64>());
+    let port: Result<Port, _> = settings.try_provide_ref_with(context);
+    assert_eq!(port, Ok(Port(8080)));
+
+    let settings = Failpoint::new(Settings {
+        cached: Port(80),
+        raw: -1,
+    });
+    let context = ()
+        .then_fail_after(0)
+        .then_or_else(().then_fail_after(usize::MAX).then_try_from::<i64>());
+    let port: Result<Port, _> = settings.try_provide_ref_with(context);
+    assert!(port.is_err());
+}
+
+#[test]
+fn both_fail() {
+    let provider = Failpoint::new(String::from("config"));
+
+    let context = ().then_fail_after(0).then_or_else(FailAfter::new(0, ()));
+    let config: Result<&str, _> = provider.try_provide_ref_with(context);
+    assert_eq!(config, Err(FailpointError::Injected));
+
+    let context = ().then_fail_after(0).then_or_else(FailAfter::new(usize::MAX, ()));
+    let config: Result<&str, _> = provider.try_provide_ref_with(context);
+    assert_eq!(config, Ok("config"));
+}
@@ -0,0 +1,115 @@
+use provide::{
+    pair::{Contains, Find, Flatten, Here, Leaf, Len, Normalize, Pair, Remove, There},
+    with::{TryWith, With, WithBack, WithFront},
+};
+
+#[test]
+fn with_front_back() {
+    let pair = Pair::new(2, ()).with_front(1).with_back(3);
+    assert_eq!(pair, Pair::from((1, 2, 3)));
+    assert_eq!(pair.with(0).flatten(), (0, 1, 2, 3));
+}
+
+#[test]
+fn try_with() {
+    let pair = Pair::new(1, ()).try_with("hello");
+    assert_eq!(pair.map(Flatten::flatten), Ok(("hello", 1)));
+}
+
+#[test]
+fn len() {
+    let pair = Pair::from((1, "hello", 2.0));
+    assert_eq!(pair.len(), 3);
+    assert!(!pair.is_empty());
+    assert_eq!(<Pair<i32, ()>>::LEN, 1);
+}
+
+#[test]
+fn find() {
+    let mut pair = Pair::from((1, "hello", 2.0));
+    let dependency: &&str = pair.find();
+    assert_eq!(*dependency, "hello");
+
+    let dependency: &mut f64 = pair.find_mut();
+    *dependency = 3.0;
+    assert_eq!(pair.flatten(), (1, "hello", 3.0));
+}
+
+#[test]
+fn find_ambiguous() {
+    let mut pair = Pair::from((1, "hello", 2));
+    let dependency = <_ as Find<i32, Here>>::find(&pair);
+    assert_eq!(*dependency, 1);
+
+    let dependency = <_ as Find<i32, There<There<Here>>>>::find_mut(&mut pair);
+    *dependency = 3;
+    assert_eq!(pair.flatten(), (1, "hello", 3));
+}
+
+#[test]
+fn contains() {
+    fn index_of<P, I>(_: &P) -> &'static str
+    where
+        P: Contains<f64, I>,
+    {
+        core::any::type_name::<I>()
+    }
+
+    use core::any::type_name;
+
+    assert_eq!(index_of(&Pair::from((1, 2.0))), type_name::<There<Here>>());
+    assert_eq!(
+        index_of(&(1, "hello", 2.0)),
+        type_name::<There<There<Here>>>()
+    );
+}
+
+#[test]
+fn remove() {
+    fn without_name<P, I>(provider: P) -> P::Output
+    where
+        P: Remove<&'static str, I>,
+    {
+        let (_, rest) = provider.remove();
+        rest
+    }
+
+    let pair = Pair::from((1, "hello", 2.0));
+    assert_eq!(without_name(pair).flatten(), (1, 2.0));
+
+    let pair = Pair::from((1, "hello", 2));
+    let (dependency, rest) = <_ as Remove<i32, There<There<Here>>>>::remove(pair);
+    assert_eq!(dependency, 2);
+    assert_eq!(rest.flatten(), (1, "hello"));
+}
+
+#[test]
+fn normalize() {
+    #[derive(Debug, PartialEq)]
+    struct Name(&'static str);
+
+    impl Leaf for Name {}
+
+    let expected = Pair::from((1, Name("hello"), 2.0));
+    assert_eq!(((1, Name("hello")), (2.0,)).normalize(), expected);
+    assert_eq!((1, ((Name("hello"),), 2.0)).normalize(), expected);
+    assert_eq!(Pair::new((1,), (Name("hello"), 2.0)).normalize(), expected);
+    assert_eq!(
+        ((), expected).normalize().flatten(),
+        (1, Name("hello"), 2.0)
+    );
+}
+
+#[cfg(feature = "tuples_32")]
+#[test]
+fn flatten_large() {
+    let tuple = (
+        1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+        26, 27, 28, 29, 30, 31, 32,
+    );
+    let pair = Pair::from(tuple);
+    assert_eq!(*pair.head(), 1);
+
+    let tuple = pair.flatten();
+    assert_eq!((tuple.0, tuple.31), (1, 32));
+}
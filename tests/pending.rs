@@ -0,0 +1,67 @@
+use std::cell::Cell;
+
+use provide::{
+    context::{failpoint::FailAfter, Context},
+    failpoint::{Failpoint, FailpointError},
+    pending::Pending,
+    Provide, ProvideRef,
+};
+
+#[derive(Debug, PartialEq)]
+struct Connection(u32);
+
+#[derive(Debug, Default)]
+struct Provider {
+    connections: Cell<u32>,
+}
+
+impl<'me> ProvideRef<'me, Connection> for Provider {
+    fn provide_ref(&'me self) -> Connection {
+        let Self { connections } = self;
+        connections.set(connections.get() + 1);
+        Connection(connections.get())
+    }
+}
+
+impl Provide<Connection> for Provider {
+    type Remainder = Self;
+
+    fn provide(self) -> (Connection, Self::Remainder) {
+        let dependency = self.provide_ref();
+        (dependency, self)
+    }
+}
+
+#[test]
+fn deferred() {
+    let pending: Pending<Option<Connection>, _, _> =
+        Pending::new(Provider::default(), ().then_some());
+    assert_eq!(pending.get_ref(), Some(Connection(1)));
+    assert_eq!(pending.get_ref(), Some(Connection(2)));
+
+    let (provider, _) = pending.into_inner();
+    assert_eq!(provider.connections.get(), 2);
+}
+
+#[test]
+fn by_value() {
+    let pending: Pending<Connection, _> = Pending::new(Provider::default(), ());
+    if cfg!(not(feature = "compact_names")) {
+        assert!(format!("{pending:?}").contains("Connection"));
+    }
+
+    let (dependency, provider) = pending.get();
+    assert_eq!(dependency, Connection(1));
+    assert_eq!(provider.connections.get(), 1);
+}
+
+#[test]
+fn fallible() {
+    let provider = Failpoint::new(Provider::default());
+    let pending: Pending<Connection, _, _> = Pending::new(provider, FailAfter::new(usize::MAX, ()));
+    assert_eq!(pending.try_get_ref(), Ok(Connection(1)));
+
+    let (provider, _) = pending.into_inner();
+    let pending: Pending<Connection, _, _> = Pending::new(provider, FailAfter::new(0, ()));
+    assert_eq!(pending.try_get_ref(), Err(FailpointError::Injected));
+}
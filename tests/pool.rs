@@ -0,0 +1,142 @@
+#![cfg(feature = "pool")]
+
+use core::cell::Cell;
+
+use provide::{
+    context::{
+        pool::{Acquire, AcquireError},
+        Context,
+    },
+    failpoint::{Failpoint, FailpointError},
+    pool::{Pool, PoolProvider},
+    with::{TryProvideRefWith, TryProvideWith},
+};
+
+#[derive(Debug, PartialEq)]
+struct Connection {
+    id: u32,
+}
+
+#[derive(Debug, PartialEq)]
+struct Exhausted;
+
+struct ConnectionPool {
+    next: Cell<u32>,
+    size: u32,
+}
+
+impl ConnectionPool {
+    fn new(size: u32) -> Self {
+        Self {
+            next: Cell::new(0),
+            size,
+        }
+    }
+}
+
+impl Pool for ConnectionPool {
+    type Guard = Connection;
+
+    type Error = Exhausted;
+
+    fn get(&self) -> Result<Self::Guard, Self::Error> {
+        let Self { next, size } = self;
+        let id = next.get();
+        if id >= *size {
+            return Err(Exhausted);
+        }
+        next.set(id + 1);
+        Ok(Connection { id })
+    }
+}
+
+#[test]
+fn by_ref() {
+    let provider = PoolProvider::new(ConnectionPool::new(1));
+
+    let connection: Result<Connection, _> = provider.try_provide_ref_with(().then_acquire());
+    assert_eq!(connection, Ok(Connection { id: 0 }));
+
+    let connection: Result<Connection, _> = provider.try_provide_ref_with(Acquire::default());
+    assert_eq!(connection, Err(AcquireError::Acquire(Exhausted)));
+}
+
+#[test]
+fn by_value() {
+    let provider = PoolProvider::new(ConnectionPool::new(2));
+
+    let result: Result<(Connection, _), _> = provider.try_provide_with(Acquire::default());
+    let (connection, provider) = result.unwrap();
+    assert_eq!(connection, Connection { id: 0 });
+    assert_eq!(provider.pool().next.get(), 1);
+}
+
+struct FailingPool;
+
+impl Pool for FailingPool {
+    type Guard = Failpoint<Connection>;
+
+    type Error = Exhausted;
+
+    fn get(&self) -> Result<Self::Guard, Self::Error> {
+        Ok(Failpoint::new(Connection { id: 0 }))
+    }
+}
+
+#[test]
+fn guard_failure() {
+    let provider = PoolProvider::new(FailingPool);
+
+    let connection: Result<Connection, _> =
+        provider.try_provide_ref_with(().then_fail_after(0).then_acquire());
+    assert_eq!(
+        connection,
+        Err(AcquireError::Provide(FailpointError::Injected)),
+    );
+
+    let connection: Result<Connection, _> =
+        provider.try_provide_ref_with(().then_fail_after(usize::MAX).then_acquire());
+    assert_eq!(connection, Ok(Connection { id: 0 }));
+}
+
+#[cfg(feature = "async")]
+mod r#async {
+    use futures::executor::block_on;
+    use provide::{
+        context::pool::{Acquire, AcquireError},
+        pool::{AsyncPool, PoolProvider},
+        with::TryProvideRefAsyncWith,
+    };
+
+    use super::{Connection, ConnectionPool, Exhausted, Pool};
+
+    struct AsyncConnectionPool(ConnectionPool);
+
+    impl AsyncPool for AsyncConnectionPool {
+        type Guard = Connection;
+
+        type Error = Exhausted;
+
+        async fn get(&self) -> Result<Self::Guard, Self::Error> {
+            let Self(pool) = self;
+            pool.get()
+        }
+    }
+
+    #[test]
+    fn by_ref() {
+        block_on(async {
+            let provider = PoolProvider::new(AsyncConnectionPool(ConnectionPool::new(1)));
+
+            let connection: Result<Connection, _> = provider
+                .try_provide_ref_async_with(Acquire::default())
+                .await;
+            assert_eq!(connection, Ok(Connection { id: 0 }));
+
+            let connection: Result<Connection, _> = provider
+                .try_provide_ref_async_with(Acquire::default())
+                .await;
+            assert_eq!(connection, Err(AcquireError::Acquire(Exhausted)));
+        });
+    }
+}
@@ -0,0 +1,33 @@
+use provide::{
+    context::{profile::Profiled, Context},
+    profile::{GatedError, Profile, ProfileGated},
+    with::{TryProvideMutWith, TryProvideWith},
+};
+
+#[test]
+fn by_value() {
+    let provider = ProfileGated::new(1, Profile::Test);
+    let result: Result<(i32, _), _> = provider.try_provide_with(Profiled::new(Profile::Test, ()));
+    assert!(matches!(result, Ok((1, ()))));
+
+    let provider = ProfileGated::new(1, Profile::Test);
+    let result: Result<(i32, _), _> = provider.try_provide_with(().then_profile(Profile::Prod));
+    assert!(matches!(
+        result,
+        Err(GatedError::Inactive {
+            required: Profile::Test,
+            active: Profile::Prod,
+        }),
+    ));
+}
+
+#[test]
+fn by_mut() {
+    let mut provider = ProfileGated::new(vec![1, 2, 3], Profile::Dev);
+    let dependency: Result<&mut [i32], _> = provider.try_provide_mut_with(Profiled::default());
+    dependency.unwrap()[0] = 0;
+
+    let (provider, profile) = provider.into_inner();
+    assert_eq!(provider, [0, 2, 3]);
+    assert_eq!(profile, Profile::Dev);
+}
@@ -0,0 +1,58 @@
+use provide::{
+    context::Context,
+    project::Project,
+    with::{ProvideRefWith, TryProvideRefWith},
+    ProvideExt, ProvideRef,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Config {
+    database: Database,
+    retries: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Database {
+    url: String,
+}
+
+#[test]
+fn by_ref() {
+    let config = Config {
+        database: Database {
+            url: String::from("localhost"),
+        },
+        retries: 3,
+    };
+    let provider = Project::new(config, |config: &Config| &config.database);
+
+    let database: &Database = provider.provide_ref();
+    assert_eq!(database.url, "localhost");
+
+    let database: Option<&Database> = provider.provide_ref_with(().then_some());
+    assert_eq!(
+        database,
+        Some(&Database {
+            url: String::from("localhost"),
+        })
+    );
+
+    let url: Result<&Database, _> = provider.try_provide_ref_with(());
+    assert_eq!(url.map(|database| database.url.as_str()), Ok("localhost"));
+}
+
+#[test]
+fn unsized_projection() {
+    let config = Config {
+        database: Database {
+            url: String::from("localhost"),
+        },
+        retries: 3,
+    };
+    let provider = Project::new(config, |config: &Config| config.database.url.as_str());
+    assert_eq!(provider.get_dep::<str>(), "localhost");
+    assert_eq!(provider.provider().retries, 3);
+
+    let (config, _) = provider.into_inner();
+    assert_eq!(config.retries, 3);
+}
@@ -0,0 +1,139 @@
+use provide::{
+    Provide, ProvideAll, ProvideAllError, TryProvide, TryProvideAll, TryProvideRef,
+    TryProvideRefEach,
+};
+
+struct Provider {
+    id: i32,
+    ratio: Option<f32>,
+    name: &'static str,
+}
+
+#[derive(Debug, PartialEq)]
+struct RatioName {
+    ratio: Option<f32>,
+    name: &'static str,
+}
+
+#[derive(Debug, PartialEq)]
+struct Name {
+    name: &'static str,
+}
+
+impl Provide<i32> for Provider {
+    type Remainder = RatioName;
+
+    fn provide(self) -> (i32, Self::Remainder) {
+        let Self { id, ratio, name } = self;
+        (id, RatioName { ratio, name })
+    }
+}
+
+impl TryProvide<f32> for RatioName {
+    type Remainder = Name;
+
+    type Error = Self;
+
+    fn try_provide(self) -> Result<(f32, Self::Remainder), Self::Error> {
+        match self {
+            Self {
+                ratio: Some(ratio),
+                name,
+            } => Ok((ratio, Name { name })),
+            remainder => Err(remainder),
+        }
+    }
+}
+
+impl Provide<&'static str> for Name {
+    type Remainder = ();
+
+    fn provide(self) -> (&'static str, Self::Remainder) {
+        let Self { name } = self;
+        (name, ())
+    }
+}
+
+#[test]
+fn provide_all() {
+    let provider: i32 = 1;
+    let ((id,), remainder): ((i64,), _) = provider.provide_all();
+    assert_eq!(id, 1);
+    assert_eq!(remainder, ());
+
+    let provider = Name { name: "hello" };
+    let ((), remainder) = ProvideAll::<()>::provide_all(provider);
+    assert_eq!(remainder, Name { name: "hello" });
+}
+
+#[test]
+fn try_provide_all() {
+    let provider = Provider {
+        id: 1,
+        ratio: Some(2.0),
+        name: "hello",
+    };
+    let result: Result<((i32, f32, &str), _), _> = provider.try_provide_all();
+    assert_eq!(result, Ok(((1, 2.0, "hello"), ())));
+}
+
+#[test]
+fn try_provide_all_fails_fast() {
+    let provider = Provider {
+        id: 1,
+        ratio: None,
+        name: "hello",
+    };
+    let result: Result<((i32, f32, &str), _), _> = provider.try_provide_all();
+    let Err(ProvideAllError::Tail(id, ProvideAllError::Head(remainder))) = result else {
+        panic!("provision should fail on the second dependency");
+    };
+    assert_eq!(id, 1);
+    assert_eq!(
+        remainder,
+        RatioName {
+            ratio: None,
+            name: "hello",
+        },
+    );
+}
+
+#[test]
+fn try_provide_ref_each() {
+    struct Config {
+        port: Option<u16>,
+        host: Option<&'static str>,
+    }
+
+    impl<'me> TryProvideRef<'me, u16> for Config {
+        type Error = &'static str;
+
+        fn try_provide_ref(&'me self) -> Result<u16, Self::Error> {
+            let Self { port, .. } = self;
+            port.ok_or("port is missing")
+        }
+    }
+
+    impl<'me> TryProvideRef<'me, &'me str> for Config {
+        type Error = &'static str;
+
+        fn try_provide_ref(&'me self) -> Result<&'me str, Self::Error> {
+            let Self { host, .. } = self;
+            host.ok_or("host is missing")
+        }
+    }
+
+    let config = Config {
+        port: None,
+        host: None,
+    };
+    let results = TryProvideRefEach::<(u16, &str)>::try_provide_ref_each(&config);
+    assert_eq!(results, (Err("port is missing"), Err("host is missing")));
+
+    let config = Config {
+        port: Some(8080),
+        host: None,
+    };
+    let results = TryProvideRefEach::<(u16, &str)>::try_provide_ref_each(&config);
+    assert_eq!(results, (Ok(8080), Err("host is missing")));
+}
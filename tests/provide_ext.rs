@@ -0,0 +1,23 @@
+use std::cell::Cell;
+
+use provide::ProvideExt;
+
+#[test]
+fn inherent_methods() {
+    let mut provider = vec![1, 2, 3];
+    assert_eq!(provider.get(1), Some(&2));
+    if let Some(second) = provider.get_mut(1) {
+        *second = 0;
+    }
+    assert_eq!(provider.get_dep::<[i32]>(), [1, 0, 3]);
+
+    let mut provider = Some(String::from("hello"));
+    assert_eq!(provider.take().as_deref(), Some("hello"));
+    assert_eq!(provider, None);
+
+    let provider = Cell::new(1);
+    assert_eq!(provider.get(), 1);
+    assert_eq!(provider.take(), 1);
+    let (dependency, _) = provider.take_dep::<Cell<i32>>();
+    assert_eq!(dependency.get(), 0);
+}
@@ -0,0 +1,82 @@
+use std::cell::Cell;
+
+use provide::{ProvideExt, ProvideRef, ProvidedFn};
+
+#[derive(Debug, PartialEq)]
+struct Database(i32);
+
+struct Provider {
+    database: Database,
+    name: &'static str,
+    requests: Cell<u32>,
+}
+
+impl AsRef<Database> for Provider {
+    fn as_ref(&self) -> &Database {
+        let Self { database, .. } = self;
+        database
+    }
+}
+
+impl ProvideRef<'_, &'static str> for Provider {
+    fn provide_ref(&self) -> &'static str {
+        let Self { name, .. } = self;
+        name
+    }
+}
+
+impl ProvideRef<'_, u32> for Provider {
+    fn provide_ref(&self) -> u32 {
+        let Self { requests, .. } = self;
+        requests.set(requests.get() + 1);
+        requests.get()
+    }
+}
+
+#[test]
+fn arities() {
+    let provider = Provider {
+        database: Database(1),
+        name: "service",
+        requests: Cell::new(0),
+    };
+
+    assert_eq!((|| 0).call_provided(&provider), 0);
+    assert_eq!((|Database(id): &Database| *id).call_provided(&provider), 1,);
+    assert_eq!(
+        (|name: &'static str, request: u32| format!("{name}:{request}")).call_provided(&provider),
+        "service:1",
+    );
+}
+
+#[test]
+fn resolved_at_call_time() {
+    let provider = Provider {
+        database: Database(1),
+        name: "service",
+        requests: Cell::new(0),
+    };
+    let callback = provider.provide_map(|database: &Database, request: u32| (database.0, request));
+
+    assert_eq!(callback(), (1, 1));
+    assert_eq!(callback(), (1, 2));
+    assert_eq!(provider.requests.get(), 2);
+}
+
+fn register<F>(callback: F) -> impl Fn() -> String
+where
+    F: Fn() -> String,
+{
+    move || callback()
+}
+
+#[test]
+fn registered() {
+    let provider = Provider {
+        database: Database(1),
+        name: "service",
+        requests: Cell::new(0),
+    };
+    let callback = register(provider.provide_map(|name: &'static str| name.to_uppercase()));
+    assert_eq!(callback(), "SERVICE");
+}
@@ -0,0 +1,96 @@
+use core::{error::Error, fmt};
+
+use provide::{
+    context::{
+        restore::{Restore, RestoreError},
+        Context,
+    },
+    pair::Pair,
+    with::{ProvideWith, TryProvideWith, TryWith},
+    Provide,
+};
+
+#[derive(Debug, PartialEq)]
+struct Workers(Vec<&'static str>);
+
+#[derive(Debug, PartialEq)]
+struct CapacityExceeded;
+
+impl fmt::Display for CapacityExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("capacity exceeded")
+    }
+}
+
+impl Error for CapacityExceeded {}
+
+impl TryWith<&'static str> for Workers {
+    type Output = Self;
+
+    type Error = CapacityExceeded;
+
+    fn try_with(self, dependency: &'static str) -> Result<Self::Output, Self::Error> {
+        let Self(mut workers) = self;
+        if workers.len() >= 2 {
+            return Err(CapacityExceeded);
+        }
+        workers.push(dependency);
+        Ok(Self(workers))
+    }
+}
+
+struct Team {
+    lead: &'static str,
+    workers: Workers,
+}
+
+impl Provide<&'static str> for Team {
+    type Remainder = Workers;
+
+    fn provide(self) -> (&'static str, Self::Remainder) {
+        let Self { lead, workers } = self;
+        (lead, workers)
+    }
+}
+
+#[test]
+fn restore() {
+    let (dependency, remainder): (i32, _) = 1.provide_with(Restore::default());
+    assert_eq!((dependency, remainder), (1, 1));
+
+    let provider = Pair::new(1, ());
+    let (dependency, remainder): (Pair<i32, ()>, _) = provider.provide_with(().then_restore());
+    assert_eq!(dependency, provider);
+    assert_eq!(remainder, provider);
+}
+
+#[test]
+fn try_restore() {
+    let team = Team {
+        lead: "alice",
+        workers: Workers(vec!["bob"]),
+    };
+
+    let result: Result<(&str, _), _> = team.try_provide_with(Restore::default());
+    assert_eq!(result, Ok(("alice", Workers(vec!["bob", "alice"]))));
+}
+
+#[test]
+fn try_restore_rejected() {
+    let team = Team {
+        lead: "alice",
+        workers: Workers(vec!["bob", "carol"]),
+    };
+
+    let result: Result<(&str, Workers), _> = team.try_provide_with(().then_restore());
+    let error = result.err().unwrap();
+    assert_eq!(error, RestoreError::Restore(CapacityExceeded));
+    assert_eq!(
+        error.to_string(),
+        "failed to restore dependency into the remainder",
+    );
+    assert_eq!(
+        error.source().map(ToString::to_string).as_deref(),
+        Some("capacity exceeded"),
+    );
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "alloc")]
+
+use provide::{
+    context::{spy::Spy, Context},
+    spy::{Flavor, Request, SpyProvider},
+    with::{ProvideWith, TryProvideMutWith, TryProvideRefWith},
+    Provide, ProvideExt,
+};
+
+struct Cache;
+
+struct Database;
+
+struct Provider {
+    cache: Cache,
+    database: Database,
+}
+
+impl AsRef<Cache> for Provider {
+    fn as_ref(&self) -> &Cache {
+        let Self { cache, .. } = self;
+        cache
+    }
+}
+
+impl AsRef<Database> for Provider {
+    fn as_ref(&self) -> &Database {
+        let Self { database, .. } = self;
+        database
+    }
+}
+
+impl Provide<Database> for Provider {
+    type Remainder = Cache;
+
+    fn provide(self) -> (Database, Self::Remainder) {
+        let Self { cache, database } = self;
+        (database, cache)
+    }
+}
+
+impl AsMut<Database> for Provider {
+    fn as_mut(&mut self) -> &mut Database {
+        let Self { database, .. } = self;
+        database
+    }
+}
+
+#[test]
+fn cache_before_database() {
+    let mut provider = SpyProvider::new(Provider {
+        cache: Cache,
+        database: Database,
+    });
+    let _: &Cache = provider.get_dep();
+    let _: &Database = provider.get_dep();
+    let _: &mut Database = provider.get_dep_mut();
+
+    let requests = provider.requests();
+    let names: Vec<_> = requests.iter().map(Request::type_name).collect();
+    assert_eq!(
+        names,
+        [
+            core::any::type_name::<Cache>(),
+            core::any::type_name::<Database>(),
+            core::any::type_name::<Database>(),
+        ]
+    );
+    assert_eq!(requests[2].flavor(), Flavor::Mut);
+
+    provider.clear();
+    assert!(provider.requests().is_empty());
+}
+
+#[test]
+fn every_flavor() {
+    let mut provider = SpyProvider::new(Provider {
+        cache: Cache,
+        database: Database,
+    });
+    let _: Result<Option<&Cache>, _> = provider.try_provide_ref_with(().then_some().then_spy());
+    let _: Result<&mut Database, _> = provider.try_provide_mut_with(Spy::default());
+    let (_, remainder): (Database, _) = provider.provide_with(Spy::default());
+
+    let (_, requests): (Cache, _) = remainder.into_inner();
+    assert_eq!(
+        requests,
+        [
+            Request::new::<Option<&Cache>>(Flavor::Ref),
+            Request::new::<&mut Database>(Flavor::Mut),
+            Request::new::<Database>(Flavor::Value),
+        ],
+    );
+}
@@ -0,0 +1,62 @@
+use provide::{
+    context::{
+        take::{ReplaceDependency, TakeDependency},
+        Context,
+    },
+    with::{ProvideMutWith, ProvideWith},
+};
+
+#[derive(Debug, Default, PartialEq)]
+struct Buffer(Vec<u8>);
+
+struct Provider {
+    buffer: Buffer,
+}
+
+impl AsMut<Buffer> for Provider {
+    fn as_mut(&mut self) -> &mut Buffer {
+        let Self { buffer } = self;
+        buffer
+    }
+}
+
+#[test]
+fn by_mut() {
+    let mut provider = Provider {
+        buffer: Buffer(vec![1, 2, 3]),
+    };
+
+    let buffer: Buffer = provider.provide_mut_with(TakeDependency::default());
+    assert_eq!(buffer, Buffer(vec![1, 2, 3]));
+    assert_eq!(provider.buffer, Buffer::default());
+
+    provider.buffer.0.push(4);
+    let buffer: Option<Buffer> = provider.provide_mut_with(().then_take().then_some());
+    assert_eq!(buffer, Some(Buffer(vec![4])));
+}
+
+#[test]
+fn by_value() {
+    let mut provider = Provider {
+        buffer: Buffer(vec![1]),
+    };
+
+    let (buffer, ()): (Buffer, _) = (&mut provider).provide_with(().then_take());
+    assert_eq!(buffer, Buffer(vec![1]));
+    assert_eq!(provider.buffer, Buffer::default());
+}
+
+#[test]
+fn replace() {
+    let mut provider = Provider {
+        buffer: Buffer(vec![1]),
+    };
+
+    let buffer: Buffer = provider.provide_mut_with(ReplaceDependency::new(Buffer(vec![2]), ()));
+    assert_eq!(buffer, Buffer(vec![1]));
+    assert_eq!(provider.buffer, Buffer(vec![2]));
+
+    let (buffer, ()): (Buffer, _) = (&mut provider).provide_with(().then_replace(Buffer(vec![3])));
+    assert_eq!(buffer, Buffer(vec![2]));
+    assert_eq!(provider.buffer, Buffer(vec![3]));
+}
@@ -0,0 +1,58 @@
+#![cfg(all(feature = "tokio", feature = "std"))]
+
+use futures::executor::block_on;
+use provide::task_scope::{self, NotInScope};
+
+struct Config {
+    url: &'static str,
+}
+
+struct Database {
+    url: String,
+}
+
+#[test]
+fn not_in_scope() {
+    assert_eq!(task_scope::current::<Config>().err(), Some(NotInScope));
+    block_on(task_scope::with(1_i32, async {
+        assert_eq!(task_scope::current::<Config>().err(), Some(NotInScope));
+    }));
+}
+
+#[test]
+fn nested() {
+    let config = Config { url: "localhost" };
+    block_on(task_scope::with(config, async {
+        let database = task_scope::try_with(|config: &Config| Database {
+            url: format!("postgres://{}", config.url),
+        })
+        .unwrap();
+
+        task_scope::with(database, async {
+            let url = task_scope::try_with(|database: &Database| database.url.clone());
+            assert_eq!(url.as_deref(), Ok("postgres://localhost"));
+            assert_eq!(
+                task_scope::try_with(|config: &Config| config.url),
+                Ok("localhost")
+            );
+        })
+        .await;
+
+        assert_eq!(task_scope::current::<Database>().err(), Some(NotInScope));
+    }));
+}
+
+#[test]
+fn spawned() {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let url = runtime.block_on(task_scope::with(Config { url: "localhost" }, async {
+        let config = task_scope::current::<Config>().unwrap();
+        let task = tokio::spawn(task_scope::with_arc(config, async {
+            task_scope::try_with(|config: &Config| config.url)
+        }));
+        task.await.unwrap()
+    }));
+    assert_eq!(url, Ok("localhost"));
+}
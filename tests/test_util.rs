@@ -0,0 +1,113 @@
+#![cfg(feature = "test_util")]
+
+use core::time::Duration;
+
+use std::panic;
+
+use provide::{
+    assert_provider_eq,
+    clock::{Clock, Timer},
+    describe::Exhausted,
+    pair::Pair,
+    test_util::{FakeClock, ManualTimer, ProviderEq},
+    ProvideExt,
+};
+
+struct Provider {
+    clock: FakeClock,
+    timer: ManualTimer,
+}
+
+impl AsRef<FakeClock> for Provider {
+    fn as_ref(&self) -> &FakeClock {
+        let Self { clock, .. } = self;
+        clock
+    }
+}
+
+impl AsRef<ManualTimer> for Provider {
+    fn as_ref(&self) -> &ManualTimer {
+        let Self { timer, .. } = self;
+        timer
+    }
+}
+
+struct Session<C, T> {
+    clock: C,
+    timer: T,
+    started: Duration,
+}
+
+impl<C, T> Session<C, T>
+where
+    C: Clock,
+    T: Timer,
+{
+    fn new(clock: C, timer: T) -> Self {
+        let started = clock.now();
+        Self {
+            clock,
+            timer,
+            started,
+        }
+    }
+
+    fn age(&self) -> Duration {
+        self.clock.now() - self.started
+    }
+
+    fn is_alive(&self) -> bool {
+        !self.timer.is_expired()
+    }
+}
+
+#[test]
+fn deterministic() {
+    let provider = Provider {
+        clock: FakeClock::new(Duration::from_secs(100)),
+        timer: ManualTimer::new(),
+    };
+    let session = Session::new(
+        provider.get_dep::<FakeClock>(),
+        provider.get_dep::<ManualTimer>(),
+    );
+    assert_eq!(session.age(), Duration::ZERO);
+    assert!(session.is_alive());
+
+    provider.clock.advance(Duration::from_secs(30));
+    assert_eq!(session.age(), Duration::from_secs(30));
+    assert!(session.is_alive());
+
+    provider.timer.expire();
+    assert!(!session.is_alive());
+
+    provider.clock.set(Duration::from_secs(100));
+    assert_eq!(session.age(), Duration::ZERO);
+}
+
+#[test]
+fn provider_eq() {
+    let expected = Pair::new(
+        5432_u16,
+        Pair::new(Exhausted::<i32>::new(), ("localhost", 1.5)),
+    );
+    let actual = Pair::new(
+        5432_u16,
+        Pair::new(Exhausted::<i32>::new(), ("localhost", 1.5)),
+    );
+    assert_provider_eq!(actual, expected);
+
+    let actual = Pair::new(
+        5433_u16,
+        Pair::new(Exhausted::<i32>::new(), ("remote", 1.5)),
+    );
+    assert!(!actual.provider_eq(&expected));
+    assert_eq!(
+        actual.diff(&expected).to_string(),
+        "\n  u16: left = 5433, right = 5432\n  &str: left = \"remote\", right = \"localhost\"",
+    );
+
+    let panic = panic::catch_unwind(|| assert_provider_eq!(actual, expected)).unwrap_err();
+    let message = panic.downcast_ref::<String>().unwrap();
+    assert!(message.starts_with("assertion `left == right` failed: providers differ\n  u16"));
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "std")]
+
+use std::time::Duration;
+
+#[cfg(feature = "test_util")]
+use provide::test_util::FakeClock;
+use provide::{
+    context::{
+        failpoint::FailAfter,
+        throttle::{Throttle, ThrottleWith},
+        Context,
+    },
+    failpoint::{Failpoint, FailpointError},
+    throttle::{ThrottleError, Throttled},
+    with::{TryProvideMutWith, TryProvideRefWith, TryProvideWith},
+};
+
+#[test]
+fn by_value() {
+    let provider = Throttled::new(1, 1, Duration::from_secs(60));
+    let result: Result<(i32, _), ThrottleError<_>> = provider.try_provide_with(Throttle::default());
+    assert!(matches!(result, Ok((1, ()))));
+}
+
+#[test]
+fn by_ref() {
+    let provider = Throttled::new(Box::new(1), 2, Duration::from_secs(60));
+    assert_eq!(provider.available(), 2);
+
+    let result: Result<&i32, _> = provider.try_provide_ref_with(Throttle::default());
+    assert_eq!(result, Ok(&1));
+    let result: Result<&i32, _> = provider.try_provide_ref_with(().then_throttle());
+    assert_eq!(result, Ok(&1));
+    assert_eq!(provider.available(), 0);
+
+    let result: Result<&i32, _> = provider.try_provide_ref_with(Throttle::default());
+    let Err(ThrottleError::Throttled { retry_after }) = result else {
+        panic!("provision should be throttled");
+    };
+    assert!(retry_after <= Duration::from_secs(60));
+}
+
+#[test]
+fn by_mut() {
+    let mut provider = Throttled::new(Box::new(1), 1, Duration::from_secs(60));
+
+    let result: Result<&mut i32, _> = provider.try_provide_mut_with(Throttle::default());
+    *result.unwrap() += 1;
+
+    let result: Result<&mut i32, _> = provider.try_provide_mut_with(Throttle::default());
+    assert!(matches!(result, Err(ThrottleError::Throttled { .. })));
+    assert_eq!(*provider.into_inner(), 2);
+}
+
+#[test]
+#[cfg(feature = "test_util")]
+fn refill() {
+    let clock = FakeClock::default();
+    let provider = Throttled::with_clock(Box::new(1), 2, Duration::from_secs(60), &clock);
+
+    let result: Result<&i32, _> = provider.try_provide_ref_with(Throttle::default());
+    assert_eq!(result, Ok(&1));
+    let result: Result<&i32, _> = provider.try_provide_ref_with(Throttle::default());
+    assert_eq!(result, Ok(&1));
+
+    clock.advance(Duration::from_secs(30));
+    let result: Result<&i32, _> = provider.try_provide_ref_with(Throttle::default());
+    assert_eq!(
+        result,
+        Err(ThrottleError::Throttled {
+            retry_after: Duration::from_secs(30),
+        }),
+    );
+
+    clock.advance(Duration::from_secs(30));
+    assert_eq!(provider.available(), 1);
+    clock.advance(Duration::from_secs(600));
+    assert_eq!(provider.available(), 2);
+}
+
+#[test]
+fn failed() {
+    let mut provider = Throttled::new(Failpoint::new(Box::new(1)), 1, Duration::from_secs(60));
+
+    let result: Result<&i32, _> =
+        provider.try_provide_ref_with(ThrottleWith::new(FailAfter::new(0, ())));
+    assert_eq!(
+        result,
+        Err(ThrottleError::Provide(FailpointError::Injected)),
+    );
+    let result: Result<&mut i32, _> =
+        provider.try_provide_mut_with(ThrottleWith::new(FailAfter::new(0, ())));
+    assert!(matches!(result, Err(ThrottleError::Provide(_))));
+    assert_eq!(provider.available(), 1);
+
+    let result: Result<(Box<i32>, _), _> =
+        provider.try_provide_with(ThrottleWith::new(FailAfter::new(0, ())));
+    assert!(matches!(result, Err(ThrottleError::Provide(_))));
+}
+
+#[test]
+#[should_panic = "interval of the throttle should not be zero"]
+fn zero_interval() {
+    let _ = Throttled::new((), 1, Duration::ZERO);
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "alloc")]
+
+use std::path::{Path, PathBuf};
+
+use provide::{
+    context::{
+        to_owned::{ToOwnedDependency, ToOwnedDependencyWith},
+        Context,
+    },
+    failpoint::{Failpoint, FailpointError},
+    with::{ProvideRefWith, ProvideWith, TryProvideRefWith, TryProvideWith},
+};
+
+struct Config {
+    name: String,
+    root: PathBuf,
+}
+
+impl AsRef<str> for Config {
+    fn as_ref(&self) -> &str {
+        let Self { name, .. } = self;
+        name
+    }
+}
+
+impl AsRef<Path> for Config {
+    fn as_ref(&self) -> &Path {
+        let Self { root, .. } = self;
+        root
+    }
+}
+
+#[test]
+fn by_value() {
+    let (name, ()): (String, _) = "server".provide_with(ToOwnedDependency::<&str>::default());
+    assert_eq!(name, "server");
+
+    let result: Result<(Vec<i32>, _), _> = [1, 2, 3]
+        .as_slice()
+        .try_provide_with(ToOwnedDependency::<&[i32]>::default());
+    assert!(matches!(result, Ok((numbers, ())) if numbers == [1, 2, 3]));
+}
+
+#[test]
+fn by_ref() {
+    let provider = Config {
+        name: String::from("server"),
+        root: PathBuf::from("/srv"),
+    };
+
+    let name: String = provider.provide_ref_with(ToOwnedDependency::<&str>::default());
+    assert_eq!(name, "server");
+
+    let root: Option<PathBuf> =
+        provider.provide_ref_with(ToOwnedDependencyWith::<&Path, _>::new(()).then_some());
+    assert_eq!(root.as_deref(), Some(Path::new("/srv")));
+
+    let provider = Failpoint::new(Config {
+        name: String::from("server"),
+        root: PathBuf::from("/srv"),
+    });
+    let name: Result<String, _> =
+        provider.try_provide_ref_with(().then_fail_after(1).then_to_owned::<&str>());
+    assert_eq!(name.as_deref(), Ok("server"));
+    let name: Result<String, _> =
+        provider.try_provide_ref_with(().then_fail_after(1).then_to_owned::<&str>());
+    assert_eq!(name, Err(FailpointError::Injected));
+}
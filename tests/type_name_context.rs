@@ -0,0 +1,53 @@
+use provide::{
+    context::{failpoint::FailFor, type_name::TypeName, Context},
+    failpoint::{Failpoint, FailpointError},
+    with::{TryProvideMutWith, TryProvideRefWith, TryProvideWith},
+};
+
+#[derive(Debug, PartialEq)]
+struct Database(i32);
+
+struct Provider(Database);
+
+impl AsRef<Database> for Provider {
+    fn as_ref(&self) -> &Database {
+        let Self(database) = self;
+        database
+    }
+}
+
+impl AsMut<Database> for Provider {
+    fn as_mut(&mut self) -> &mut Database {
+        let Self(database) = self;
+        database
+    }
+}
+
+#[test]
+fn annotated() {
+    let mut provider = Failpoint::new(Provider(Database(1)));
+
+    let result: Result<&Database, _> =
+        provider.try_provide_ref_with(().then_fail_after(0).then_type_name());
+    let error = result.unwrap_err();
+    assert!(error.type_name().ends_with("Database"));
+    assert_eq!(error.error(), &FailpointError::Injected);
+    assert!(error.to_string().contains("Database"));
+
+    let result: Result<&mut Database, _> =
+        provider.try_provide_mut_with(FailFor::<&mut Database>::default().then_type_name());
+    let error = result.unwrap_err();
+    assert!(error.type_name().starts_with("&mut "));
+    assert_eq!(error.into_inner(), FailpointError::Injected);
+}
+
+#[test]
+fn passthrough() {
+    let provider = Failpoint::new(1);
+    let result: Result<(i64, _), _> =
+        provider.try_provide_with(FailFor::<i32>::default().then_type_name());
+    assert!(matches!(result, Ok((1, ()))));
+
+    let result: Result<(i32, ()), _> = 1.try_provide_with(TypeName::default());
+    assert!(matches!(result, Ok((1, ()))));
+}
@@ -0,0 +1,94 @@
+use core::fmt::Debug;
+
+use provide::{
+    with::{ProvideMutWith, ProvideRefWith, TryProvideMutWith, TryProvideRefWith},
+    ProvideExt, ProvideMut, ProvideRef, TryProvideMut, TryProvideRef,
+};
+
+struct Provider {
+    name: String,
+    items: Vec<i32>,
+    debug: Box<dyn Debug>,
+}
+
+impl AsRef<str> for Provider {
+    fn as_ref(&self) -> &str {
+        let Self { name, .. } = self;
+        name
+    }
+}
+
+impl AsMut<str> for Provider {
+    fn as_mut(&mut self) -> &mut str {
+        let Self { name, .. } = self;
+        name
+    }
+}
+
+impl AsRef<[i32]> for Provider {
+    fn as_ref(&self) -> &[i32] {
+        let Self { items, .. } = self;
+        items
+    }
+}
+
+impl AsMut<[i32]> for Provider {
+    fn as_mut(&mut self) -> &mut [i32] {
+        let Self { items, .. } = self;
+        items
+    }
+}
+
+impl AsRef<dyn Debug> for Provider {
+    fn as_ref(&self) -> &(dyn Debug + 'static) {
+        let Self { debug, .. } = self;
+        debug.as_ref()
+    }
+}
+
+impl AsMut<dyn Debug> for Provider {
+    fn as_mut(&mut self) -> &mut (dyn Debug + 'static) {
+        let Self { debug, .. } = self;
+        debug.as_mut()
+    }
+}
+
+#[test]
+fn by_ref() {
+    let provider = Provider {
+        name: String::from("hello"),
+        items: vec![1, 2, 3],
+        debug: Box::new(42),
+    };
+
+    let name: &str = provider.provide_ref();
+    let items: &[i32] = provider.provide_ref_with(());
+    let debug: Result<&dyn Debug, _> = provider.try_provide_ref();
+    assert_eq!((name, items), ("hello", [1, 2, 3].as_slice()));
+    assert_eq!(format!("{:?}", debug.unwrap()), "42");
+
+    let name: Result<&str, _> = provider.try_provide_ref_with(());
+    assert_eq!(name, Ok("hello"));
+    assert_eq!(format!("{:?}", provider.get_dep::<dyn Debug>()), "42");
+}
+
+#[test]
+fn by_mut() {
+    let mut provider = Provider {
+        name: String::from("hello"),
+        items: vec![1, 2, 3],
+        debug: Box::new(42),
+    };
+
+    let name: &mut str = provider.provide_mut();
+    name.make_ascii_uppercase();
+    let items: &mut [i32] = provider.provide_mut_with(());
+    items.reverse();
+    let debug: Result<&mut dyn Debug, _> = provider.try_provide_mut();
+    assert_eq!(format!("{:?}", debug.unwrap()), "42");
+
+    let name: Result<&mut str, _> = provider.try_provide_mut_with(());
+    assert_eq!(name.as_deref(), Ok("HELLO"));
+    assert_eq!(provider.get_dep_mut::<[i32]>(), [3, 2, 1]);
+    assert_eq!(format!("{:?}", provider.get_dep_mut::<dyn Debug>()), "42");
+}
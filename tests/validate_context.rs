@@ -0,0 +1,97 @@
+use core::convert::Infallible;
+
+use provide::{
+    context::{
+        validate::{ValidateDependency, ValidateError},
+        Context,
+    },
+    failpoint::{Failpoint, FailpointError},
+    with::{TryProvideMutWith, TryProvideRefWith, TryProvideWith},
+};
+
+#[derive(Debug, PartialEq)]
+struct Name(String);
+
+struct Config {
+    name: Name,
+    port: u16,
+}
+
+impl AsRef<Name> for Config {
+    fn as_ref(&self) -> &Name {
+        let Self { name, .. } = self;
+        name
+    }
+}
+
+impl AsRef<u16> for Config {
+    fn as_ref(&self) -> &u16 {
+        let Self { port, .. } = self;
+        port
+    }
+}
+
+impl AsMut<u16> for Config {
+    fn as_mut(&mut self) -> &mut u16 {
+        let Self { port, .. } = self;
+        port
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct EmptyName;
+
+fn nonempty(Name(name): &&Name) -> Result<(), EmptyName> {
+    if name.is_empty() {
+        Err(EmptyName)
+    } else {
+        Ok(())
+    }
+}
+
+#[test]
+fn by_value() {
+    let validate =
+        ValidateDependency::new(|port: &u16| if *port == 0 { Err(()) } else { Ok(()) }, ());
+
+    let result: Result<(u16, _), _> = 8080_u16.try_provide_with(validate);
+    assert!(matches!(result, Ok((8080, ()))));
+
+    let result: Result<(u16, _), ValidateError<(), Infallible>> = 0_u16.try_provide_with(validate);
+    assert!(matches!(result, Err(ValidateError::Invalid(()))));
+    let error = result.err().unwrap();
+    assert_eq!(error.to_string(), "dependency is invalid");
+}
+
+#[test]
+fn by_ref() {
+    let mut provider = Config {
+        name: Name(String::new()),
+        port: 8080,
+    };
+
+    let name: Result<&Name, _> = provider.try_provide_ref_with(().then_validate(nonempty));
+    assert_eq!(name, Err(ValidateError::Invalid(EmptyName)));
+
+    provider.name = Name(String::from("server"));
+    let name: Result<&Name, _> = provider.try_provide_ref_with(().then_validate(nonempty));
+    assert_eq!(name, Ok(&Name(String::from("server"))));
+
+    let port: Result<&mut u16, _> = provider.try_provide_mut_with(
+        ().then_validate(|port: &&mut u16| if **port == 0 { Err(()) } else { Ok(()) }),
+    );
+    *port.unwrap() = 0;
+    assert_eq!(provider.port, 0);
+}
+
+#[test]
+fn not_provided() {
+    let provider = Failpoint::new(Config {
+        name: Name(String::from("server")),
+        port: 8080,
+    });
+
+    let name: Result<&Name, _> =
+        provider.try_provide_ref_with(().then_fail_after(0).then_validate(nonempty));
+    assert_eq!(name, Err(ValidateError::Provide(FailpointError::Injected)));
+}
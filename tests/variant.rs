@@ -0,0 +1,66 @@
+use provide::{
+    context::{type_name::TypeName, Context},
+    variant::{Variants, WrongVariant},
+    variants,
+    with::{TryProvideMutWith, TryProvideRefWith},
+    TryProvide, TryProvideMut,
+};
+
+#[derive(Debug, PartialEq)]
+struct Config(&'static str);
+
+#[derive(Debug, PartialEq)]
+struct Connection(u32);
+
+variants! {
+    #[derive(Debug, PartialEq)]
+    enum State {
+        Idle(Config),
+        /// Client is connected.
+        Connected(Connection),
+    }
+}
+
+#[test]
+fn variants() {
+    assert_eq!(State::VARIANTS, ["Idle", "Connected"]);
+    assert_eq!(State::Idle(Config("localhost")).variant(), "Idle");
+    assert_eq!(State::Connected(Connection(1)).variant(), "Connected");
+}
+
+#[test]
+fn by_value() {
+    let result: Result<(Config, _), _> = State::Idle(Config("localhost")).try_provide();
+    assert_eq!(result, Ok((Config("localhost"), ())));
+
+    let result: Result<(Connection, _), _> = State::Idle(Config("localhost")).try_provide();
+    let error = result.unwrap_err();
+    assert_eq!(error.expected(), "Connected");
+    assert_eq!(error.actual(), "Idle");
+    assert_eq!(error.into_provider(), State::Idle(Config("localhost")));
+}
+
+#[test]
+fn by_ref() {
+    let state = State::Connected(Connection(1));
+
+    let result: Result<&Connection, _> = state.try_provide_ref_with(());
+    assert_eq!(result, Ok(&Connection(1)));
+
+    let result: Result<&Config, _> = state.try_provide_ref_with(TypeName::default());
+    let error = result.unwrap_err();
+    assert!(error.type_name().ends_with("Config"));
+    assert_eq!(error.error(), &WrongVariant::new("Idle", &state));
+}
+
+#[test]
+fn by_mut() {
+    let mut state = State::Connected(Connection(1));
+
+    let result: Result<&mut Connection, _> = state.try_provide_mut();
+    result.unwrap().0 = 2;
+
+    let result: Result<&mut Config, _> = state.try_provide_mut_with(().then_type_name());
+    assert!(result.is_err());
+    assert_eq!(state, State::Connected(Connection(2)));
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "alloc")]
+
+use std::{
+    rc::{self, Rc},
+    sync::{Arc, Weak},
+};
+
+use provide::{
+    context::{
+        weak::{Dangling, Upgrade},
+        Context,
+    },
+    with::{TryProvideMutWith, TryProvideRefWith, TryProvideWith},
+};
+
+struct Provider {
+    config: Weak<str>,
+    cache: rc::Weak<i32>,
+}
+
+impl AsRef<Weak<str>> for Provider {
+    fn as_ref(&self) -> &Weak<str> {
+        let Self { config, .. } = self;
+        config
+    }
+}
+
+impl AsMut<rc::Weak<i32>> for Provider {
+    fn as_mut(&mut self) -> &mut rc::Weak<i32> {
+        let Self { cache, .. } = self;
+        cache
+    }
+}
+
+#[test]
+fn upgrade() {
+    let config: Arc<str> = Arc::from("config");
+    let cache = Rc::new(1);
+    let mut provider = Provider {
+        config: Arc::downgrade(&config),
+        cache: Rc::downgrade(&cache),
+    };
+
+    let dependency: Result<Arc<str>, _> = provider.try_provide_ref_with(Upgrade::default());
+    assert_eq!(dependency, Ok(config.clone()));
+
+    let other: Result<Rc<i32>, _> = provider.try_provide_mut_with(().then_upgrade());
+    assert_eq!(other, Ok(cache.clone()));
+    drop(other);
+
+    drop(dependency);
+    drop((config, cache));
+
+    let dependency: Result<Arc<str>, _> = provider.try_provide_ref_with(().then_upgrade());
+    assert_eq!(dependency, Err(Dangling));
+
+    let dependency: Result<Rc<i32>, _> = provider.try_provide_mut_with(Upgrade::default());
+    assert_eq!(dependency, Err(Dangling));
+}
+
+#[test]
+fn owned() {
+    let strong = Rc::new(1);
+    let provider = Rc::downgrade(&strong);
+
+    let dependency: Result<(Rc<i32>, _), _> = provider.try_provide_with(Upgrade::default());
+    assert_eq!(dependency, Ok((strong, ())));
+}
@@ -0,0 +1,107 @@
+use provide::{
+    context::{
+        wrap::{OptionDependency, ResultDependency},
+        Context,
+    },
+    with::{ProvideMutWith, ProvideRefWith, ProvideWith, TryProvideRefWith},
+};
+
+#[test]
+fn option() {
+    let provider = 1;
+    let (dependency, ()): (Option<i64>, _) = provider.provide_with(OptionDependency::default());
+    assert_eq!(dependency, Some(1));
+
+    let mut provider = vec![1, 2, 3];
+    let dependency: Option<&mut [i32]> = provider.provide_mut_with(().then_some());
+    dependency.unwrap()[0] = 0;
+
+    let dependency: Option<&[i32]> = provider.provide_ref_with(OptionDependency::default());
+    assert_eq!(dependency, Some([0, 2, 3].as_slice()));
+
+    let dependency: Result<Option<Option<&[i32]>>, _> =
+        provider.try_provide_ref_with(().then_some().then_some());
+    assert_eq!(dependency, Ok(Some(Some([0, 2, 3].as_slice()))));
+}
+
+#[test]
+fn result() {
+    let provider = 1;
+    let (dependency, ()): (Result<i64, ()>, _) = provider.provide_with(ResultDependency::default());
+    assert_eq!(dependency, Ok(1));
+
+    let provider = String::from("hello");
+    let dependency: Result<Option<&str>, ()> = provider.provide_ref_with(().then_some().then_ok());
+    assert_eq!(dependency, Ok(Some("hello")));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn boxed() {
+    use provide::context::wrap::BoxDependency;
+
+    let provider = String::from("hello");
+    let dependency: Box<&str> = provider.provide_ref_with(BoxDependency::default());
+    assert_eq!(*dependency, "hello");
+
+    let provider = 1;
+    let (dependency, ()): (Box<Option<i32>>, _) = provider.provide_with(().then_some().then_box());
+    assert_eq!(*dependency, Some(1));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn shared() {
+    use std::{rc::Rc, sync::Arc};
+
+    let provider = String::from("hello");
+    let dependency: Rc<&str> = provider.provide_ref_with(().then_rc());
+    assert_eq!(*dependency, "hello");
+
+    let dependency: Arc<Box<&str>> = provider.provide_ref_with(().then_box().then_arc());
+    assert_eq!(**dependency, "hello");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn pinned() {
+    use core::{
+        future::{self, Ready},
+        pin::Pin,
+    };
+
+    use futures::executor::block_on;
+
+    let provider = future::ready(1);
+    let (future, ()): (Pin<Box<Ready<i32>>>, _) = provider.provide_with(().then_box_pin());
+    assert_eq!(block_on(future), 1);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn synchronized() {
+    use std::sync::{Arc, Mutex, RwLock};
+
+    let provider = vec![1, 2, 3];
+    let dependency: Arc<Mutex<&[i32]>> = provider.provide_ref_with(().then_mutex().then_arc());
+    assert_eq!(*dependency.lock().unwrap(), [1, 2, 3]);
+
+    let (dependency, ()): (RwLock<Vec<i32>>, _) = provider.provide_with(().then_rw_lock());
+    dependency.write().unwrap().push(4);
+    assert_eq!(*dependency.read().unwrap(), [1, 2, 3, 4]);
+}
+
+#[test]
+fn interior_mutability() {
+    use core::cell::{Cell, RefCell};
+
+    let provider = 1;
+    let (dependency, ()): (Cell<i64>, _) = provider.provide_with(().then_cell());
+    dependency.set(dependency.get() + 1);
+    assert_eq!(dependency.get(), 2);
+
+    let mut provider = vec![1, 2, 3];
+    let dependency: RefCell<&mut [i32]> = provider.provide_mut_with(().then_ref_cell());
+    dependency.borrow_mut()[0] = 0;
+    assert_eq!(provider, [0, 2, 3]);
+}
@@ -0,0 +1,167 @@
+use provide::{
+    context::{either::Either, failpoint::FailAfter, zip::ZipDependency, Context},
+    failpoint::{Failpoint, FailpointError},
+    with::{ProvideRefWith, ProvideWith, TryProvideRefWith, TryProvideWith},
+    Provide,
+};
+
+#[derive(Debug, PartialEq)]
+struct Name(&'static str);
+
+#[derive(Debug, PartialEq)]
+struct Port(u16);
+
+struct Provider {
+    name: Name,
+    port: Port,
+}
+
+struct Remainder {
+    port: Port,
+}
+
+impl Provide<Name> for Provider {
+    type Remainder = Remainder;
+
+    fn provide(self) -> (Name, Self::Remainder) {
+        let Self { name, port } = self;
+        (name, Remainder { port })
+    }
+}
+
+impl Provide<Port> for Remainder {
+    type Remainder = ();
+
+    fn provide(self) -> (Port, Self::Remainder) {
+        let Self { port } = self;
+        (port, ())
+    }
+}
+
+impl AsRef<Name> for Provider {
+    fn as_ref(&self) -> &Name {
+        let Self { name, .. } = self;
+        name
+    }
+}
+
+impl AsRef<Port> for Provider {
+    fn as_ref(&self) -> &Port {
+        let Self { port, .. } = self;
+        port
+    }
+}
+
+struct Registration {
+    name: Name,
+    port: Port,
+}
+
+impl Provide<Name> for Registration {
+    type Remainder = Failpoint<Port>;
+
+    fn provide(self) -> (Name, Self::Remainder) {
+        let Self { name, port } = self;
+        (name, Failpoint::new(port))
+    }
+}
+
+#[test]
+fn by_value() {
+    let provider = Provider {
+        name: Name("localhost"),
+        port: Port(8080),
+    };
+    let ((name, port), ()): ((Name, Port), _) = provider.provide_with(ZipDependency::new((), ()));
+    assert_eq!((name, port), (Name("localhost"), Port(8080)));
+
+    let provider = Provider {
+        name: Name("localhost"),
+        port: Port(8080),
+    };
+    let ((name, port), ()): ((Name, Option<Port>), _) =
+        provider.provide_with(().then_zip(().then_some()));
+    assert_eq!((name, port), (Name("localhost"), Some(Port(8080))));
+
+    let provider = Provider {
+        name: Name("localhost"),
+        port: Port(8080),
+    };
+    let ((name, port), ()): ((Option<Name>, Port), _) =
+        provider.provide_with(().then_some().then_zip(()));
+    assert_eq!((name, port), (Some(Name("localhost")), Port(8080)));
+}
+
+#[test]
+fn by_value_error() {
+    let provider = Failpoint::new(Registration {
+        name: Name("localhost"),
+        port: Port(8080),
+    });
+    let result: Result<((Name, Port), _), _> = provider.try_provide_with(
+        ().then_fail_after(usize::MAX)
+            .then_zip(FailAfter::new(usize::MAX, ())),
+    );
+    assert_eq!(result, Ok(((Name("localhost"), Port(8080)), ())));
+
+    let provider = Failpoint::new(Registration {
+        name: Name("localhost"),
+        port: Port(8080),
+    });
+    let result: Result<((Name, Port), _), _> = provider.try_provide_with(
+        ().then_fail_after(0)
+            .then_zip(FailAfter::new(usize::MAX, ())),
+    );
+    assert_eq!(result, Err(Either::Left(FailpointError::Injected)));
+
+    let provider = Failpoint::new(Registration {
+        name: Name("localhost"),
+        port: Port(8080),
+    });
+    let result: Result<((Name, Port), _), _> = provider.try_provide_with(
+        ().then_fail_after(usize::MAX)
+            .then_zip(FailAfter::new(0, ())),
+    );
+    assert_eq!(result, Err(Either::Right(FailpointError::Injected)));
+}
+
+#[test]
+fn by_ref() {
+    let provider = Provider {
+        name: Name("localhost"),
+        port: Port(8080),
+    };
+
+    let (name, port): (&Name, &Port) = provider.provide_ref_with(().then_zip(()));
+    assert_eq!((name, port), (&Name("localhost"), &Port(8080)));
+
+    let (port, name): (Option<&Port>, &Name) =
+        provider.provide_ref_with(().then_some().then_zip(()));
+    assert_eq!((port, name), (Some(&Port(8080)), &Name("localhost")));
+}
+
+#[test]
+fn by_ref_error() {
+    let provider = Failpoint::new(Provider {
+        name: Name("localhost"),
+        port: Port(8080),
+    });
+
+    let result: Result<(&Name, &Port), _> = provider.try_provide_ref_with(
+        ().then_fail_after(usize::MAX)
+            .then_zip(FailAfter::new(usize::MAX, ())),
+    );
+    assert_eq!(result, Ok((&Name("localhost"), &Port(8080))));
+
+    let result: Result<(&Name, &Port), _> = provider.try_provide_ref_with(
+        ().then_fail_after(usize::MAX)
+            .then_zip(FailAfter::new(0, ())),
+    );
+    assert_eq!(result, Err(Either::Right(FailpointError::Injected)));
+
+    let result: Result<(&Port, &Name), _> = provider.try_provide_ref_with(
+        ().then_fail_after(0)
+            .then_zip(FailAfter::new(usize::MAX, ())),
+    );
+    assert_eq!(result, Err(Either::Left(FailpointError::Injected)));
+}